@@ -0,0 +1,7 @@
+-- Migration: add a user-defined display order to categories
+
+ALTER TABLE categories ADD COLUMN display_order INTEGER NOT NULL DEFAULT 0;
+
+
+-- Cover listing categories in display order, tie-broken by code
+CREATE INDEX IF NOT EXISTS idx_categories_display_order ON categories(display_order, code);
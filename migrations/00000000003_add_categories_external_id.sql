@@ -0,0 +1,8 @@
+-- Migration: add an optional external reference to categories
+
+-- Identifier assigned by a third-party tool (bank import, YNAB migration)
+ALTER TABLE categories ADD COLUMN external_id TEXT;
+
+
+-- Each external id maps to at most one category; NULLs are not compared
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_external_id ON categories(external_id);
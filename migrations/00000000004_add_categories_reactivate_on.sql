@@ -0,0 +1,9 @@
+-- Migration: add an optional automatic reactivation date to categories
+
+-- When set on an inactive category, the reactivation sweep sets is_active
+-- back to TRUE once this moment has passed
+ALTER TABLE categories ADD COLUMN reactivate_on TEXT;
+
+
+-- The sweep only looks at categories with a pending reactivation
+CREATE INDEX IF NOT EXISTS idx_categories_reactivate_on ON categories(reactivate_on) WHERE reactivate_on IS NOT NULL;
@@ -0,0 +1,10 @@
+-- Migration: add an optional parent to categories, forming a hierarchy
+
+-- ID of the parent category, NULL for top-level categories. There is no
+-- foreign key: deleting a parent leaves its children pointing at a missing
+-- row, and the tree assembly treats those orphans as top-level categories
+ALTER TABLE categories ADD COLUMN parent_id UUID;
+
+
+-- Cover looking up the children of a category
+CREATE INDEX IF NOT EXISTS idx_categories_parent_id ON categories(parent_id);
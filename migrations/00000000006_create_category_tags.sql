@@ -0,0 +1,13 @@
+-- Migration: add free-form tags to categories
+
+-- One row per tag on a category. Deleting a category removes its tags
+CREATE TABLE IF NOT EXISTS category_tags (
+    category_id UUID NOT NULL REFERENCES categories(id) ON DELETE CASCADE,
+    tag TEXT NOT NULL,
+    created_on TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ','now')),
+    PRIMARY KEY (category_id, tag)
+);
+
+
+-- Cover finding the categories carrying a tag
+CREATE INDEX IF NOT EXISTS idx_category_tags_tag ON category_tags(tag);
@@ -0,0 +1,16 @@
+-- Migration: bump updated_on whenever an update fails to advance it
+
+-- The original trigger only caught updates that left updated_on untouched.
+-- Compare as julian days so a stale value written back (an older timestamp,
+-- or the same instant in another text format) is corrected too.
+DROP TRIGGER IF EXISTS trg_categories_set_updated_on;
+
+CREATE TRIGGER IF NOT EXISTS trg_categories_set_updated_on
+AFTER UPDATE ON categories
+FOR EACH ROW
+WHEN julianday(NEW.updated_on) IS NULL OR julianday(NEW.updated_on) <= julianday(OLD.updated_on)
+BEGIN
+    UPDATE categories
+    SET updated_on = (strftime('%Y-%m-%dT%H:%M:%fZ','now'))
+    WHERE rowid = NEW.rowid;
+END;
@@ -0,0 +1,9 @@
+-- Migration: add an optional soft-delete timestamp to categories
+
+-- When set, the category was soft deleted at this moment. Soft-deleted rows
+-- are hard deleted by the retention purge once they are old enough
+ALTER TABLE categories ADD COLUMN deleted_on TEXT;
+
+
+-- The purge only looks at soft-deleted categories
+CREATE INDEX IF NOT EXISTS idx_categories_deleted_on ON categories(deleted_on) WHERE deleted_on IS NOT NULL;
@@ -17,11 +17,32 @@ pub type ConfigResult<T> = std::result::Result<T, ConfigError>;
 pub enum ConfigError {
     /// Error from the underlying config crate during file loading or parsing.
     ///
-    /// This wraps errors from the `config` crate such as file not found,
-    /// invalid syntax, or parsing failures.
+    /// This wraps errors from the `config` crate such as a value that does
+    /// not fit its key. A missing or malformed config file is reported as
+    /// [`FileNotFound`](Self::FileNotFound) or [`Parse`](Self::Parse) instead.
     #[error("Configuration parsing error: {0}")]
     Parsing(#[from] ConfigLibError),
 
+    /// A config file that was asked for explicitly does not exist.
+    #[error("Configuration file not found: {}", .0.display())]
+    FileNotFound(std::path::PathBuf),
+
+    /// A config file whose syntax is invalid.
+    ///
+    /// `line` and `column` are 1-based, and are set when the file format
+    /// reports where parsing failed, as TOML and JSON do.
+    #[error("Failed to parse configuration file {}{}: {message}", path.display(), position_suffix(*line, *column))]
+    Parse {
+        /// The file that failed to parse.
+        path: std::path::PathBuf,
+        /// Line of the error, when known.
+        line: Option<u32>,
+        /// Column of the error, when known.
+        column: Option<u32>,
+        /// What the parser rejected.
+        message: String,
+    },
+
     /// Validation error with a descriptive message.
     ///
     /// This is used for configuration validation failures such as invalid
@@ -37,6 +58,15 @@ pub enum ConfigError {
     InvalidServerAddress(#[from] std::net::AddrParseError),
 }
 
+/// " at line L, column C" when the position of a parse error is known.
+fn position_suffix(line: Option<u32>, column: Option<u32>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!(" at line {}, column {}", line, column),
+        (Some(line), None) => format!(" at line {}", line),
+        _ => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +89,28 @@ mod tests {
         assert!(s.starts_with("Invalid server address:"));
     }
 
+    #[test]
+    fn file_not_found_and_parse_variants_format_as_expected() {
+        let err = ConfigError::FileNotFound(std::path::PathBuf::from("/etc/ledger.toml"));
+        assert_eq!(err.to_string(), "Configuration file not found: /etc/ledger.toml");
+
+        let err = ConfigError::Parse {
+            path: std::path::PathBuf::from("ledger.toml"),
+            line: Some(3),
+            column: Some(7),
+            message: "invalid string".into(),
+        };
+        assert_eq!(err.to_string(), "Failed to parse configuration file ledger.toml at line 3, column 7: invalid string");
+
+        let err = ConfigError::Parse {
+            path: std::path::PathBuf::from("ledger.conf"),
+            line: None,
+            column: None,
+            message: "bad section".into(),
+        };
+        assert_eq!(err.to_string(), "Failed to parse configuration file ledger.conf: bad section");
+    }
+
     #[test]
     fn parsing_variant_wraps_config_error() {
         // Create a temporary file with invalid JSON to provoke a parse error
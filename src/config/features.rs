@@ -0,0 +1,83 @@
+//! # Feature Registry
+//!
+//! This module gathers the on/off state of client-visible features, which is
+//! otherwise spread across [`ServerConfig`] fields, into one map clients can
+//! query with the `GetFeatures` Utilities RPC. The UI uses it to hide features
+//! a deployment does not serve, without coordinating redeploys.
+//!
+//! Each feature is derived from a config toggle:
+//!
+//! - [`FEATURE_STREAMING`] from `streaming_enabled`
+//! - [`FEATURE_TAGS`] from `tags_enabled`
+//! - [`FEATURE_HIERARCHY`] from `hierarchy_enabled`
+//! - [`FEATURE_EVENTS`] from `events_enabled`
+//! - [`FEATURE_TLS`] from `tls_enabled`
+//! - [`FEATURE_ADMIN_HTTP`] when `admin_port` is set
+
+use std::collections::BTreeMap;
+
+use super::ServerConfig;
+
+/// Streaming RPCs, such as the CSV export.
+pub const FEATURE_STREAMING: &str = "streaming";
+
+/// Free-form category tags.
+pub const FEATURE_TAGS: &str = "tags";
+
+/// Nested categories and the `CategoriesTree` RPC.
+pub const FEATURE_HIERARCHY: &str = "hierarchy";
+
+/// The `EventStream` live feed of changes.
+pub const FEATURE_EVENTS: &str = "events";
+
+/// TLS on the gRPC listener.
+pub const FEATURE_TLS: &str = "tls";
+
+/// The plain HTTP health and metrics endpoint.
+pub const FEATURE_ADMIN_HTTP: &str = "admin_http";
+
+/// Map every known feature name to whether `server_config` enables it.
+pub fn feature_flags(server_config: &ServerConfig) -> BTreeMap<&'static str, bool> {
+    BTreeMap::from([
+        (FEATURE_STREAMING, server_config.streaming_enabled),
+        (FEATURE_TAGS, server_config.tags_enabled),
+        (FEATURE_HIERARCHY, server_config.hierarchy_enabled),
+        (FEATURE_EVENTS, server_config.events_enabled),
+        (FEATURE_TLS, server_config.tls_enabled),
+        (FEATURE_ADMIN_HTTP, server_config.admin_port.is_some()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_features() {
+        let features = feature_flags(&ServerConfig::default());
+        assert_eq!(
+            features,
+            BTreeMap::from([
+                (FEATURE_ADMIN_HTTP, false),
+                (FEATURE_EVENTS, false),
+                (FEATURE_HIERARCHY, true),
+                (FEATURE_STREAMING, true),
+                (FEATURE_TAGS, true),
+                (FEATURE_TLS, false),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_features_follow_config_toggles() {
+        let config = ServerConfig {
+            tags_enabled: false,
+            admin_port: Some(50065),
+            ..ServerConfig::default()
+        };
+        let features = feature_flags(&config);
+        assert!(!features[FEATURE_TAGS]);
+        assert!(features[FEATURE_ADMIN_HTTP]);
+        assert!(features[FEATURE_STREAMING]);
+    }
+}
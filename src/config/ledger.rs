@@ -5,12 +5,13 @@
 //! optional config files, and environment variables.
 //!
 //! Configuration is merged from three sources (in increasing precedence):
-//! defaults, an optional INI file under `config/`, and environment variables
-//! prefixed with `LEDGER_BACKEND_`.
+//! defaults, an optional INI, TOML or JSON file under `config/`, and
+//! environment variables prefixed with `LEDGER_BACKEND_` or `LEDGER_`.
 //!
 //! The `ServerConfig` and `ConfigError` types are re-exported by the parent
 //! [`super::mod`] module for convenience.
 
+use config::builder::{ConfigBuilder, DefaultState};
 use config::{Config, Environment, File, FileFormat};
 
 /// Environment variable prefix used for overriding configuration values.
@@ -19,10 +20,32 @@ use config::{Config, Environment, File, FileFormat};
 /// For example, to override `server.address` set `LEDGER_BACKEND_SERVER_ADDRESS`.
 pub const ENV_SUFFIX: &str = "LEDGER_BACKEND";
 
+/// Environment variable prefix for overrides using nested key separators.
+///
+/// Environment variables are expected in the form `LEDGER_<SECTION>__<KEY>`,
+/// with [`ENV_SEPARATOR`] between nested keys so keys containing underscores
+/// can be addressed. For example, to override `server.port` set
+/// `LEDGER_SERVER__PORT`, and for `server.shutdown_grace_period_secs` set
+/// `LEDGER_SERVER__SHUTDOWN_GRACE_PERIOD_SECS`. These take precedence over
+/// the `LEDGER_BACKEND_` variables.
+pub const ENV_PREFIX: &str = "LEDGER";
+
+/// Separator between nested keys in `LEDGER_` environment variables.
+pub const ENV_SEPARATOR: &str = "__";
+
+/// Config file extensions [`LedgerConfig::parse`] looks for, in order.
+///
+/// `.conf` is INI, the format of the file shipped in the repository; `.toml`
+/// and `.json` suit teams that template their configuration with other tools.
+pub const CONFIG_FILE_EXTENSIONS: [&str; 3] = ["conf", "toml", "json"];
+
+/// Placeholder printed instead of a secret in [`LedgerConfig::redacted_summary`].
+pub const REDACTED: &str = "******";
+
 /// Top-level application configuration.
 ///
 /// `LedgerConfig` is deserialised from a combination of defaults, an optional
-/// INI configuration file and environment variables. The structure currently
+/// INI, TOML or JSON configuration file and environment variables. The structure currently
 /// contains the `server` section (network bind, TLS, and database settings).
 #[derive(Debug, Clone, serde::Deserialize, Default)]
 pub struct LedgerConfig {
@@ -37,16 +60,22 @@ impl LedgerConfig {
     /// override earlier ones):
     ///
     /// 1. Programmatic defaults (lowest priority)
-    /// 2. Optional INI file at `config/<CONFIG_FILE_NAME>.conf` (if present)
-    /// 3. Environment variables prefixed with `LEDGER_BACKEND` (highest priority)
+    /// 2. Optional file at `config/<CONFIG_FILE_NAME>.<ext>` (if present),
+    ///    trying each of [`CONFIG_FILE_EXTENSIONS`] in turn
+    /// 3. Environment variables prefixed with `LEDGER_BACKEND`
+    /// 4. Environment variables prefixed with `LEDGER` using `__` between
+    ///    nested keys, e.g. `LEDGER_SERVER__PORT` (highest priority)
     ///
     /// The config file is looked for in the current working directory under
-    /// a `config/` directory (for example `config/ledger-backend.conf`). If
-    /// the file is absent the loader will continue with defaults and any
+    /// a `config/` directory (for example `config/ledger-backend.conf`), and
+    /// its format follows its extension as in [`parse_file`](Self::parse_file).
+    /// If no file is present the loader will continue with defaults and any
     /// provided environment variables.
     ///
     /// Returns a `ConfigError` if there is a problem reading/parsing any of
-    /// the sources or deserialising into `LedgerConfig`.
+    /// the sources or deserialising into `LedgerConfig`, including an
+    /// environment variable whose value does not fit its key (for example a
+    /// non-numeric port).
     pub fn parse() -> super::ConfigResult<LedgerConfig> {
         // Get the directory that the binary is being run from
         let binary_path = std::env::current_dir()
@@ -55,40 +84,107 @@ impl LedgerConfig {
         // Set the configuration directory for the app
         let config_directory = binary_path.join("config");
 
-        // Set the configuration file name to be the package name with .conf extension
-        let config_filename = format!("{}.conf", super::server::CONFIG_FILE_NAME);
-
-        // Set the default config file path
-        let config_file_path = config_directory.join(config_filename);
+        // Use the first config file named after the package with a supported extension
+        let config_file_path = CONFIG_FILE_EXTENSIONS
+            .iter()
+            .map(|extension| config_directory.join(format!("{}.{}", super::server::CONFIG_FILE_NAME, extension)))
+            .find(|path| path.exists());
 
-        // Start with defaults (lowest priority)
-        let mut builder = Config::builder()
-            .set_default("server.address", super::server::DEFAULT_SERVER_ADDRESS)?
-            .set_default("server.port", super::server::DEFAULT_SERVER_PORT)?
-            .set_default("server.data_dir", super::server::DEFAULT_DATA_DIR)?
-            .set_default("server.tls_enabled", super::server::DEFAULT_TLS_ENABLED)?;
-
-        // If the config file exists, load it (overrides defaults). If not, warn and continue with defaults
-        if config_file_path.exists() {
-            // The repository contains `config/ledger-backend.conf` as an INI-style
-            // file. Explicitly load the file as INI so the parser accepts `.conf`.
-            builder = builder
-                .add_source(File::from(config_file_path).format(FileFormat::Ini));
-        } else {
+        if config_file_path.is_none() {
             tracing::warn!(
                 "Config file '{}' not found; using defaults and environment variables if set",
-                config_file_path.display()
+                config_directory.join(format!("{}.conf", super::server::CONFIG_FILE_NAME)).display()
             );
         }
 
-        // Finally add environment variables (highest priority)
+        Self::load(config_file_path.as_deref())
+    }
+
+    /// Load the application configuration from the file at `path`, in place
+    /// of the file [`parse`](Self::parse) looks for under `config/`.
+    ///
+    /// The format follows the extension: `.conf` or `.ini` for INI, `.toml`
+    /// for TOML and `.json` for JSON. All three deserialise into the same
+    /// structs, and defaults and environment variables apply as for `parse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`](super::ConfigError::Validation) if
+    /// the extension is not one of the above,
+    /// [`ConfigError::FileNotFound`](super::ConfigError::FileNotFound) if the
+    /// file is missing, and [`ConfigError::Parse`](super::ConfigError::Parse),
+    /// with the line and column for TOML and JSON, if its syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::LedgerConfig;
+    ///
+    /// let config = LedgerConfig::parse_file("deploy/ledger-backend.json")?;
+    /// # Ok::<(), personal_ledger_backend::config::ConfigError>(())
+    /// ```
+    pub fn parse_file(path: impl AsRef<std::path::Path>) -> super::ConfigResult<LedgerConfig> {
+        Self::load(Some(path.as_ref()))
+    }
+
+    /// A one-line summary of the main settings that is safe to log.
+    ///
+    /// Covers the bind address, database engine and location, and TLS. The
+    /// `auth_token` and `page_token_secret` secrets are shown as
+    /// [`REDACTED`] when set, so their values never reach the output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::LedgerConfig;
+    ///
+    /// let summary = LedgerConfig::default().redacted_summary();
+    /// assert!(summary.contains("engine=sqlite"));
+    /// ```
+    pub fn redacted_summary(&self) -> String {
+        let server = &self.server;
+        let database = server.database_url().unwrap_or_else(|e| format!("<{}>", e));
+        let secret = |is_set: bool| if is_set { REDACTED } else { "unset" };
+
+        format!(
+            "address={} port={} engine=sqlite database={} tls={} auth_token={} page_token_secret={}",
+            server.address,
+            server.port,
+            database,
+            if server.tls_enabled { "on" } else { "off" },
+            secret(server.auth_token.is_some()),
+            secret(server.page_token_secret.is_some()),
+        )
+    }
+
+    /// Merge the defaults, the optional config file and environment variables.
+    fn load(config_file_path: Option<&std::path::Path>) -> super::ConfigResult<LedgerConfig> {
+        // Start with defaults (lowest priority)
+        let mut builder = with_defaults(Config::builder())?;
+
+        // Load the config file, if any (overrides defaults)
+        if let Some(config_file_path) = config_file_path {
+            builder = builder.add_source(File::from(config_file_path).format(file_format(config_file_path)?));
+        }
+
+        // Then add environment variables
         builder = builder.add_source(
             Environment::with_prefix(ENV_SUFFIX)
                 .prefix_separator("_")
                 .separator("_"),
         );
 
-        let config = builder.build()?;
+        // Finally add environment variables with nested key separators (highest priority)
+        builder = builder.add_source(
+            Environment::with_prefix(ENV_PREFIX)
+                .prefix_separator("_")
+                .separator(ENV_SEPARATOR),
+        );
+
+        let config = builder.build().map_err(|e| match config_file_path {
+            Some(config_file_path) => file_error(e, config_file_path),
+            None => e.into(),
+        })?;
 
         // Deserialize the generic `config::Config` into our `LedgerConfig` domain type.
         let ledger_config: LedgerConfig = config.try_deserialize()?;
@@ -108,6 +204,95 @@ impl LedgerConfig {
     }
 }
 
+/// Seed `builder` with the default value of every server setting, so a
+/// configuration without a file or environment overrides still deserializes.
+fn with_defaults(builder: ConfigBuilder<DefaultState>) -> super::ConfigResult<ConfigBuilder<DefaultState>> {
+    Ok(builder
+        .set_default("server.address", super::server::DEFAULT_SERVER_ADDRESS)?
+        .set_default("server.port", super::server::DEFAULT_SERVER_PORT)?
+        .set_default("server.data_dir", super::server::DEFAULT_DATA_DIR)?
+        .set_default("server.tls_enabled", super::server::DEFAULT_TLS_ENABLED)?
+        .set_default("server.require_tls", super::server::DEFAULT_REQUIRE_TLS)?
+        .set_default("server.require_migrated", super::server::DEFAULT_REQUIRE_MIGRATED)?
+        .set_default("server.startup_self_test", super::server::DEFAULT_STARTUP_SELF_TEST)?
+        .set_default("server.shutdown_grace_period_secs", super::server::DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS)?
+        .set_default("server.max_concurrent_streams", super::server::DEFAULT_MAX_CONCURRENT_STREAMS as u64)?
+        .set_default("server.reflection_optional", super::server::DEFAULT_REFLECTION_OPTIONAL)?
+        .set_default("server.streaming_enabled", super::server::DEFAULT_STREAMING_ENABLED)?
+        .set_default("server.tags_enabled", super::server::DEFAULT_TAGS_ENABLED)?
+        .set_default("server.hierarchy_enabled", super::server::DEFAULT_HIERARCHY_ENABLED)?
+        .set_default("server.events_enabled", super::server::DEFAULT_EVENTS_ENABLED)?
+        .set_default("server.accept_client_ids", super::server::DEFAULT_ACCEPT_CLIENT_IDS)?
+        .set_default("server.enable_json_api", super::server::DEFAULT_ENABLE_JSON_API)?
+        .set_default("server.category_cache_size", super::server::DEFAULT_CATEGORY_CACHE_SIZE as u64)?
+        .set_default("server.max_categories", super::server::DEFAULT_MAX_CATEGORIES as u64)?
+        .set_default("server.strict_enums", super::server::DEFAULT_STRICT_ENUMS)?
+        .set_default("server.max_requests_per_connection", super::server::DEFAULT_MAX_REQUESTS_PER_CONNECTION)?
+        .set_default("server.max_metadata_entries", super::server::DEFAULT_MAX_METADATA_ENTRIES as u64)?
+        .set_default("server.max_metadata_bytes", super::server::DEFAULT_MAX_METADATA_BYTES as u64)?
+        .set_default("server.batch_max_duration_ms", super::server::DEFAULT_BATCH_MAX_DURATION_MS)?
+        .set_default("server.batch_progress_interval", super::server::DEFAULT_BATCH_PROGRESS_INTERVAL as u64)?)
+}
+
+/// Map a `config` crate error about the config file at `path` to
+/// [`ConfigError::FileNotFound`](super::ConfigError::FileNotFound) or
+/// [`ConfigError::Parse`](super::ConfigError::Parse), with the position of a
+/// TOML or JSON syntax error. Other errors are wrapped unchanged.
+fn file_error(error: config::ConfigError, path: &std::path::Path) -> super::ConfigError {
+    match error {
+        config::ConfigError::Foreign(cause)
+            if cause.downcast_ref::<std::io::Error>().is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound) =>
+        {
+            super::ConfigError::FileNotFound(path.to_path_buf())
+        }
+        config::ConfigError::FileParse { cause, .. } => {
+            let (line, column, message) = if let Some(e) = cause.downcast_ref::<toml::de::Error>() {
+                let (line, column) = e
+                    .span()
+                    .and_then(|span| Some(line_and_column(&std::fs::read_to_string(path).ok()?, span.start)))
+                    .unzip();
+                (line, column, e.message().to_string())
+            } else if let Some(e) = cause.downcast_ref::<serde_json::Error>() {
+                // serde_json's message already ends with the position
+                let message = e.to_string();
+                let suffix = format!(" at line {} column {}", e.line(), e.column());
+                let message = message.strip_suffix(&suffix).map_or(message.clone(), str::to_string);
+                let (line, column) = (u32::try_from(e.line()).ok(), u32::try_from(e.column()).ok());
+                (line, column, message)
+            } else {
+                (None, None, cause.to_string())
+            };
+            super::ConfigError::Parse { path: path.to_path_buf(), line, column, message }
+        }
+        other => other.into(),
+    }
+}
+
+/// The 1-based line and column of the byte `offset` in `text`.
+fn line_and_column(text: &str, offset: usize) -> (u32, u32) {
+    let before = text.get(..offset).unwrap_or(text);
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (u32::try_from(line).unwrap_or(u32::MAX), u32::try_from(column).unwrap_or(u32::MAX))
+}
+
+/// The config file format for `path`, from its extension.
+///
+/// `.conf` is not a format the `config` crate recognises, so the format is
+/// always set explicitly rather than left to the crate to guess.
+fn file_format(path: &std::path::Path) -> super::ConfigResult<FileFormat> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("conf" | "ini") => Ok(FileFormat::Ini),
+        Some("toml") => Ok(FileFormat::Toml),
+        Some("json") => Ok(FileFormat::Json),
+        _ => Err(super::ConfigError::Validation(format!(
+            "Unsupported config file '{}', expected a .conf, .ini, .toml or .json extension",
+            path.display()
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,19 +301,8 @@ mod tests {
     fn environment_variable_overrides_take_precedence() {
         // Use temp_env::with_var to set env var for the closure duration
         temp_env::with_var("LEDGER_BACKEND_SERVER_ADDRESS", Some("0.0.0.0"), || {
-            // Use the public LedgerConfig defaults so this test only depends on the
-            // LedgerConfig public API rather than internal server constants.
-            let defaults = LedgerConfig::default();
-
-            let cfglib = ConfigLib::builder()
-                // Provide the same defaults the real loader uses so deserialization succeeds
-                .set_default("server.address", defaults.server.address.clone())
-                .unwrap()
-                .set_default("server.port", defaults.server.port)
-                .unwrap()
-                .set_default("server.data_dir", defaults.server.data_dir.as_ref().map(|p| p.to_string_lossy().to_string()))
-                .unwrap()
-                .set_default("server.tls_enabled", defaults.server.tls_enabled)
+            // Seed the same defaults the real loader uses so deserialization succeeds
+            let cfglib = with_defaults(ConfigLib::builder())
                 .unwrap()
                 .add_source(
                     config::Environment::with_prefix(ENV_SUFFIX)
@@ -145,4 +319,177 @@ mod tests {
         });
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn nested_environment_variables_override_file_values() {
+        temp_env::with_vars(
+            [
+                ("LEDGER_SERVER__PORT", Some("9090")),
+                ("LEDGER_SERVER__SHUTDOWN_GRACE_PERIOD_SECS", Some("42")),
+                ("LEDGER_SERVER__TLS_ENABLED", Some("true")),
+            ],
+            || {
+                let ledger_cfg = LedgerConfig::parse().expect("should parse with env overrides");
+
+                assert_eq!(ledger_cfg.server.port, 9090);
+                assert_eq!(ledger_cfg.server.shutdown_grace_period_secs, 42);
+                assert!(ledger_cfg.server.tls_enabled);
+            },
+        );
+    }
+
+    #[test]
+    fn nested_environment_variables_win_over_prefixed_variables() {
+        temp_env::with_vars(
+            [
+                ("LEDGER_BACKEND_SERVER_PORT", Some("9191")),
+                ("LEDGER_SERVER__PORT", Some("9090")),
+            ],
+            || {
+                let ledger_cfg = LedgerConfig::parse().expect("should parse with env overrides");
+
+                assert_eq!(ledger_cfg.server.port, 9090);
+            },
+        );
+    }
+
+    /// The same settings, including secrets, as INI, TOML and JSON.
+    const INI_FIXTURE: &str = "[server]\naddress = 0.0.0.0\nport = 9000\nrequire_tls = true\nmax_categories = 250\nrequest_timeout_secs = 15\ndatabase_path = /var/lib/ledger/ledger.db\nauth_token = s3cr3t-token\npage_token_secret = page-secret\n";
+    const TOML_FIXTURE: &str = "[server]\naddress = \"0.0.0.0\"\nport = 9000\nrequire_tls = true\nmax_categories = 250\nrequest_timeout_secs = 15\ndatabase_path = \"/var/lib/ledger/ledger.db\"\nauth_token = \"s3cr3t-token\"\npage_token_secret = \"page-secret\"\n";
+    const JSON_FIXTURE: &str = r#"{
+        "server": {
+            "address": "0.0.0.0",
+            "port": 9000,
+            "require_tls": true,
+            "max_categories": 250,
+            "request_timeout_secs": 15,
+            "database_path": "/var/lib/ledger/ledger.db",
+            "auth_token": "s3cr3t-token",
+            "page_token_secret": "page-secret"
+        }
+    }"#;
+
+    /// Write `contents` to a uniquely named file with `extension` in the temp directory.
+    fn write_fixture(extension: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("ledger-config-{}.{}", uuid::Uuid::now_v7(), extension));
+        std::fs::write(&path, contents).expect("write config fixture");
+        path
+    }
+
+    /// Parse a fixture, holding the environment lock so other tests' variables do not leak in.
+    fn parse_fixture(extension: &str, contents: &str) -> crate::config::ConfigResult<LedgerConfig> {
+        let path = write_fixture(extension, contents);
+        let result = temp_env::with_vars_unset(["LEDGER_SERVER__PORT", "LEDGER_BACKEND_SERVER_PORT"], || {
+            LedgerConfig::parse_file(&path)
+        });
+        std::fs::remove_file(&path).ok();
+        result
+    }
+
+    #[test]
+    fn ini_toml_and_json_files_parse_to_the_same_config() {
+        use secrecy::ExposeSecret;
+
+        let configs = [("conf", INI_FIXTURE), ("toml", TOML_FIXTURE), ("json", JSON_FIXTURE)]
+            .map(|(extension, contents)| parse_fixture(extension, contents).expect(extension));
+
+        for config in &configs {
+            let server = &config.server;
+            assert_eq!(server.address, "0.0.0.0");
+            assert_eq!(server.port, 9000);
+            assert!(server.require_tls);
+            assert_eq!(server.max_categories, 250);
+            assert_eq!(server.request_timeout_secs, Some(15));
+            assert_eq!(server.database_path, Some(std::path::PathBuf::from("/var/lib/ledger/ledger.db")));
+            assert_eq!(server.auth_token.as_ref().map(|token| token.expose_secret()), Some("s3cr3t-token"));
+            assert_eq!(server.page_token_secret.as_ref().map(|secret| secret.expose_secret()), Some("page-secret"));
+            // Values the files leave out still come from the defaults
+            assert_eq!(server.shutdown_grace_period_secs, crate::config::server::DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS);
+        }
+        assert_eq!(format!("{:?}", configs[0]), format!("{:?}", configs[1]));
+        assert_eq!(format!("{:?}", configs[0]), format!("{:?}", configs[2]));
+    }
+
+    #[test]
+    fn unknown_config_file_extension_is_a_validation_error() {
+        let result = parse_fixture("yaml", "server:\n  port: 9000\n");
+
+        match result {
+            Err(crate::config::ConfigError::Validation(message)) => {
+                assert!(message.contains("Unsupported config file"), "{}", message);
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn invalid_environment_variable_is_a_config_error() {
+        temp_env::with_var("LEDGER_SERVER__PORT", Some("not-a-port"), || {
+            let result = LedgerConfig::parse();
+
+            assert!(matches!(result, Err(super::super::ConfigError::Parsing(_))), "{:?}", result);
+        });
+    }
+
+    #[test]
+    fn redacted_summary_never_contains_secrets() {
+        let mut config = LedgerConfig::default();
+        config.server.address = "ledger.example.com".to_string();
+        config.server.auth_token = Some(secrecy::SecretString::from("hunter2-auth"));
+        config.server.page_token_secret = Some(secrecy::SecretString::from("hunter2-page"));
+
+        let summary = config.redacted_summary();
+        assert!(summary.contains("address=ledger.example.com"), "{}", summary);
+        assert!(summary.contains(&format!("auth_token={}", REDACTED)), "{}", summary);
+        assert!(summary.contains(&format!("page_token_secret={}", REDACTED)), "{}", summary);
+        assert!(!summary.contains("hunter2"), "{}", summary);
+        assert!(!summary.contains('\n'));
+
+        let summary = LedgerConfig::default().redacted_summary();
+        assert!(summary.contains("auth_token=unset"), "{}", summary);
+    }
+
+    #[test]
+    fn missing_config_file_is_file_not_found() {
+        let path = std::env::temp_dir().join(format!("ledger-config-{}.json", uuid::Uuid::now_v7()));
+
+        match LedgerConfig::parse_file(&path) {
+            Err(crate::config::ConfigError::FileNotFound(missing)) => assert_eq!(missing, path),
+            other => panic!("expected FileNotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_toml_is_a_parse_error_with_position() {
+        let result = parse_fixture("toml", "[server]\naddress = \"0.0.0.0\"\nport = = 9000\n");
+
+        match result {
+            Err(crate::config::ConfigError::Parse { line, column, message, .. }) => {
+                assert_eq!(line, Some(3));
+                assert_eq!(column, Some(8));
+                assert!(!message.is_empty());
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_json_is_a_parse_error_with_position() {
+        let result = parse_fixture("json", "{\n  \"server\": {\n    \"port\": 9000,\n  }\n}\n");
+
+        match result {
+            Err(crate::config::ConfigError::Parse { line, column, message, .. }) => {
+                assert_eq!(line, Some(4));
+                assert!(column.is_some());
+                assert!(!message.contains(" at line "), "{}", message);
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn line_and_column_counts_from_one() {
+        assert_eq!(line_and_column("abc", 0), (1, 1));
+        assert_eq!(line_and_column("abc\ndef", 5), (2, 2));
+        assert_eq!(line_and_column("abc\n", 4), (2, 1));
+    }
+}
@@ -8,6 +8,7 @@
 //! ## Structure
 //!
 //! - [`error`] - Configuration error types
+//! - [`features`] - Client-visible feature flags derived from the server config
 //! - [`ledger`] - Top-level application configuration
 //! - [`server`] - Server networking, TLS, and database path configuration
 //!
@@ -22,9 +23,16 @@ mod error;
 pub use error::{ConfigResult, ConfigError};
 
 mod ledger;
-/// The top-level application configuration type.
-pub use ledger::LedgerConfig;
+/// The top-level application configuration type, and the placeholder its
+/// redacted summary prints for secrets.
+pub use ledger::{LedgerConfig, REDACTED};
 
 mod server;
 /// Server-specific configuration values and defaults.
 pub use server::ServerConfig;
+
+mod features;
+/// Feature flags derived from the server configuration.
+pub use features::{
+    feature_flags, FEATURE_ADMIN_HTTP, FEATURE_EVENTS, FEATURE_HIERARCHY, FEATURE_STREAMING, FEATURE_TAGS, FEATURE_TLS,
+};
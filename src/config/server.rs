@@ -60,6 +60,13 @@ pub const DEFAULT_TLS_CERT_PATH: Option<&str> = None;
 /// readable by the server process with appropriate file permissions.
 pub const DEFAULT_TLS_KEY_PATH: Option<&str> = None;
 
+/// Default for whether every connection must use TLS.
+///
+/// Defaults to `false`. When set, startup fails unless TLS is enabled with a
+/// certificate and key, and requests arriving over a plaintext connection are
+/// rejected with `PermissionDenied`.
+pub const DEFAULT_REQUIRE_TLS: bool = false;
+
 /// Default data directory for storing application files.
 ///
 /// Defaults to `"data/"` in the current working directory. This directory is used
@@ -74,6 +81,125 @@ pub const DEFAULT_DATA_DIR: Option<&str> = Some("data/");
 /// databases, set this to `":memory:"` in the configuration.
 pub const DEFAULT_DATABASE_PATH: Option<&str> = Some("personal_ledger.db");
 
+/// Default for whether startup requires the database to be fully migrated.
+///
+/// Defaults to `false`, so pending migrations are applied automatically at
+/// startup. Production deployments that run migrations as an explicit deploy
+/// step should set this to `true` so the server refuses to start on a stale schema.
+pub const DEFAULT_REQUIRE_MIGRATED: bool = false;
+
+/// Default for whether startup runs a database self-test.
+///
+/// Defaults to `false`. When `true`, startup inserts, reads back and deletes a
+/// temporary category before accepting traffic, and fails if any step errors.
+pub const DEFAULT_STARTUP_SELF_TEST: bool = false;
+
+/// Default shutdown grace period in seconds.
+///
+/// Defaults to `5`. On shutdown the server keeps serving for this long after
+/// the health service reports `NOT_SERVING`, so load balancers can stop routing
+/// to it before connections are refused and in-flight requests are drained.
+pub const DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS: u64 = 5;
+
+/// Default cap on concurrent streaming RPCs across all clients.
+///
+/// Defaults to `64`. Streaming responses (such as the CSV export) are long-lived,
+/// so a handful of clients could otherwise hold open enough streams to exhaust
+/// server resources. Streams past the cap are rejected with `ResourceExhausted`.
+/// Set to `0` to disable the cap.
+pub const DEFAULT_MAX_CONCURRENT_STREAMS: usize = 64;
+
+/// Default for whether the server may start without gRPC reflection.
+///
+/// Defaults to `false`, so a reflection descriptor set that fails to build
+/// aborts startup. Reflection is only a debugging aid, so deployments that
+/// prefer availability can set this to `true` to log a warning and start
+/// without it.
+pub const DEFAULT_REFLECTION_OPTIONAL: bool = false;
+
+/// Default for whether streaming RPCs, such as the CSV export, are served.
+///
+/// Defaults to `true`. When disabled, streaming RPCs answer `Unimplemented`, for
+/// deployments behind proxies that cannot carry long-lived streams.
+pub const DEFAULT_STREAMING_ENABLED: bool = true;
+
+/// Default for whether category tags are served.
+///
+/// Defaults to `true`. When disabled, filtering the category list by tag
+/// answers `Unimplemented`.
+pub const DEFAULT_TAGS_ENABLED: bool = true;
+
+/// Default for whether the category hierarchy is served.
+///
+/// Defaults to `true`. When disabled, `CategoriesTree` answers `Unimplemented`.
+pub const DEFAULT_HIERARCHY_ENABLED: bool = true;
+
+/// Default for whether the live event feed is served.
+///
+/// Defaults to `false`, since the feed is a debugging aid that shows every
+/// change to any client. When disabled, `EventStream` answers `Unimplemented`.
+pub const DEFAULT_EVENTS_ENABLED: bool = false;
+
+/// Default for whether create requests may supply the category id.
+///
+/// Defaults to `false`, so the server generates every id and ignores any id
+/// sent on create. Deployments whose clients create categories offline can
+/// set this to `true` to keep the client's UUID v7 id.
+pub const DEFAULT_ACCEPT_CLIENT_IDS: bool = false;
+
+/// Default for whether the admin server also serves the JSON API.
+///
+/// Defaults to `false`. When `true`, category CRUD is exposed as JSON over
+/// HTTP under `/api/categories` on `admin_port`, which must then be set.
+pub const DEFAULT_ENABLE_JSON_API: bool = false;
+
+/// Default number of categories kept in the in-memory lookup cache.
+///
+/// Defaults to `0`, which disables the cache so every read goes to the database.
+pub const DEFAULT_CATEGORY_CACHE_SIZE: usize = 0;
+
+/// Default for whether unknown proto enum values are rejected.
+///
+/// Defaults to `true`. When `false`, an unknown value is replaced by the enum's
+/// lenient default, e.g. `expense` for a category type, and a warning is logged.
+pub const DEFAULT_STRICT_ENUMS: bool = true;
+
+/// Default number of requests served on one connection before it is closed.
+///
+/// Defaults to `0`, for no limit.
+pub const DEFAULT_MAX_REQUESTS_PER_CONNECTION: u64 = 0;
+
+/// Default cap on the number of categories stored.
+///
+/// Defaults to `0`, for no cap.
+pub const DEFAULT_MAX_CATEGORIES: usize = 0;
+
+/// Default cap on the number of metadata entries in a request.
+///
+/// Defaults to `64`, well above the handful of headers gRPC clients send.
+/// Requests over the cap are rejected with `InvalidArgument` before reaching a
+/// handler. Set to `0` to disable the cap.
+pub const DEFAULT_MAX_METADATA_ENTRIES: usize = 64;
+
+/// Default cap on the total size of a request's metadata, in bytes.
+///
+/// Defaults to `8192` (8 KiB), counting every header name and value. Requests
+/// over the cap are rejected with `InvalidArgument` before reaching a handler.
+/// Set to `0` to disable the cap.
+pub const DEFAULT_MAX_METADATA_BYTES: usize = 8192;
+
+/// Default time budget for a batch write transaction, in milliseconds.
+///
+/// Defaults to `30000` (30 seconds). A batch insert still running after this
+/// long is aborted and rolled back, so it cannot hold the SQLite write lock
+/// indefinitely. Set to `0` to disable the budget.
+pub const DEFAULT_BATCH_MAX_DURATION_MS: u64 = 30_000;
+
+/// Default number of rows between batch write progress log lines.
+///
+/// Defaults to `500`. Set to `0` to disable progress logging.
+pub const DEFAULT_BATCH_PROGRESS_INTERVAL: usize = 500;
+
 
 #[derive(Debug, Clone, serde::Deserialize)]
 /// Server-specific configuration values for the Personal Ledger backend.
@@ -83,14 +209,45 @@ pub const DEFAULT_DATABASE_PATH: Option<&str> = Some("personal_ledger.db");
 /// deserialization from config files or environment sources.
 ///
 /// # Fields
-/// - `address`: The IP address to bind the server to (default: 127.0.0.1)
+/// - `address`: Comma separated IP addresses to bind the server to (default: 127.0.0.1)
 /// - `port`: The port number to bind the server on (default: 50059)
 /// - `data_dir`: Optional data directory for application files
 /// - `log_level`: Logging level for the application
 /// - `tls_enabled`: Whether TLS is enabled
 /// - `tls_cert_path`: Path to the TLS certificate file (PEM format)
 /// - `tls_key_path`: Path to the TLS private key file (PEM format)
+/// - `require_tls`: Refuse to start without TLS and reject plaintext requests (default: false)
 /// - `database_path`: Path to the SQLite database file (default: personal_ledger.db)
+/// - `require_migrated`: Refuse to start if migrations are pending (default: false)
+/// - `startup_self_test`: Check a create/read/delete round trip at startup (default: false)
+/// - `database_max_connections`: Most connections the database pool opens (default: 10)
+/// - `database_acquire_timeout_secs`: Seconds to wait for a free database connection (default: 30)
+/// - `shutdown_grace_period_secs`: Seconds to keep serving as not serving before draining on shutdown (default: 5)
+/// - `max_concurrent_streams`: Cap on concurrent streaming RPCs, `0` for no cap (default: 64)
+/// - `page_token_secret`: Secret used to sign list page tokens (default: random per process)
+/// - `admin_port`: Port for the plain HTTP health and metrics endpoint (default: disabled)
+/// - `reflection_optional`: Start without reflection if it fails to build (default: false)
+/// - `http2_initial_connection_window_size`: HTTP/2 connection flow control window in bytes (default: transport default)
+/// - `http2_initial_stream_window_size`: HTTP/2 per-stream flow control window in bytes (default: transport default)
+/// - `connection_idle_timeout_secs`: Close connections that stop answering keepalive pings (default: disabled)
+/// - `streaming_enabled`: Serve streaming RPCs such as the CSV export (default: true)
+/// - `tags_enabled`: Serve category tags (default: true)
+/// - `hierarchy_enabled`: Serve the category hierarchy (default: true)
+/// - `events_enabled`: Serve the live event feed for debugging (default: false)
+/// - `accept_client_ids`: Keep a client-supplied id on create (default: false)
+/// - `enable_json_api`: Serve category CRUD as JSON on the admin port (default: false)
+/// - `category_cache_size`: Categories cached for lookups by id, `0` to disable (default: 0)
+/// - `max_categories`: Cap on the number of categories stored, `0` for no cap (default: 0)
+/// - `strict_enums`: Reject unknown proto enum values instead of defaulting them (default: true)
+/// - `max_requests_per_connection`: Requests served before a connection is closed, `0` for no limit (default: 0)
+/// - `max_metadata_entries`: Cap on metadata entries per request, `0` for no cap (default: 64)
+/// - `max_metadata_bytes`: Cap on total metadata bytes per request, `0` for no cap (default: 8192)
+/// - `batch_max_duration_ms`: Time budget for a batch write, `0` for no budget (default: 30000)
+/// - `batch_progress_interval`: Rows between batch write progress logs, `0` to disable (default: 500)
+/// - `request_timeout_secs`: Time limit for answering a request (default: none)
+/// - `method_timeouts`: Per-method overrides of `request_timeout_secs` (default: none)
+/// - `display_timezone`: IANA timezone for localized timestamps in responses (default: none)
+/// - `auth_token`: Bearer token required on the application services (default: none)
 ///
 /// # Example
 ///
@@ -101,7 +258,9 @@ pub const DEFAULT_DATABASE_PATH: Option<&str> = Some("personal_ledger.db");
 /// assert_eq!(config.port, 50059);
 /// ```
 pub struct ServerConfig {
-    /// The IP address to bind the server to.
+    /// The IP address to bind the server to, or a comma separated list of
+    /// addresses (for example `"0.0.0.0, ::"`) to listen on several at once.
+    /// Every address shares the configured `port`.
     /// Defaults to "127.0.0.1".
     pub address: String,
 
@@ -133,9 +292,151 @@ pub struct ServerConfig {
     /// `tls_enabled` is true.
     pub tls_key_path: Option<PathBuf>,
 
+    /// When true, the server refuses to start unless TLS is enabled, and
+    /// rejects any request that did not arrive over TLS with `PermissionDenied`.
+    pub require_tls: bool,
+
     /// Path to the SQLite database file. If None, defaults to "personal_ledger.db" 
     /// in the current directory.
     pub database_path: Option<PathBuf>,
+
+    /// When true, startup checks the migration status and fails if any
+    /// migration is pending instead of applying it. When false, pending
+    /// migrations are applied automatically.
+    pub require_migrated: bool,
+
+    /// When true, startup inserts, reads back and deletes a temporary
+    /// category, and fails if any step errors, see
+    /// [`crate::database::run_self_test`].
+    pub startup_self_test: bool,
+
+    /// Most connections the database pool may open. `None` uses
+    /// [`crate::database::DEFAULT_MAX_CONNECTIONS`].
+    pub database_max_connections: Option<u32>,
+
+    /// Seconds a query waits for a free pooled connection before failing.
+    /// `None` uses [`crate::database::DEFAULT_ACQUIRE_TIMEOUT`].
+    pub database_acquire_timeout_secs: Option<u64>,
+
+    /// Number of seconds to keep serving, with the health service reporting
+    /// `NOT_SERVING`, after shutdown begins and before the server stops
+    /// accepting connections and drains in-flight requests.
+    pub shutdown_grace_period_secs: u64,
+
+    /// Maximum number of streaming RPCs served at once across all clients.
+    /// New streams past the cap are rejected with `ResourceExhausted`; unary
+    /// RPCs are not counted. `0` disables the cap.
+    ///
+    /// This is distinct from the HTTP/2 per-connection stream limit.
+    pub max_concurrent_streams: usize,
+
+    /// Secret key used to sign the opaque page tokens returned by list RPCs,
+    /// so clients cannot forge or probe them. When `None` a random key is
+    /// generated at startup, and page tokens stop working after a restart.
+    pub page_token_secret: Option<secrecy::SecretString>,
+
+    /// Port for the admin HTTP server exposing `/healthz`, `/readyz`,
+    /// `/metrics` and `/descriptor` on the primary bind address, so standard
+    /// tooling can probe and scrape the server without speaking gRPC. `None`
+    /// disables it.
+    pub admin_port: Option<u16>,
+
+    /// When true, a failure to build the gRPC reflection service is logged as
+    /// a warning and the server starts without reflection, instead of
+    /// failing startup.
+    pub reflection_optional: bool,
+
+    /// Initial HTTP/2 flow control window for a whole connection, in bytes.
+    /// `None` keeps the transport default.
+    pub http2_initial_connection_window_size: Option<u32>,
+
+    /// Initial HTTP/2 flow control window for each stream, in bytes.
+    /// `None` keeps the transport default.
+    pub http2_initial_stream_window_size: Option<u32>,
+
+    /// Seconds between HTTP/2 keepalive pings, and how long to wait for each
+    /// ping's acknowledgement before closing the connection. Idle peers that
+    /// have gone away, or half-open connections, are reaped this way. `None`
+    /// disables keepalive pings.
+    pub connection_idle_timeout_secs: Option<u64>,
+
+    /// Whether streaming RPCs are served. When false they answer `Unimplemented`.
+    pub streaming_enabled: bool,
+
+    /// Whether category tags are served. When false, filtering the category
+    /// list by tag answers `Unimplemented`.
+    pub tags_enabled: bool,
+
+    /// Whether the category hierarchy is served. When false, `CategoriesTree`
+    /// answers `Unimplemented`.
+    pub hierarchy_enabled: bool,
+
+    /// Whether the live event feed is served. When false, `EventStream`
+    /// answers `Unimplemented`.
+    pub events_enabled: bool,
+
+    /// Whether create requests may supply the category id. When true a
+    /// non-empty id must be a UUID v7 and is kept; when false it is ignored
+    /// and the server generates one.
+    pub accept_client_ids: bool,
+
+    /// Whether the admin server also serves category CRUD as JSON under
+    /// `/api/categories`. Requires `admin_port`.
+    pub enable_json_api: bool,
+
+    /// Number of categories cached in memory for lookups by id, evicting the
+    /// least recently used. `0` disables the cache.
+    pub category_cache_size: usize,
+
+    /// Maximum number of categories stored. Creates that would go past it
+    /// are rejected with `ResourceExhausted`. `0` disables the cap.
+    pub max_categories: usize,
+
+    /// Reject unknown proto enum values with `InvalidArgument`. When `false`
+    /// they are replaced by the enum's lenient default.
+    pub strict_enums: bool,
+
+    /// Maximum number of requests served on one connection. The request past
+    /// it is rejected with `Unavailable` and the connection is closed, so the
+    /// client reconnects. `0` disables the limit.
+    pub max_requests_per_connection: u64,
+
+    /// Maximum number of metadata entries a request may carry. Requests with
+    /// more are rejected with `InvalidArgument`. `0` disables the cap.
+    pub max_metadata_entries: usize,
+
+    /// Maximum total size in bytes of a request's metadata names and values.
+    /// Larger requests are rejected with `InvalidArgument`. `0` disables the cap.
+    pub max_metadata_bytes: usize,
+
+    /// Time budget in milliseconds for a batch write transaction. Batches still
+    /// running after it are rolled back. `0` disables the budget.
+    pub batch_max_duration_ms: u64,
+
+    /// Number of rows between progress log lines during a batch write. `0`
+    /// disables progress logging.
+    pub batch_progress_interval: usize,
+
+    /// Seconds a request may take before it fails with `DeadlineExceeded`.
+    /// `None` sets no limit. For streaming RPCs this bounds the time to the
+    /// first response, not the whole stream.
+    pub request_timeout_secs: Option<u64>,
+
+    /// Comma separated `/package.Service/Method=seconds` overrides of
+    /// `request_timeout_secs`, such as a longer limit for the CSV export.
+    pub method_timeouts: Option<String>,
+
+    /// IANA timezone name, such as `Australia/Sydney`, used to render
+    /// timestamps for display alongside their UTC value. `None` leaves
+    /// responses in UTC only.
+    pub display_timezone: Option<String>,
+
+    /// Static bearer token clients must send in the `authorization` metadata
+    /// of every Utilities and Categories request, and in the `Authorization`
+    /// header of every JSON API request. `None`, or a blank token, leaves the
+    /// services open. Health and reflection never require it. Redacted in
+    /// debug output.
+    pub auth_token: Option<secrecy::SecretString>,
 }
 
 impl Default for ServerConfig {
@@ -147,6 +448,7 @@ impl Default for ServerConfig {
     /// - Data directory at `data/`
     /// - Log level set to `WARN`
     /// - TLS disabled
+    /// - Pending migrations applied automatically
     ///
     /// # Examples
     ///
@@ -166,17 +468,47 @@ impl Default for ServerConfig {
             tls_enabled: DEFAULT_TLS_ENABLED,
             tls_cert_path: DEFAULT_TLS_CERT_PATH.map(PathBuf::from),
             tls_key_path: DEFAULT_TLS_KEY_PATH.map(PathBuf::from),
+            require_tls: DEFAULT_REQUIRE_TLS,
             database_path: DEFAULT_DATABASE_PATH.map(PathBuf::from),
+            require_migrated: DEFAULT_REQUIRE_MIGRATED,
+            startup_self_test: DEFAULT_STARTUP_SELF_TEST,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: DEFAULT_REFLECTION_OPTIONAL,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: DEFAULT_STREAMING_ENABLED,
+            tags_enabled: DEFAULT_TAGS_ENABLED,
+            hierarchy_enabled: DEFAULT_HIERARCHY_ENABLED,
+            events_enabled: DEFAULT_EVENTS_ENABLED,
+            accept_client_ids: DEFAULT_ACCEPT_CLIENT_IDS,
+            enable_json_api: DEFAULT_ENABLE_JSON_API,
+            category_cache_size: DEFAULT_CATEGORY_CACHE_SIZE,
+            max_categories: DEFAULT_MAX_CATEGORIES,
+            strict_enums: DEFAULT_STRICT_ENUMS,
+            max_requests_per_connection: DEFAULT_MAX_REQUESTS_PER_CONNECTION,
+            max_metadata_entries: DEFAULT_MAX_METADATA_ENTRIES,
+            max_metadata_bytes: DEFAULT_MAX_METADATA_BYTES,
+            batch_max_duration_ms: DEFAULT_BATCH_MAX_DURATION_MS,
+            batch_progress_interval: DEFAULT_BATCH_PROGRESS_INTERVAL,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         }
     }
 }
 
 impl ServerConfig {
-    /// Build and return the bind `SocketAddr` for the server.
+    /// Build and return the primary bind `SocketAddr` for the server.
     ///
-    /// Combines the configured `address` and `port` fields into a valid socket address
-    /// that can be used to bind the gRPC server. This validates that the address and
-    /// port form a valid network endpoint.
+    /// This is the first entry of [`ServerConfig::addresses`], so a single
+    /// configured address behaves exactly as before.
     ///
     /// # Returns
     ///
@@ -184,10 +516,8 @@ impl ServerConfig {
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The address is not a valid IP address or hostname
-    /// - The port is invalid (though `u16` type ensures this at compile time)
-    /// - The combined address:port string cannot be parsed as a `SocketAddr`
+    /// Returns an error if any configured address is not a valid IP address,
+    /// or if no address is configured.
     ///
     /// # Examples
     ///
@@ -198,9 +528,55 @@ impl ServerConfig {
     /// assert_eq!(addr.port(), 50059);
     /// ```
     pub fn address(&self) -> ConfigResult<core::net::SocketAddr> {
-        let address = format!("{}:{}", self.address, self.port);
-        let address = address.parse()?;
-        Ok(address)
+        let addresses = self.addresses()?;
+        Ok(addresses[0])
+    }
+
+    /// Build and return every bind `SocketAddr` for the server.
+    ///
+    /// The `address` field is split on commas and each entry is combined with
+    /// `port`. IPv6 addresses may be written bare (`::1`) or bracketed
+    /// (`[::1]`). Duplicate addresses are only returned once, in the order
+    /// they were first listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidServerAddress`] if an entry is not a valid
+    /// IP address, or [`ConfigError::Validation`] if no address is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use personal_ledger_backend::config::ServerConfig;
+    /// let config = ServerConfig {
+    ///     address: "0.0.0.0, ::".to_string(),
+    ///     ..ServerConfig::default()
+    /// };
+    /// let addresses = config.addresses().expect("both addresses should parse");
+    /// assert_eq!(addresses.len(), 2);
+    /// assert!(addresses[1].is_ipv6());
+    /// ```
+    pub fn addresses(&self) -> ConfigResult<Vec<core::net::SocketAddr>> {
+        let mut addresses = Vec::new();
+
+        for entry in self.address.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let ip = entry
+                .strip_prefix('[')
+                .and_then(|entry| entry.strip_suffix(']'))
+                .unwrap_or(entry)
+                .parse::<core::net::IpAddr>()?;
+            let address = core::net::SocketAddr::new(ip, self.port);
+
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+
+        if addresses.is_empty() {
+            return Err(ConfigError::Validation("server address cannot be empty".to_string()));
+        }
+
+        Ok(addresses)
     }
 
     /// Return the configured log level or the default.
@@ -220,6 +596,94 @@ impl ServerConfig {
         self.log_level.unwrap_or(DEFAULT_LOG_LEVEL)
     }
 
+    /// Return the shutdown grace period as a [`std::time::Duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use personal_ledger_backend::config::ServerConfig;
+    /// let config = ServerConfig::default();
+    /// assert_eq!(config.shutdown_grace_period().as_secs(), 5);
+    /// ```
+    pub fn shutdown_grace_period(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.shutdown_grace_period_secs)
+    }
+
+    /// Validate the TLS settings and return the certificate and key paths.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some((cert_path, key_path)))` when TLS is enabled, or
+    /// `Ok(None)` when it is disabled and not required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] if `require_tls` is set while TLS is
+    /// disabled, or if TLS is enabled without both a certificate and key path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use personal_ledger_backend::config::ServerConfig;
+    /// let mut config = ServerConfig::default();
+    /// assert!(config.tls_files().unwrap().is_none());
+    ///
+    /// config.require_tls = true;
+    /// assert!(config.tls_files().is_err());
+    /// ```
+    pub fn tls_files(&self) -> ConfigResult<Option<(&std::path::Path, &std::path::Path)>> {
+        if !self.tls_enabled {
+            if self.require_tls {
+                return Err(ConfigError::Validation(
+                    "require_tls is set but TLS is not enabled; set tls_enabled with a certificate and key".to_string(),
+                ));
+            }
+            return Ok(None);
+        }
+
+        match (self.tls_cert_path.as_deref(), self.tls_key_path.as_deref()) {
+            (Some(cert_path), Some(key_path)) => Ok(Some((cert_path, key_path))),
+            _ => Err(ConfigError::Validation(
+                "TLS is enabled but tls_cert_path and tls_key_path are not both set".to_string(),
+            )),
+        }
+    }
+
+    /// Parses the configured `display_timezone`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` when no display timezone is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] if `display_timezone` is not an IANA
+    /// timezone name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use personal_ledger_backend::config::ServerConfig;
+    /// let mut config = ServerConfig::default();
+    /// assert!(config.display_tz().unwrap().is_none());
+    ///
+    /// config.display_timezone = Some("Australia/Sydney".to_string());
+    /// assert_eq!(config.display_tz().unwrap(), Some(chrono_tz::Australia::Sydney));
+    ///
+    /// config.display_timezone = Some("Mars/Olympus_Mons".to_string());
+    /// assert!(config.display_tz().is_err());
+    /// ```
+    pub fn display_tz(&self) -> ConfigResult<Option<chrono_tz::Tz>> {
+        self.display_timezone
+            .as_deref()
+            .map(|name| {
+                name.trim().parse::<chrono_tz::Tz>().map_err(|_| {
+                    ConfigError::Validation(format!("display_timezone '{}' is not an IANA timezone name", name))
+                })
+            })
+            .transpose()
+    }
+
     /// Constructs a SQLite connection URL from the configured database path.
     ///
     /// Builds a SQLx-compatible SQLite connection string using the `database_path`
@@ -273,8 +737,39 @@ mod tests {
         assert_eq!(s.address, DEFAULT_SERVER_ADDRESS);
         assert_eq!(s.port, DEFAULT_SERVER_PORT);
         assert_eq!(s.tls_enabled, DEFAULT_TLS_ENABLED);
+        assert_eq!(s.require_tls, DEFAULT_REQUIRE_TLS);
+        assert_eq!(s.require_migrated, DEFAULT_REQUIRE_MIGRATED);
+        assert_eq!(s.startup_self_test, DEFAULT_STARTUP_SELF_TEST);
+        assert_eq!(s.database_max_connections, None);
+        assert_eq!(s.database_acquire_timeout_secs, None);
+        assert_eq!(s.shutdown_grace_period_secs, DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS);
+        assert_eq!(s.max_concurrent_streams, DEFAULT_MAX_CONCURRENT_STREAMS);
         assert!(s.tls_cert_path.is_none());
         assert!(s.tls_key_path.is_none());
+        assert!(s.page_token_secret.is_none());
+        assert!(s.admin_port.is_none());
+        assert_eq!(s.reflection_optional, DEFAULT_REFLECTION_OPTIONAL);
+        assert_eq!(s.http2_initial_connection_window_size, None);
+        assert_eq!(s.http2_initial_stream_window_size, None);
+        assert_eq!(s.connection_idle_timeout_secs, None);
+        assert_eq!(s.streaming_enabled, DEFAULT_STREAMING_ENABLED);
+        assert_eq!(s.tags_enabled, DEFAULT_TAGS_ENABLED);
+        assert_eq!(s.hierarchy_enabled, DEFAULT_HIERARCHY_ENABLED);
+        assert_eq!(s.events_enabled, DEFAULT_EVENTS_ENABLED);
+        assert_eq!(s.accept_client_ids, DEFAULT_ACCEPT_CLIENT_IDS);
+        assert_eq!(s.enable_json_api, DEFAULT_ENABLE_JSON_API);
+        assert_eq!(s.category_cache_size, DEFAULT_CATEGORY_CACHE_SIZE);
+        assert_eq!(s.max_categories, DEFAULT_MAX_CATEGORIES);
+        assert_eq!(s.strict_enums, DEFAULT_STRICT_ENUMS);
+        assert_eq!(s.max_requests_per_connection, DEFAULT_MAX_REQUESTS_PER_CONNECTION);
+        assert_eq!(s.max_metadata_entries, DEFAULT_MAX_METADATA_ENTRIES);
+        assert_eq!(s.max_metadata_bytes, DEFAULT_MAX_METADATA_BYTES);
+        assert_eq!(s.batch_max_duration_ms, DEFAULT_BATCH_MAX_DURATION_MS);
+        assert_eq!(s.batch_progress_interval, DEFAULT_BATCH_PROGRESS_INTERVAL);
+        assert_eq!(s.request_timeout_secs, None);
+        assert_eq!(s.method_timeouts, None);
+        assert_eq!(s.display_timezone, None);
+        assert!(s.auth_token.is_none());
         assert_eq!(s.database_path, Some(std::path::PathBuf::from("personal_ledger.db")));
     }
 
@@ -292,8 +787,39 @@ mod tests {
             tls_enabled: false,
             tls_cert_path: None,
             tls_key_path: None,
+            require_tls: false,
             data_dir: None,
             database_path: Some(std::path::PathBuf::from("test.db")),
+            require_migrated: false,
+            startup_self_test: false,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: false,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: true,
+            tags_enabled: true,
+            hierarchy_enabled: true,
+            events_enabled: false,
+            accept_client_ids: false,
+            enable_json_api: false,
+            category_cache_size: 0,
+            max_categories: 0,
+            strict_enums: true,
+            max_requests_per_connection: 0,
+            max_metadata_entries: 64,
+            max_metadata_bytes: 8192,
+            batch_max_duration_ms: 30_000,
+            batch_progress_interval: 500,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         };
 
         let addr = cfg.address().expect("address should parse");
@@ -313,8 +839,39 @@ mod tests {
             tls_enabled: false,
             tls_cert_path: None,
             tls_key_path: None,
+            require_tls: false,
             data_dir: None,
             database_path: None,
+            require_migrated: false,
+            startup_self_test: false,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: false,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: true,
+            tags_enabled: true,
+            hierarchy_enabled: true,
+            events_enabled: false,
+            accept_client_ids: false,
+            enable_json_api: false,
+            category_cache_size: 0,
+            max_categories: 0,
+            strict_enums: true,
+            max_requests_per_connection: 0,
+            max_metadata_entries: 64,
+            max_metadata_bytes: 8192,
+            batch_max_duration_ms: 30_000,
+            batch_progress_interval: 500,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         };
 
         assert!(cfg.address().is_err(), "invalid address should return an error");
@@ -329,8 +886,48 @@ mod tests {
             .unwrap()
             .set_default("tls_enabled", DEFAULT_TLS_ENABLED)
             .unwrap()
+            .set_default("require_tls", DEFAULT_REQUIRE_TLS)
+            .unwrap()
             .set_default("database_path", "personal_ledger.db")
             .unwrap()
+            .set_default("require_migrated", DEFAULT_REQUIRE_MIGRATED)
+            .unwrap()
+            .set_default("startup_self_test", DEFAULT_STARTUP_SELF_TEST)
+            .unwrap()
+            .set_default("shutdown_grace_period_secs", DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS)
+            .unwrap()
+            .set_default("max_concurrent_streams", DEFAULT_MAX_CONCURRENT_STREAMS as u64)
+            .unwrap()
+            .set_default("reflection_optional", DEFAULT_REFLECTION_OPTIONAL)
+            .unwrap()
+            .set_default("streaming_enabled", DEFAULT_STREAMING_ENABLED)
+            .unwrap()
+            .set_default("tags_enabled", DEFAULT_TAGS_ENABLED)
+            .unwrap()
+            .set_default("hierarchy_enabled", DEFAULT_HIERARCHY_ENABLED)
+            .unwrap()
+            .set_default("events_enabled", DEFAULT_EVENTS_ENABLED)
+            .unwrap()
+            .set_default("accept_client_ids", DEFAULT_ACCEPT_CLIENT_IDS)
+            .unwrap()
+            .set_default("enable_json_api", DEFAULT_ENABLE_JSON_API)
+            .unwrap()
+            .set_default("category_cache_size", DEFAULT_CATEGORY_CACHE_SIZE as u64)
+            .unwrap()
+            .set_default("max_categories", DEFAULT_MAX_CATEGORIES as u64)
+            .unwrap()
+            .set_default("strict_enums", DEFAULT_STRICT_ENUMS)
+            .unwrap()
+            .set_default("max_requests_per_connection", DEFAULT_MAX_REQUESTS_PER_CONNECTION)
+            .unwrap()
+            .set_default("max_metadata_entries", DEFAULT_MAX_METADATA_ENTRIES as u64)
+            .unwrap()
+            .set_default("max_metadata_bytes", DEFAULT_MAX_METADATA_BYTES as u64)
+            .unwrap()
+            .set_default("batch_max_duration_ms", DEFAULT_BATCH_MAX_DURATION_MS)
+            .unwrap()
+            .set_default("batch_progress_interval", DEFAULT_BATCH_PROGRESS_INTERVAL as u64)
+            .unwrap()
             .build()
             .unwrap();
 
@@ -338,9 +935,108 @@ mod tests {
         assert_eq!(server_cfg.address, DEFAULT_SERVER_ADDRESS);
         assert_eq!(server_cfg.port, DEFAULT_SERVER_PORT);
         assert_eq!(server_cfg.tls_enabled, DEFAULT_TLS_ENABLED);
+        assert!(!server_cfg.require_migrated);
         assert_eq!(server_cfg.database_path, Some(std::path::PathBuf::from("personal_ledger.db")));
     }
 
+    #[test]
+    fn tls_files_disabled_and_not_required() {
+        let cfg = ServerConfig::default();
+        assert_eq!(cfg.tls_files().unwrap(), None);
+    }
+
+    #[test]
+    fn tls_files_required_without_tls_fails() {
+        let cfg = ServerConfig { require_tls: true, ..ServerConfig::default() };
+        assert!(matches!(cfg.tls_files(), Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn tls_files_enabled_requires_both_paths() {
+        let cfg = ServerConfig {
+            tls_enabled: true,
+            tls_cert_path: Some(PathBuf::from("cert.pem")),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(cfg.tls_files(), Err(ConfigError::Validation(_))));
+
+        let cfg = ServerConfig {
+            tls_key_path: Some(PathBuf::from("key.pem")),
+            require_tls: true,
+            ..cfg
+        };
+        let (cert_path, key_path) = cfg.tls_files().unwrap().expect("TLS files should be returned");
+        assert_eq!(cert_path, std::path::Path::new("cert.pem"));
+        assert_eq!(key_path, std::path::Path::new("key.pem"));
+    }
+
+    #[test]
+    fn auth_token_is_redacted_in_debug_output() {
+        use secrecy::ExposeSecret;
+
+        let cfg = ServerConfig { auth_token: Some(secrecy::SecretString::from("hunter2")), ..ServerConfig::default() };
+        let debug = format!("{:?}", cfg);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+        assert!(debug.contains("REDACTED"), "{}", debug);
+        assert_eq!(cfg.auth_token.unwrap().expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn display_tz_parses_iana_names() {
+        let cfg = ServerConfig {
+            display_timezone: Some(" Australia/Sydney ".to_string()),
+            ..ServerConfig::default()
+        };
+        assert_eq!(cfg.display_tz().unwrap(), Some(chrono_tz::Australia::Sydney));
+
+        let cfg = ServerConfig { display_timezone: Some("AEST+10".to_string()), ..cfg };
+        assert!(matches!(cfg.display_tz(), Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn server_addresses_parses_comma_separated_list() {
+        let cfg = ServerConfig {
+            address: "0.0.0.0, ::, [::1]".to_string(),
+            port: 8080,
+            ..ServerConfig::default()
+        };
+
+        let addresses = cfg.addresses().expect("addresses should parse");
+        let expected: Vec<SocketAddr> = vec![
+            "0.0.0.0:8080".parse().unwrap(),
+            "[::]:8080".parse().unwrap(),
+            "[::1]:8080".parse().unwrap(),
+        ];
+        assert_eq!(addresses, expected);
+        assert_eq!(cfg.address().unwrap(), expected[0]);
+    }
+
+    #[test]
+    fn server_addresses_removes_duplicates() {
+        let cfg = ServerConfig {
+            address: "127.0.0.1,127.0.0.1, ".to_string(),
+            ..ServerConfig::default()
+        };
+
+        assert_eq!(cfg.addresses().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn server_addresses_rejects_invalid_or_empty_entries() {
+        let invalid = ServerConfig {
+            address: "127.0.0.1, localhost".to_string(),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(invalid.addresses(), Err(ConfigError::InvalidServerAddress(_))));
+
+        let empty = ServerConfig {
+            address: " , ".to_string(),
+            ..ServerConfig::default()
+        };
+        assert!(matches!(empty.addresses(), Err(ConfigError::Validation(_))));
+        assert!(empty.address().is_err());
+    }
+
     #[test]
     fn server_config_default_is_valid_socket() {
         let s = ServerConfig::default();
@@ -361,7 +1057,38 @@ mod tests {
             tls_enabled: false,
             tls_cert_path: None,
             tls_key_path: None,
+            require_tls: false,
             database_path: Some(PathBuf::from("custom.db")),
+            require_migrated: false,
+            startup_self_test: false,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: false,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: true,
+            tags_enabled: true,
+            hierarchy_enabled: true,
+            events_enabled: false,
+            accept_client_ids: false,
+            enable_json_api: false,
+            category_cache_size: 0,
+            max_categories: 0,
+            strict_enums: true,
+            max_requests_per_connection: 0,
+            max_metadata_entries: 64,
+            max_metadata_bytes: 8192,
+            batch_max_duration_ms: 30_000,
+            batch_progress_interval: 500,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         };
         assert_eq!(s.database_url().unwrap(), "sqlite://custom.db");
     }
@@ -376,7 +1103,38 @@ mod tests {
             tls_enabled: false,
             tls_cert_path: None,
             tls_key_path: None,
+            require_tls: false,
             database_path: None,
+            require_migrated: false,
+            startup_self_test: false,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: false,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: true,
+            tags_enabled: true,
+            hierarchy_enabled: true,
+            events_enabled: false,
+            accept_client_ids: false,
+            enable_json_api: false,
+            category_cache_size: 0,
+            max_categories: 0,
+            strict_enums: true,
+            max_requests_per_connection: 0,
+            max_metadata_entries: 64,
+            max_metadata_bytes: 8192,
+            batch_max_duration_ms: 30_000,
+            batch_progress_interval: 500,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         };
         assert_eq!(s.database_url().unwrap(), "sqlite://personal_ledger.db");
     }
@@ -391,7 +1149,38 @@ mod tests {
             tls_enabled: false,
             tls_cert_path: None,
             tls_key_path: None,
+            require_tls: false,
             database_path: Some(PathBuf::from("")),
+            require_migrated: false,
+            startup_self_test: false,
+            database_max_connections: None,
+            database_acquire_timeout_secs: None,
+            shutdown_grace_period_secs: DEFAULT_SHUTDOWN_GRACE_PERIOD_SECS,
+            max_concurrent_streams: DEFAULT_MAX_CONCURRENT_STREAMS,
+            page_token_secret: None,
+            admin_port: None,
+            reflection_optional: false,
+            http2_initial_connection_window_size: None,
+            http2_initial_stream_window_size: None,
+            connection_idle_timeout_secs: None,
+            streaming_enabled: true,
+            tags_enabled: true,
+            hierarchy_enabled: true,
+            events_enabled: false,
+            accept_client_ids: false,
+            enable_json_api: false,
+            category_cache_size: 0,
+            max_categories: 0,
+            strict_enums: true,
+            max_requests_per_connection: 0,
+            max_metadata_entries: 64,
+            max_metadata_bytes: 8192,
+            batch_max_duration_ms: 30_000,
+            batch_progress_interval: 500,
+            request_timeout_secs: None,
+            method_timeouts: None,
+            display_timezone: None,
+            auth_token: None,
         };
         let result = s.database_url();
         assert!(result.is_err());
@@ -11,7 +11,7 @@
 use crate::{database, domain};
 
 
-/// Errors emitted by [`CategoryBuilder::build`] when required data is missing.
+/// Errors emitted by [`CategoryBuilder::build`] when required data is missing or invalid.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum CategoryBuilderError {
 	/// The category name was not provided.
@@ -25,6 +25,23 @@ pub enum CategoryBuilderError {
 	/// The category code was not provided.
 	#[error("category code is required")]
 	Code,
+
+	/// The category name was provided but is empty or whitespace only.
+	#[error("category name cannot be blank")]
+	BlankName,
+
+	/// The category code was provided but is empty or whitespace only.
+	#[error("category code cannot be blank")]
+	BlankCode,
+
+	/// The `updated_on` timestamp is earlier than `created_on`.
+	#[error("category updated_on cannot be earlier than created_on")]
+	TimestampOrder,
+
+	/// An automatic URL slug was requested but the name has no characters
+	/// usable in a slug.
+	#[error("category name does not produce a URL slug")]
+	EmptySlug,
 }
 
 /// Fluent builder for [`Category`](crate::database::categories::Category) rows.
@@ -40,10 +57,15 @@ pub struct CategoriesBuilder {
 	name: Option<String>,
 	description: Option<String>,
 	url_slug: Option<domain::UrlSlug>,
+	auto_slug: bool,
 	category_type: Option<domain::CategoryTypes>,
 	color: Option<domain::HexColor>,
 	icon: Option<String>,
 	is_active: Option<bool>,
+	display_order: Option<i64>,
+	external_id: Option<domain::ExternalRef>,
+	reactivate_on: Option<chrono::DateTime<chrono::Utc>>,
+	parent_id: Option<domain::RowID>,
 	created_on: Option<chrono::DateTime<chrono::Utc>>,
 	updated_on: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -111,6 +133,18 @@ impl CategoriesBuilder {
 		self
 	}
 
+	/// Derive the URL slug from the name at build time, unless a slug is set
+	/// explicitly.
+	///
+	/// [`build`](Self::build) fails with [`CategoryBuilderError::EmptySlug`] if
+	/// the name has no characters usable in a slug, such as a name of only
+	/// punctuation.
+	#[must_use]
+	pub fn with_auto_slug(mut self) -> Self {
+		self.auto_slug = true;
+		self
+	}
+
 	/// Assign the accounting category type.
 	#[must_use]
 	pub fn with_category_type(mut self, category_type: domain::CategoryTypes) -> Self {
@@ -160,6 +194,62 @@ impl CategoriesBuilder {
 		self
 	}
 
+	/// Set the position of the category in user-defined ordering.
+	#[must_use]
+	pub fn with_display_order(mut self, display_order: i64) -> Self {
+		self.display_order = Some(display_order);
+		self
+	}
+
+	/// Provide an optional display order.
+	#[must_use]
+	pub fn with_display_order_opt(mut self, display_order: Option<i64>) -> Self {
+		self.display_order = display_order;
+		self
+	}
+
+	/// Record the identifier an external system uses for the category.
+	#[must_use]
+	pub fn with_external_id(mut self, external_id: domain::ExternalRef) -> Self {
+		self.external_id = Some(external_id);
+		self
+	}
+
+	/// Provide an optional external identifier.
+	#[must_use]
+	pub fn with_external_id_opt(mut self, external_id: Option<domain::ExternalRef>) -> Self {
+		self.external_id = external_id;
+		self
+	}
+
+	/// Schedule an inactive category to be reactivated at `reactivate_on`.
+	#[must_use]
+	pub fn with_reactivate_on(mut self, reactivate_on: chrono::DateTime<chrono::Utc>) -> Self {
+		self.reactivate_on = Some(reactivate_on);
+		self
+	}
+
+	/// Provide an optional reactivation timestamp.
+	#[must_use]
+	pub fn with_reactivate_on_opt(mut self, reactivate_on: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+		self.reactivate_on = reactivate_on;
+		self
+	}
+
+	/// Nest the category under the category with ID `parent_id`.
+	#[must_use]
+	pub fn with_parent_id(mut self, parent_id: domain::RowID) -> Self {
+		self.parent_id = Some(parent_id);
+		self
+	}
+
+	/// Provide an optional parent category ID.
+	#[must_use]
+	pub fn with_parent_id_opt(mut self, parent_id: Option<domain::RowID>) -> Self {
+		self.parent_id = parent_id;
+		self
+	}
+
 	/// Set the creation timestamp.
 	#[must_use]
 	pub fn with_created_on(mut self, created_on: chrono::DateTime<chrono::Utc>) -> Self {
@@ -187,7 +277,13 @@ impl CategoriesBuilder {
 		self.updated_on = updated_on;
 		self
 	}
-	/// Build the [`Category`], returning an error when required fields are missing.
+	/// Build the [`Category`], returning an error when required fields are missing
+	/// or invalid.
+	///
+	/// Name and code must not be blank, and `updated_on` must not be earlier than
+	/// `created_on`. With [`with_auto_slug`](Self::with_auto_slug) and no
+	/// explicit slug, the name must produce a non-empty slug. Missing timestamps default to now, adjusted so that a lone
+	/// `created_on` or `updated_on` never produces an out-of-order pair.
 	pub fn build(self) -> Result<database::Categories, CategoryBuilderError> {
 		let name = self
 			.name
@@ -199,9 +295,29 @@ impl CategoriesBuilder {
 			.code
 			.ok_or(CategoryBuilderError::Code)?;
 
+		if name.trim().is_empty() {
+			return Err(CategoryBuilderError::BlankName);
+		}
+		if code.trim().is_empty() {
+			return Err(CategoryBuilderError::BlankCode);
+		}
+
 	  let id = self.id.unwrap_or_default();
-		let url_slug = self.url_slug;
+		let url_slug = match self.url_slug {
+			None if self.auto_slug => {
+				Some(domain::UrlSlug::parse(name.as_str()).map_err(|_| CategoryBuilderError::EmptySlug)?)
+			}
+			url_slug => url_slug,
+		};
 		let now = chrono::Utc::now();
+		let created_on = self
+			.created_on
+			.unwrap_or_else(|| self.updated_on.map_or(now, |updated_on| updated_on.min(now)));
+		let updated_on = self.updated_on.unwrap_or(created_on.max(now));
+
+		if updated_on < created_on {
+			return Err(CategoryBuilderError::TimestampOrder);
+		}
 
 		Ok(database::Categories {
 			id,
@@ -213,8 +329,13 @@ impl CategoriesBuilder {
 			color: self.color,
 			icon: self.icon,
 			is_active: self.is_active.unwrap_or(true),
-			created_on: self.created_on.unwrap_or(now),
-			updated_on: self.updated_on.unwrap_or(now),
+			display_order: self.display_order.unwrap_or(0),
+			external_id: self.external_id,
+			reactivate_on: self.reactivate_on,
+			parent_id: self.parent_id,
+			created_on,
+			updated_on,
+			deleted_on: None,
 		})
 	}
 }
@@ -320,4 +441,367 @@ mod tests {
 		assert!(category.url_slug.is_none()); // not generated from name
 		assert!(category.is_active); // default restored
 	}
+
+	#[test]
+	fn build_rejects_blank_name_and_code() {
+		let result = CategoriesBuilder::new()
+			.with_name("   ")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("BLANK.001")
+			.build();
+		assert_eq!(result.unwrap_err(), CategoryBuilderError::BlankName);
+
+		let result = CategoriesBuilder::new()
+			.with_name("Blank code")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("")
+			.build();
+		assert_eq!(result.unwrap_err(), CategoryBuilderError::BlankCode);
+	}
+
+	#[test]
+	fn auto_slug_derives_slug_from_name() {
+		let category = CategoriesBuilder::new()
+			.with_name("Dining Out")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("DIN.001")
+			.with_auto_slug()
+			.build()
+			.expect("build should succeed");
+		assert_eq!(category.url_slug.as_ref().map(UrlSlug::as_str), Some("dining-out"));
+
+		let category = CategoriesBuilder::new()
+			.with_name("Food & Drink: Take-away!")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("FOOD.001")
+			.with_auto_slug()
+			.build()
+			.expect("build should succeed");
+		assert_eq!(category.url_slug.as_ref().map(UrlSlug::as_str), Some("food-drink-take-away"));
+	}
+
+	#[test]
+	fn auto_slug_keeps_explicit_slug() {
+		let slug = UrlSlug::parse("eating-out").unwrap();
+		let category = CategoriesBuilder::new()
+			.with_name("Dining Out")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("DIN.001")
+			.with_auto_slug()
+			.with_url_slug(slug.clone())
+			.build()
+			.expect("build should succeed");
+		assert_eq!(category.url_slug, Some(slug));
+	}
+
+	#[test]
+	fn auto_slug_rejects_name_without_slug_characters() {
+		let result = CategoriesBuilder::new()
+			.with_name("?!&")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("PUNCT.001")
+			.with_auto_slug()
+			.build();
+		assert_eq!(result.unwrap_err(), CategoryBuilderError::EmptySlug);
+	}
+
+	#[test]
+	fn build_rejects_updated_before_created() {
+		let created_on = chrono::Utc::now();
+		let result = CategoriesBuilder::new()
+			.with_name("Out of order")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("ORDER.001")
+			.with_created_on(created_on)
+			.with_updated_on(created_on - chrono::Duration::seconds(1))
+			.build();
+		assert_eq!(result.unwrap_err(), CategoryBuilderError::TimestampOrder);
+	}
+
+	#[test]
+	fn build_defaults_created_on_before_past_updated_on() {
+		let updated_on = chrono::Utc::now() - chrono::Duration::days(1);
+		let category = CategoriesBuilder::new()
+			.with_name("Past")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("PAST.001")
+			.with_updated_on(updated_on)
+			.build()
+			.expect("build should succeed");
+
+		assert_eq!(category.created_on, updated_on);
+	}
+
+	#[test]
+	fn build_defaults_updated_on_after_future_created_on() {
+		let created_on = chrono::Utc::now() + chrono::Duration::days(1);
+		let category = CategoriesBuilder::new()
+			.with_name("Future")
+			.with_category_type(CategoryTypes::Expense)
+			.with_code("FUT.001")
+			.with_created_on(created_on)
+			.build()
+			.expect("build should succeed");
+
+		assert_eq!(category.updated_on, created_on);
+	}
+
+	/// Number of random builder sequences exercised by the invariant fuzzer.
+	const FUZZ_ITERATIONS: usize = 500;
+
+	/// Maximum number of builder calls in a single random sequence.
+	const FUZZ_MAX_STEPS: usize = 16;
+
+	/// Values the fuzzer expects the builder to hold after a sequence of calls.
+	#[derive(Debug, Default)]
+	struct ExpectedFields {
+		id: Option<domain::RowID>,
+		code: Option<String>,
+		name: Option<String>,
+		description: Option<String>,
+		url_slug: Option<UrlSlug>,
+		category_type: Option<CategoryTypes>,
+		color: Option<HexColor>,
+		icon: Option<String>,
+		is_active: Option<bool>,
+		created_on: Option<chrono::DateTime<chrono::Utc>>,
+		updated_on: Option<chrono::DateTime<chrono::Utc>>,
+	}
+
+	/// Random text that is sometimes blank, to exercise blank-value validation.
+	fn fuzz_text() -> String {
+		use fake::Fake;
+		use fake::faker::lorem::en::Words;
+
+		match crate::mock_rng::with_rng(|rng| (0..10).fake_with_rng::<u8, _>(rng)) {
+			0 => String::new(),
+			1 => " \t ".to_string(),
+			_ => crate::mock_rng::with_rng(|rng| Words(1..4).fake_with_rng::<Vec<String>, _>(rng)).join(" "),
+		}
+	}
+
+	/// Random timestamp within a few days either side of now.
+	fn fuzz_datetime() -> chrono::DateTime<chrono::Utc> {
+		use fake::Fake;
+
+		let seconds: i64 = crate::mock_rng::with_rng(|rng| (-300_000..300_000).fake_with_rng(rng));
+		chrono::Utc::now() + chrono::Duration::seconds(seconds)
+	}
+
+	/// Apply one randomly chosen builder method, mirroring it in `expected`.
+	fn apply_random_step(builder: CategoriesBuilder, expected: &mut ExpectedFields) -> CategoriesBuilder {
+		use fake::Fake;
+		use fake::faker::boolean::en::Boolean;
+
+		let some: bool = crate::mock_rng::with_rng(|rng| Boolean(70).fake_with_rng(rng));
+
+		match crate::mock_rng::with_rng(|rng| (0..21).fake_with_rng::<u8, _>(rng)) {
+			0 => {
+				let id = domain::RowID::mock();
+				expected.id = Some(id);
+				builder.with_id(id)
+			}
+			1 => {
+				let code = fuzz_text();
+				expected.code = Some(code.clone());
+				builder.with_code(code)
+			}
+			2 => {
+				let code = some.then(fuzz_text);
+				expected.code = code.clone();
+				builder.with_code_opt(code)
+			}
+			3 => {
+				let name = fuzz_text();
+				expected.name = Some(name.clone());
+				builder.with_name(name)
+			}
+			4 => {
+				let description = fuzz_text();
+				expected.description = Some(description.clone());
+				builder.with_description(description)
+			}
+			5 => {
+				let description = some.then(fuzz_text);
+				expected.description = description.clone();
+				builder.with_description_opt(description)
+			}
+			6 => {
+				let slug = UrlSlug::parse(format!("{} {}", fuzz_text(), database::Categories::mock().code))
+					.expect("slug source always contains alphanumerics");
+				expected.url_slug = Some(slug.clone());
+				builder.with_url_slug(slug)
+			}
+			7 => {
+				let slug = some.then(|| UrlSlug::from(database::Categories::mock().name));
+				expected.url_slug = slug.clone();
+				builder.with_url_slug_opt(slug)
+			}
+			8 | 9 => {
+				let category_type = CategoryTypes::mock();
+				expected.category_type = Some(category_type);
+				builder.with_category_type(category_type)
+			}
+			10 => {
+				let color = HexColor::mock();
+				expected.color = Some(color.clone());
+				builder.with_color(color)
+			}
+			11 => {
+				let color = HexColor::mock_with_option();
+				expected.color = color.clone();
+				builder.with_color_opt(color)
+			}
+			12 => {
+				let icon = fuzz_text();
+				expected.icon = Some(icon.clone());
+				builder.with_icon(icon)
+			}
+			13 => {
+				let icon = some.then(fuzz_text);
+				expected.icon = icon.clone();
+				builder.with_icon_opt(icon)
+			}
+			14 => {
+				let is_active: bool = crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng));
+				expected.is_active = Some(is_active);
+				builder.with_is_active(is_active)
+			}
+			15 => {
+				let is_active = some.then(|| crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)));
+				expected.is_active = is_active;
+				builder.with_is_active_opt(is_active)
+			}
+			16 => {
+				let created_on = fuzz_datetime();
+				expected.created_on = Some(created_on);
+				builder.with_created_on(created_on)
+			}
+			17 => {
+				let created_on = some.then(fuzz_datetime);
+				expected.created_on = created_on;
+				builder.with_created_on_opt(created_on)
+			}
+			18 => {
+				let updated_on = fuzz_datetime();
+				expected.updated_on = Some(updated_on);
+				builder.with_updated_on(updated_on)
+			}
+			19 => {
+				let updated_on = some.then(fuzz_datetime);
+				expected.updated_on = updated_on;
+				builder.with_updated_on_opt(updated_on)
+			}
+			_ => {
+				let name = database::Categories::mock().name;
+				expected.name = Some(name.clone());
+				builder.with_name(name)
+			}
+		}
+	}
+
+	/// The error `build` must return for `expected`, if any, in check order.
+	fn expected_error(expected: &ExpectedFields) -> Option<CategoryBuilderError> {
+		let name = match &expected.name {
+			None => return Some(CategoryBuilderError::Name),
+			Some(name) => name,
+		};
+		if expected.category_type.is_none() {
+			return Some(CategoryBuilderError::CategoryType);
+		}
+		let code = match &expected.code {
+			None => return Some(CategoryBuilderError::Code),
+			Some(code) => code,
+		};
+		if name.trim().is_empty() {
+			return Some(CategoryBuilderError::BlankName);
+		}
+		if code.trim().is_empty() {
+			return Some(CategoryBuilderError::BlankCode);
+		}
+		if let (Some(created_on), Some(updated_on)) = (expected.created_on, expected.updated_on)
+			&& updated_on < created_on
+		{
+			return Some(CategoryBuilderError::TimestampOrder);
+		}
+		None
+	}
+
+	/// Check the invariants every successfully built category must satisfy.
+	fn assert_category_invariants(category: &database::Categories, expected: &ExpectedFields) {
+		assert!(!category.code.trim().is_empty(), "code must not be blank: {:?}", category);
+		assert!(!category.name.trim().is_empty(), "name must not be blank: {:?}", category);
+		assert!(category.created_on <= category.updated_on, "timestamps out of order: {:?}", category);
+
+		if let Some(slug) = &category.url_slug {
+			assert!(!slug.is_empty());
+			assert_eq!(UrlSlug::parse(slug.as_str()).as_ref(), Ok(slug), "slug must be canonical");
+		}
+		if let Some(color) = &category.color {
+			assert_eq!(HexColor::parse(color.as_str()).as_ref(), Ok(color), "color must be canonical");
+		}
+
+		// The last value set for each field wins
+		assert_eq!(Some(&category.code), expected.code.as_ref());
+		assert_eq!(Some(&category.name), expected.name.as_ref());
+		assert_eq!(category.description, expected.description);
+		assert_eq!(category.url_slug, expected.url_slug);
+		assert_eq!(Some(&category.category_type), expected.category_type.as_ref());
+		assert_eq!(category.color, expected.color);
+		assert_eq!(category.icon, expected.icon);
+		assert_eq!(category.is_active, expected.is_active.unwrap_or(true));
+		if let Some(id) = expected.id {
+			assert_eq!(category.id, id);
+		}
+		if let Some(created_on) = expected.created_on {
+			assert_eq!(category.created_on, created_on);
+		}
+		if let Some(updated_on) = expected.updated_on {
+			assert_eq!(category.updated_on, updated_on);
+		}
+	}
+
+	#[test]
+	fn fuzz_random_builder_sequences_uphold_invariants() {
+		use fake::Fake;
+
+		let mut built = 0;
+
+		for _ in 0..FUZZ_ITERATIONS {
+			let mut expected = ExpectedFields::default();
+			let mut builder = CategoriesBuilder::new();
+
+			// Bias towards usable builders by usually seeding required fields first
+			if crate::mock_rng::with_rng(|rng| (0..4).fake_with_rng::<u8, _>(rng)) > 0 {
+				let seed = database::Categories::mock();
+				expected.name = Some(seed.name.clone());
+				expected.code = Some(seed.code.clone());
+				expected.category_type = Some(seed.category_type);
+				builder = builder
+					.with_name(seed.name)
+					.with_code(seed.code)
+					.with_category_type(seed.category_type);
+			}
+
+			let steps: usize = crate::mock_rng::with_rng(|rng| (0..=FUZZ_MAX_STEPS).fake_with_rng(rng));
+			for _ in 0..steps {
+				builder = apply_random_step(builder, &mut expected);
+			}
+
+			match (builder.build(), expected_error(&expected)) {
+				(Ok(category), None) => {
+					assert_category_invariants(&category, &expected);
+					built += 1;
+				}
+				(Err(error), Some(expected_error)) => assert_eq!(error, expected_error),
+				(result, expected_error) => panic!(
+					"builder result {:?} did not match expected error {:?} for {:?}",
+					result, expected_error, expected
+				),
+			}
+		}
+
+		// Guard against the generator drifting into only producing errors
+		assert!(built > FUZZ_ITERATIONS / 4, "only {} of {} sequences built", built, FUZZ_ITERATIONS);
+	}
 }
@@ -0,0 +1,269 @@
+//! # Category Cache
+//!
+//! An optional, size-bounded LRU cache in front of
+//! [`find_by_id`](database::Categories::find_by_id). Writers call
+//! [`CategoryCache::invalidate`] with the ids they changed, or
+//! [`CategoryCache::clear`] when they cannot name them, so a read after a
+//! write always sees the write.
+//!
+//! A lookup that races a write does not cache the row it read: every
+//! invalidation bumps a generation counter, and the row is only stored if the
+//! generation is unchanged since the lookup started.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::database::{self, DatabaseResult};
+use crate::domain;
+
+/// LRU cache of categories by id, shared by every clone.
+///
+/// A capacity of `0` disables caching; every read goes to the database.
+#[derive(Debug, Clone)]
+pub struct CategoryCache {
+    state: Arc<Mutex<CacheState>>,
+    database_lookups: Arc<AtomicU64>,
+}
+
+#[derive(Debug)]
+struct CacheState {
+    capacity: usize,
+    entries: HashMap<domain::RowID, CacheEntry>,
+    /// Incremented on every access, to order entries by last use.
+    clock: u64,
+    /// Incremented on every invalidation, to drop rows read during a write.
+    generation: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    category: database::Categories,
+    last_used: u64,
+}
+
+impl Default for CategoryCache {
+    /// A disabled cache.
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl CategoryCache {
+    /// Create a cache holding up to `capacity` categories.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(CacheState {
+                capacity,
+                entries: HashMap::with_capacity(capacity),
+                clock: 0,
+                generation: 0,
+            })),
+            database_lookups: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Find a category by id, from the cache when it holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the category is not cached and the database query fails.
+    pub async fn find_by_id(
+        &self,
+        id: domain::RowID,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Option<database::Categories>> {
+        let generation = {
+            let mut state = self.lock();
+            if let Some(category) = state.get(&id) {
+                return Ok(Some(category));
+            }
+            state.generation
+        };
+
+        self.database_lookups.fetch_add(1, Ordering::Relaxed);
+        let category = database::Categories::find_by_id(id, pool).await?;
+
+        if let Some(category) = &category {
+            let mut state = self.lock();
+            if state.generation == generation {
+                state.put(category.clone());
+            }
+        }
+
+        Ok(category)
+    }
+
+    /// Drop the category `id`, after it was inserted, updated or deleted.
+    pub fn invalidate(&self, id: domain::RowID) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.entries.remove(&id);
+    }
+
+    /// Drop every cached category, after a write to rows that are not known.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.generation += 1;
+        state.entries.clear();
+    }
+
+    /// Number of categories cached.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns `true` if no categories are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of reads the cache could not answer and passed to the database.
+    pub fn database_lookups(&self) -> u64 {
+        self.database_lookups.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        // The state stays consistent if a holder panics, so keep using it
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl CacheState {
+    fn get(&mut self, id: &domain::RowID) -> Option<database::Categories> {
+        self.clock += 1;
+        let entry = self.entries.get_mut(id)?;
+        entry.last_used = self.clock;
+        Some(entry.category.clone())
+    }
+
+    fn put(&mut self, category: database::Categories) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&category.id) {
+            let least_recent = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(id, _)| *id);
+            if let Some(id) = least_recent {
+                self.entries.remove(&id);
+            }
+        }
+
+        self.clock += 1;
+        self.entries.insert(category.id, CacheEntry { category, last_used: self.clock });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    /// Insert a mock category whose unique columns are derived from `index`,
+    /// as mock names and slugs come from a small word list
+    async fn insert_mock(index: usize, pool: &SqlitePool) -> database::Categories {
+        let category = database::Categories {
+            code: format!("CACHE.{:03}", index),
+            name: format!("Cache Category {}", index),
+            url_slug: Some(domain::UrlSlug::from(format!("cache-category-{}", index))),
+            ..database::Categories::mock()
+        };
+        category.insert(pool).await.unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_cached_read_does_not_hit_database(pool: SqlitePool) {
+        let cache = CategoryCache::new(8);
+        let category = insert_mock(0, &pool).await;
+
+        let first = cache.find_by_id(category.id, &pool).await.unwrap();
+        let second = cache.find_by_id(category.id, &pool).await.unwrap();
+
+        assert_eq!(first, Some(category.clone()));
+        assert_eq!(second, first);
+        assert_eq!(cache.database_lookups(), 1);
+
+        // Served from memory even once the row is gone behind the cache's back
+        sqlx::query("DELETE FROM categories").execute(&pool).await.unwrap();
+        assert_eq!(cache.find_by_id(category.id, &pool).await.unwrap(), Some(category));
+        assert_eq!(cache.database_lookups(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_update_invalidates_entry(pool: SqlitePool) {
+        let cache = CategoryCache::new(8);
+        let mut category = insert_mock(0, &pool).await;
+        cache.find_by_id(category.id, &pool).await.unwrap();
+
+        category.name = "Renamed".to_string();
+        category.update(&pool).await.unwrap();
+        cache.invalidate(category.id);
+
+        let found = cache.find_by_id(category.id, &pool).await.unwrap().unwrap();
+        assert_eq!(found.name, "Renamed");
+        assert_eq!(cache.database_lookups(), 2);
+    }
+
+    #[sqlx::test]
+    async fn test_evicts_least_recently_used(pool: SqlitePool) {
+        let cache = CategoryCache::new(2);
+        let first = insert_mock(0, &pool).await;
+        let second = insert_mock(1, &pool).await;
+        let third = insert_mock(2, &pool).await;
+
+        cache.find_by_id(first.id, &pool).await.unwrap();
+        cache.find_by_id(second.id, &pool).await.unwrap();
+        // Use the first again, so the second is the least recently used
+        cache.find_by_id(first.id, &pool).await.unwrap();
+        cache.find_by_id(third.id, &pool).await.unwrap();
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.database_lookups(), 3);
+
+        cache.find_by_id(first.id, &pool).await.unwrap();
+        assert_eq!(cache.database_lookups(), 3);
+        cache.find_by_id(second.id, &pool).await.unwrap();
+        assert_eq!(cache.database_lookups(), 4);
+    }
+
+    #[sqlx::test]
+    async fn test_disabled_cache_reads_through(pool: SqlitePool) {
+        let cache = CategoryCache::default();
+        let category = insert_mock(0, &pool).await;
+
+        cache.find_by_id(category.id, &pool).await.unwrap();
+        cache.find_by_id(category.id, &pool).await.unwrap();
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.database_lookups(), 2);
+    }
+
+    #[sqlx::test]
+    async fn test_missing_category_is_not_cached(pool: SqlitePool) {
+        let cache = CategoryCache::new(8);
+        let id = domain::RowID::new();
+        assert_eq!(cache.find_by_id(id, &pool).await.unwrap(), None);
+
+        let mut category = database::Categories::mock();
+        category.id = id;
+        category.insert(&pool).await.unwrap();
+
+        assert_eq!(cache.find_by_id(id, &pool).await.unwrap().map(|found| found.id), Some(id));
+    }
+
+    #[sqlx::test]
+    async fn test_clear_drops_every_entry(pool: SqlitePool) {
+        let cache = CategoryCache::new(8);
+        for index in 0..3 {
+            let category = insert_mock(index, &pool).await;
+            cache.find_by_id(category.id, &pool).await.unwrap();
+        }
+        assert_eq!(cache.len(), 3);
+
+        cache.clone().clear();
+        assert!(cache.is_empty());
+    }
+}
@@ -124,6 +124,36 @@ impl database::Categories {
         id: domain::RowID,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<()> {
+        Self::delete_by_id_on(id, pool).await
+    }
+
+    /// Deletes the category `id` as part of the transaction `tx`.
+    ///
+    /// Behaves like [`delete_by_id`](Self::delete_by_id), but the row is only
+    /// removed once the caller commits `tx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`delete_by_id`](Self::delete_by_id).
+    #[tracing::instrument(
+        name = "Delete category in transaction",
+        skip(tx),
+        fields(id = %id),
+        err
+    )]
+    pub async fn delete_by_id_tx(
+        id: domain::RowID,
+        tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    ) -> DatabaseResult<()> {
+        Self::delete_by_id_on(id, &mut **tx).await
+    }
+
+    /// Delete on `executor`, shared by [`delete_by_id`](Self::delete_by_id) and
+    /// [`delete_by_id_tx`](Self::delete_by_id_tx).
+    async fn delete_by_id_on<'e, E>(id: domain::RowID, executor: E) -> DatabaseResult<()>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
         let delete_query = sqlx::query!(
             r#"
                 DELETE FROM categories
@@ -132,7 +162,7 @@ impl database::Categories {
             id
         );
 
-        let rows_affected = delete_query.execute(pool).await?.rows_affected();
+        let rows_affected = delete_query.execute(executor).await?.rows_affected();
 
         if rows_affected == 0 {
             return Err(database::DatabaseError::NotFound(format!(
@@ -233,6 +263,53 @@ impl database::Categories {
         Ok(())
     }
 
+    /// Deletes the categories with the given IDs in a single statement.
+    ///
+    /// Unlike [`delete_many_by_id`](Self::delete_many_by_id), IDs that do not
+    /// match a category are skipped rather than treated as an error, and the
+    /// rows are removed with one `DELETE ... WHERE id IN (...)` instead of one
+    /// statement per ID. Each ID is a bound parameter, so the slice must stay
+    /// within SQLite's limit on host parameters (32766 by default).
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the categories to delete
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of categories actually deleted. An empty slice
+    /// returns `0` without querying the database.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the statement fails, for example when a deletion
+    /// violates a foreign key constraint.
+    #[tracing::instrument(
+        name = "Delete categories in one statement",
+        skip(ids, pool),
+        fields(count = ids.len()),
+        err
+    )]
+    pub async fn delete_many(ids: &[domain::RowID], pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<u64> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let mut delete_query = sqlx::QueryBuilder::<sqlx::Sqlite>::new("DELETE FROM categories WHERE id IN (");
+        let mut placeholders = delete_query.separated(", ");
+        for id in ids {
+            placeholders.push_bind(*id);
+        }
+        placeholders.push_unseparated(")");
+
+        let rows_affected = delete_query.build().execute(pool).await?.rows_affected();
+
+        tracing::info!("Deleted {} of {} requested categories from database", rows_affected, ids.len());
+
+        Ok(rows_affected)
+    }
+
     /// Deletes all inactive categories from the database.
     ///
     /// This is a convenience function for cleaning up deactivated categories.
@@ -290,6 +367,180 @@ impl database::Categories {
         Ok(rows_affected)
     }
 
+    /// Soft deletes a category by its ID.
+    ///
+    /// Sets the category's `deleted_on` timestamp instead of removing the row,
+    /// so it keeps its id and references to it stay valid. Soft-deleted
+    /// categories are hidden from the `find_*` queries; use
+    /// [`find_including_deleted`](Self::find_including_deleted) to see them and
+    /// [`restore`](Self::restore) to bring them back. The row is only removed
+    /// for good by [`purge_soft_deleted_before`](Self::purge_soft_deleted_before).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to soft delete
+    /// * `pool` - The database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::NotFound`](database::DatabaseError::NotFound) if
+    /// no live category has the ID, including one already soft deleted, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::RowID;
+    ///
+    /// # async fn example(pool: &DatabasePool, id: RowID) -> Result<(), Box<dyn std::error::Error>> {
+    /// Category::soft_delete(id, pool).await?;
+    /// assert!(Category::find_by_id(id, pool).await?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Soft delete category in database",
+        skip(pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn soft_delete(id: domain::RowID, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+        let deleted_on = chrono::Utc::now();
+        let soft_delete_query = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET deleted_on = ?
+                WHERE id = ? AND deleted_on IS NULL
+            "#,
+            deleted_on,
+            id
+        );
+
+        let rows_affected = soft_delete_query.execute(pool).await?.rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Category with id {} not found",
+                id
+            )));
+        }
+
+        tracing::info!("Soft deleted category {} in database", id);
+
+        Ok(())
+    }
+
+    /// Restores a soft-deleted category by its ID.
+    ///
+    /// Clears the category's `deleted_on` timestamp, so it shows up in the
+    /// `find_*` queries again.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to restore
+    /// * `pool` - The database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::NotFound`](database::DatabaseError::NotFound) if
+    /// no soft-deleted category has the ID, or a `DatabaseError` if the update
+    /// fails.
+    #[tracing::instrument(
+        name = "Restore soft-deleted category in database",
+        skip(pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn restore(id: domain::RowID, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+        let restore_query = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET deleted_on = NULL
+                WHERE id = ? AND deleted_on IS NOT NULL
+            "#,
+            id
+        );
+
+        let rows_affected = restore_query.execute(pool).await?.rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Soft-deleted category with id {} not found",
+                id
+            )));
+        }
+
+        tracing::info!("Restored soft-deleted category {} in database", id);
+
+        Ok(())
+    }
+
+    /// Permanently deletes soft-deleted categories older than a cutoff.
+    ///
+    /// A category is soft deleted when its `deleted_on` column is set. This
+    /// retention purge hard deletes those whose `deleted_on` is before
+    /// `cutoff`, and leaves recently soft-deleted and live categories alone.
+    /// It is meant to be run periodically, by a scheduled task or an admin
+    /// command.
+    ///
+    /// # Arguments
+    ///
+    /// * `cutoff` - Soft-deleted categories before this moment are purged
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of categories purged, or a `DatabaseError` if the operation fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Database connection fails
+    /// - The deletion violates foreign key constraints
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Keep soft-deleted categories for 30 days
+    /// let cutoff = chrono::Utc::now() - chrono::Duration::days(30);
+    /// let purged_count = Category::purge_soft_deleted_before(cutoff, pool).await?;
+    /// println!("Purged {} soft-deleted categories", purged_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Purge soft-deleted categories from database",
+        skip(pool),
+        fields(cutoff = %cutoff),
+        err
+    )]
+    pub async fn purge_soft_deleted_before(
+        cutoff: chrono::DateTime<chrono::Utc>,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<u64> {
+        // Compare as julian days so the stored text format does not matter
+        let purge_query = sqlx::query!(
+            r#"
+                DELETE FROM categories
+                WHERE deleted_on IS NOT NULL
+                  AND julianday(deleted_on) < julianday(?)
+            "#,
+            cutoff
+        );
+
+        let rows_affected = purge_query.execute(pool).await?.rows_affected();
+
+        tracing::info!("Purged {} soft-deleted categories from database", rows_affected);
+
+        Ok(rows_affected)
+    }
+
     /// Deletes a category by its code.
     ///
     /// This is a convenience function for deleting categories when you have the code
@@ -372,8 +623,15 @@ mod tests {
     /// Helper function to create multiple test categories
     async fn create_test_categories(count: usize, pool: &SqlitePool) -> Vec<database::Categories> {
         let mut categories = Vec::with_capacity(count);
-        for _ in 0..count {
-            let category = database::Categories::mock();
+        for index in 0..count {
+            // Mock names and slugs come from a small word list, so derive them
+            // from the index to keep the unique columns distinct
+            let category = database::Categories {
+                code: format!("DELETE.{:03}", index),
+                name: format!("Delete Category {}", index),
+                url_slug: Some(UrlSlug::from(format!("delete-category-{}", index))),
+                ..database::Categories::mock()
+            };
             database::Categories::insert(&category, pool).await.unwrap();
             categories.push(category);
         }
@@ -462,6 +720,44 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[sqlx::test]
+    async fn test_delete_many_deletes_subset(pool: SqlitePool) {
+        let categories = create_test_categories(4, &pool).await;
+        let ids = [categories[0].id, categories[2].id];
+
+        let deleted = database::Categories::delete_many(&ids, &pool).await.unwrap();
+        assert_eq!(deleted, 2);
+
+        for (index, category) in categories.iter().enumerate() {
+            let found = database::Categories::find_by_id(category.id, &pool).await.unwrap();
+            assert_eq!(found.is_none(), ids.contains(&category.id), "category {}", index);
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_delete_many_counts_only_existing_ids(pool: SqlitePool) {
+        let category = create_test_category(&pool).await;
+        let ids = [category.id, RowID::new(), RowID::new()];
+
+        let deleted = database::Categories::delete_many(&ids, &pool).await.unwrap();
+        assert_eq!(deleted, 1);
+
+        // Deleting again finds nothing left
+        let deleted = database::Categories::delete_many(&ids, &pool).await.unwrap();
+        assert_eq!(deleted, 0);
+    }
+
+    #[sqlx::test]
+    async fn test_delete_many_empty_slice_is_noop(pool: SqlitePool) {
+        let category = create_test_category(&pool).await;
+
+        // A closed pool fails any query, so this proves the database is untouched
+        pool.close().await;
+        let deleted = database::Categories::delete_many(&[], &pool).await.unwrap();
+        assert_eq!(deleted, 0);
+        assert!(database::Categories::delete_many(&[category.id], &pool).await.is_err());
+    }
+
     #[sqlx::test]
     async fn test_delete_inactive_categories(pool: SqlitePool) {
         // Create some active categories
@@ -618,4 +914,82 @@ mod tests {
         let found = database::Categories::find_by_id(category.id, &pool).await.unwrap();
         assert!(found.is_none());
     }
-}
\ No newline at end of file
+
+    /// Helper function to soft delete a category at a given moment
+    async fn soft_delete_at(id: RowID, deleted_on: chrono::DateTime<chrono::Utc>, pool: &SqlitePool) {
+        sqlx::query("UPDATE categories SET deleted_on = ? WHERE id = ?")
+            .bind(deleted_on)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_purge_soft_deleted_before_removes_only_old_rows(pool: SqlitePool) {
+        let categories = create_test_categories(3, &pool).await;
+        let now = chrono::Utc::now();
+        soft_delete_at(categories[0].id, now - chrono::Duration::days(90), &pool).await;
+        soft_delete_at(categories[1].id, now - chrono::Duration::days(1), &pool).await;
+
+        let cutoff = now - chrono::Duration::days(30);
+        let purged_count = database::Categories::purge_soft_deleted_before(cutoff, &pool).await.unwrap();
+        assert_eq!(purged_count, 1);
+
+        // The old soft-deleted row is gone; the recent one and the live one remain
+        let remaining: Vec<RowID> = database::Categories::find_including_deleted(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|category| category.id)
+            .collect();
+        assert!(!remaining.contains(&categories[0].id));
+        assert!(remaining.contains(&categories[1].id));
+        assert!(remaining.contains(&categories[2].id));
+
+        // Running it again purges nothing more
+        let purged_count = database::Categories::purge_soft_deleted_before(cutoff, &pool).await.unwrap();
+        assert_eq!(purged_count, 0);
+    }
+
+    #[sqlx::test]
+    async fn test_soft_delete_hides_category_until_restored(pool: SqlitePool) {
+        let categories = create_test_categories(2, &pool).await;
+        let deleted = &categories[0];
+
+        database::Categories::soft_delete(deleted.id, &pool).await.unwrap();
+
+        let live = database::Categories::find_all(&pool).await.unwrap();
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].id, categories[1].id);
+        assert!(database::Categories::find_by_id(deleted.id, &pool).await.unwrap().is_none());
+        assert!(database::Categories::find_by_code(&deleted.code, &pool).await.unwrap().is_none());
+
+        let stored = database::Categories::find_including_deleted(&pool).await.unwrap();
+        assert_eq!(stored.len(), 2);
+        let stored_deleted = stored.iter().find(|category| category.id == deleted.id).unwrap();
+        assert!(stored_deleted.deleted_on.is_some());
+
+        database::Categories::restore(deleted.id, &pool).await.unwrap();
+
+        let restored = database::Categories::find_by_id(deleted.id, &pool).await.unwrap().unwrap();
+        assert!(restored.deleted_on.is_none());
+        assert_eq!(database::Categories::find_all(&pool).await.unwrap().len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn test_soft_delete_and_restore_report_missing_rows(pool: SqlitePool) {
+        let category = create_test_category(&pool).await;
+
+        // Restoring a live category, or soft deleting twice, finds nothing to change
+        let result = database::Categories::restore(category.id, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+
+        database::Categories::soft_delete(category.id, &pool).await.unwrap();
+        let result = database::Categories::soft_delete(category.id, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+
+        let result = database::Categories::soft_delete(RowID::new(), &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+    }
+}
@@ -1,10 +1,57 @@
+use std::collections::HashMap;
+
 use crate::database::{self, DatabaseResult};
 use crate::domain;
 
+/// Which identifier matched in [`find_by_slug_or_code`](database::Categories::find_by_slug_or_code).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryMatchedBy {
+    /// The value parsed as a URL slug and matched a category's `url_slug`.
+    Slug,
+
+    /// The value matched a category's `code`.
+    Code,
+}
+
+/// A category together with the categories nested under it, as returned by
+/// [`find_all_as_tree`](database::Categories::find_all_as_tree).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryNode {
+    /// The category at this position in the tree.
+    pub category: database::Categories,
+
+    /// Categories whose `parent_id` is this category, in display order.
+    pub children: Vec<CategoryNode>,
+}
+
+/// Recommended fields that [`find_incomplete`](database::Categories::find_incomplete)
+/// checks. A category is incomplete when any field set to `true` is empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecommendedFields {
+    /// Require a `color`.
+    pub color: bool,
+
+    /// Require an `icon`.
+    pub icon: bool,
+
+    /// Require a `description`.
+    pub description: bool,
+}
+
+impl RecommendedFields {
+    /// Require every recommended field.
+    pub fn all() -> Self {
+        Self { color: true, icon: true, description: true }
+    }
+}
+
 /// Read operations for Category database records.
 ///
 /// This module provides functions for retrieving existing category records from the database,
 /// including single record lookups, bulk retrieval, and filtered queries.
+///
+/// Soft-deleted categories, those with `deleted_on` set, are left out of every
+/// `find_*` query except [`find_including_deleted`](database::Categories::find_including_deleted).
 impl database::Categories {
     /// Finds a category by its ID.
     ///
@@ -61,10 +108,15 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE id = ?
+                WHERE id = ? AND deleted_on IS NULL
             "#,
             id
         )
@@ -127,10 +179,15 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE code = ?
+                WHERE code = ? AND deleted_on IS NULL
             "#,
             code
         )
@@ -140,19 +197,23 @@ impl database::Categories {
         Ok(category)
     }
 
-    /// Finds a category by its URL slug.
+    /// Finds a category by its code, ignoring case.
     ///
-    /// This function retrieves a single category record from the database by its URL slug.
-    /// URL slugs are case-sensitive and must be unique.
+    /// Matches `food.001` and `Food.001` to a stored `FOOD.001`. Use
+    /// [`find_by_code`](Self::find_by_code) when the exact code is needed.
+    ///
+    /// Codes are only unique as written, so two codes differing only by case,
+    /// such as `FOOD.001` and `food.001`, can both be stored. Both match, and
+    /// the one created first is returned.
     ///
     /// # Arguments
     ///
-    /// * `slug` - The URL slug of the category to find
+    /// * `code` - The code of the category to find, in any case
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns `Some(Category)` if the category exists, or `None` if not found.
+    /// Returns `Some(Category)` if a category matches, or `None` if not found.
     /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
@@ -160,26 +221,22 @@ impl database::Categories {
     /// ```rust,no_run
     /// use personal_ledger_backend::database::categories::Category;
     /// use personal_ledger_backend::database::DatabasePool;
-    /// use personal_ledger_backend::domain::UrlSlug;
     ///
     /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let slug = UrlSlug::parse("groceries")?;
-    /// if let Some(category) = Category::find_by_url_slug(&slug, pool).await? {
-    ///     println!("Found category: {}", category.name);
-    /// } else {
-    ///     println!("Category not found");
+    /// if let Some(category) = Category::find_by_code_ci("food.001", pool).await? {
+    ///     println!("Found category: {}", category.code);
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
-        name = "Find category by URL slug",
+        name = "Find category by code ignoring case",
         skip(pool),
-        fields(slug = %slug),
+        fields(code = %code),
         err
     )]
-    pub async fn find_by_url_slug(
-        slug: &domain::UrlSlug,
+    pub async fn find_by_code_ci(
+        code: &str,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Option<Self>> {
         let category = sqlx::query_as!(
@@ -195,12 +252,19 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE url_slug = ?
+                WHERE lower(code) = lower(?) AND deleted_on IS NULL
+                ORDER BY created_on ASC, id ASC
+                LIMIT 1
             "#,
-            slug
+            code
         )
         .fetch_optional(pool)
         .await?;
@@ -208,44 +272,49 @@ impl database::Categories {
         Ok(category)
     }
 
-    /// Retrieves all categories from the database.
+    /// Finds a category by its URL slug.
     ///
-    /// This function returns all category records ordered by creation date (newest first).
-    /// Use this function when you need to display all categories or perform bulk operations.
+    /// This function retrieves a single category record from the database by its URL slug.
+    /// URL slugs are case-sensitive and must be unique.
     ///
     /// # Arguments
     ///
+    /// * `slug` - The URL slug of the category to find
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns a vector of all categories, or a `DatabaseError` if the query fails.
+    /// Returns `Some(Category)` if the category exists, or `None` if not found.
+    /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use personal_ledger_backend::database::categories::Category;
     /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::UrlSlug;
     ///
     /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let all_categories = Category::find_all(pool).await?;
-    /// println!("Found {} categories", all_categories.len());
-    ///
-    /// for category in all_categories {
-    ///     println!("- {} ({})", category.name, category.code);
+    /// let slug = UrlSlug::parse("groceries")?;
+    /// if let Some(category) = Category::find_by_url_slug(&slug, pool).await? {
+    ///     println!("Found category: {}", category.name);
+    /// } else {
+    ///     println!("Category not found");
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
-        name = "Find all categories",
+        name = "Find category by URL slug",
         skip(pool),
+        fields(slug = %slug),
         err
     )]
-    pub async fn find_all(
+    pub async fn find_by_url_slug(
+        slug: &domain::UrlSlug,
         pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Vec<Self>> {
-        let categories = sqlx::query_as!(
+    ) -> DatabaseResult<Option<Self>> {
+        let category = sqlx::query_as!(
             database::Categories,
             r#"
                 SELECT
@@ -258,59 +327,113 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                ORDER BY created_on DESC
-            "#
+                WHERE url_slug = ? AND deleted_on IS NULL
+            "#,
+            slug
         )
-        .fetch_all(pool)
+        .fetch_optional(pool)
         .await?;
 
-        tracing::info!("Retrieved {} categories from database", categories.len());
+        Ok(category)
+    }
 
-        Ok(categories)
+    /// Returns `base`, or `base` with the first free `-2`, `-3`, ... suffix,
+    /// so a category can be stored with a slug no other category uses.
+    ///
+    /// Unlike [`Categories::find_by_url_slug`], soft-deleted categories count
+    /// as using their slug, since they still hold it in the unique index. The
+    /// slugs in use are read in one query and passed to
+    /// [`domain::UrlSlug::make_unique`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::Categories;
+    /// use personal_ledger_backend::domain::UrlSlug;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let base = UrlSlug::parse("Groceries")?;
+    /// let slug = Categories::unique_slug(&base, pool).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find unique category URL slug", skip(pool), fields(base = %base), err)]
+    pub async fn unique_slug(
+        base: &domain::UrlSlug,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<domain::UrlSlug> {
+        // Only the base and its suffixed forms can collide
+        let prefix = format!("{}-", base);
+        let taken: std::collections::HashSet<String> = sqlx::query_scalar!(
+            r#"
+                SELECT url_slug AS "url_slug!"
+                FROM categories
+                WHERE url_slug = ? OR substr(url_slug, 1, length(?)) = ?
+            "#,
+            base,
+            prefix,
+            prefix
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        Ok(domain::UrlSlug::make_unique(base, |slug| taken.contains(slug.as_str())))
     }
 
-    /// Retrieves all active categories from the database.
+    /// Finds a category by the identifier an external system assigned to it.
     ///
-    /// This function returns only categories that are marked as active (is_active = true),
-    /// ordered by creation date (newest first). This is useful for displaying categories
-    /// in user interfaces where inactive categories should be hidden.
+    /// Imports use this to recognise categories they created on an earlier
+    /// run. External ids are unique, so at most one category matches.
     ///
     /// # Arguments
     ///
+    /// * `external_id` - The external reference to look up
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns a vector of active categories, or a `DatabaseError` if the query fails.
+    /// Returns `Some(Category)` if a category has this external id, or `None` if not.
+    /// Returns a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use personal_ledger_backend::database::categories::Category;
-    /// use personal_ledger_backend::database::DatabasePool;
-    ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let active_categories = Category::find_all_active(pool).await?;
-    /// println!("Found {} active categories", active_categories.len());
+    /// use personal_ledger_backend::database::Categories;
+    /// use personal_ledger_backend::domain::ExternalRef;
     ///
-    /// for category in active_categories {
-    ///     println!("- {} ({})", category.name, category.code);
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let external_id = ExternalRef::parse("ynab:groceries")?;
+    /// if Categories::find_by_external_id(&external_id, pool).await?.is_none() {
+    ///     println!("Not imported yet");
     /// }
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
-        name = "Find all active categories",
+        name = "Find category by external id",
         skip(pool),
+        fields(external_id = %external_id),
         err
     )]
-    pub async fn find_all_active(
+    pub async fn find_by_external_id(
+        external_id: &domain::ExternalRef,
         pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Vec<Self>> {
-        let categories = sqlx::query_as!(
+    ) -> DatabaseResult<Option<Self>> {
+        let category = sqlx::query_as!(
             database::Categories,
             r#"
                 SELECT
@@ -323,60 +446,111 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE is_active = true
-                ORDER BY created_on DESC
-            "#
+                WHERE external_id = ? AND deleted_on IS NULL
+            "#,
+            external_id
         )
-        .fetch_all(pool)
+        .fetch_optional(pool)
         .await?;
 
-        tracing::info!("Retrieved {} active categories from database", categories.len());
+        Ok(category)
+    }
 
-        Ok(categories)
+    /// Finds a category from a value that may be either a URL slug or a code.
+    ///
+    /// The value is first parsed into a [`UrlSlug`](domain::UrlSlug) and looked
+    /// up by slug. If it is not a valid slug, or no category has that slug, the
+    /// raw value is looked up by code. Slugs take priority, so if one category's
+    /// slug equals another category's code the slug match is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A URL slug or category code
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((Category, CategoryMatchedBy))` naming which identifier
+    /// matched, or `None` if neither does. Returns a `DatabaseError` if a query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::{Categories, CategoryMatchedBy};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// match Categories::find_by_slug_or_code("groceries", pool).await? {
+    ///     Some((category, CategoryMatchedBy::Slug)) => println!("Slug match: {}", category.name),
+    ///     Some((category, CategoryMatchedBy::Code)) => println!("Code match: {}", category.name),
+    ///     None => println!("Category not found"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find category by slug or code",
+        skip(pool),
+        fields(value = %value),
+        err
+    )]
+    pub async fn find_by_slug_or_code(
+        value: &str,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Option<(Self, CategoryMatchedBy)>> {
+        if let Ok(slug) = domain::UrlSlug::parse(value)
+            && let Some(category) = Self::find_by_url_slug(&slug, pool).await?
+        {
+            return Ok(Some((category, CategoryMatchedBy::Slug)));
+        }
+
+        let category = Self::find_by_code(value, pool).await?;
+
+        Ok(category.map(|category| (category, CategoryMatchedBy::Code)))
     }
 
-    /// Retrieves all categories of a specific type.
+    /// Retrieves all categories from the database.
     ///
-    /// This function returns categories filtered by their category type (Expense or Income),
-    /// ordered by creation date (newest first). This is useful for separating expense
-    /// and income categories in financial applications.
+    /// This function returns all category records ordered by creation date (newest first).
+    /// Use this function when you need to display all categories or perform bulk operations.
     ///
     /// # Arguments
     ///
-    /// * `category_type` - The type of categories to retrieve
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns a vector of categories of the specified type, or a `DatabaseError` if the query fails.
+    /// Returns a vector of all categories, or a `DatabaseError` if the query fails.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// use personal_ledger_backend::database::categories::Category;
     /// use personal_ledger_backend::database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
     ///
     /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let expense_categories = Category::find_by_type(CategoryTypes::Expense, pool).await?;
-    /// println!("Found {} expense categories", expense_categories.len());
+    /// let all_categories = Category::find_all(pool).await?;
+    /// println!("Found {} categories", all_categories.len());
     ///
-    /// let income_categories = Category::find_by_type(CategoryTypes::Income, pool).await?;
-    /// println!("Found {} income categories", income_categories.len());
+    /// for category in all_categories {
+    ///     println!("- {} ({})", category.name, category.code);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
-        name = "Find categories by type",
+        name = "Find all categories",
         skip(pool),
-        fields(category_type = %category_type),
         err
     )]
-    pub async fn find_by_type(
-        category_type: domain::CategoryTypes,
+    pub async fn find_all(
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
         let categories = sqlx::query_as!(
@@ -392,58 +566,45 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE category_type = ?
-                ORDER BY created_on DESC
-            "#,
-            category_type
+                WHERE deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
+            "#
         )
         .fetch_all(pool)
         .await?;
 
-        tracing::info!("Retrieved {} categories of type {} from database", categories.len(), category_type);
+        tracing::info!("Retrieved {} categories from database", categories.len());
 
         Ok(categories)
     }
 
-    /// Retrieves all active categories of a specific type.
+    /// Retrieves all categories, including soft-deleted ones.
     ///
-    /// This function returns active categories filtered by their category type (Expense or Income),
-    /// ordered by creation date (newest first). This combines the filtering of `find_by_type`
-    /// and `find_all_active` for convenience.
+    /// Ordered like [`find_all`](Self::find_all), newest first. Soft-deleted
+    /// categories have `deleted_on` set.
     ///
     /// # Arguments
     ///
-    /// * `category_type` - The type of categories to retrieve
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns a vector of active categories of the specified type, or a `DatabaseError` if the query fails.
-    ///
-    /// # Examples
-    ///
-    /// ```rust,no_run
-    /// use personal_ledger_backend::database::categories::Category;
-    /// use personal_ledger_backend::database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
-    ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// let active_expense_categories = Category::find_active_by_type(CategoryTypes::Expense, pool).await?;
-    /// println!("Found {} active expense categories", active_expense_categories.len());
-    /// # Ok(())
-    /// # }
-    /// ```
+    /// Returns a vector of every stored category, or a `DatabaseError` if the
+    /// query fails.
     #[tracing::instrument(
-        name = "Find active categories by type",
+        name = "Find all categories including deleted",
         skip(pool),
-        fields(category_type = %category_type),
         err
     )]
-    pub async fn find_active_by_type(
-        category_type: domain::CategoryTypes,
+    pub async fn find_including_deleted(
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
         let categories = sqlx::query_as!(
@@ -459,73 +620,1065 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE category_type = ? AND is_active = true
-                ORDER BY created_on DESC
-            "#,
-            category_type
+                ORDER BY created_on DESC, id DESC
+            "#
         )
         .fetch_all(pool)
         .await?;
 
-        tracing::info!("Retrieved {} active categories of type {} from database", categories.len(), category_type);
-
         Ok(categories)
     }
 
-    /// Retrieves categories with flexible filtering, sorting, and pagination.
+    /// Returns `true` if any stored category uses `code`, soft-deleted or not.
     ///
-    /// This function provides comprehensive category listing with support for:
-    /// - Filtering by category type and active status
-    /// - Sorting by any field in ascending or descending order
-    /// - Pagination with offset and limit
+    /// The unique constraint on `code` covers soft-deleted rows too, so this
+    /// is the check to make before inserting a new code.
     ///
     /// # Arguments
     ///
-    /// * `category_type_filter` - Optional filter by category type
-    /// * `is_active_filter` - Optional filter by active status
-    /// * `sort_by` - Optional field to sort by (defaults to "created_on")
-    /// * `sort_desc` - Whether to sort in descending order (defaults to true)
-    /// * `offset` - Number of records to skip (for pagination)
-    /// * `limit` - Maximum number of records to return
+    /// * `code` - The category code to look up
     /// * `pool` - The database connection pool
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Returns a tuple of (categories, total_count) where total_count is the total
-    /// number of categories matching the filters (before pagination).
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Check category code in use", skip(pool), fields(code = %code), err)]
+    pub async fn code_in_use(code: &str, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<bool> {
+        let in_use = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM categories WHERE code = ?) AS "in_use!: bool""#,
+            code
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(in_use)
+    }
+
+    /// Returns `true` if a migration has created the `transactions` table.
     ///
-    /// # Examples
+    /// The table is unknown to the compile-time checked queries, so code
+    /// touching it looks it up at runtime and uses unchecked queries.
+    pub(super) async fn has_transactions_table<'e, E>(executor: E) -> DatabaseResult<bool>
+    where
+        E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+    {
+        let exists = sqlx::query_scalar!(
+            r#"
+                SELECT EXISTS (
+                    SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'transactions'
+                ) AS "exists!: bool"
+            "#
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(exists)
+    }
+
+    /// Retrieves all categories with the number of transactions referencing each.
     ///
-    /// ```rust,no_run
-    /// use personal_ledger_backend::database::categories::Category;
-    /// use personal_ledger_backend::database::DatabasePool;
-    /// use personal_ledger_backend::domain::CategoryTypes;
+    /// Counts come from a `LEFT JOIN` on `transactions.category_id`, so unused
+    /// categories count `0`. Until a migration creates the `transactions` table
+    /// the join is skipped and every count is `0`; the table is looked up at
+    /// runtime, as it is unknown to the compile-time checked queries.
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// // Get first 10 active expense categories, sorted by name
-    /// let (categories, total) = Category::find_with_filters(
-    ///     Some(CategoryTypes::Expense),
-    ///     Some(true),
-    ///     Some("name"),
-    ///     Some(false), // ascending
-    ///     0,
-    ///     10,
-    ///     pool
-    /// ).await?;
+    /// Categories are ordered newest first, as in [`find_all`](Self::find_all).
     ///
-    /// println!("Found {} of {} total categories", categories.len(), total);
-    /// # Ok(())
-    /// # }
-    /// ```
-    #[tracing::instrument(
-        name = "Find categories with filters",
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns each category paired with its usage count, or a `DatabaseError`
+    /// if the query fails.
+    #[tracing::instrument(
+        name = "Find all categories with usage counts",
+        skip(pool),
+        err
+    )]
+    pub async fn find_all_with_usage(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<(Self, i64)>> {
+        if !Self::has_transactions_table(pool).await? {
+            let categories = Self::find_all(pool).await?;
+            return Ok(categories.into_iter().map(|category| (category, 0)).collect());
+        }
+
+        let rows = sqlx::query(
+            r#"
+                SELECT categories.*, COUNT(transactions.category_id) AS usage_count
+                FROM categories
+                LEFT JOIN transactions ON transactions.category_id = categories.id
+                WHERE categories.deleted_on IS NULL
+                GROUP BY categories.id
+                ORDER BY categories.created_on DESC, categories.id DESC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let categories = rows
+            .iter()
+            .map(|row| {
+                use sqlx::{FromRow, Row};
+                Ok((Self::from_row(row)?, row.try_get("usage_count")?))
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+        tracing::info!("Retrieved usage counts for {} categories", categories.len());
+
+        Ok(categories)
+    }
+
+    /// Retrieves all categories in their user-defined display order.
+    ///
+    /// Categories are ordered by `display_order` ascending, with `code` as a
+    /// tiebreaker so categories sharing a position are returned consistently.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of all categories, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::Categories;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for category in Categories::find_all_ordered_by_display(pool).await? {
+    ///     println!("{}. {}", category.display_order, category.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find all categories ordered by display order",
+        skip(pool),
+        err
+    )]
+    pub async fn find_all_ordered_by_display(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE deleted_on IS NULL
+                ORDER BY display_order ASC, code ASC, id ASC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} categories in display order", categories.len());
+
+        Ok(categories)
+    }
+
+    /// Retrieves all categories nested by `parent_id`.
+    ///
+    /// The categories are loaded with one query and assembled in memory.
+    /// Categories without a parent, and orphans whose parent no longer exists,
+    /// are returned at the top level. Siblings keep their display order.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the top-level category nodes, or a `DatabaseError` if the query fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DatabaseError::Validation` naming the affected categories if the
+    /// hierarchy contains a cycle, rather than recursing forever.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::Categories;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// for node in Categories::find_all_as_tree(pool).await? {
+    ///     println!("{} ({} children)", node.category.name, node.children.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find all categories as tree",
+        skip(pool),
+        err
+    )]
+    pub async fn find_all_as_tree(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<CategoryNode>> {
+        let categories = Self::find_all_ordered_by_display(pool).await?;
+        Self::assemble_tree(categories)
+    }
+
+    /// Nest `categories` by `parent_id`, keeping their order among siblings.
+    fn assemble_tree(categories: Vec<Self>) -> DatabaseResult<Vec<CategoryNode>> {
+        let positions: HashMap<domain::RowID, usize> = categories
+            .iter()
+            .enumerate()
+            .map(|(position, category)| (category.id, position))
+            .collect();
+
+        let mut roots = Vec::new();
+        let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (position, category) in categories.iter().enumerate() {
+            match category.parent_id.and_then(|parent_id| positions.get(&parent_id)) {
+                Some(&parent) => children.entry(parent).or_default().push(position),
+                None => {
+                    if let Some(parent_id) = category.parent_id {
+                        tracing::warn!(
+                            "Category {} has missing parent {}, placing it at the top level",
+                            category.id,
+                            parent_id
+                        );
+                    }
+                    roots.push(position);
+                }
+            }
+        }
+
+        // Each category has one parent, so every category reachable from the top
+        // level is reached exactly once; anything left over sits on or under a cycle
+        let mut reached = vec![false; categories.len()];
+        let mut pending = roots.clone();
+        while let Some(position) = pending.pop() {
+            reached[position] = true;
+            pending.extend(children.get(&position).into_iter().flatten());
+        }
+        if reached.contains(&false) {
+            let unreachable = categories
+                .iter()
+                .zip(&reached)
+                .filter(|(_, reached)| !**reached)
+                .map(|(category, _)| category.id.to_string())
+                .collect::<Vec<_>>();
+            return Err(database::DatabaseError::Validation(format!(
+                "Category hierarchy contains a cycle through {}",
+                unreachable.join(", ")
+            )));
+        }
+
+        fn build(
+            position: usize,
+            slots: &mut [Option<database::Categories>],
+            children: &HashMap<usize, Vec<usize>>,
+        ) -> CategoryNode {
+            let category = slots[position].take().expect("tree positions are visited once");
+            let children = children
+                .get(&position)
+                .map(|child_positions| {
+                    child_positions.iter().map(|&child| build(child, slots, children)).collect()
+                })
+                .unwrap_or_default();
+            CategoryNode { category, children }
+        }
+
+        let mut slots: Vec<Option<Self>> = categories.into_iter().map(Some).collect();
+        Ok(roots.into_iter().map(|root| build(root, &mut slots, &children)).collect())
+    }
+
+    /// Streams all categories from the database one row at a time.
+    ///
+    /// Unlike [`find_all`](Self::find_all) this does not buffer the result set,
+    /// making it suitable for exports over very large category tables. Rows are
+    /// ordered by code so streamed output is stable between runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream yielding each category, or a `DatabaseError` for any row
+    /// that fails to fetch or decode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use personal_ledger_backend::database::Categories;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut rows = Categories::fetch_all_stream(pool);
+    /// while let Some(category) = rows.try_next().await? {
+    ///     println!("- {} ({})", category.name, category.code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_all_stream(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> futures_util::stream::BoxStream<'_, DatabaseResult<Self>> {
+        use futures_util::{StreamExt, TryStreamExt};
+
+        sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE deleted_on IS NULL
+                ORDER BY code ASC, id ASC
+            "#
+        )
+        .fetch(pool)
+        .map_err(database::DatabaseError::from)
+        .boxed()
+    }
+
+    /// Retrieves all active categories from the database.
+    ///
+    /// This function returns only categories that are marked as active (is_active = true),
+    /// ordered by creation date (newest first). This is useful for displaying categories
+    /// in user interfaces where inactive categories should be hidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of active categories, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let active_categories = Category::find_all_active(pool).await?;
+    /// println!("Found {} active categories", active_categories.len());
+    ///
+    /// for category in active_categories {
+    ///     println!("- {} ({})", category.name, category.code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find all active categories",
+        skip(pool),
+        err
+    )]
+    pub async fn find_all_active(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE is_active = true AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} active categories from database", categories.len());
+
+        Ok(categories)
+    }
+
+    /// Retrieves categories missing any of the `required` recommended fields,
+    /// for prompting the user to complete their setup.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - The fields a complete category must have
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the incomplete categories, newest first, or an empty vector
+    /// when no field is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::{Categories, RecommendedFields};
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let missing_icons = Categories::find_incomplete(
+    ///     RecommendedFields { color: false, icon: true, description: false },
+    ///     pool,
+    /// ).await?;
+    /// println!("{} categories have no icon", missing_icons.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(name = "Find incomplete categories", skip(pool), fields(required = ?required), err)]
+    pub async fn find_incomplete(
+        required: RecommendedFields,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE deleted_on IS NULL
+                  AND ((? AND color IS NULL)
+                   OR (? AND icon IS NULL)
+                   OR (? AND description IS NULL))
+                ORDER BY created_on DESC, id DESC
+            "#,
+            required.color,
+            required.icon,
+            required.description
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Found {} incomplete categories", categories.len());
+
+        Ok(categories)
+    }
+
+    /// Retrieves all categories of a specific type.
+    ///
+    /// This function returns categories filtered by their category type (Expense or Income),
+    /// ordered by creation date (newest first). This is useful for separating expense
+    /// and income categories in financial applications.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type` - The type of categories to retrieve
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of categories of the specified type, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let expense_categories = Category::find_by_type(CategoryTypes::Expense, pool).await?;
+    /// println!("Found {} expense categories", expense_categories.len());
+    ///
+    /// let income_categories = Category::find_by_type(CategoryTypes::Income, pool).await?;
+    /// println!("Found {} income categories", income_categories.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find categories by type",
+        skip(pool),
+        fields(category_type = %category_type),
+        err
+    )]
+    pub async fn find_by_type(
+        category_type: domain::CategoryTypes,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE category_type = ? AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
+            "#,
+            category_type
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} categories of type {} from database", categories.len(), category_type);
+
+        Ok(categories)
+    }
+
+    /// Retrieves all active categories of a specific type.
+    ///
+    /// This function returns active categories filtered by their category type (Expense or Income),
+    /// ordered by creation date (newest first). This combines the filtering of `find_by_type`
+    /// and `find_all_active` for convenience.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type` - The type of categories to retrieve
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of active categories of the specified type, or a `DatabaseError` if the query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let active_expense_categories = Category::find_active_by_type(CategoryTypes::Expense, pool).await?;
+    /// println!("Found {} active expense categories", active_expense_categories.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find active categories by type",
+        skip(pool),
+        fields(category_type = %category_type),
+        err
+    )]
+    pub async fn find_active_by_type(
+        category_type: domain::CategoryTypes,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE category_type = ? AND is_active = true AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
+            "#,
+            category_type
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} active categories of type {} from database", categories.len(), category_type);
+
+        Ok(categories)
+    }
+
+    /// Retrieves all categories sharing a color, ordered by name.
+    ///
+    /// The color is compared in its canonical form (`#RRGGBB`, upper case), so
+    /// `#ff8800` and `#FF8800` find the same categories, including rows whose
+    /// stored color was written in a different case.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to match
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of categories with the color, which may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::Categories;
+    /// use personal_ledger_backend::domain::HexColor;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let orange = HexColor::parse("#ff8800")?;
+    /// let project = Categories::find_by_color(&orange, pool).await?;
+    /// println!("Found {} orange categories", project.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find categories by color",
+        skip(pool),
+        fields(color = %color),
+        err
+    )]
+    pub async fn find_by_color(
+        color: &domain::HexColor,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let canonical = color.as_str();
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE UPPER(color) = ? AND deleted_on IS NULL
+                ORDER BY name ASC, id ASC
+            "#,
+            canonical
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} categories with color {} from database", categories.len(), color);
+
+        Ok(categories)
+    }
+
+    /// Finds categories whose name starts with `prefix`, for type-ahead search.
+    ///
+    /// `%` and `_` in the prefix are matched literally rather than as `LIKE`
+    /// wildcards. Matching follows SQLite's `LIKE`, so it ignores case for
+    /// ASCII letters. Soft-deleted categories are excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The start of the name, as typed by the user
+    /// * `limit` - The most categories to return; negative values return none
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the matching categories ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Search categories by name prefix", skip(pool), err)]
+    pub async fn search_by_name_prefix(
+        prefix: &str,
+        limit: i64,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let pattern = format!("{}%", escape_like(prefix));
+        let limit = limit.max(0);
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE name LIKE ? ESCAPE '\' AND deleted_on IS NULL
+                ORDER BY name ASC, id ASC
+                LIMIT ?
+            "#,
+            pattern,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        tracing::info!("Retrieved {} categories with name prefix '{}' from database", categories.len(), prefix);
+
+        Ok(categories)
+    }
+
+    /// Finds the lowest unused numeric code in the category type's range.
+    ///
+    /// Auto-generated codes follow the chart of accounts numbering scheme in
+    /// [`CategoryTypes::code_range`](domain::CategoryTypes::code_range). A code
+    /// counts as used if any category, of any type, has it, since codes are
+    /// unique across categories. Non-numeric codes are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type` - The category type whose range to search
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the next free code as a string, for example `"1003"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Validation`](database::DatabaseError::Validation)
+    /// if every code in the range is used, or an error if the query fails.
+    #[tracing::instrument(name = "Find next category code in range", skip(pool), err)]
+    pub async fn next_code_in_range(
+        category_type: domain::CategoryTypes,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<String> {
+        Self::next_unused_code(category_type.code_range(), pool).await
+    }
+
+    /// Finds the lowest code in `range` not used by any category.
+    async fn next_unused_code(
+        range: std::ops::RangeInclusive<u32>,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<String> {
+        let (start, end) = (i64::from(*range.start()), i64::from(*range.end()));
+
+        // Only canonical numbers count, so "01000" does not reserve "1000"
+        let used_codes = sqlx::query_scalar!(
+            r#"
+                SELECT CAST(code AS INTEGER) AS "code!: i64"
+                FROM categories
+                WHERE CAST(CAST(code AS INTEGER) AS TEXT) = code
+                  AND CAST(code AS INTEGER) BETWEEN ?1 AND ?2
+            "#,
+            start,
+            end
+        )
+        .fetch_all(pool)
+        .await?;
+        let used_codes: std::collections::HashSet<i64> = used_codes.into_iter().collect();
+
+        (start..=end)
+            .find(|code| !used_codes.contains(code))
+            .map(|code| code.to_string())
+            .ok_or_else(|| {
+                database::DatabaseError::Validation(format!("No unused category codes left in {}-{}", start, end))
+            })
+    }
+
+    /// Counts all categories without loading them. Soft-deleted categories
+    /// are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of stored categories, active or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Count categories", skip(pool), err)]
+    pub async fn count(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<i64> {
+        let count = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!: i64" FROM categories WHERE deleted_on IS NULL"#)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Counts the active categories without loading them. Soft-deleted
+    /// categories are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of categories with `is_active = true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Count active categories", skip(pool), err)]
+    pub async fn count_active(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<i64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!: i64" FROM categories WHERE is_active = true AND deleted_on IS NULL"#
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Counts the categories of one type without loading them. Soft-deleted
+    /// categories are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type` - The category type to count
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of stored categories of `category_type`, active or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Count categories by type", skip(pool), fields(category_type = %category_type), err)]
+    pub async fn count_by_type(
+        category_type: domain::CategoryTypes,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<i64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!: i64" FROM categories WHERE category_type = ? AND deleted_on IS NULL"#,
+            category_type
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Counts the categories created at or after `since` without loading them.
+    /// Soft-deleted categories are not counted.
+    ///
+    /// Filters on `created_on` rather than the time embedded in the `RowID`,
+    /// since imported categories keep their original creation time.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - The earliest creation time to count
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of categories with `created_on >= since`, `0` when
+    /// `since` is in the future.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Count categories created since", skip(pool), fields(since = %since), err)]
+    pub async fn count_since(since: chrono::DateTime<chrono::Utc>, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<i64> {
+        let count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!: i64" FROM categories WHERE julianday(created_on) >= julianday(?) AND deleted_on IS NULL"#,
+            since
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Counts categories per category type and active status, for summary badges.
+    ///
+    /// Uses a single grouped `COUNT(*)` query instead of loading the rows. Every
+    /// combination of [`CategoryTypes::all`](domain::CategoryTypes::all) and
+    /// active status is present in the result, with `0` for empty buckets.
+    /// Soft-deleted categories are not counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a map from `(category_type, is_active)` to the number of categories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Count categories by active status and type", skip(pool), err)]
+    pub async fn count_by_active_and_type(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<HashMap<(domain::CategoryTypes, bool), i64>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    is_active       AS "is_active!: bool",
+                    COUNT(*)        AS "count!: i64"
+                FROM categories
+                WHERE deleted_on IS NULL
+                GROUP BY category_type, is_active
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut counts: HashMap<(domain::CategoryTypes, bool), i64> = domain::CategoryTypes::all()
+            .iter()
+            .flat_map(|category_type| [((*category_type, true), 0), ((*category_type, false), 0)])
+            .collect();
+        for row in rows {
+            counts.insert((row.category_type, row.is_active), row.count);
+        }
+
+        Ok(counts)
+    }
+
+    /// Retrieves categories with flexible filtering, sorting, and pagination.
+    ///
+    /// This function provides comprehensive category listing with support for:
+    /// - Filtering by category type, active status and tag
+    /// - Sorting by any field in ascending or descending order
+    /// - Pagination with offset and limit
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type_filter` - Optional filter by category type
+    /// * `is_active_filter` - Optional filter by active status
+    /// * `tag_filter` - Optional tag the categories must carry
+    /// * `sort_by` - Optional field to sort by (defaults to "created_on")
+    /// * `sort_desc` - Whether to sort in descending order (defaults to true)
+    /// * `offset` - Number of records to skip (for pagination)
+    /// * `limit` - Maximum number of records to return
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a tuple of (categories, total_count) where total_count is the total
+    /// number of categories matching the filters (before pagination).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Get first 10 active expense categories, sorted by name
+    /// let (categories, total) = Category::find_with_filters(
+    ///     Some(CategoryTypes::Expense),
+    ///     Some(true),
+    ///     None, // any tags
+    ///     Some("name"),
+    ///     Some(false), // ascending
+    ///     0,
+    ///     10,
+    ///     pool
+    /// ).await?;
+    ///
+    /// println!("Found {} of {} total categories", categories.len(), total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Find categories with filters",
         skip(pool),
         fields(
             category_type = ?category_type_filter,
             is_active = ?is_active_filter,
+            tag = ?tag_filter,
             sort_by = ?sort_by,
             sort_desc = ?sort_desc,
             offset = %offset,
@@ -533,9 +1686,11 @@ impl database::Categories {
         ),
         err
     )]
+    #[allow(clippy::too_many_arguments)]
     pub async fn find_with_filters(
         category_type_filter: Option<domain::CategoryTypes>,
         is_active_filter: Option<bool>,
+        tag_filter: Option<&domain::Tag>,
         sort_by: Option<&str>,
         sort_desc: Option<bool>,
         offset: i32,
@@ -545,9 +1700,14 @@ impl database::Categories {
         // For now, implement a simpler version that handles the most common cases
         // TODO: Implement full dynamic filtering when needed
 
+        if let Some(tag) = tag_filter {
+            return Self::find_by_tag_with_pagination(tag, category_type_filter, is_active_filter, offset, limit, pool)
+                .await;
+        }
+
         let (categories, total_count) = match (category_type_filter, is_active_filter) {
-            (Some(category_type), Some(_is_active)) => {
-                Self::find_active_by_type_with_pagination(category_type, offset, limit, pool).await?
+            (Some(category_type), Some(is_active)) => {
+                Self::find_by_type_and_status_with_pagination(category_type, is_active, offset, limit, pool).await?
             }
             (Some(category_type), None) => {
                 Self::find_by_type_with_pagination(category_type, offset, limit, pool).await?
@@ -564,7 +1724,156 @@ impl database::Categories {
             }
         };
 
-        Ok((categories, total_count))
+        Ok((categories, total_count))
+    }
+
+    /// Retrieves a page of categories in creation order, for walking the
+    /// whole table in bounded batches.
+    ///
+    /// Pages are keyed on `(created_on, id)` rather than an offset, so rows
+    /// inserted or deleted between pages neither repeat nor skip others. Pass
+    /// the `created_on` and `id` of the last row of one page as `after` to
+    /// fetch the next. Soft-deleted categories are excluded.
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The `created_on` and `id` of the last category already
+    ///   read, or `None` for the first page
+    /// * `limit` - Maximum number of categories to return
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `limit` categories ordered by `created_on`, then `id`.
+    /// A page shorter than `limit` is the last.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(name = "Find page of categories by creation", skip(pool), err)]
+    pub async fn find_page_by_created(
+        after: Option<(chrono::DateTime<chrono::Utc>, domain::RowID)>,
+        limit: i64,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let (after_created_on, after_id) = after.unzip();
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE deleted_on IS NULL
+                  AND (?1 IS NULL OR (created_on, id) > (?1, ?2))
+                ORDER BY created_on ASC, id ASC
+                LIMIT ?3
+            "#,
+            after_created_on,
+            after_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    /// Retrieves the page of categories that follows `after`, for cursor pagination.
+    ///
+    /// Rows are in the same order as [`find_with_filters`](Self::find_with_filters)
+    /// (newest `created_on` first, ties broken by `id`), so a cursor taken from
+    /// the last row of an offset page continues where that page stopped.
+    ///
+    /// # Arguments
+    ///
+    /// * `category_type_filter` - Optional filter by category type
+    /// * `is_active_filter` - Optional filter by active status
+    /// * `tag_filter` - Optional tag the categories must carry
+    /// * `after` - `created_on` and ID of the last category on the previous
+    ///   page. The category need not exist any more, so a cursor survives its
+    ///   row being deleted.
+    /// * `limit` - Maximum number of records to return
+    /// * `pool` - The database connection pool
+    ///
+    /// # Errors
+    ///
+    /// Returns a database error if the query fails.
+    #[tracing::instrument(
+        name = "Find categories with filters after cursor",
+        skip(pool),
+        fields(
+            category_type = ?category_type_filter,
+            is_active = ?is_active_filter,
+            tag = ?tag_filter,
+            after = ?after,
+            limit = %limit
+        ),
+        err
+    )]
+    pub async fn find_with_filters_after(
+        category_type_filter: Option<domain::CategoryTypes>,
+        is_active_filter: Option<bool>,
+        tag_filter: Option<&domain::Tag>,
+        after: (chrono::DateTime<chrono::Utc>, domain::RowID),
+        limit: i32,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let (after_created_on, after_id) = after;
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE deleted_on IS NULL
+                  AND (?1 IS NULL OR category_type = ?1)
+                  AND (?2 IS NULL OR is_active = ?2)
+                  AND (?5 IS NULL OR id IN (SELECT category_id FROM category_tags WHERE tag = ?5))
+                  AND (created_on, id) < (?3, ?6)
+                ORDER BY created_on DESC, id DESC
+                LIMIT ?4
+            "#,
+            category_type_filter,
+            is_active_filter,
+            after_created_on,
+            limit,
+            tag_filter,
+            after_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(categories)
     }
 
     /// Helper method to find all categories with pagination
@@ -573,7 +1882,7 @@ impl database::Categories {
         limit: i32,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories")
+        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE deleted_on IS NULL")
             .fetch_one(pool)
             .await?;
 
@@ -590,10 +1899,16 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                ORDER BY created_on DESC
+                WHERE deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
                 LIMIT ? OFFSET ?
             "#,
             limit,
@@ -611,7 +1926,7 @@ impl database::Categories {
         limit: i32,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = true")
+        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = true AND deleted_on IS NULL")
             .fetch_one(pool)
             .await?;
 
@@ -628,11 +1943,16 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE is_active = true
-                ORDER BY created_on DESC
+                WHERE is_active = true AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
                 LIMIT ? OFFSET ?
             "#,
             limit,
@@ -650,7 +1970,7 @@ impl database::Categories {
         limit: i32,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = false")
+        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE is_active = false AND deleted_on IS NULL")
             .fetch_one(pool)
             .await?;
 
@@ -667,11 +1987,16 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE is_active = false
-                ORDER BY created_on DESC
+                WHERE is_active = false AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
                 LIMIT ? OFFSET ?
             "#,
             limit,
@@ -690,8 +2015,8 @@ impl database::Categories {
         limit: i32,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ?")
-            .bind(&category_type)
+        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ? AND deleted_on IS NULL")
+            .bind(category_type)
             .fetch_one(pool)
             .await?;
 
@@ -708,11 +2033,16 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE category_type = ?
-                ORDER BY created_on DESC
+                WHERE category_type = ? AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
                 LIMIT ? OFFSET ?
             "#,
             category_type,
@@ -725,15 +2055,17 @@ impl database::Categories {
         Ok((categories, total_count))
     }
 
-    /// Helper method to find active categories by type with pagination
-    async fn find_active_by_type_with_pagination(
+    /// Helper method to find categories by type and active status with pagination
+    async fn find_by_type_and_status_with_pagination(
         category_type: domain::CategoryTypes,
+        is_active: bool,
         offset: i32,
         limit: i32,
         pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<(Vec<Self>, i32)> {
-        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ? AND is_active = true")
-            .bind(&category_type)
+        let total_count: i32 = sqlx::query_scalar("SELECT COUNT(*) as count FROM categories WHERE category_type = ? AND is_active = ? AND deleted_on IS NULL")
+            .bind(category_type)
+            .bind(is_active)
             .fetch_one(pool)
             .await?;
 
@@ -750,14 +2082,20 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE category_type = ? AND is_active = true
-                ORDER BY created_on DESC
+                WHERE category_type = ? AND is_active = ? AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
                 LIMIT ? OFFSET ?
             "#,
             category_type,
+            is_active,
             limit,
             offset
         )
@@ -768,6 +2106,19 @@ impl database::Categories {
     }
 }
 
+/// Escape `LIKE` wildcards, and the `\` escape character itself, so `value`
+/// matches literally in a pattern using `ESCAPE '\'`.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -874,6 +2225,40 @@ mod tests {
         assert_eq!(result.unwrap().id, category.id);
     }
 
+    #[sqlx::test]
+    async fn test_find_by_code_ci_ignores_case(pool: SqlitePool) {
+        let mut category = database::Categories::mock();
+        category.code = "FOOD.001".to_string();
+        database::Categories::insert(&category, &pool).await.unwrap();
+
+        for code in ["food.001", "Food.001", "FOOD.001"] {
+            let found = database::Categories::find_by_code_ci(code, &pool).await.unwrap();
+            assert_eq!(found.map(|found| found.id), Some(category.id), "{}", code);
+        }
+
+        // The exact match is unchanged
+        assert!(database::Categories::find_by_code("food.001", &pool).await.unwrap().is_none());
+        assert!(database::Categories::find_by_code_ci("FOOD.002", &pool).await.unwrap().is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_code_ci_returns_first_created_on_collision(pool: SqlitePool) {
+        let now = chrono::Utc::now();
+
+        let mut newer = database::Categories::mock();
+        newer.code = "food.001".to_string();
+        newer.created_on = now;
+        database::Categories::insert(&newer, &pool).await.unwrap();
+
+        let mut older = database::Categories::mock();
+        older.code = "FOOD.001".to_string();
+        older.created_on = now - chrono::Duration::days(1);
+        database::Categories::insert(&older, &pool).await.unwrap();
+
+        let found = database::Categories::find_by_code_ci("Food.001", &pool).await.unwrap();
+        assert_eq!(found.map(|found| found.id), Some(older.id));
+    }
+
     #[sqlx::test]
     async fn test_find_by_url_slug_existing_category(pool: SqlitePool) {
         // Create a test category
@@ -902,6 +2287,28 @@ mod tests {
         }
     }
 
+    #[sqlx::test]
+    async fn test_unique_slug_suffixes_colliding_slug(pool: SqlitePool) {
+        let base = domain::UrlSlug::parse("weekly-groceries").unwrap();
+        assert_eq!(database::Categories::unique_slug(&base, &pool).await.unwrap(), base);
+
+        let first = database::Categories { url_slug: Some(base.clone()), ..database::Categories::mock() };
+        first.insert(&pool).await.unwrap();
+        let suffixed = database::Categories::unique_slug(&base, &pool).await.unwrap();
+        assert_eq!(suffixed.as_str(), "weekly-groceries-2");
+
+        // The suffixed slug can be stored, and a soft-deleted holder still counts
+        let second = database::Categories { url_slug: Some(suffixed), ..database::Categories::mock() };
+        let second = second.insert(&pool).await.unwrap();
+        database::Categories::soft_delete(second.id, &pool).await.unwrap();
+        let unique = database::Categories::unique_slug(&base, &pool).await.unwrap();
+        assert_eq!(unique.as_str(), "weekly-groceries-3");
+
+        // Another category's slug starting with the base does not make the base taken
+        let other = domain::UrlSlug::parse("weekly").unwrap();
+        assert_eq!(database::Categories::unique_slug(&other, &pool).await.unwrap(), other);
+    }
+
     #[sqlx::test]
     async fn test_find_by_url_slug_nonexistent_category(pool: SqlitePool) {
         // Try to find a category with a slug that doesn't exist
@@ -912,6 +2319,121 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[sqlx::test]
+    async fn test_find_by_slug_or_code_matches_slug(pool: SqlitePool) {
+        let slug = domain::UrlSlug::parse("weekly-groceries").unwrap();
+        let category = database::Categories {
+            url_slug: Some(slug.clone()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+
+        let (found, matched_by) = database::Categories::find_by_slug_or_code(slug.as_str(), &pool)
+            .await
+            .unwrap()
+            .expect("slug should match");
+
+        assert_eq!(found.id, category.id);
+        assert_eq!(matched_by, CategoryMatchedBy::Slug);
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_slug_or_code_falls_back_to_code(pool: SqlitePool) {
+        let category = database::Categories {
+            url_slug: None,
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+
+        let (found, matched_by) = database::Categories::find_by_slug_or_code(&category.code, &pool)
+            .await
+            .unwrap()
+            .expect("code should match");
+
+        assert_eq!(found.id, category.id);
+        assert_eq!(matched_by, CategoryMatchedBy::Code);
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_slug_or_code_prefers_slug(pool: SqlitePool) {
+        // One category's slug equals another category's code
+        let by_code = database::Categories {
+            code: "shared-value".to_string(),
+            url_slug: None,
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        let by_slug = database::Categories {
+            url_slug: Some(domain::UrlSlug::parse("shared-value").unwrap()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+
+        let (found, matched_by) = database::Categories::find_by_slug_or_code(&by_code.code, &pool)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(found.id, by_slug.id);
+        assert_eq!(matched_by, CategoryMatchedBy::Slug);
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_slug_or_code_matches_neither(pool: SqlitePool) {
+        create_test_category(&pool).await;
+
+        let result = database::Categories::find_by_slug_or_code("no-such-category", &pool)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+
+        // Values that are not valid slugs still fall through to the code lookup
+        let result = database::Categories::find_by_slug_or_code("!!!", &pool).await.unwrap();
+        assert!(result.is_none());
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_external_id(pool: SqlitePool) {
+        let external_id = domain::ExternalRef::parse("ynab:category-1").unwrap();
+        let category = database::Categories {
+            external_id: Some(external_id.clone()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+        assert_eq!(category.external_id.as_ref(), Some(&external_id));
+
+        let found = database::Categories::find_by_external_id(&external_id, &pool)
+            .await
+            .unwrap()
+            .expect("external id should match");
+        assert_eq!(found, category);
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_external_id_nonexistent(pool: SqlitePool) {
+        database::Categories {
+            external_id: Some(domain::ExternalRef::parse("bank:1").unwrap()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await
+        .unwrap();
+
+        let missing = domain::ExternalRef::parse("bank:2").unwrap();
+        let result = database::Categories::find_by_external_id(&missing, &pool).await.unwrap();
+        assert!(result.is_none());
+    }
+
     #[sqlx::test]
     async fn test_find_all_with_categories(pool: SqlitePool) {
         // Create some test categories
@@ -939,6 +2461,39 @@ mod tests {
         assert!(all_categories.is_empty());
     }
 
+    #[sqlx::test]
+    async fn test_fetch_all_stream_yields_every_category_ordered_by_code(pool: SqlitePool) {
+        use futures_util::TryStreamExt;
+
+        let created = create_test_categories(5, &pool).await;
+
+        let streamed: Vec<database::Categories> = database::Categories::fetch_all_stream(&pool)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(streamed.len(), created.len());
+        let codes: Vec<&str> = streamed.iter().map(|c| c.code.as_str()).collect();
+        let mut sorted_codes = codes.clone();
+        sorted_codes.sort();
+        assert_eq!(codes, sorted_codes);
+        for category in &created {
+            assert!(streamed.iter().any(|c| c.id == category.id));
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_fetch_all_stream_empty_database(pool: SqlitePool) {
+        use futures_util::TryStreamExt;
+
+        let streamed: Vec<database::Categories> = database::Categories::fetch_all_stream(&pool)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(streamed.is_empty());
+    }
+
     #[sqlx::test]
     async fn test_find_all_active_with_mixed_categories(pool: SqlitePool) {
         // Create test categories (some active, some inactive)
@@ -980,8 +2535,13 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: false, // Inactive
+                display_order: 0,
+                external_id: None,
+                reactivate_on: None,
+                parent_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
             };
             database::Categories::insert(&category, &pool).await.unwrap();
             inactive_categories.push(category);
@@ -1048,8 +2608,13 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: true,
+                display_order: 0,
+                external_id: None,
+                reactivate_on: None,
+                parent_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
             };
             database::Categories::insert(&category, &pool).await.unwrap();
         }
@@ -1111,8 +2676,13 @@ mod tests {
                 color: domain::HexColor::mock_with_option(),
                 icon: Some("test-icon".to_string()),
                 is_active: false, // Inactive
+                display_order: 0,
+                external_id: None,
+                reactivate_on: None,
+                parent_id: None,
                 created_on: chrono::Utc::now(),
                 updated_on: chrono::Utc::now(),
+                deleted_on: None,
             };
             database::Categories::insert(&category, &pool).await.unwrap();
         }
@@ -1123,4 +2693,549 @@ mod tests {
         // Should return empty vector
         assert!(active_income_categories.is_empty());
     }
-}
\ No newline at end of file
+
+    #[sqlx::test]
+    async fn test_count_active_by_type_and_by_active_and_type(pool: SqlitePool) {
+        use domain::CategoryTypes::{Asset, Expense, Income};
+
+        // Known distribution: no Liability or Equity categories at all, and no
+        // inactive Asset categories
+        let distribution = [
+            (Expense, true, 3),
+            (Expense, false, 2),
+            (Income, true, 1),
+            (Income, false, 4),
+            (Asset, true, 2),
+        ];
+        let mut index = 0;
+        for (category_type, is_active, count) in distribution {
+            for _ in 0..count {
+                let mut category = database::Categories::mock();
+                category.code = format!("COUNT.{:03}", index);
+                category.name = format!("Count Category {}", index);
+                category.url_slug = None;
+                category.external_id = None;
+                category.category_type = category_type;
+                category.is_active = is_active;
+                database::Categories::insert(&category, &pool).await.unwrap();
+                index += 1;
+            }
+        }
+
+        assert_eq!(database::Categories::count(&pool).await.unwrap(), 12);
+        assert_eq!(database::Categories::count_active(&pool).await.unwrap(), 6);
+        for category_type in domain::CategoryTypes::all() {
+            let expected: i64 = distribution
+                .iter()
+                .filter(|(t, _, _)| t == category_type)
+                .map(|(_, _, count)| *count)
+                .sum();
+            assert_eq!(
+                database::Categories::count_by_type(*category_type, &pool).await.unwrap(),
+                expected,
+                "{:?}", category_type
+            );
+        }
+
+        let counts = database::Categories::count_by_active_and_type(&pool).await.unwrap();
+        assert_eq!(counts.len(), domain::CategoryTypes::all().len() * 2);
+        for category_type in domain::CategoryTypes::all() {
+            for is_active in [true, false] {
+                let expected = distribution
+                    .iter()
+                    .find(|(t, a, _)| t == category_type && *a == is_active)
+                    .map_or(0, |(_, _, count)| *count);
+                assert_eq!(
+                    counts[&(*category_type, is_active)], expected,
+                    "{:?} active={}", category_type, is_active
+                );
+            }
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_count_since(pool: SqlitePool) {
+        let base = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2025, 6, 1, 0, 0, 0).unwrap();
+        for (index, days) in [0, 10, 20, 30].into_iter().enumerate() {
+            let mut category = database::Categories::mock();
+            category.code = format!("SINCE.{:03}", index);
+            category.name = format!("Since Category {}", index);
+            category.url_slug = None;
+            category.external_id = None;
+            category.created_on = base + chrono::Duration::days(days);
+            category.updated_on = category.created_on;
+            database::Categories::insert(&category, &pool).await.unwrap();
+        }
+
+        let count_since = |since| database::Categories::count_since(since, &pool);
+        assert_eq!(count_since(base - chrono::Duration::days(1)).await.unwrap(), 4);
+
+        // The boundary is inclusive
+        assert_eq!(count_since(base).await.unwrap(), 4);
+        assert_eq!(count_since(base + chrono::Duration::days(10)).await.unwrap(), 3);
+        assert_eq!(count_since(base + chrono::Duration::seconds(10 * 86_400 + 1)).await.unwrap(), 2);
+        assert_eq!(count_since(base + chrono::Duration::days(30)).await.unwrap(), 1);
+
+        // Nothing was created in the future
+        assert_eq!(count_since(chrono::Utc::now() + chrono::Duration::days(1)).await.unwrap(), 0);
+    }
+
+    #[sqlx::test]
+    async fn test_counts_exclude_soft_deleted(pool: SqlitePool) {
+        let base = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2025, 6, 1, 0, 0, 0).unwrap();
+        let mut ids = Vec::new();
+        for index in 0..3 {
+            let mut category = database::Categories::mock();
+            category.code = format!("SOFT.{:03}", index);
+            category.name = format!("Soft Category {}", index);
+            category.url_slug = None;
+            category.external_id = None;
+            category.category_type = domain::CategoryTypes::Expense;
+            category.is_active = true;
+            category.created_on = base;
+            category.updated_on = base;
+            ids.push(database::Categories::insert(&category, &pool).await.unwrap().id);
+        }
+
+        let since = base - chrono::Duration::days(1);
+        let active_expense = (domain::CategoryTypes::Expense, true);
+        assert_eq!(database::Categories::count(&pool).await.unwrap(), 3);
+        assert_eq!(database::Categories::count_active(&pool).await.unwrap(), 3);
+        assert_eq!(database::Categories::count_since(since, &pool).await.unwrap(), 3);
+        assert_eq!(database::Categories::count_by_active_and_type(&pool).await.unwrap()[&active_expense], 3);
+
+        database::Categories::soft_delete(ids[0], &pool).await.unwrap();
+
+        assert_eq!(database::Categories::count(&pool).await.unwrap(), 2);
+        assert_eq!(database::Categories::count_active(&pool).await.unwrap(), 2);
+        assert_eq!(database::Categories::count_since(since, &pool).await.unwrap(), 2);
+        assert_eq!(database::Categories::count_by_active_and_type(&pool).await.unwrap()[&active_expense], 2);
+    }
+
+    #[sqlx::test]
+    async fn test_count_by_type_excludes_soft_deleted(pool: SqlitePool) {
+        let mut ids = Vec::new();
+        for index in 0..2 {
+            let mut category = database::Categories::mock();
+            category.code = format!("SOFT.TYPE.{:03}", index);
+            category.name = format!("Soft Type Category {}", index);
+            category.url_slug = None;
+            category.external_id = None;
+            category.category_type = domain::CategoryTypes::Income;
+            ids.push(database::Categories::insert(&category, &pool).await.unwrap().id);
+        }
+
+        let count_income = || database::Categories::count_by_type(domain::CategoryTypes::Income, &pool);
+        assert_eq!(count_income().await.unwrap(), 2);
+
+        database::Categories::soft_delete(ids[0], &pool).await.unwrap();
+        assert_eq!(count_income().await.unwrap(), 1);
+    }
+
+    #[sqlx::test]
+    async fn test_find_by_color(pool: SqlitePool) {
+        let colors = [
+            ("TEAL.B", "Teal B", Some("#00AACC")),
+            ("TEAL.A", "Teal A", Some("#00AACC")),
+            ("ORANGE", "Orange", Some("#FF8800")),
+            ("NO.COLOR", "No Color", None),
+        ];
+        for (code, name, color) in colors {
+            let mut category = database::Categories::mock();
+            category.code = code.to_string();
+            category.name = name.to_string();
+            category.url_slug = None;
+            category.external_id = None;
+            category.color = color.map(|color| domain::HexColor::parse(color).unwrap());
+            database::Categories::insert(&category, &pool).await.unwrap();
+        }
+
+        // A row written in lower case, outside HexColor
+        let mut legacy = database::Categories::mock();
+        legacy.code = "TEAL.LEGACY".to_string();
+        legacy.name = "Teal Legacy".to_string();
+        legacy.url_slug = None;
+        legacy.external_id = None;
+        database::Categories::insert(&legacy, &pool).await.unwrap();
+        sqlx::query("UPDATE categories SET color = '#00aacc' WHERE code = 'TEAL.LEGACY'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let names = |found: Vec<database::Categories>| found.into_iter().map(|category| category.name).collect::<Vec<_>>();
+
+        let teal = domain::HexColor::parse("#00aaCC").unwrap();
+        let found = database::Categories::find_by_color(&teal, &pool).await.unwrap();
+        assert_eq!(names(found), ["Teal A", "Teal B", "Teal Legacy"]);
+
+        let orange = domain::HexColor::parse("ff8800").unwrap();
+        let found = database::Categories::find_by_color(&orange, &pool).await.unwrap();
+        assert_eq!(names(found), ["Orange"]);
+
+        let unused = domain::HexColor::parse("#123456").unwrap();
+        assert!(database::Categories::find_by_color(&unused, &pool).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_find_incomplete(pool: SqlitePool) {
+        let fields = [
+            ("COMPLETE", true, true, true),
+            ("NO.COLOR", false, true, true),
+            ("NO.ICON", true, false, true),
+            ("NO.DESCRIPTION", true, true, false),
+        ];
+        for (code, color, icon, description) in fields {
+            let mut category = database::Categories::mock();
+            category.code = code.to_string();
+            category.name = format!("Incomplete {}", code);
+            category.url_slug = None;
+            category.external_id = None;
+            category.color = color.then(|| domain::HexColor::parse("#336699").unwrap());
+            category.icon = icon.then(|| "star".to_string());
+            category.description = description.then(|| "Has a description".to_string());
+            database::Categories::insert(&category, &pool).await.unwrap();
+        }
+
+        let pool = &pool;
+        let incomplete_codes = |required| async move {
+            let mut codes: Vec<String> = database::Categories::find_incomplete(required, pool)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|category| category.code)
+                .collect();
+            codes.sort();
+            codes
+        };
+
+        assert_eq!(incomplete_codes(RecommendedFields::all()).await, ["NO.COLOR", "NO.DESCRIPTION", "NO.ICON"]);
+        assert_eq!(
+            incomplete_codes(RecommendedFields { color: false, icon: true, description: false }).await,
+            ["NO.ICON"]
+        );
+        assert_eq!(
+            incomplete_codes(RecommendedFields { color: true, icon: false, description: true }).await,
+            ["NO.COLOR", "NO.DESCRIPTION"]
+        );
+        assert!(incomplete_codes(RecommendedFields { color: false, icon: false, description: false }).await.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_code_in_use_includes_soft_deleted(pool: SqlitePool) {
+        let mut category = database::Categories::mock();
+        category.code = "IN.USE".to_string();
+        let category = database::Categories::insert(&category, &pool).await.unwrap();
+
+        assert!(database::Categories::code_in_use("IN.USE", &pool).await.unwrap());
+        assert!(!database::Categories::code_in_use("NOT.USED", &pool).await.unwrap());
+
+        database::Categories::soft_delete(category.id, &pool).await.unwrap();
+        assert!(database::Categories::code_in_use("IN.USE", &pool).await.unwrap());
+    }
+
+    #[sqlx::test]
+    async fn test_counts_on_empty_table(pool: SqlitePool) {
+        assert_eq!(database::Categories::count(&pool).await.unwrap(), 0);
+        assert_eq!(database::Categories::count_active(&pool).await.unwrap(), 0);
+        assert_eq!(database::Categories::count_by_type(domain::CategoryTypes::Expense, &pool).await.unwrap(), 0);
+
+        let counts = database::Categories::count_by_active_and_type(&pool).await.unwrap();
+        assert_eq!(counts.len(), domain::CategoryTypes::all().len() * 2);
+        assert!(counts.values().all(|count| *count == 0));
+    }
+
+    /// Insert a category with the given code
+    async fn insert_with_code(code: &str, category_type: domain::CategoryTypes, pool: &SqlitePool) {
+        let mut category = database::Categories::mock();
+        category.code = code.to_string();
+        category.name = format!("Code {}", code);
+        category.url_slug = None;
+        category.external_id = None;
+        category.category_type = category_type;
+        database::Categories::insert(&category, pool).await.unwrap();
+    }
+
+    #[sqlx::test]
+    async fn test_next_code_in_range_starts_at_range_start(pool: SqlitePool) {
+        for category_type in domain::CategoryTypes::all() {
+            let code = database::Categories::next_code_in_range(*category_type, &pool).await.unwrap();
+            assert_eq!(code, category_type.code_range().start().to_string());
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_next_code_in_range_skips_used_codes(pool: SqlitePool) {
+        use domain::CategoryTypes::{Asset, Expense};
+
+        insert_with_code("1000", Asset, &pool).await;
+        insert_with_code("1001", Asset, &pool).await;
+        insert_with_code("1003", Asset, &pool).await;
+        // Codes count as used whatever the category type, and non-canonical
+        // or non-numeric codes are ignored
+        insert_with_code("5000", Asset, &pool).await;
+        insert_with_code("05001", Expense, &pool).await;
+        insert_with_code("EXP.5001", Expense, &pool).await;
+
+        assert_eq!(database::Categories::next_code_in_range(Asset, &pool).await.unwrap(), "1002");
+        insert_with_code("1002", Asset, &pool).await;
+        assert_eq!(database::Categories::next_code_in_range(Asset, &pool).await.unwrap(), "1004");
+
+        assert_eq!(database::Categories::next_code_in_range(Expense, &pool).await.unwrap(), "5001");
+    }
+
+    #[sqlx::test]
+    async fn test_next_code_errors_when_range_exhausted(pool: SqlitePool) {
+        for code in ["7000", "7001", "7002"] {
+            insert_with_code(code, domain::CategoryTypes::Expense, &pool).await;
+        }
+
+        let result = database::Categories::next_unused_code(7000..=7002, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::Validation(_))), "{:?}", result);
+
+        assert_eq!(database::Categories::next_unused_code(7000..=7003, &pool).await.unwrap(), "7003");
+    }
+
+    async fn insert_with_parent(
+        name: &str,
+        parent_id: Option<domain::RowID>,
+        display_order: i64,
+        pool: &SqlitePool,
+    ) -> database::Categories {
+        database::Categories {
+            name: name.to_string(),
+            url_slug: None,
+            parent_id,
+            display_order,
+            ..database::Categories::mock()
+        }
+        .insert(pool)
+        .await
+        .unwrap()
+    }
+
+    fn names(nodes: &[CategoryNode]) -> Vec<&str> {
+        nodes.iter().map(|node| node.category.name.as_str()).collect()
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_as_tree_nests_multiple_levels(pool: SqlitePool) {
+        let food = insert_with_parent("Food", None, 0, &pool).await;
+        let groceries = insert_with_parent("Groceries", Some(food.id), 1, &pool).await;
+        insert_with_parent("Fruit", Some(groceries.id), 0, &pool).await;
+        insert_with_parent("Dining Out", Some(food.id), 0, &pool).await;
+        insert_with_parent("Rent", None, 1, &pool).await;
+
+        let tree = database::Categories::find_all_as_tree(&pool).await.unwrap();
+
+        assert_eq!(names(&tree), ["Food", "Rent"]);
+        assert_eq!(names(&tree[0].children), ["Dining Out", "Groceries"]);
+        assert!(tree[0].children[0].children.is_empty());
+        assert_eq!(names(&tree[0].children[1].children), ["Fruit"]);
+        assert!(tree[1].children.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_as_tree_places_orphans_at_top_level(pool: SqlitePool) {
+        let parent = insert_with_parent("Parent", None, 0, &pool).await;
+        insert_with_parent("Child", Some(parent.id), 0, &pool).await;
+        insert_with_parent("Orphan", Some(domain::RowID::new()), 1, &pool).await;
+
+        let tree = database::Categories::find_all_as_tree(&pool).await.unwrap();
+
+        assert_eq!(names(&tree), ["Parent", "Orphan"]);
+        assert_eq!(names(&tree[0].children), ["Child"]);
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_as_tree_reports_cycle(pool: SqlitePool) {
+        insert_with_parent("Root", None, 0, &pool).await;
+        let first = insert_with_parent("First", None, 1, &pool).await;
+        let second = insert_with_parent("Second", Some(first.id), 2, &pool).await;
+        database::Categories { parent_id: Some(second.id), ..first.clone() }.update(&pool).await.unwrap();
+        let below = insert_with_parent("Below cycle", Some(second.id), 3, &pool).await;
+
+        let result = database::Categories::find_all_as_tree(&pool).await;
+
+        let Err(database::DatabaseError::Validation(message)) = result else {
+            panic!("expected a cycle to be reported, got {:?}", result);
+        };
+        assert!(message.contains(&first.id.to_string()));
+        assert!(message.contains(&second.id.to_string()));
+        assert!(message.contains(&below.id.to_string()));
+    }
+
+    #[test]
+    fn test_assemble_tree_reports_self_parent() {
+        let mut category = database::Categories::mock();
+        category.parent_id = Some(category.id);
+
+        let result = database::Categories::assemble_tree(vec![category]);
+        assert!(matches!(result, Err(database::DatabaseError::Validation(_))));
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_with_usage_without_transactions_table(pool: SqlitePool) {
+        let categories = create_test_categories(2, &pool).await;
+
+        let usage = database::Categories::find_all_with_usage(&pool).await.unwrap();
+
+        assert_eq!(usage.len(), categories.len());
+        assert!(usage.iter().all(|(_, count)| *count == 0));
+    }
+
+    #[sqlx::test]
+    async fn test_find_all_with_usage_counts_transactions(pool: SqlitePool) {
+        let categories = create_test_categories(3, &pool).await;
+
+        // Stand-in for the transactions table until it has a migration
+        sqlx::query("CREATE TABLE transactions (id UUID PRIMARY KEY, category_id UUID NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (category, count) in [(&categories[0], 2), (&categories[1], 1)] {
+            for _ in 0..count {
+                sqlx::query("INSERT INTO transactions (id, category_id) VALUES (?, ?)")
+                    .bind(domain::RowID::new())
+                    .bind(category.id)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+            }
+        }
+
+        let usage: HashMap<domain::RowID, (database::Categories, i64)> =
+            database::Categories::find_all_with_usage(&pool)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(category, count)| (category.id, (category, count)))
+                .collect();
+
+        assert_eq!(usage.len(), 3);
+        assert_eq!(usage[&categories[0].id], (categories[0].clone(), 2));
+        assert_eq!(usage[&categories[1].id], (categories[1].clone(), 1));
+        assert_eq!(usage[&categories[2].id], (categories[2].clone(), 0));
+    }
+
+    #[sqlx::test]
+    async fn test_ordering_is_stable_for_identical_created_on(pool: SqlitePool) {
+        let created_on = chrono::Utc::now();
+        let mut categories = Vec::new();
+        for i in 0..5 {
+            let category = database::Categories {
+                code: format!("TIE.{:03}", i),
+                name: format!("Tie Category {}", i),
+                url_slug: Some(domain::UrlSlug::from(format!("tie-category-{}", i))),
+                is_active: true,
+                created_on,
+                updated_on: created_on,
+                ..database::Categories::mock()
+            };
+            categories.push(category.insert(&pool).await.unwrap());
+        }
+
+        // Ties on created_on are broken by id, newest id first
+        let mut expected: Vec<domain::RowID> = categories.iter().map(|category| category.id).collect();
+        expected.sort();
+        expected.reverse();
+
+        for _ in 0..3 {
+            let found: Vec<_> = database::Categories::find_all(&pool).await.unwrap().iter().map(|c| c.id).collect();
+            assert_eq!(found, expected);
+
+            let active: Vec<_> = database::Categories::find_all_active(&pool).await.unwrap().iter().map(|c| c.id).collect();
+            assert_eq!(active, expected);
+        }
+
+        // Offset pages walk the same total order without gaps or repeats
+        let mut paged = Vec::new();
+        for offset in [0, 2, 4] {
+            let (page, _) = database::Categories::find_with_filters(None, None, None, None, None, offset, 2, &pool)
+                .await
+                .unwrap();
+            paged.extend(page.iter().map(|c| c.id));
+        }
+        assert_eq!(paged, expected);
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("Food"), "Food");
+        assert_eq!(escape_like("100%_off\\"), "100\\%\\_off\\\\");
+    }
+
+    #[sqlx::test]
+    async fn test_search_by_name_prefix(pool: SqlitePool) {
+        for (code, name) in [("GROC", "Groceries"), ("GAS", "Gas"), ("GRANTS", "Grants"), ("RENT", "Rent"), ("GYM", "Gym")] {
+            let mut category = database::Categories::mock();
+            category.code = code.to_string();
+            category.name = name.to_string();
+            category.url_slug = None;
+            category.external_id = None;
+            database::Categories::insert(&category, &pool).await.unwrap();
+        }
+        let names = |found: Vec<database::Categories>| found.into_iter().map(|category| category.name).collect::<Vec<_>>();
+
+        let found = database::Categories::search_by_name_prefix("Gr", 10, &pool).await.unwrap();
+        assert_eq!(names(found), vec!["Grants", "Groceries"]);
+
+        let found = database::Categories::search_by_name_prefix("g", 10, &pool).await.unwrap();
+        assert_eq!(names(found), vec!["Gas", "Grants", "Groceries", "Gym"]);
+
+        let found = database::Categories::search_by_name_prefix("G", 2, &pool).await.unwrap();
+        assert_eq!(names(found), vec!["Gas", "Grants"]);
+
+        assert!(database::Categories::search_by_name_prefix("Z", 10, &pool).await.unwrap().is_empty());
+        assert!(database::Categories::search_by_name_prefix("G", -1, &pool).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn test_search_by_name_prefix_matches_wildcards_literally(pool: SqlitePool) {
+        for (code, name) in [("PCT", "100% Off"), ("ZEROS", "1000 Club"), ("UNDER", "A_B"), ("ALPHA", "AXB")] {
+            let mut category = database::Categories::mock();
+            category.code = code.to_string();
+            category.name = name.to_string();
+            category.url_slug = None;
+            category.external_id = None;
+            database::Categories::insert(&category, &pool).await.unwrap();
+        }
+        let names = |found: Vec<database::Categories>| found.into_iter().map(|category| category.name).collect::<Vec<_>>();
+
+        let found = database::Categories::search_by_name_prefix("100%", 10, &pool).await.unwrap();
+        assert_eq!(names(found), vec!["100% Off"]);
+
+        let found = database::Categories::search_by_name_prefix("A_", 10, &pool).await.unwrap();
+        assert_eq!(names(found), vec!["A_B"]);
+    }
+
+    #[sqlx::test]
+    async fn test_find_page_by_created_walks_every_category_once(pool: SqlitePool) {
+        let base = chrono::Utc::now();
+        let mut expected = Vec::new();
+        for i in 0..7 {
+            let mut category = database::Categories::mock();
+            category.code = format!("PAGE.{:03}", i);
+            category.name = format!("Page Category {}", i);
+            category.url_slug = None;
+            category.external_id = None;
+            // Two categories share each timestamp, so pages also split ties on id
+            category.created_on = base + chrono::Duration::seconds(i / 2);
+            database::Categories::insert(&category, &pool).await.unwrap();
+            expected.push((category.created_on, category.id));
+        }
+        expected.sort();
+
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = database::Categories::find_page_by_created(after, 3, &pool).await.unwrap();
+            seen.extend(page.iter().map(|category| (category.created_on, category.id)));
+            match page.last() {
+                Some(last) if page.len() == 3 => after = Some((last.created_on, last.id)),
+                _ => break,
+            }
+        }
+
+        assert_eq!(seen, expected);
+    }
+}
@@ -1,6 +1,41 @@
 use crate::database::{self, DatabaseResult};
 use crate::domain;
 
+/// Outcome of [`Categories::restore_diff`](database::Categories::restore_diff).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RestoreSummary {
+    /// Rows that did not exist and were inserted.
+    pub created: u64,
+
+    /// Rows that existed with different data and were updated.
+    pub updated: u64,
+
+    /// Rows that already matched the stored data and were not written.
+    pub unchanged: u64,
+}
+
+/// Time budget and progress logging for a batch write transaction.
+///
+/// The default has no budget and no progress logging.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchLimits {
+    /// Roll back the batch once its transaction has run this long. `None` for no budget.
+    pub max_duration: Option<std::time::Duration>,
+
+    /// Log progress every this many rows. `0` disables progress logging.
+    pub progress_interval: usize,
+}
+
+impl BatchLimits {
+    /// Create limits from `batch_max_duration_ms` and `batch_progress_interval`.
+    pub fn from_config(server_config: &crate::config::ServerConfig) -> Self {
+        Self {
+            max_duration: (server_config.batch_max_duration_ms > 0)
+                .then(|| std::time::Duration::from_millis(server_config.batch_max_duration_ms)),
+            progress_interval: server_config.batch_progress_interval,
+        }
+    }
+}
 
 impl database::Categories {
     /// Inserts a new category into the database.
@@ -52,6 +87,7 @@ impl database::Categories {
     ///     color: Some("#FF5733".parse()?),
     ///     icon: Some("shopping-cart".to_string()),
     ///     is_active: true,
+    ///     display_order: 0,
     ///     created_on: chrono::Utc::now(),
     ///     updated_on: chrono::Utc::now(),
     /// };
@@ -109,12 +145,37 @@ impl database::Categories {
         ),
     )]
     pub async fn insert(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let mut connection = pool.acquire().await?;
+        self.insert_on(&mut connection).await
+    }
+
+    /// Inserts this category as part of the transaction `tx`.
+    ///
+    /// Behaves like [`insert`](Self::insert), but the row is only stored once
+    /// the caller commits `tx`, so several writes can succeed or fail together.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`insert`](Self::insert).
+    #[tracing::instrument(
+        name = "Insert new Category in transaction",
+        skip(self, tx),
+        fields(id = %self.id, code = %self.code),
+        err
+    )]
+    pub async fn insert_tx(&self, tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>) -> DatabaseResult<Self> {
+        self.insert_on(tx).await
+    }
+
+    /// Insert on `connection`, shared by [`insert`](Self::insert) and
+    /// [`insert_tx`](Self::insert_tx).
+    async fn insert_on(&self, connection: &mut sqlx::SqliteConnection) -> DatabaseResult<Self> {
         // 1) INSERT: SQLite uses `?` placeholders and does not reliably support
         // `RETURNING *` for compile-time checked macros. Execute the insert first.
         let insert_query = sqlx::query!(
             r#"
-                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, display_order, external_id, reactivate_on, parent_id, created_on, updated_on)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             self.id,
             self.code,
@@ -125,11 +186,15 @@ impl database::Categories {
             self.color,
             self.icon,
             self.is_active,
+            self.display_order,
+            self.external_id,
+            self.reactivate_on,
+            self.parent_id,
             self.created_on,
             self.updated_on
         );
 
-        insert_query.execute(pool).await?;
+        insert_query.execute(&mut *connection).await?;
 
         tracing::info!("New Category inserted into the database.");
 
@@ -148,14 +213,19 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
                 WHERE id = ?
             "#,
             self.id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *connection)
         .await?;
 
         tracing::debug!("Newly created Category retrived from the database.");
@@ -169,6 +239,9 @@ impl database::Categories {
     /// successfully, or none are inserted if any operation fails. This is useful for
     /// seeding data or importing multiple categories at once.
     ///
+    /// The batch runs without a time budget; use
+    /// [`insert_many_with_limits`](Self::insert_many_with_limits) to bound it.
+    ///
     /// # Arguments
     ///
     /// * `categories` - A slice of categories to insert
@@ -217,11 +290,50 @@ impl database::Categories {
     pub async fn insert_many(
         categories: &[Self],
         pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        Self::insert_many_with_limits(categories, BatchLimits::default(), pool).await
+    }
+
+    /// Inserts multiple categories in a single transaction within a time budget.
+    ///
+    /// Behaves like [`insert_many`](Self::insert_many), and also logs progress
+    /// every `limits.progress_interval` rows. If the transaction is still running
+    /// after `limits.max_duration`, it is rolled back so no row is inserted.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - A slice of categories to insert
+    /// * `limits` - The time budget and progress logging interval
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of the inserted categories in the same order as provided,
+    /// or a `DatabaseError` if any insertion fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The batch exceeds `limits.max_duration` ([`DatabaseError::Timeout`](database::DatabaseError::Timeout))
+    /// - Any category violates database constraints (duplicate code/name/url_slug)
+    /// - Database connection fails
+    /// - Transaction fails to commit
+    #[tracing::instrument(
+        name = "Bulk insert categories into database within limits",
+        skip(categories, limits, pool),
+        fields(count = categories.len())
+    )]
+    pub async fn insert_many_with_limits(
+        categories: &[Self],
+        limits: BatchLimits,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
     ) -> DatabaseResult<Vec<Self>> {
         if categories.is_empty() {
             return Ok(Vec::new());
         }
 
+        let started = std::time::Instant::now();
+
         // Use a transaction for atomicity
         let mut tx = pool.begin().await?;
 
@@ -231,8 +343,8 @@ impl database::Categories {
             // Insert each category
             let insert_query = sqlx::query!(
                 r#"
-                    INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, display_order, external_id, reactivate_on, parent_id, created_on, updated_on)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
                 category.id,
                 category.code,
@@ -243,6 +355,10 @@ impl database::Categories {
                 category.color,
                 category.icon,
                 category.is_active,
+                category.display_order,
+                category.external_id,
+                category.reactivate_on,
+                category.parent_id,
                 category.created_on,
                 category.updated_on
             );
@@ -263,8 +379,13 @@ impl database::Categories {
                         color           AS "color?: domain::HexColor",
                         icon,
                         is_active       AS "is_active!: bool",
+                        display_order   AS "display_order!: i64",
+                        external_id     AS "external_id?: domain::ExternalRef",
+                        reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                        parent_id       AS "parent_id?: domain::RowID",
                         created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                     FROM categories
                     WHERE id = ?
                 "#,
@@ -274,6 +395,33 @@ impl database::Categories {
             .await?;
 
             inserted_categories.push(inserted);
+
+            if limits.progress_interval > 0 && inserted_categories.len() % limits.progress_interval == 0 {
+                tracing::info!(
+                    "Inserted {} of {} categories in {:?}",
+                    inserted_categories.len(),
+                    categories.len(),
+                    started.elapsed()
+                );
+            }
+
+            // Dropping the transaction on return rolls back the rows inserted so far
+            if let Some(max_duration) = limits.max_duration {
+                let elapsed = started.elapsed();
+                if elapsed > max_duration {
+                    tracing::warn!(
+                        "Rolling back batch insert after {:?} with {} of {} categories inserted",
+                        elapsed,
+                        inserted_categories.len(),
+                        categories.len()
+                    );
+                    return Err(database::DatabaseError::Timeout(format!(
+                        "Batch insert of {} categories exceeded its {} ms budget and was rolled back",
+                        categories.len(),
+                        max_duration.as_millis()
+                    )));
+                }
+            }
         }
 
         // Commit the transaction
@@ -345,8 +493,8 @@ impl database::Categories {
         // Use SQLite's UPSERT syntax (INSERT ... ON CONFLICT)
         let upsert_query = sqlx::query!(
             r#"
-                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, created_on, updated_on)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, display_order, external_id, reactivate_on, parent_id, created_on, updated_on)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ON CONFLICT(id) DO UPDATE SET
                     code = excluded.code,
                     name = excluded.name,
@@ -356,6 +504,10 @@ impl database::Categories {
                     color = excluded.color,
                     icon = excluded.icon,
                     is_active = excluded.is_active,
+                    display_order = excluded.display_order,
+                    external_id = excluded.external_id,
+                    reactivate_on = excluded.reactivate_on,
+                    parent_id = excluded.parent_id,
                     updated_on = excluded.updated_on
                 WHERE id = excluded.id
             "#,
@@ -368,6 +520,10 @@ impl database::Categories {
             category.color,
             category.icon,
             category.is_active,
+            category.display_order,
+            category.external_id,
+            category.reactivate_on,
+            category.parent_id,
             category.created_on,
             category.updated_on
         );
@@ -388,8 +544,13 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
                 WHERE id = ?
             "#,
@@ -402,6 +563,221 @@ impl database::Categories {
 
         Ok(result)
     }
+
+    /// Restores categories from a backup, writing only rows that changed.
+    ///
+    /// Each category is compared with the stored row of the same `id`, ignoring
+    /// `created_on` and `updated_on`. Missing rows are inserted with their backup
+    /// timestamps, differing rows are updated with `updated_on` set to now, and
+    /// identical rows are left untouched so their `updated_on` is preserved.
+    ///
+    /// `external_id`, `reactivate_on` and `parent_id` of an existing row are
+    /// kept, as the CSV export does not carry them.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - The categories to restore
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns how many rows were created, updated and left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A row violates database constraints (e.g., a code used by another id)
+    /// - Database connection fails
+    /// - Transaction fails to commit
+    ///
+    /// All rows are restored in one transaction, so on error nothing is written.
+    #[tracing::instrument(
+        name = "Restore categories from backup diff",
+        skip(categories, pool),
+        fields(count = categories.len())
+    )]
+    pub async fn restore_diff(
+        categories: &[Self],
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<RestoreSummary> {
+        let mut summary = RestoreSummary::default();
+        let mut tx = pool.begin().await?;
+
+        for category in categories {
+            let existing = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        display_order   AS "display_order!: i64",
+                        external_id     AS "external_id?: domain::ExternalRef",
+                        reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                    FROM categories
+                    WHERE id = ?
+                "#,
+                category.id
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            let Some(existing) = existing else {
+                sqlx::query!(
+                    r#"
+                        INSERT INTO categories (id, code, name, description, url_slug, category_type, color, icon, is_active, display_order, external_id, reactivate_on, parent_id, created_on, updated_on)
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                    category.id,
+                    category.code,
+                    category.name,
+                    category.description,
+                    category.url_slug,
+                    category.category_type,
+                    category.color,
+                    category.icon,
+                    category.is_active,
+                    category.display_order,
+                    category.external_id,
+                    category.reactivate_on,
+                    category.parent_id,
+                    category.created_on,
+                    category.updated_on
+                )
+                .execute(&mut *tx)
+                .await?;
+
+                summary.created += 1;
+                continue;
+            };
+
+            let incoming = Self {
+                external_id: existing.external_id.clone(),
+                reactivate_on: existing.reactivate_on,
+                parent_id: existing.parent_id,
+                ..category.clone()
+            };
+            if incoming.eq_ignoring_timestamps(&existing) {
+                summary.unchanged += 1;
+                continue;
+            }
+
+            let updated_on = chrono::Utc::now();
+            sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
+                        color = ?, icon = ?, is_active = ?, display_order = ?, updated_on = ?
+                    WHERE id = ?
+                "#,
+                incoming.code,
+                incoming.name,
+                incoming.description,
+                incoming.url_slug,
+                incoming.category_type,
+                incoming.color,
+                incoming.icon,
+                incoming.is_active,
+                incoming.display_order,
+                updated_on,
+                incoming.id
+            )
+            .execute(&mut *tx)
+            .await?;
+
+            summary.updated += 1;
+        }
+
+        tx.commit().await?;
+
+        tracing::info!(
+            "Restored categories: {} created, {} updated, {} unchanged",
+            summary.created,
+            summary.updated,
+            summary.unchanged
+        );
+
+        Ok(summary)
+    }
+
+    /// Copies every category of `source_type` into new rows, with codes
+    /// produced by `code_transform`.
+    ///
+    /// Each clone gets a new id. Names must be unique, so a clone is named
+    /// after its source with the new code appended, e.g. `Rent (RENT.2025)`,
+    /// and its url_slug is regenerated from that name when the source had one.
+    /// Colors, icons, descriptions, active state and display order are copied;
+    /// external ids are not. A parent that is also cloned is replaced by its
+    /// clone, so the copied tree keeps its shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_type` - The category type to copy
+    /// * `code_transform` - Maps a source code to the clone's code
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the inserted clones, in the order [`find_by_type`](Self::find_by_type)
+    /// returns their sources.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A regenerated url_slug is invalid
+    /// - A clone violates database constraints (e.g., a transformed code is already used)
+    /// - Database connection fails
+    /// - Transaction fails to commit
+    ///
+    /// All clones are inserted in one transaction, so on error nothing is written.
+    #[tracing::instrument(name = "Clone categories of a type", skip(code_transform, pool))]
+    pub async fn clone_type(
+        source_type: domain::CategoryTypes,
+        code_transform: impl Fn(&str) -> String,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let sources = Self::find_by_type(source_type, pool).await?;
+        let new_ids: std::collections::HashMap<domain::RowID, domain::RowID> =
+            sources.iter().map(|source| (source.id, domain::RowID::new())).collect();
+
+        let now = chrono::Utc::now();
+        let mut clones = Vec::with_capacity(sources.len());
+        for source in sources {
+            let code = code_transform(&source.code);
+            let name = format!("{} ({})", source.name, code);
+            let url_slug = match source.url_slug {
+                Some(_) => Some(
+                    domain::UrlSlug::parse(name.as_str())
+                        .map_err(|e| database::DatabaseError::Validation(e.to_string()))?,
+                ),
+                None => None,
+            };
+
+            clones.push(Self {
+                id: new_ids[&source.id],
+                code,
+                name,
+                url_slug,
+                external_id: None,
+                parent_id: source.parent_id.map(|parent_id| new_ids.get(&parent_id).copied().unwrap_or(parent_id)),
+                created_on: now,
+                updated_on: now,
+                ..source
+            });
+        }
+
+        Self::insert_many(&clones, pool).await
+    }
 }
 
 #[cfg(test)]
@@ -420,8 +796,8 @@ pub mod tests {
         use fake::faker::lorem::en::Word;
 
         // Generate a unique code using fake words
-        let word1: String = Word().fake();
-        let word2: String = Word().fake();
+        let word1: String = crate::mock_rng::with_rng(|rng| Word().fake_with_rng(rng));
+        let word2: String = crate::mock_rng::with_rng(|rng| Word().fake_with_rng(rng));
         format!("{}.{}", word1.to_uppercase(), word2.to_uppercase())
     }
 
@@ -430,7 +806,7 @@ pub mod tests {
         use fake::Fake;
         use fake::faker::lorem::en::Words;
 
-        let words: Vec<String> = Words(1..4).fake();
+        let words: Vec<String> = crate::mock_rng::with_rng(|rng| Words(1..4).fake_with_rng(rng));
         words.join(" ")
     }
 
@@ -440,9 +816,9 @@ pub mod tests {
         use fake::faker::boolean::en::Boolean;
         use fake::faker::lorem::en::Sentence;
 
-        let has_description: bool = Boolean(70).fake(); // 70% chance of having description
+        let has_description: bool = crate::mock_rng::with_rng(|rng| Boolean(70).fake_with_rng(rng)); // 70% chance of having description
         if has_description {
-            Some(Sentence(3..8).fake())
+            Some(crate::mock_rng::with_rng(|rng| Sentence(3..8).fake_with_rng(rng)))
         } else {
             None
         }
@@ -454,9 +830,9 @@ pub mod tests {
         use fake::faker::boolean::en::Boolean;
         use fake::faker::lorem::en::Word;
 
-        let has_icon: bool = Boolean(60).fake(); // 60% chance of having icon
+        let has_icon: bool = crate::mock_rng::with_rng(|rng| Boolean(60).fake_with_rng(rng)); // 60% chance of having icon
         if has_icon {
-            Some(Word().fake())
+            Some(crate::mock_rng::with_rng(|rng| Word().fake_with_rng(rng)))
         } else {
             None
         }
@@ -467,7 +843,7 @@ pub mod tests {
         use fake::Fake;
         use fake::faker::boolean::en::Boolean;
 
-        let category_type = match Boolean(50).fake() {
+        let category_type = match crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)) {
             true => domain::CategoryTypes::Expense,
             false => domain::CategoryTypes::Income,
         };
@@ -481,9 +857,14 @@ pub mod tests {
             category_type,
             color: domain::HexColor::mock_with_option(),
             icon: generate_fake_icon(),
-            is_active: Boolean(85).fake(), // 85% chance of active
+            is_active: crate::mock_rng::with_rng(|rng| Boolean(85).fake_with_rng(rng)), // 85% chance of active
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         }
     }
 
@@ -521,8 +902,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         let inserted = category.insert(&pool).await?;
@@ -558,8 +944,13 @@ pub mod tests {
             color: Some(color.clone()),
             icon: Some(fake_icon.clone()),
             is_active: false,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         let inserted = category.insert(&pool).await?;
@@ -589,8 +980,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         let category2 = database::Categories {
@@ -603,8 +999,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         // First insert should succeed
@@ -617,6 +1018,34 @@ pub mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn insert_fails_on_duplicate_external_id(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let external_id = domain::ExternalRef::parse("bank:import-42")?;
+
+        database::Categories {
+            external_id: Some(external_id.clone()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+
+        let duplicate = database::Categories {
+            external_id: Some(external_id),
+            ..database::Categories::mock()
+        };
+        let result = duplicate.insert(&pool).await;
+        assert!(result.is_err());
+
+        // Categories without an external id never conflict with each other
+        for _ in 0..2 {
+            database::Categories { external_id: None, ..database::Categories::mock() }
+                .insert(&pool)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn insert_fails_on_duplicate_name(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
         let duplicate_name = generate_fake_name();
@@ -633,8 +1062,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         let category2 = database::Categories {
@@ -647,8 +1081,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         // First insert should succeed
@@ -685,8 +1124,13 @@ pub mod tests {
             color: Some(valid_color),
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         // This should succeed since our domain type validates the color
@@ -831,8 +1275,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         database::Categories::insert_or_update(&category1, &pool).await?;
@@ -848,8 +1297,13 @@ pub mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         let result = database::Categories::insert_or_update(&category2, &pool).await;
@@ -871,4 +1325,160 @@ pub mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[sqlx::test]
+    async fn restore_diff_writes_only_changed_rows(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut categories = (0..3).map(|i| database::Categories {
+            code: format!("RESTORE.{:03}", i),
+            name: format!("Restore Category {}", i),
+            url_slug: Some(domain::UrlSlug::from(format!("restore-category-{}", i))),
+            ..database::Categories::mock()
+        });
+        let identical = categories.next().unwrap().insert(&pool).await?;
+        let changed = categories.next().unwrap().insert(&pool).await?;
+        let created = categories.next().unwrap();
+
+        let backup = vec![
+            identical.clone(),
+            database::Categories { name: format!("{} renamed", changed.name), ..changed.clone() },
+            created.clone(),
+        ];
+        let summary = database::Categories::restore_diff(&backup, &pool).await?;
+
+        assert_eq!(summary, RestoreSummary { created: 1, updated: 1, unchanged: 1 });
+        assert_eq!(database::Categories::find_by_id(identical.id, &pool).await?.unwrap(), identical);
+
+        let restored = database::Categories::find_by_id(changed.id, &pool).await?.unwrap();
+        assert_eq!(restored.name, backup[1].name);
+        assert!(restored.updated_on > changed.updated_on);
+
+        assert!(database::Categories::find_by_id(created.id, &pool).await?.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_many_with_limits_rolls_back_over_budget(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let categories: Vec<_> = (0..200)
+            .map(|i| database::Categories {
+                code: format!("BUDGET.{:03}", i),
+                name: format!("Budget Category {}", i),
+                url_slug: Some(domain::UrlSlug::from(format!("budget-category-{}", i))),
+                ..database::Categories::mock()
+            })
+            .collect();
+        let limits = BatchLimits {
+            max_duration: Some(std::time::Duration::from_nanos(1)),
+            progress_interval: 50,
+        };
+
+        let result = database::Categories::insert_many_with_limits(&categories, limits, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::Timeout(_))), "{:?}", result);
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories").fetch_one(&pool).await?;
+        assert_eq!(count, 0, "No category should survive the rollback");
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn clone_type_copies_every_category_of_the_type(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let parent = database::Categories {
+            code: "RENT".to_string(),
+            name: "Rent".to_string(),
+            url_slug: Some(domain::UrlSlug::from("rent")),
+            category_type: domain::CategoryTypes::Expense,
+            color: Some(domain::HexColor::parse("#AA0000")?),
+            parent_id: None,
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+        let child = database::Categories {
+            code: "RENT.BOND".to_string(),
+            name: "Bond".to_string(),
+            url_slug: None,
+            category_type: domain::CategoryTypes::Expense,
+            color: Some(domain::HexColor::parse("#00AA00")?),
+            parent_id: Some(parent.id),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+        let other = database::Categories {
+            category_type: domain::CategoryTypes::Income,
+            parent_id: None,
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+
+        let clones = database::Categories::clone_type(
+            domain::CategoryTypes::Expense,
+            |code| format!("{}.2025", code),
+            &pool,
+        )
+        .await?;
+        assert_eq!(clones.len(), 2);
+
+        let parent_clone = clones.iter().find(|clone| clone.code == "RENT.2025").unwrap();
+        let child_clone = clones.iter().find(|clone| clone.code == "RENT.BOND.2025").unwrap();
+
+        assert_ne!(parent_clone.id, parent.id);
+        assert_ne!(child_clone.id, child.id);
+        assert_eq!(parent_clone.name, "Rent (RENT.2025)");
+        assert_eq!(parent_clone.color, parent.color);
+        assert_eq!(parent_clone.url_slug, Some(domain::UrlSlug::parse("Rent (RENT.2025)")?));
+        assert_eq!(child_clone.color, child.color);
+        assert_eq!(child_clone.url_slug, None);
+        assert_eq!(child_clone.parent_id, Some(parent_clone.id));
+
+        assert_eq!(database::Categories::find_by_type(domain::CategoryTypes::Expense, &pool).await?.len(), 4);
+        assert_eq!(database::Categories::find_by_type(other.category_type, &pool).await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn clone_type_rolls_back_on_code_collision(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        for i in 0..3 {
+            database::Categories {
+                code: format!("CLONE.{}", i),
+                name: format!("Clone Source {}", i),
+                url_slug: None,
+                category_type: domain::CategoryTypes::Expense,
+                parent_id: None,
+                ..database::Categories::mock()
+            }
+            .insert(&pool)
+            .await?;
+        }
+
+        // Every clone maps to the same code, so the second insert collides
+        let result =
+            database::Categories::clone_type(domain::CategoryTypes::Expense, |_| "CLONE.COPY".to_string(), &pool)
+                .await;
+        assert!(result.is_err());
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories").fetch_one(&pool).await?;
+        assert_eq!(count, 3, "No clone should survive the rollback");
+
+        Ok(())
+    }
+
+    #[test]
+    fn batch_limits_from_config() {
+        let mut server_config = crate::config::ServerConfig {
+            batch_max_duration_ms: 250,
+            batch_progress_interval: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            BatchLimits::from_config(&server_config),
+            BatchLimits { max_duration: Some(std::time::Duration::from_millis(250)), progress_interval: 10 }
+        );
+
+        server_config.batch_max_duration_ms = 0;
+        assert_eq!(BatchLimits::from_config(&server_config).max_duration, None);
+    }
+}
@@ -0,0 +1,577 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::database::{self, DatabaseResult};
+use crate::domain;
+
+/// A group of categories that may be the same category entered more than once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateCluster {
+    /// The fields that matched within the cluster: `name`, `url_slug` and/or `code`.
+    pub matched_on: BTreeSet<&'static str>,
+
+    /// The categories in the cluster, oldest first.
+    pub categories: Vec<database::Categories>,
+}
+
+/// A stored value that cannot be read back, from `Categories::verify_all`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidStoredValue {
+    /// ID of the row, as stored.
+    pub id: String,
+
+    /// The column holding the value, e.g. `category_type`.
+    pub column: &'static str,
+
+    /// The stored value.
+    pub value: String,
+
+    /// Why the value cannot be read, as reported when decoding the row.
+    pub message: String,
+}
+
+/// Data hygiene operations for Category database records.
+///
+/// These scan the whole table and are meant for occasional maintenance, such
+/// as a review screen, not for request hot paths.
+impl database::Categories {
+    /// Finds rows holding values that would fail to decode.
+    ///
+    /// Queries that read such a row fail, so this reads the columns as plain
+    /// text to name every bad row at once. Currently checks `category_type`,
+    /// which a bad migration or a manual edit could leave holding a value that
+    /// is not a category type.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the invalid values ordered by row id, empty when every row is valid.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database connection fails.
+    #[tracing::instrument(name = "Verify stored category values", skip(pool), err)]
+    pub async fn verify_all(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Vec<InvalidStoredValue>> {
+        let rows = sqlx::query!(
+            r#"
+                SELECT id AS "id!: String", category_type AS "category_type!: String"
+                FROM categories
+                ORDER BY id
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let invalid: Vec<InvalidStoredValue> = rows
+            .into_iter()
+            .filter_map(|row| {
+                let message = domain::CategoryTypes::from_stored(&row.category_type).err()?;
+                Some(InvalidStoredValue { id: row.id, column: "category_type", value: row.category_type, message })
+            })
+            .collect();
+
+        for value in &invalid {
+            tracing::warn!("Category {} has an invalid stored value: {}", value.id, value.message);
+        }
+
+        Ok(invalid)
+    }
+
+    /// Finds groups of categories that are likely duplicates of each other.
+    ///
+    /// Two categories are potential duplicates when any of these match:
+    /// - Their names after [normalisation](Self::duplicate_key), so "Food",
+    ///   "foods" and "F.O.O.D" match
+    /// - Their URL slugs after the same normalisation
+    /// - Their codes, ignoring case
+    ///
+    /// Matches are transitive: if A matches B by name and B matches C by code,
+    /// all three form one cluster.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the clusters of two or more categories, ordered by their oldest
+    /// category. Categories with no potential duplicate are left out.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database connection fails.
+    #[tracing::instrument(
+        name = "Find potential duplicate categories",
+        skip(pool),
+        err
+    )]
+    pub async fn find_potential_duplicates(
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<DuplicateCluster>> {
+        let mut categories = Self::find_all(pool).await?;
+        categories.sort_by(|a, b| a.created_on.cmp(&b.created_on).then(a.id.cmp(&b.id)));
+
+        let mut clusters = DisjointSet::new(categories.len());
+        let mut first_with_key: HashMap<(&'static str, String), usize> = HashMap::new();
+        let mut matches = Vec::new();
+
+        for (index, category) in categories.iter().enumerate() {
+            let keys = [
+                ("name", Some(Self::duplicate_key(&category.name))),
+                ("url_slug", category.url_slug.as_ref().map(|slug| Self::duplicate_key(slug.as_str()))),
+                ("code", Some(category.code.trim().to_lowercase())),
+            ];
+
+            for (field, key) in keys {
+                let Some(key) = key.filter(|key| !key.is_empty()) else {
+                    continue;
+                };
+                match first_with_key.get(&(field, key.clone())) {
+                    Some(&first) => {
+                        clusters.union(first, index);
+                        matches.push((index, field));
+                    }
+                    None => {
+                        first_with_key.insert((field, key), index);
+                    }
+                }
+            }
+        }
+
+        let mut matched_on: HashMap<usize, BTreeSet<&'static str>> = HashMap::new();
+        for (index, field) in matches {
+            matched_on.entry(clusters.find(index)).or_default().insert(field);
+        }
+
+        let mut members: HashMap<usize, Vec<database::Categories>> = HashMap::new();
+        let mut roots_in_order = Vec::new();
+        for (index, category) in categories.into_iter().enumerate() {
+            let root = clusters.find(index);
+            let group = members.entry(root).or_default();
+            if group.is_empty() {
+                roots_in_order.push(root);
+            }
+            group.push(category);
+        }
+
+        let duplicates: Vec<DuplicateCluster> = roots_in_order
+            .into_iter()
+            .filter_map(|root| {
+                let categories = members.remove(&root)?;
+                (categories.len() > 1).then(|| DuplicateCluster {
+                    matched_on: matched_on.remove(&root).unwrap_or_default(),
+                    categories,
+                })
+            })
+            .collect();
+
+        tracing::info!("Found {} clusters of potential duplicate categories", duplicates.len());
+
+        Ok(duplicates)
+    }
+
+    /// Merges the source category into the target, then deletes the source.
+    ///
+    /// In one transaction, everything referencing the source is moved to the
+    /// target:
+    /// - Tags, skipping tags the target already has
+    /// - Child categories, which become children of the target
+    /// - Transactions, once a migration has created the `transactions` table
+    ///
+    /// If the target is nested under the source, it first takes the source's
+    /// place in the hierarchy so no cycle is created. The target's other
+    /// fields are kept and its `updated_on` is set to now.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The ID of the category to merge away
+    /// * `target_id` - The ID of the category that survives
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the surviving target category.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - `source_id` and `target_id` are the same ([`Validation`](database::DatabaseError::Validation))
+    /// - The source or target does not exist ([`NotFound`](database::DatabaseError::NotFound))
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Merge categories",
+        skip(pool),
+        fields(source_id = %source_id, target_id = %target_id),
+        err
+    )]
+    pub async fn merge(
+        source_id: domain::RowID,
+        target_id: domain::RowID,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        if source_id == target_id {
+            return Err(database::DatabaseError::Validation(format!(
+                "Cannot merge category {} into itself",
+                source_id
+            )));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        for (role, id) in [("Source", source_id), ("Target", target_id)] {
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS (SELECT 1 FROM categories WHERE id = ? AND deleted_on IS NULL) AS "exists!: bool""#,
+                id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if !exists {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "{} category with id {} not found",
+                    role, id
+                )));
+            }
+        }
+
+        sqlx::query!(
+            r#"
+                INSERT OR IGNORE INTO category_tags (category_id, tag, created_on)
+                SELECT ?1, tag, created_on FROM category_tags WHERE category_id = ?2
+            "#,
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // A target nested under the source moves up to the source's parent,
+        // otherwise re-parenting the source's children could create a cycle
+        let source_is_ancestor = sqlx::query_scalar!(
+            r#"
+                WITH RECURSIVE ancestors(id) AS (
+                    SELECT parent_id FROM categories WHERE id = ?1
+                    UNION
+                    SELECT categories.parent_id
+                    FROM categories JOIN ancestors ON categories.id = ancestors.id
+                    WHERE categories.parent_id IS NOT NULL
+                )
+                SELECT EXISTS (SELECT 1 FROM ancestors WHERE id = ?2) AS "is_ancestor!: bool"
+            "#,
+            target_id,
+            source_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if source_is_ancestor {
+            sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET parent_id = (SELECT parent_id FROM categories WHERE id = ?2)
+                    WHERE id = ?1
+                "#,
+                target_id,
+                source_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query!(
+            r#"
+                UPDATE categories
+                SET parent_id = ?1
+                WHERE parent_id = ?2
+            "#,
+            target_id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if Self::has_transactions_table(&mut *tx).await? {
+            let moved = sqlx::query("UPDATE transactions SET category_id = ? WHERE category_id = ?")
+                .bind(target_id)
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            tracing::debug!("Moved {} transactions to category {}", moved, target_id);
+        }
+
+        let now = chrono::Utc::now();
+        sqlx::query!(
+            r#"
+                UPDATE categories
+                SET updated_on = ?
+                WHERE id = ?
+            "#,
+            now,
+            target_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+                DELETE FROM categories
+                WHERE id = ?
+            "#,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        tracing::info!("Merged category {} into {}", source_id, target_id);
+
+        Self::find_by_id(target_id, pool).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!("Target category with id {} not found", target_id))
+        })
+    }
+
+    /// Normalises a name or slug for duplicate detection.
+    ///
+    /// Keeps only letters and digits, lowercased, and drops one trailing `s`
+    /// from words longer than three characters so simple plurals match.
+    pub fn duplicate_key(value: &str) -> String {
+        let mut key: String = value
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+
+        if key.chars().count() > 3 && key.ends_with('s') && !key.ends_with("ss") {
+            key.pop();
+        }
+
+        key
+    }
+}
+
+/// Union-find over category indexes, used to merge transitive matches.
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect() }
+    }
+
+    fn find(&mut self, index: usize) -> usize {
+        let parent = self.parents[index];
+        if parent == index {
+            return index;
+        }
+        let root = self.find(parent);
+        self.parents[index] = root;
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a != root_b {
+            // Keep the older category as the root
+            let (root, child) = if root_a < root_b { (root_a, root_b) } else { (root_b, root_a) };
+            self.parents[child] = root;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// Insert a category, each one created a second after the previous.
+    async fn insert(code: &str, name: &str, slug: &str, pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<database::Categories> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories").fetch_one(pool).await?;
+        let category = database::Categories {
+            created_on: chrono::DateTime::UNIX_EPOCH + chrono::Duration::seconds(count),
+            code: code.to_string(),
+            name: name.to_string(),
+            url_slug: Some(domain::UrlSlug::from(slug.to_string())),
+            ..database::Categories::mock()
+        };
+        Ok(category.insert(pool).await?)
+    }
+
+    /// Store `category_type` without the schema's CHECK constraint, as a bad migration could.
+    async fn corrupt_category_type(id: domain::RowID, value: &str, pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut connection = pool.acquire().await?;
+        sqlx::query("PRAGMA ignore_check_constraints = ON").execute(&mut *connection).await?;
+        sqlx::query("UPDATE categories SET category_type = ? WHERE id = ?")
+            .bind(value)
+            .bind(id)
+            .execute(&mut *connection)
+            .await?;
+        sqlx::query("PRAGMA ignore_check_constraints = OFF").execute(&mut *connection).await?;
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn verify_all_flags_invalid_category_types(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let valid = insert("EXP.VER.001", "Valid", "valid", &pool).await?;
+        let savings = insert("EXP.VER.002", "Savings", "savings", &pool).await?;
+        let blank = insert("EXP.VER.003", "Blank", "blank", &pool).await?;
+        assert!(database::Categories::verify_all(&pool).await?.is_empty());
+
+        corrupt_category_type(savings.id, "savings", &pool).await?;
+        corrupt_category_type(blank.id, "", &pool).await?;
+
+        let mut invalid = database::Categories::verify_all(&pool).await?;
+        invalid.sort_by(|a, b| a.value.cmp(&b.value));
+        assert_eq!(invalid.len(), 2);
+        assert_eq!((invalid[0].id.as_str(), invalid[0].value.as_str()), (blank.id.to_string().as_str(), ""));
+        assert_eq!((invalid[1].id.as_str(), invalid[1].value.as_str()), (savings.id.to_string().as_str(), "savings"));
+        assert!(invalid.iter().all(|value| value.column == "category_type"));
+        assert!(invalid[1].message.contains("'savings'"), "{}", invalid[1].message);
+
+        // Reading the bad row fails with the same explanation
+        let err = database::Categories::find_by_id(savings.id, &pool).await.unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Invalid value 'savings' in the category_type column"), "{}", message);
+        assert!(message.contains("asset, liability, income, expense, equity"), "{}", message);
+
+        assert!(database::Categories::find_by_id(valid.id, &pool).await?.is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn duplicate_key_normalises_case_punctuation_and_plurals() {
+        assert_eq!(database::Categories::duplicate_key("Foods"), "food");
+        assert_eq!(database::Categories::duplicate_key(" F.O.O.D "), "food");
+        assert_eq!(database::Categories::duplicate_key("eating-out"), "eatingout");
+        assert_eq!(database::Categories::duplicate_key("Gas"), "gas");
+        assert_eq!(database::Categories::duplicate_key("Business"), "business");
+    }
+
+    #[sqlx::test]
+    async fn find_potential_duplicates_groups_normalised_matches(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let food = insert("EXP.FOO.001", "Food", "food", &pool).await?;
+        let foods = insert("EXP.FOO.002", "Foods", "foods", &pool).await?;
+        let shouting = insert("EXP.FOO.003", "FOOD!", "food-shouting", &pool).await?;
+        let rent = insert("EXP.REN.001", "Rent", "rent", &pool).await?;
+        let rent_code = insert("exp.ren.001", "Housing", "housing", &pool).await?;
+        insert("INC.SAL.001", "Salary", "salary", &pool).await?;
+        insert("EXP.TRA.001", "Transport", "transport", &pool).await?;
+
+        let clusters = database::Categories::find_potential_duplicates(&pool).await?;
+
+        let ids: Vec<Vec<domain::RowID>> = clusters
+            .iter()
+            .map(|cluster| cluster.categories.iter().map(|category| category.id).collect())
+            .collect();
+        assert_eq!(ids, vec![vec![food.id, foods.id, shouting.id], vec![rent.id, rent_code.id]]);
+
+        assert_eq!(clusters[0].matched_on, BTreeSet::from(["name", "url_slug"]));
+        assert_eq!(clusters[1].matched_on, BTreeSet::from(["code"]));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_potential_duplicates_is_empty_for_distinct_categories(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        insert("EXP.FOO.001", "Food", "food", &pool).await?;
+        insert("EXP.FOD.001", "Fodder", "fodder", &pool).await?;
+
+        assert!(database::Categories::find_potential_duplicates(&pool).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn merge_moves_references_and_deletes_source(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let source = insert("EXP.FOO.001", "Foods", "foods", &pool).await?;
+        let target = insert("EXP.FOO.002", "Food", "food", &pool).await?;
+        let child = insert("EXP.FOO.003", "Takeaway", "takeaway", &pool).await?;
+        database::Categories { parent_id: Some(source.id), ..child.clone() }.update(&pool).await?;
+
+        let shared = domain::Tag::parse("groceries")?;
+        let source_only = domain::Tag::parse("weekly")?;
+        database::Categories::add_tag(source.id, &shared, &pool).await?;
+        database::Categories::add_tag(source.id, &source_only, &pool).await?;
+        database::Categories::add_tag(target.id, &shared, &pool).await?;
+
+        sqlx::query("CREATE TABLE transactions (id UUID PRIMARY KEY, category_id UUID NOT NULL)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("INSERT INTO transactions (id, category_id) VALUES (?, ?)")
+            .bind(domain::RowID::new())
+            .bind(source.id)
+            .execute(&pool)
+            .await?;
+
+        let merged = database::Categories::merge(source.id, target.id, &pool).await?;
+
+        assert_eq!(merged.id, target.id);
+        assert_eq!(merged.name, target.name);
+        assert!(merged.updated_on > target.updated_on);
+        assert!(database::Categories::find_by_id(source.id, &pool).await?.is_none());
+        assert_eq!(database::Categories::find_tags(target.id, &pool).await?, vec![shared, source_only]);
+
+        let child = database::Categories::find_by_id(child.id, &pool).await?.unwrap();
+        assert_eq!(child.parent_id, Some(target.id));
+
+        let moved: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM transactions WHERE category_id = ?")
+            .bind(target.id)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(moved, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn merge_into_descendant_does_not_create_cycle(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let source = insert("EXP.FOO.001", "Foods", "foods", &pool).await?;
+        let middle = insert("EXP.FOO.002", "Dining", "dining", &pool).await?;
+        let target = insert("EXP.FOO.003", "Food", "food", &pool).await?;
+        database::Categories { parent_id: Some(source.id), ..middle.clone() }.update(&pool).await?;
+        database::Categories { parent_id: Some(middle.id), ..target.clone() }.update(&pool).await?;
+
+        let merged = database::Categories::merge(source.id, target.id, &pool).await?;
+
+        assert_eq!(merged.parent_id, None);
+        let middle = database::Categories::find_by_id(middle.id, &pool).await?.unwrap();
+        assert_eq!(middle.parent_id, Some(target.id));
+        assert!(database::Categories::find_all_as_tree(&pool).await.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn merge_into_itself_is_rejected(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = insert("EXP.FOO.001", "Food", "food", &pool).await?;
+
+        let result = database::Categories::merge(category.id, category.id, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::Validation(_))));
+        assert!(database::Categories::find_by_id(category.id, &pool).await?.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn merge_with_missing_category_is_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = insert("EXP.FOO.001", "Food", "food", &pool).await?;
+
+        let missing_source = database::Categories::merge(domain::RowID::new(), category.id, &pool).await;
+        assert!(matches!(missing_source, Err(database::DatabaseError::NotFound(_))));
+
+        let missing_target = database::Categories::merge(category.id, domain::RowID::new(), &pool).await;
+        assert!(matches!(missing_target, Err(database::DatabaseError::NotFound(_))));
+        assert!(database::Categories::find_by_id(category.id, &pool).await?.is_some());
+
+        Ok(())
+    }
+}
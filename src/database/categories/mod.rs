@@ -3,19 +3,43 @@
 //! Provides data access helpers, builders, and models for working with
 //! category records in the persistence layer. The module exposes the
 //! database representation of a category alongside utilities for creating
-//! and inserting records during tests or data seeding.
+//! and inserting records during tests or data seeding, for managing
+//! the free-form tags attached to categories, and for data hygiene checks
+//! such as finding potential duplicates and merging them. An optional LRU
+//! cache can sit in front of lookups by id.
 
 // #![allow(unused)] // For development only
 
 mod builder;
+mod cache;
 mod model;
 mod insert;
 mod update;
 mod delete;
 mod find;
+mod tags;
+mod maintenance;
 
 /// Database row model representing a persisted category.
-pub use model::Categories;
+pub use model::{Categories, FieldChange};
 
 /// Fluent builder for constructing `Category` instances in tests and fixtures.
-pub use builder::CategoriesBuilder;
\ No newline at end of file
+pub use builder::CategoriesBuilder;
+
+pub use cache::CategoryCache;
+
+/// Identifier that matched in `Categories::find_by_slug_or_code`.
+pub use find::CategoryMatchedBy;
+
+/// A category and its nested children, from `Categories::find_all_as_tree`.
+pub use find::{CategoryNode, RecommendedFields};
+
+/// A group of likely duplicates, from `Categories::find_potential_duplicates`,
+/// and a value that cannot be read back, from `Categories::verify_all`.
+pub use maintenance::{DuplicateCluster, InvalidStoredValue};
+
+/// Partial update written by `Categories::update_fields`.
+pub use update::CategoryPatch;
+
+/// Created, updated and unchanged counts from `Categories::restore_diff`.
+pub use insert::{BatchLimits, RestoreSummary};
@@ -13,11 +13,79 @@ pub struct Categories {
     pub color: Option<domain::HexColor>,
     pub icon: Option<String>,
     pub is_active: bool,
+    pub display_order: i64,
+    pub external_id: Option<domain::ExternalRef>,
+    pub reactivate_on: Option<chrono::DateTime<chrono::Utc>>,
+    pub parent_id: Option<domain::RowID>,
     pub created_on: chrono::DateTime<chrono::Utc>,
     pub updated_on: chrono::DateTime<chrono::Utc>,
+    /// When the category was soft deleted, see [`Categories::soft_delete`].
+    /// Inserts and updates leave it unchanged.
+    pub deleted_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// One field that differs between two categories, see [`Categories::diff`].
+///
+/// Values are rendered as text so fields of any type fit one shape; `None`
+/// stands for an unset optional field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The field name, as used in update masks.
+    pub field: &'static str,
+
+    /// The value in the original category.
+    pub old_value: Option<String>,
+
+    /// The value in the other category.
+    pub new_value: Option<String>,
 }
 
 impl database::Categories {
+    /// Returns `true` if both categories hold the same data, ignoring
+    /// `created_on` and `updated_on`.
+    pub fn eq_ignoring_timestamps(&self, other: &Self) -> bool {
+        Self { created_on: other.created_on, updated_on: other.updated_on, ..self.clone() } == *other
+    }
+
+    /// Lists the fields that differ from `self` to `other`, in declaration order.
+    ///
+    /// `created_on` and `updated_on` are ignored, so a category compared with a
+    /// saved copy of itself yields an empty diff.
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        fn text<T: ToString>(value: &T) -> Option<String> {
+            Some(value.to_string())
+        }
+        fn optional<T: ToString>(value: &Option<T>) -> Option<String> {
+            value.as_ref().map(ToString::to_string)
+        }
+
+        let fields = [
+            ("id", text(&self.id), text(&other.id)),
+            ("code", text(&self.code), text(&other.code)),
+            ("name", text(&self.name), text(&other.name)),
+            ("description", optional(&self.description), optional(&other.description)),
+            ("url_slug", optional(&self.url_slug), optional(&other.url_slug)),
+            ("category_type", text(&self.category_type), text(&other.category_type)),
+            ("color", optional(&self.color), optional(&other.color)),
+            ("icon", optional(&self.icon), optional(&other.icon)),
+            ("is_active", text(&self.is_active), text(&other.is_active)),
+            ("display_order", text(&self.display_order), text(&other.display_order)),
+            ("external_id", optional(&self.external_id), optional(&other.external_id)),
+            (
+                "reactivate_on",
+                self.reactivate_on.map(|at| at.to_rfc3339()),
+                other.reactivate_on.map(|at| at.to_rfc3339()),
+            ),
+            ("parent_id", optional(&self.parent_id), optional(&other.parent_id)),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, old_value, new_value)| old_value != new_value)
+            .map(|(field, old_value, new_value)| FieldChange { field, old_value, new_value })
+            .collect()
+    }
+
     /// Generates a mock `Category` instance with randomized test data.
     ///
     /// This function creates realistic test data for categories, using the `fake` crate
@@ -45,6 +113,8 @@ impl database::Categories {
             .with_color_opt(domain::HexColor::mock_with_option())
             .with_icon_opt(Self::generate_mock_icon())
             .with_is_active_opt(Some(Self::generate_mock_is_active()))
+            .with_display_order(Self::generate_mock_display_order())
+            .with_external_id_opt(domain::ExternalRef::mock_with_option())
             .with_created_on_opt(Some(chrono::Utc::now()))
             .with_updated_on_opt(Some(chrono::Utc::now()))
             .build()
@@ -56,12 +126,12 @@ impl database::Categories {
         use rand::Rng;
 
         // Generate 9 random alphanumeric chars, uppercase, then split into 3 groups
-        let mut rng = rand::rng();
-        let s: String = (&mut rng)
-            .sample_iter(&rand::distr::Alphanumeric)
-            .take(9)
-            .map(|b| (b as char).to_ascii_uppercase())
-            .collect();
+        let s: String = crate::mock_rng::with_rng(|rng| {
+            rng.sample_iter(&rand::distr::Alphanumeric)
+                .take(9)
+                .map(|b| (b as char).to_ascii_uppercase())
+                .collect()
+        });
 
         format!("{}.{}.{}", &s[0..3], &s[3..6], &s[6..9])
     }
@@ -71,7 +141,7 @@ impl database::Categories {
         use fake::Fake;
         use fake::faker::lorem::en::Words;
 
-        let words: Vec<String> = Words(1..3).fake();
+        let words: Vec<String> = crate::mock_rng::with_rng(|rng| Words(1..3).fake_with_rng(rng));
         words.join(" ")
     }
 
@@ -81,9 +151,9 @@ impl database::Categories {
         use fake::faker::boolean::en::Boolean;
         use fake::faker::lorem::en::Words;
 
-        let is_some: bool = Boolean(50).fake(); // 50% chance of Some
+        let is_some: bool = crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)); // 50% chance of Some
         if is_some {
-            let words: Vec<String> = Words(3..8).fake();
+            let words: Vec<String> = crate::mock_rng::with_rng(|rng| Words(3..8).fake_with_rng(rng));
             Some(words.join(" "))
         } else {
             None
@@ -101,9 +171,9 @@ impl database::Categories {
         use fake::faker::boolean::en::Boolean;
         use fake::faker::lorem::en::Word;
 
-        let is_some: bool = Boolean(50).fake(); // 50% chance of Some
+        let is_some: bool = crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)); // 50% chance of Some
         if is_some {
-            Some(Word().fake())
+            Some(crate::mock_rng::with_rng(|rng| Word().fake_with_rng(rng)))
         } else {
             None
         }
@@ -114,7 +184,14 @@ impl database::Categories {
         use fake::Fake;
         use fake::faker::boolean::en::Boolean;
 
-        Boolean(80).fake() // 80% chance of active for more realistic data
+        crate::mock_rng::with_rng(|rng| Boolean(80).fake_with_rng(rng)) // 80% chance of active for more realistic data
+    }
+
+    #[cfg(test)]
+    fn generate_mock_display_order() -> i64 {
+        use fake::Fake;
+
+        crate::mock_rng::with_rng(|rng| (0..1_000).fake_with_rng(rng))
     }
 }
 
@@ -133,6 +210,63 @@ mod tests {
         assert!(cat.updated_on <= chrono::Utc::now());
     }
 
+    #[test]
+    fn mock_replays_with_the_same_seed() {
+        crate::mock_rng::reseed(1247);
+        let first = Categories::mock();
+        crate::mock_rng::reseed(1247);
+        let second = Categories::mock();
+        crate::mock_rng::reseed(7421);
+        let other = Categories::mock();
+
+        assert!(first.eq_ignoring_timestamps(&second), "{:?} != {:?}", first, second);
+        assert!(!first.eq_ignoring_timestamps(&other));
+        assert_ne!(first.id, other.id);
+    }
+
+    #[test]
+    fn diff_reports_single_field_change() {
+        let category = Categories::mock();
+        let renamed = Categories { name: format!("{} renamed", category.name), ..category.clone() };
+
+        assert_eq!(
+            category.diff(&renamed),
+            vec![FieldChange {
+                field: "name",
+                old_value: Some(category.name.clone()),
+                new_value: Some(renamed.name.clone()),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_reports_multiple_changes_in_field_order() {
+        let category = Categories { icon: Some("cart".to_string()), is_active: true, ..Categories::mock() };
+        let changed = Categories {
+            icon: None,
+            is_active: false,
+            display_order: category.display_order + 1,
+            ..category.clone()
+        };
+
+        let changes = category.diff(&changed);
+        let fields: Vec<_> = changes.iter().map(|change| change.field).collect();
+        assert_eq!(fields, vec!["icon", "is_active", "display_order"]);
+        assert_eq!(changes[0].old_value.as_deref(), Some("cart"));
+        assert_eq!(changes[0].new_value, None);
+        assert_eq!(changes[1].new_value.as_deref(), Some("false"));
+    }
+
+    #[test]
+    fn diff_of_identical_categories_is_empty() {
+        let category = Categories::mock();
+        assert!(category.diff(&category.clone()).is_empty());
+
+        // Timestamps are ignored
+        let touched = Categories { updated_on: category.updated_on + chrono::Duration::seconds(5), ..category.clone() };
+        assert!(category.diff(&touched).is_empty());
+    }
+
     #[test]
     fn mock_randomizes_optional_fields() {
         // Run multiple times to check randomization
@@ -188,10 +322,9 @@ mod tests {
         let mut has_some = false;
         let mut has_none = false;
         for _ in 0..20 {
-            let desc = Categories::generate_mock_description();
-            if desc.is_some() {
+            if let Some(desc) = Categories::generate_mock_description() {
                 has_some = true;
-                assert!(!desc.as_ref().unwrap().is_empty());
+                assert!(!desc.is_empty());
             } else {
                 has_none = true;
             }
@@ -212,11 +345,10 @@ mod tests {
         let mut has_some = false;
         let mut has_none = false;
         for _ in 0..20 {
-            let icon = Categories::generate_mock_icon();
-            if icon.is_some() {
+            if let Some(icon) = Categories::generate_mock_icon() {
                 has_some = true;
-                assert!(!icon.as_ref().unwrap().is_empty());
-                assert!(icon.as_ref().unwrap().chars().all(|c| c.is_alphabetic()));
+                assert!(!icon.is_empty());
+                assert!(icon.chars().all(|c| c.is_alphabetic()));
             } else {
                 has_none = true;
             }
@@ -254,4 +386,18 @@ mod tests {
         let deserialized: Categories = serde_json::from_str(&json).unwrap();
         assert_eq!(cat1, deserialized);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn eq_ignoring_timestamps_skips_only_timestamps() {
+        let category = Categories::mock();
+        let restamped = Categories {
+            created_on: category.created_on - chrono::Duration::days(1),
+            updated_on: category.updated_on + chrono::Duration::days(1),
+            ..category.clone()
+        };
+        assert!(category.eq_ignoring_timestamps(&restamped));
+
+        let renamed = Categories { name: format!("{} renamed", category.name), ..category.clone() };
+        assert!(!category.eq_ignoring_timestamps(&renamed));
+    }
+}
@@ -0,0 +1,347 @@
+use crate::database::{self, DatabaseResult};
+use crate::domain;
+
+/// Tag operations for Category database records.
+///
+/// Tags are free-form labels stored in the `category_tags` join table. A
+/// category can carry many tags and a tag can be on many categories; deleting
+/// a category removes its tags through the foreign key cascade.
+impl database::Categories {
+    /// Adds a tag to a category.
+    ///
+    /// Adding a tag the category already carries is not an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to tag
+    /// * `tag` - The tag to add
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the tag was added, `false` if the category already had it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category with the given ID does not exist
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Add tag to category",
+        skip(pool),
+        fields(id = %id, tag = %tag),
+        err
+    )]
+    pub async fn add_tag(
+        id: domain::RowID,
+        tag: &domain::Tag,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<bool> {
+        let result = sqlx::query!(
+            r#"
+                INSERT INTO category_tags (category_id, tag)
+                VALUES (?, ?)
+                ON CONFLICT(category_id, tag) DO NOTHING
+            "#,
+            id,
+            tag
+        )
+        .execute(pool)
+        .await;
+
+        match result {
+            Ok(result) => Ok(result.rows_affected() > 0),
+            Err(sqlx::Error::Database(e)) if e.is_foreign_key_violation() => {
+                Err(database::DatabaseError::NotFound(format!("Category with id '{}' not found", id)))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Removes a tag from a category.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to untag
+    /// * `tag` - The tag to remove
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the tag was removed, `false` if the category did not have it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the database connection fails.
+    #[tracing::instrument(
+        name = "Remove tag from category",
+        skip(pool),
+        fields(id = %id, tag = %tag),
+        err
+    )]
+    pub async fn remove_tag(
+        id: domain::RowID,
+        tag: &domain::Tag,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<bool> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM category_tags
+                WHERE category_id = ? AND tag = ?
+            "#,
+            id,
+            tag
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Retrieves the tags on a category, in alphabetical order.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the category's tags, empty if it has none or does not exist.
+    #[tracing::instrument(
+        name = "Find tags of category",
+        skip(pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn find_tags(
+        id: domain::RowID,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<domain::Tag>> {
+        let tags = sqlx::query_scalar!(
+            r#"
+                SELECT tag AS "tag!: domain::Tag"
+                FROM category_tags
+                WHERE category_id = ?
+                ORDER BY tag ASC
+            "#,
+            id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    /// Retrieves every category carrying a tag.
+    ///
+    /// Categories are ordered newest first, the same order as
+    /// [`find_with_filters`](Self::find_with_filters).
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to look for
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the tagged categories, empty if no category has the tag.
+    #[tracing::instrument(
+        name = "Find categories by tag",
+        skip(pool),
+        fields(tag = %tag),
+        err
+    )]
+    pub async fn find_by_tag(
+        tag: &domain::Tag,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        let (categories, _) = Self::find_by_tag_with_pagination(tag, None, None, 0, -1, pool).await?;
+        Ok(categories)
+    }
+
+    /// Helper method to find tagged categories with filters and pagination.
+    ///
+    /// A negative `limit` returns every matching row.
+    pub(super) async fn find_by_tag_with_pagination(
+        tag: &domain::Tag,
+        category_type_filter: Option<domain::CategoryTypes>,
+        is_active_filter: Option<bool>,
+        offset: i32,
+        limit: i32,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<(Vec<Self>, i32)> {
+        let total_count = sqlx::query_scalar!(
+            r#"
+                SELECT COUNT(*) AS "count!: i32"
+                FROM categories
+                WHERE id IN (SELECT category_id FROM category_tags WHERE tag = ?1)
+                  AND (?2 IS NULL OR category_type = ?2)
+                  AND (?3 IS NULL OR is_active = ?3)
+                  AND deleted_on IS NULL
+            "#,
+            tag,
+            category_type_filter,
+            is_active_filter
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let categories = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE id IN (SELECT category_id FROM category_tags WHERE tag = ?1)
+                  AND (?2 IS NULL OR category_type = ?2)
+                  AND (?3 IS NULL OR is_active = ?3)
+                  AND deleted_on IS NULL
+                ORDER BY created_on DESC, id DESC
+                LIMIT ?4 OFFSET ?5
+            "#,
+            tag,
+            category_type_filter,
+            is_active_filter,
+            limit,
+            offset
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok((categories, total_count))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    /// Insert `count` mock categories with distinct codes, names and slugs.
+    async fn insert_categories(count: usize, pool: &sqlx::Pool<sqlx::Sqlite>) -> Result<Vec<database::Categories>> {
+        let mut categories = Vec::with_capacity(count);
+        for i in 0..count {
+            let category = database::Categories {
+                code: format!("TAGS.{:03}", i),
+                name: format!("Tagged Category {}", i),
+                url_slug: Some(domain::UrlSlug::from(format!("tagged-category-{}", i))),
+                ..database::Categories::mock()
+            };
+            categories.push(category.insert(pool).await?);
+        }
+        Ok(categories)
+    }
+
+    #[sqlx::test]
+    async fn add_tag_tags_category_once(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let recurring = domain::Tag::parse("recurring")?;
+        let deductible = domain::Tag::parse("tax-deductible")?;
+
+        assert!(database::Categories::add_tag(category.id, &recurring, &pool).await?);
+        assert!(!database::Categories::add_tag(category.id, &recurring, &pool).await?);
+        assert!(database::Categories::add_tag(category.id, &deductible, &pool).await?);
+
+        let tags = database::Categories::find_tags(category.id, &pool).await?;
+        assert_eq!(tags, vec![recurring, deductible]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn add_tag_to_missing_category_is_not_found(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Categories::add_tag(domain::RowID::new(), &domain::Tag::mock(), &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn remove_tag_untags_category(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let tag = domain::Tag::mock();
+        database::Categories::add_tag(category.id, &tag, &pool).await?;
+
+        assert!(database::Categories::remove_tag(category.id, &tag, &pool).await?);
+        assert!(!database::Categories::remove_tag(category.id, &tag, &pool).await?);
+        assert!(database::Categories::find_tags(category.id, &pool).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_by_tag_returns_only_tagged_categories(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let [first, second, untagged]: [database::Categories; 3] =
+            insert_categories(3, &pool).await?.try_into().unwrap();
+        let tag = domain::Tag::mock();
+        database::Categories::add_tag(first.id, &tag, &pool).await?;
+        database::Categories::add_tag(second.id, &tag, &pool).await?;
+        database::Categories::add_tag(untagged.id, &domain::Tag::mock(), &pool).await?;
+
+        let mut found: Vec<_> = database::Categories::find_by_tag(&tag, &pool)
+            .await?
+            .into_iter()
+            .map(|category| category.id)
+            .collect();
+        found.sort();
+        let mut expected = vec![first.id, second.id];
+        expected.sort();
+        assert_eq!(found, expected);
+
+        assert!(database::Categories::find_by_tag(&domain::Tag::mock(), &pool).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn find_with_filters_applies_tag_filter(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let tagged = insert_categories(2, &pool).await?.remove(0);
+        let tag = domain::Tag::mock();
+        database::Categories::add_tag(tagged.id, &tag, &pool).await?;
+
+        let (categories, total) =
+            database::Categories::find_with_filters(None, None, Some(&tag), None, None, 0, 10, &pool).await?;
+        assert_eq!(total, 1);
+        assert_eq!(categories, vec![tagged]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deleting_category_removes_its_tags(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        let tag = domain::Tag::mock();
+        database::Categories::add_tag(category.id, &tag, &pool).await?;
+
+        database::Categories::delete_by_id(category.id, &pool).await?;
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM category_tags WHERE category_id = ?")
+            .bind(category.id)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(remaining, 0);
+        assert!(database::Categories::find_by_tag(&tag, &pool).await?.is_empty());
+
+        Ok(())
+    }
+}
@@ -1,6 +1,33 @@
 use crate::database::{self, DatabaseResult};
 use crate::domain;
 
+/// A partial update for [`Categories::update_fields`](database::Categories::update_fields).
+///
+/// Only fields set to `Some` are written. Nullable columns take an
+/// `Option<Option<_>>`, where `Some(None)` clears the stored value.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CategoryPatch {
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub url_slug: Option<Option<domain::UrlSlug>>,
+    pub category_type: Option<domain::CategoryTypes>,
+    pub color: Option<Option<domain::HexColor>>,
+    pub icon: Option<Option<String>>,
+    pub is_active: Option<bool>,
+    pub display_order: Option<i64>,
+    pub external_id: Option<Option<domain::ExternalRef>>,
+    pub reactivate_on: Option<Option<chrono::DateTime<chrono::Utc>>>,
+    pub parent_id: Option<Option<domain::RowID>>,
+}
+
+impl CategoryPatch {
+    /// Returns `true` if the patch does not change any field.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Update operations for Category database records.
 ///
 /// This module provides functions for updating existing category records in the database,
@@ -23,7 +50,7 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category with the given ID does not exist
+    /// - The category with the given ID does not exist or is soft-deleted
     /// - The updated category violates database constraints (duplicate code, name, or url_slug)
     /// - The category_type is invalid
     /// - The color format is invalid
@@ -63,13 +90,38 @@ impl database::Categories {
         err
     )]
     pub async fn update(&self, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let mut connection = pool.acquire().await?;
+        self.update_on(&mut connection).await
+    }
+
+    /// Updates this category as part of the transaction `tx`.
+    ///
+    /// Behaves like [`update`](Self::update), but the change is only stored
+    /// once the caller commits `tx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`update`](Self::update).
+    #[tracing::instrument(
+        name = "Update category in transaction",
+        skip(self, tx),
+        fields(id = %self.id, code = %self.code),
+        err
+    )]
+    pub async fn update_tx(&self, tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>) -> DatabaseResult<Self> {
+        self.update_on(tx).await
+    }
+
+    /// Update on `connection`, shared by [`update`](Self::update) and
+    /// [`update_tx`](Self::update_tx).
+    async fn update_on(&self, connection: &mut sqlx::SqliteConnection) -> DatabaseResult<Self> {
         // Update the category record
         let update_query = sqlx::query!(
             r#"
                 UPDATE categories
                 SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
-                    color = ?, icon = ?, is_active = ?, updated_on = ?
-                WHERE id = ?
+                    color = ?, icon = ?, is_active = ?, display_order = ?, external_id = ?, reactivate_on = ?, parent_id = ?, updated_on = ?
+                WHERE id = ? AND deleted_on IS NULL
             "#,
             self.code,
             self.name,
@@ -79,11 +131,15 @@ impl database::Categories {
             self.color,
             self.icon,
             self.is_active,
+            self.display_order,
+            self.external_id,
+            self.reactivate_on,
+            self.parent_id,
             self.updated_on,
             self.id
         );
 
-        let rows_affected = update_query.execute(pool).await?.rows_affected();
+        let rows_affected = update_query.execute(&mut *connection).await?.rows_affected();
 
         if rows_affected == 0 {
             return Err(database::DatabaseError::NotFound(format!(
@@ -108,13 +164,169 @@ impl database::Categories {
                     color           AS "color?: domain::HexColor",
                     icon,
                     is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
                     created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                 FROM categories
-                WHERE id = ?
+                WHERE id = ? AND deleted_on IS NULL
             "#,
             self.id
         )
+        .fetch_one(&mut *connection)
+        .await?;
+
+        Ok(updated)
+    }
+
+    /// Updates only the fields set in `changes` on the category with `id`.
+    ///
+    /// The `SET` clause is built from the fields present in the patch, with
+    /// every value passed as a bound parameter. `updated_on` is always bumped,
+    /// even for an empty patch, and fields not in the patch are left as
+    /// stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to update
+    /// * `changes` - The fields to write
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated category as read back from the database, or a
+    /// `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category with the given ID does not exist or is soft-deleted
+    /// - The patched category violates database constraints (duplicate code, name, or url_slug)
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::{Categories, CategoryPatch};
+    ///
+    /// # async fn example(category: Categories, pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let patch = CategoryPatch {
+    ///     name: Some("Groceries".to_string()),
+    ///     ..CategoryPatch::default()
+    /// };
+    ///
+    /// let updated = Categories::update_fields(category.id, patch, pool).await?;
+    /// assert_eq!(updated.name, "Groceries");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update category fields in database",
+        skip(changes, pool),
+        fields(id = %id),
+        err
+    )]
+    pub async fn update_fields(
+        id: domain::RowID,
+        changes: CategoryPatch,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        let CategoryPatch {
+            code,
+            name,
+            description,
+            url_slug,
+            category_type,
+            color,
+            icon,
+            is_active,
+            display_order,
+            external_id,
+            reactivate_on,
+            parent_id,
+        } = changes;
+
+        let mut update_query = sqlx::QueryBuilder::<sqlx::Sqlite>::new("UPDATE categories SET ");
+        let mut assignments = update_query.separated(", ");
+        if let Some(code) = code {
+            assignments.push("code = ").push_bind_unseparated(code);
+        }
+        if let Some(name) = name {
+            assignments.push("name = ").push_bind_unseparated(name);
+        }
+        if let Some(description) = description {
+            assignments.push("description = ").push_bind_unseparated(description);
+        }
+        if let Some(url_slug) = url_slug {
+            assignments.push("url_slug = ").push_bind_unseparated(url_slug);
+        }
+        if let Some(category_type) = category_type {
+            assignments.push("category_type = ").push_bind_unseparated(category_type);
+        }
+        if let Some(color) = color {
+            assignments.push("color = ").push_bind_unseparated(color);
+        }
+        if let Some(icon) = icon {
+            assignments.push("icon = ").push_bind_unseparated(icon);
+        }
+        if let Some(is_active) = is_active {
+            assignments.push("is_active = ").push_bind_unseparated(is_active);
+        }
+        if let Some(display_order) = display_order {
+            assignments.push("display_order = ").push_bind_unseparated(display_order);
+        }
+        if let Some(external_id) = external_id {
+            assignments.push("external_id = ").push_bind_unseparated(external_id);
+        }
+        if let Some(reactivate_on) = reactivate_on {
+            assignments.push("reactivate_on = ").push_bind_unseparated(reactivate_on);
+        }
+        if let Some(parent_id) = parent_id {
+            assignments.push("parent_id = ").push_bind_unseparated(parent_id);
+        }
+        assignments.push("updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')");
+        update_query.push(" WHERE id = ").push_bind(id).push(" AND deleted_on IS NULL");
+
+        let rows_affected = update_query.build().execute(pool).await?.rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Category with id {} not found",
+                id
+            )));
+        }
+
+        tracing::info!("Patched category {} in database", id);
+
+        // Read back the updated category
+        let updated = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE id = ? AND deleted_on IS NULL
+            "#,
+            id
+        )
         .fetch_one(pool)
         .await?;
 
@@ -140,7 +352,7 @@ impl database::Categories {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - Any category with the given ID does not exist
+    /// - Any category with the given ID does not exist or is soft-deleted
     /// - Any updated category violates database constraints
     /// - Database connection fails
     /// - Transaction fails to commit
@@ -194,8 +406,8 @@ impl database::Categories {
                 r#"
                     UPDATE categories
                     SET code = ?, name = ?, description = ?, url_slug = ?, category_type = ?,
-                        color = ?, icon = ?, is_active = ?, updated_on = ?
-                    WHERE id = ?
+                        color = ?, icon = ?, is_active = ?, display_order = ?, external_id = ?, reactivate_on = ?, parent_id = ?, updated_on = ?
+                    WHERE id = ? AND deleted_on IS NULL
                 "#,
                 category.code,
                 category.name,
@@ -205,6 +417,10 @@ impl database::Categories {
                 category.color,
                 category.icon,
                 category.is_active,
+                category.display_order,
+                category.external_id,
+                category.reactivate_on,
+                category.parent_id,
                 category.updated_on,
                 category.id
             );
@@ -232,10 +448,15 @@ impl database::Categories {
                         color           AS "color?: domain::HexColor",
                         icon,
                         is_active       AS "is_active!: bool",
+                        display_order   AS "display_order!: i64",
+                        external_id     AS "external_id?: domain::ExternalRef",
+                        reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                        parent_id       AS "parent_id?: domain::RowID",
                         created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
                     FROM categories
-                    WHERE id = ?
+                    WHERE id = ? AND deleted_on IS NULL
                 "#,
                 category.id
             )
@@ -253,173 +474,612 @@ impl database::Categories {
         Ok(updated_categories)
     }
 
-    /// Updates the active status of a category.
+    /// Applies a new display order to many categories in a single transaction.
     ///
-    /// This is a convenience function for toggling category active/inactive status,
-    /// which is a common operation in category management.
+    /// Each `(id, display_order)` pair moves one category to a new position. The
+    /// whole set is applied atomically: if any ID does not exist the transaction
+    /// is rolled back and no category is reordered.
     ///
     /// # Arguments
     ///
-    /// * `id` - The ID of the category to update
-    /// * `is_active` - The new active status
+    /// * `orders` - Pairs of category ID and the display order to assign to it
     /// * `pool` - The database connection pool
     ///
     /// # Returns
     ///
-    /// Returns the updated category, or a `DatabaseError` if the update fails.
+    /// Returns the reordered categories in the same order as `orders`, or a
+    /// `DatabaseError` if any update fails.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The category with the given ID does not exist
+    /// - The same category ID appears more than once (`DatabaseError::Validation`)
+    /// - Any category with the given ID does not exist or is soft-deleted (`DatabaseError::NotFound`)
     /// - Database connection fails
+    /// - Transaction fails to commit
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// use personal_ledger_backend::database::categories::Category;
-    /// use personal_ledger_backend::database::DatabasePool;
-    /// use personal_ledger_backend::domain::RowID;
+    /// use personal_ledger_backend::database::Categories;
     ///
-    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
-    /// // Assuming we have a category ID
-    /// let category_id = RowID::new();
+    /// # async fn example(pool: &sqlx::SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let categories = Categories::find_all_ordered_by_display(pool).await?;
     ///
-    /// // Deactivate the category
-    /// let updated = Category::update_active_status(category_id, false, pool).await?;
-    /// assert!(!updated.is_active);
+    /// // Reverse the current ordering
+    /// let orders = categories
+    ///     .iter()
+    ///     .rev()
+    ///     .enumerate()
+    ///     .map(|(position, category)| (category.id, position as i64))
+    ///     .collect::<Vec<_>>();
     ///
-    /// // Reactivate the category
-    /// let updated = Category::update_active_status(category_id, true, pool).await?;
-    /// assert!(updated.is_active);
+    /// let reordered = Categories::reorder(&orders, pool).await?;
+    /// assert_eq!(reordered.len(), categories.len());
     /// # Ok(())
     /// # }
     /// ```
     #[tracing::instrument(
-        name = "Update category active status",
-        skip(pool),
-        fields(id = %id, is_active = %is_active),
+        name = "Reorder categories in database",
+        skip(orders, pool),
+        fields(count = orders.len()),
         err
     )]
-    pub async fn update_active_status(
-        id: domain::RowID,
-        is_active: bool,
+    pub async fn reorder(
+        orders: &[(domain::RowID, i64)],
         pool: &sqlx::Pool<sqlx::Sqlite>,
-    ) -> DatabaseResult<Self> {
-        // Update only the active status and updated_on timestamp
-        let update_query = sqlx::query!(
-            r#"
-                UPDATE categories
-                SET is_active = ?, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
-                WHERE id = ?
-            "#,
-            is_active,
-            id
-        );
-
-        let rows_affected = update_query.execute(pool).await?.rows_affected();
+    ) -> DatabaseResult<Vec<Self>> {
+        if orders.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        if rows_affected == 0 {
-            return Err(database::DatabaseError::NotFound(format!(
-                "Category with id {} not found",
-                id
+        let mut seen = std::collections::HashSet::with_capacity(orders.len());
+        if let Some((duplicate, _)) = orders.iter().find(|(id, _)| !seen.insert(*id)) {
+            return Err(database::DatabaseError::Validation(format!(
+                "Category with id {} appears more than once in reorder",
+                duplicate
             )));
         }
 
-        tracing::info!("Updated active status for category {} to {}", id, is_active);
-
-        // Read back the updated category
-        let updated = sqlx::query_as!(
-            database::Categories,
-            r#"
-                SELECT
-                    id              AS "id!: domain::RowID",
-                    code,
-                    name,
-                    description,
-                    url_slug        AS "url_slug?: domain::UrlSlug",
-                    category_type   AS "category_type!: domain::CategoryTypes",
-                    color           AS "color?: domain::HexColor",
-                    icon,
-                    is_active       AS "is_active!: bool",
-                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
-                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>"
-                FROM categories
-                WHERE id = ?
-            "#,
-            id
-        )
-        .fetch_one(pool)
-        .await?;
-
-        Ok(updated)
-    }
-}
-
-#[cfg(test)]
-pub mod tests {
-    // Bring module into test scope
-    use super::*;
-
-    // Override with more flexible error
-    pub type Result<T> = core::result::Result<T, Error>;
-    pub type Error = Box<dyn std::error::Error>;
-
-    #[sqlx::test]
-    async fn update_existing_category_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
-        // First insert a category
-        let original = database::Categories::mock();
-        let inserted = original.insert(&pool).await?;
-
-        // Update the category
-        let updated_name = "Updated Category Name".to_string();
-        let updated_description = Some("Updated description".to_string());
-        let original_code = inserted.code.clone();
-        let original_created_on = inserted.created_on;
+        // Use a transaction so a single unknown ID rolls back the whole set
+        let mut tx = pool.begin().await?;
 
-        let updated_category = database::Categories {
-            name: updated_name.clone(),
-            description: updated_description.clone(),
-            updated_on: chrono::Utc::now(),
-            ..inserted
-        };
+        let mut reordered = Vec::with_capacity(orders.len());
 
-        let result = updated_category.update(&pool).await?;
+        for (id, display_order) in orders {
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET display_order = ?
+                    WHERE id = ? AND deleted_on IS NULL
+                "#,
+                display_order,
+                id
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
 
-        // Verify the update
-        assert_eq!(result.id, inserted.id);
-        assert_eq!(result.code, original_code); // Code should remain the same
-        assert_eq!(result.name, updated_name);
-        assert_eq!(result.description, updated_description);
-        assert_eq!(result.created_on, original_created_on); // Created time should be preserved
-        assert_ne!(result.updated_on, inserted.updated_on); // Updated time should change
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Category with id {} not found",
+                    id
+                )));
+            }
 
-        Ok(())
-    }
+            // Read back the reordered category
+            let updated = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        display_order   AS "display_order!: i64",
+                        external_id     AS "external_id?: domain::ExternalRef",
+                        reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                    FROM categories
+                    WHERE id = ? AND deleted_on IS NULL
+                "#,
+                id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
 
-    #[sqlx::test]
-    async fn update_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
-        let fake_category = database::Categories::mock();
+            reordered.push(updated);
+        }
 
-        let result = fake_category.update(&pool).await;
+        // Commit the transaction
+        tx.commit().await?;
 
-        assert!(result.is_err());
-        // The error should be a NotFound error
-        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+        tracing::info!("Reordered {} categories in database", reordered.len());
 
-        Ok(())
+        Ok(reordered)
     }
 
-    #[sqlx::test]
-    async fn update_category_with_duplicate_name_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
-        // Insert two categories
-        let category1 = database::Categories::mock();
-        let inserted1 = category1.insert(&pool).await?;
-
-        let category2 = database::Categories::mock();
-        let inserted2 = category2.insert(&pool).await?;
-
-        // Try to update category2 with the same name as category1
+    /// Reclassifies many categories to a new category type in a single transaction.
+    ///
+    /// Categories on the chart of accounts numbering scheme, those whose code
+    /// is a plain number as generated by [`next_code_in_range`](Self::next_code_in_range),
+    /// must have a code inside the new type's
+    /// [`code_range`](domain::CategoryTypes::code_range). Other codes are not
+    /// checked. If any category is missing or its code does not fit, the
+    /// transaction is rolled back and no category changes type.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the categories to reclassify
+    /// * `new_type` - The category type to assign
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the reclassified categories in the same order as `ids`, or a
+    /// `DatabaseError` if any update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The same category ID appears more than once (`DatabaseError::Validation`)
+    /// - A numbered code falls outside the new type's range (`DatabaseError::Validation`)
+    /// - Any category with the given ID does not exist or is soft-deleted (`DatabaseError::NotFound`)
+    /// - Database connection fails
+    /// - Transaction fails to commit
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::Categories;
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// # async fn example(pool: &sqlx::SqlitePool, ids: &[personal_ledger_backend::domain::RowID]) -> Result<(), Box<dyn std::error::Error>> {
+    /// let reclassified = Categories::bulk_set_type(ids, CategoryTypes::Liability, pool).await?;
+    /// assert!(reclassified.iter().all(|c| c.category_type == CategoryTypes::Liability));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Set category type of many categories in database",
+        skip(ids, pool),
+        fields(count = ids.len(), new_type = %new_type),
+        err
+    )]
+    pub async fn bulk_set_type(
+        ids: &[domain::RowID],
+        new_type: domain::CategoryTypes,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Vec<Self>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut seen = std::collections::HashSet::with_capacity(ids.len());
+        if let Some(duplicate) = ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(database::DatabaseError::Validation(format!(
+                "Category with id {} appears more than once in type change",
+                duplicate
+            )));
+        }
+
+        // Use a transaction so one missing or out of range category rolls back the whole set
+        let mut tx = pool.begin().await?;
+
+        let range = new_type.code_range();
+        let mut out_of_range = Vec::new();
+        let mut reclassified = Vec::with_capacity(ids.len());
+
+        for id in ids {
+            let rows_affected = sqlx::query!(
+                r#"
+                    UPDATE categories
+                    SET category_type = ?, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+                    WHERE id = ? AND deleted_on IS NULL
+                "#,
+                new_type,
+                id
+            )
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            if rows_affected == 0 {
+                return Err(database::DatabaseError::NotFound(format!(
+                    "Category with id {} not found",
+                    id
+                )));
+            }
+
+            // Read back the reclassified category
+            let updated = sqlx::query_as!(
+                database::Categories,
+                r#"
+                    SELECT
+                        id              AS "id!: domain::RowID",
+                        code,
+                        name,
+                        description,
+                        url_slug        AS "url_slug?: domain::UrlSlug",
+                        category_type   AS "category_type!: domain::CategoryTypes",
+                        color           AS "color?: domain::HexColor",
+                        icon,
+                        is_active       AS "is_active!: bool",
+                        display_order   AS "display_order!: i64",
+                        external_id     AS "external_id?: domain::ExternalRef",
+                        reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                        parent_id       AS "parent_id?: domain::RowID",
+                        created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                        updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                        deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                    FROM categories
+                    WHERE id = ? AND deleted_on IS NULL
+                "#,
+                id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if numbered_code(&updated.code).is_some_and(|code| !range.contains(&code)) {
+                out_of_range.push(updated.code.clone());
+            }
+
+            reclassified.push(updated);
+        }
+
+        // Report every code that does not fit, rather than only the first
+        if !out_of_range.is_empty() {
+            return Err(database::DatabaseError::Validation(format!(
+                "Codes {} are outside the {} code range {}-{}",
+                out_of_range.join(", "),
+                new_type,
+                range.start(),
+                range.end()
+            )));
+        }
+
+        // Commit the transaction
+        tx.commit().await?;
+
+        tracing::info!("Set {} categories to type {} in database", reclassified.len(), new_type);
+
+        Ok(reclassified)
+    }
+
+    /// Updates the active status of a category.
+    ///
+    /// This is a convenience function for toggling category active/inactive status,
+    /// which is a common operation in category management. Any scheduled
+    /// reactivation is cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to update
+    /// * `is_active` - The new active status
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated category, or a `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category with the given ID does not exist or is soft-deleted
+    /// - Database connection fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::database::categories::Category;
+    /// use personal_ledger_backend::database::DatabasePool;
+    /// use personal_ledger_backend::domain::RowID;
+    ///
+    /// # async fn example(pool: &DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    /// // Assuming we have a category ID
+    /// let category_id = RowID::new();
+    ///
+    /// // Deactivate the category
+    /// let updated = Category::update_active_status(category_id, false, pool).await?;
+    /// assert!(!updated.is_active);
+    ///
+    /// // Reactivate the category
+    /// let updated = Category::update_active_status(category_id, true, pool).await?;
+    /// assert!(updated.is_active);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(
+        name = "Update category active status",
+        skip(pool),
+        fields(id = %id, is_active = %is_active),
+        err
+    )]
+    pub async fn update_active_status(
+        id: domain::RowID,
+        is_active: bool,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        // Update only the active status and updated_on timestamp
+        let update_query = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET is_active = ?, reactivate_on = NULL, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+                WHERE id = ? AND deleted_on IS NULL
+            "#,
+            is_active,
+            id
+        );
+
+        let rows_affected = update_query.execute(pool).await?.rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Category with id {} not found",
+                id
+            )));
+        }
+
+        tracing::info!("Updated active status for category {} to {}", id, is_active);
+
+        // Read back the updated category
+        let updated = sqlx::query_as!(
+            database::Categories,
+            r#"
+                SELECT
+                    id              AS "id!: domain::RowID",
+                    code,
+                    name,
+                    description,
+                    url_slug        AS "url_slug?: domain::UrlSlug",
+                    category_type   AS "category_type!: domain::CategoryTypes",
+                    color           AS "color?: domain::HexColor",
+                    icon,
+                    is_active       AS "is_active!: bool",
+                    display_order   AS "display_order!: i64",
+                    external_id     AS "external_id?: domain::ExternalRef",
+                    reactivate_on   AS "reactivate_on?: chrono::DateTime<chrono::Utc>",
+                    parent_id       AS "parent_id?: domain::RowID",
+                    created_on      AS "created_on!: chrono::DateTime<chrono::Utc>",
+                    updated_on      AS "updated_on!: chrono::DateTime<chrono::Utc>",
+                    deleted_on      AS "deleted_on?: chrono::DateTime<chrono::Utc>"
+                FROM categories
+                WHERE id = ? AND deleted_on IS NULL
+            "#,
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    /// Deactivates a category, optionally scheduling it to reactivate.
+    ///
+    /// When `reactivate_on` is set, [`Categories::reactivate_due`] sets the
+    /// category active again once that moment has passed. Passing `None`
+    /// deactivates indefinitely and clears any earlier schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the category to deactivate
+    /// * `reactivate_on` - When the category should become active again
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the deactivated category, or a `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The category with the given ID does not exist or is soft-deleted
+    /// - Database connection fails
+    #[tracing::instrument(
+        name = "Deactivate category until",
+        skip(pool),
+        fields(id = %id, reactivate_on = ?reactivate_on),
+        err
+    )]
+    pub async fn deactivate_until(
+        id: domain::RowID,
+        reactivate_on: Option<chrono::DateTime<chrono::Utc>>,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET is_active = FALSE, reactivate_on = ?, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+                WHERE id = ? AND deleted_on IS NULL
+            "#,
+            reactivate_on,
+            id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Category with id {} not found",
+                id
+            )));
+        }
+
+        tracing::info!("Deactivated category {} until {:?}", id, reactivate_on);
+
+        Self::find_by_id(id, pool).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!("Category with id {} not found", id))
+        })
+    }
+
+    /// Deactivates the category with the given code, for operators who know
+    /// the code but not the id.
+    ///
+    /// Like [`Categories::deactivate_until`] with no reactivation date, this
+    /// clears any earlier schedule. Deactivating an inactive category succeeds
+    /// and leaves it inactive. Soft-deleted categories are not matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code of the category to deactivate
+    /// * `pool` - The database connection pool
+    ///
+    /// # Returns
+    ///
+    /// Returns the deactivated category, or a `DatabaseError` if the update fails.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - No category has the given code
+    /// - Database connection fails
+    #[tracing::instrument(name = "Deactivate category by code", skip(pool), fields(code = %code), err)]
+    pub async fn deactivate_by_code(code: &str, pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET is_active = FALSE, reactivate_on = NULL, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+                WHERE code = ? AND deleted_on IS NULL
+            "#,
+            code
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(database::DatabaseError::NotFound(format!(
+                "Category with code {} not found",
+                code
+            )));
+        }
+
+        tracing::info!("Deactivated category with code {}", code);
+
+        Self::find_by_code(code, pool).await?.ok_or_else(|| {
+            database::DatabaseError::NotFound(format!("Category with code {} not found", code))
+        })
+    }
+
+    /// Reactivates every inactive category whose `reactivate_on` is at or
+    /// before `now`, clearing the schedule.
+    ///
+    /// Timestamps are compared with `julianday` so values written with a `Z`
+    /// or `+00:00` suffix compare correctly.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of categories reactivated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DatabaseError` if the update fails.
+    #[tracing::instrument(name = "Reactivate due categories", skip(pool), err)]
+    pub async fn reactivate_due(
+        now: chrono::DateTime<chrono::Utc>,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<u64> {
+        let rows_affected = sqlx::query!(
+            r#"
+                UPDATE categories
+                SET is_active = TRUE, reactivate_on = NULL, updated_on = strftime('%Y-%m-%dT%H:%M:%fZ','now')
+                WHERE is_active = FALSE
+                    AND reactivate_on IS NOT NULL
+                    AND julianday(reactivate_on) <= julianday(?)
+            "#,
+            now
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected > 0 {
+            tracing::info!("Reactivated {} categories", rows_affected);
+        }
+
+        Ok(rows_affected)
+    }
+}
+
+/// The account number of a code on the numbering scheme, or `None` for any
+/// other code. Only canonical numbers count, so `"01000"` is not `1000`.
+fn numbered_code(code: &str) -> Option<u32> {
+    code.parse::<u32>().ok().filter(|number| number.to_string() == code)
+}
+
+#[cfg(test)]
+pub mod tests {
+    // Bring module into test scope
+    use super::*;
+
+    // Override with more flexible error
+    pub type Result<T> = core::result::Result<T, Error>;
+    pub type Error = Box<dyn std::error::Error>;
+
+    #[sqlx::test]
+    async fn update_existing_category_success(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        // First insert a category
+        let original = database::Categories::mock();
+        let inserted = original.insert(&pool).await?;
+
+        // Update the category
+        let updated_name = "Updated Category Name".to_string();
+        let updated_description = Some("Updated description".to_string());
+        let original_code = inserted.code.clone();
+        let original_created_on = inserted.created_on;
+
+        let updated_category = database::Categories {
+            name: updated_name.clone(),
+            description: updated_description.clone(),
+            updated_on: chrono::Utc::now(),
+            ..inserted
+        };
+
+        let result = updated_category.update(&pool).await?;
+
+        // Verify the update
+        assert_eq!(result.id, inserted.id);
+        assert_eq!(result.code, original_code); // Code should remain the same
+        assert_eq!(result.name, updated_name);
+        assert_eq!(result.description, updated_description);
+        assert_eq!(result.created_on, original_created_on); // Created time should be preserved
+        assert_ne!(result.updated_on, inserted.updated_on); // Updated time should change
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let fake_category = database::Categories::mock();
+
+        let result = fake_category.update(&pool).await;
+
+        assert!(result.is_err());
+        // The error should be a NotFound error
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_category_with_duplicate_name_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        // Insert two categories
+        let category1 = database::Categories::mock();
+        let inserted1 = category1.insert(&pool).await?;
+
+        let category2 = database::Categories::mock();
+        let inserted2 = category2.insert(&pool).await?;
+
+        // Try to update category2 with the same name as category1
         let updated_category2 = database::Categories {
             name: inserted1.name.clone(),
             updated_on: chrono::Utc::now(),
@@ -534,6 +1194,29 @@ pub mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn updates_to_soft_deleted_category_fail(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let category = database::Categories::mock().insert(&pool).await?;
+        database::Categories::soft_delete(category.id, &pool).await?;
+
+        let mut renamed = category.clone();
+        renamed.name = "Renamed after delete".to_string();
+        let result = renamed.update(&pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+
+        let result = database::Categories::update_active_status(category.id, false, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+
+        let patch = database::CategoryPatch {
+            name: Some("Patched after delete".to_string()),
+            ..database::CategoryPatch::default()
+        };
+        let result = database::Categories::update_fields(category.id, patch, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))), "{:?}", result);
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn update_preserves_created_on_timestamp(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
         // Insert a category
@@ -558,4 +1241,395 @@ pub mod tests {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[sqlx::test]
+    async fn reorder_applies_every_display_order(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let categories = vec![
+            database::Categories::mock(),
+            database::Categories::mock(),
+            database::Categories::mock(),
+        ];
+        let inserted = database::Categories::insert_many(&categories, &pool).await?;
+
+        // Reverse the insertion order
+        let orders = inserted
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(position, category)| (category.id, position as i64))
+            .collect::<Vec<_>>();
+
+        let reordered = database::Categories::reorder(&orders, &pool).await?;
+
+        assert_eq!(reordered.len(), orders.len());
+        for ((id, display_order), category) in orders.iter().zip(&reordered) {
+            assert_eq!(category.id, *id);
+            assert_eq!(category.display_order, *display_order);
+        }
+
+        // The display ordering now follows the reversed insertion order
+        let ordered_ids = database::Categories::find_all_ordered_by_display(&pool)
+            .await?
+            .into_iter()
+            .map(|category| category.id)
+            .collect::<Vec<_>>();
+        let expected_ids = inserted.iter().rev().map(|category| category.id).collect::<Vec<_>>();
+        assert_eq!(ordered_ids, expected_ids);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reorder_with_unknown_id_rolls_back(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let first = database::Categories { display_order: 1, ..database::Categories::mock() }.insert(&pool).await?;
+        let second = database::Categories { display_order: 2, ..database::Categories::mock() }.insert(&pool).await?;
+
+        // The unknown ID comes last so the earlier updates must be rolled back
+        let orders = vec![(first.id, 20), (second.id, 10), (domain::RowID::new(), 0)];
+        let result = database::Categories::reorder(&orders, &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        let ordered = database::Categories::find_all_ordered_by_display(&pool).await?;
+        assert_eq!(ordered.len(), 2);
+        assert_eq!((ordered[0].id, ordered[0].display_order), (first.id, 1));
+        assert_eq!((ordered[1].id, ordered[1].display_order), (second.id, 2));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reorder_rejects_duplicate_ids(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+
+        let result = database::Categories::reorder(&[(inserted.id, 1), (inserted.id, 2)], &pool).await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Validation(_))));
+        let unchanged = database::Categories::find_by_id(inserted.id, &pool).await?.unwrap();
+        assert_eq!(unchanged.display_order, inserted.display_order);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reorder_empty_list(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let reordered = database::Categories::reorder(&[], &pool).await?;
+        assert!(reordered.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn reactivate_due_reactivates_past_and_keeps_future(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let now = chrono::Utc::now();
+        let past = database::Categories { is_active: true, ..database::Categories::mock() }.insert(&pool).await?;
+        let future = database::Categories { is_active: true, ..database::Categories::mock() }.insert(&pool).await?;
+
+        let deactivated =
+            database::Categories::deactivate_until(past.id, Some(now - chrono::Duration::hours(1)), &pool).await?;
+        assert!(!deactivated.is_active);
+        assert!(deactivated.reactivate_on.is_some());
+        database::Categories::deactivate_until(future.id, Some(now + chrono::Duration::days(1)), &pool).await?;
+
+        let reactivated = database::Categories::reactivate_due(now, &pool).await?;
+        assert_eq!(reactivated, 1);
+
+        let past = database::Categories::find_by_id(past.id, &pool).await?.unwrap();
+        assert!(past.is_active);
+        assert_eq!(past.reactivate_on, None);
+
+        let future = database::Categories::find_by_id(future.id, &pool).await?.unwrap();
+        assert!(!future.is_active);
+        assert!(future.reactivate_on.is_some());
+
+        // Nothing further is due
+        assert_eq!(database::Categories::reactivate_due(now, &pool).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_active_status_clears_reactivation(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+        let past = chrono::Utc::now() - chrono::Duration::hours(1);
+        database::Categories::deactivate_until(inserted.id, Some(past), &pool).await?;
+
+        // A manual deactivation replaces the schedule, so the sweep leaves it alone
+        let deactivated = database::Categories::update_active_status(inserted.id, false, &pool).await?;
+        assert_eq!(deactivated.reactivate_on, None);
+        assert_eq!(database::Categories::reactivate_due(chrono::Utc::now(), &pool).await?, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deactivate_until_nonexistent_category_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Categories::deactivate_until(domain::RowID::new(), None, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deactivate_by_code_deactivates_the_category(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut category = database::Categories::mock();
+        category.is_active = true;
+        let inserted = category.insert(&pool).await?;
+        let future = chrono::Utc::now() + chrono::Duration::days(1);
+        database::Categories::deactivate_until(inserted.id, Some(future), &pool).await?;
+        database::Categories::update_active_status(inserted.id, true, &pool).await?;
+
+        let deactivated = database::Categories::deactivate_by_code(&inserted.code, &pool).await?;
+
+        assert_eq!(deactivated.id, inserted.id);
+        assert!(!deactivated.is_active);
+        assert_eq!(deactivated.reactivate_on, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deactivate_by_code_unknown_code_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let result = database::Categories::deactivate_by_code("NO-SUCH-CODE", &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn deactivate_by_code_keeps_inactive_category_inactive(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut category = database::Categories::mock();
+        category.is_active = false;
+        let inserted = category.insert(&pool).await?;
+
+        let deactivated = database::Categories::deactivate_by_code(&inserted.code, &pool).await?;
+        assert!(!deactivated.is_active);
+
+        let again = database::Categories::deactivate_by_code(&inserted.code, &pool).await?;
+        assert_eq!(again.id, inserted.id);
+        assert!(!again.is_active);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn raw_update_without_updated_on_still_bumps_it(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+
+        // Bypass the application layer entirely
+        sqlx::query("UPDATE categories SET name = 'Renamed by hand' WHERE id = ?")
+            .bind(inserted.id)
+            .execute(&pool)
+            .await?;
+
+        let found = database::Categories::find_by_id(inserted.id, &pool).await?.unwrap();
+        assert_eq!(found.name, "Renamed by hand");
+        assert!(found.updated_on > inserted.updated_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn raw_update_with_stale_updated_on_is_corrected(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+        let stale = inserted.updated_on - chrono::Duration::days(1);
+
+        sqlx::query("UPDATE categories SET name = 'Renamed by hand', updated_on = ? WHERE id = ?")
+            .bind(stale)
+            .bind(inserted.id)
+            .execute(&pool)
+            .await?;
+
+        let found = database::Categories::find_by_id(inserted.id, &pool).await?.unwrap();
+        assert!(found.updated_on > inserted.updated_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_without_new_updated_on_still_bumps_it(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories::mock().insert(&pool).await?;
+
+        // The caller forgot to set updated_on, so the stored value is written back
+        let result = database::Categories {
+            name: "Updated Category Name".to_string(),
+            ..inserted.clone()
+        }
+        .update(&pool)
+        .await?;
+
+        assert!(result.updated_on > inserted.updated_on);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_set_type_reclassifies_every_category(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut ids = Vec::new();
+        for code in ["2001", "2002", "LEGACY.LOAN"] {
+            let category = database::Categories {
+                code: code.to_string(),
+                name: format!("Category {}", code),
+                url_slug: None,
+                category_type: domain::CategoryTypes::Expense,
+                ..database::Categories::mock()
+            };
+            ids.push(category.insert(&pool).await?.id);
+        }
+
+        let reclassified = database::Categories::bulk_set_type(&ids, domain::CategoryTypes::Liability, &pool).await?;
+
+        assert_eq!(reclassified.iter().map(|c| c.id).collect::<Vec<_>>(), ids);
+        for id in &ids {
+            let found = database::Categories::find_by_id(*id, &pool).await?.unwrap();
+            assert_eq!(found.category_type, domain::CategoryTypes::Liability);
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_set_type_rejects_codes_outside_new_range(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let mut ids = Vec::new();
+        for code in ["2001", "5001", "5002"] {
+            let category = database::Categories {
+                code: code.to_string(),
+                name: format!("Category {}", code),
+                url_slug: None,
+                category_type: domain::CategoryTypes::Expense,
+                ..database::Categories::mock()
+            };
+            ids.push(category.insert(&pool).await?.id);
+        }
+
+        let result = database::Categories::bulk_set_type(&ids, domain::CategoryTypes::Liability, &pool).await;
+
+        match result {
+            Err(database::DatabaseError::Validation(message)) => {
+                assert!(message.contains("5001, 5002"), "{}", message);
+                assert!(message.contains("2000-2999"), "{}", message);
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+
+        // Nothing changed type, including the category whose code fits
+        for id in &ids {
+            let found = database::Categories::find_by_id(*id, &pool).await?.unwrap();
+            assert_eq!(found.category_type, domain::CategoryTypes::Expense);
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn bulk_set_type_with_unknown_id_rolls_back(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories {
+            category_type: domain::CategoryTypes::Expense,
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+
+        let result = database::Categories::bulk_set_type(
+            &[inserted.id, domain::RowID::new()],
+            domain::CategoryTypes::Income,
+            &pool,
+        )
+        .await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        let found = database::Categories::find_by_id(inserted.id, &pool).await?.unwrap();
+        assert_eq!(found.category_type, domain::CategoryTypes::Expense);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_fields_writes_only_patched_fields(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories {
+            description: Some("Kept description".to_string()),
+            updated_on: chrono::Utc::now() - chrono::Duration::hours(1),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+
+        let patch = CategoryPatch {
+            name: Some("Patched Name".to_string()),
+            ..CategoryPatch::default()
+        };
+        let patched = database::Categories::update_fields(inserted.id, patch, &pool).await?;
+
+        assert_eq!(patched.name, "Patched Name");
+        assert!(patched.updated_on > inserted.updated_on);
+        assert!(
+            patched.eq_ignoring_timestamps(&database::Categories { name: "Patched Name".to_string(), ..inserted.clone() }),
+            "only the name should change: {:?}",
+            inserted.diff(&patched)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_fields_clears_nullable_fields(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories {
+            description: Some("Cleared description".to_string()),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+
+        let patch = CategoryPatch {
+            description: Some(None),
+            is_active: Some(!inserted.is_active),
+            ..CategoryPatch::default()
+        };
+        let patched = database::Categories::update_fields(inserted.id, patch, &pool).await?;
+
+        assert_eq!(patched.description, None);
+        assert_eq!(patched.is_active, !inserted.is_active);
+        assert_eq!(patched.code, inserted.code);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_fields_with_empty_patch_bumps_updated_on(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let inserted = database::Categories {
+            updated_on: chrono::Utc::now() - chrono::Duration::hours(1),
+            ..database::Categories::mock()
+        }
+        .insert(&pool)
+        .await?;
+        assert!(CategoryPatch::default().is_empty());
+
+        let patched = database::Categories::update_fields(inserted.id, CategoryPatch::default(), &pool).await?;
+
+        assert!(patched.updated_on > inserted.updated_on);
+        assert!(patched.eq_ignoring_timestamps(&inserted));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn update_fields_unknown_id_fails(pool: sqlx::Pool<sqlx::Sqlite>) -> Result<()> {
+        let patch = CategoryPatch {
+            name: Some("Nobody".to_string()),
+            ..CategoryPatch::default()
+        };
+
+        let result = database::Categories::update_fields(domain::RowID::new(), patch, &pool).await;
+        assert!(matches!(result, Err(database::DatabaseError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn numbered_code_accepts_only_canonical_numbers() {
+        assert_eq!(numbered_code("2001"), Some(2001));
+        assert_eq!(numbered_code("02001"), None);
+        assert_eq!(numbered_code("EXP.FOOD"), None);
+        assert_eq!(numbered_code("-1"), None);
+    }
+}
@@ -0,0 +1,235 @@
+//! # Database Migrations
+//!
+//! This module exposes the migrations embedded in the binary and helpers for
+//! inspecting and applying them at startup.
+//!
+//! ## Overview
+//!
+//! - [`MIGRATOR`]: The migrations under `./migrations`, compiled into the binary
+//! - [`MigrationStatus`]: Which migrations have been applied and which are pending
+//! - [`prepare_schema`]: Startup policy that either applies pending migrations or
+//!   refuses to continue when `require_migrated` is set
+//!
+//! In production, deploys are expected to run migrations explicitly. Setting
+//! `server.require_migrated = true` makes startup fail with
+//! [`DatabaseError::PendingMigrations`] instead of silently migrating.
+
+use sqlx::migrate::Migrate;
+
+use crate::database::{DatabaseError, DatabaseResult};
+
+/// Name of the bookkeeping table SQLx uses to record applied migrations.
+const MIGRATIONS_TABLE: &str = "_sqlx_migrations";
+
+/// Migrations compiled into the binary from the `./migrations` directory.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// A migration known to the binary that has not been applied to the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingMigration {
+    /// Migration version, taken from the numeric prefix of the file name.
+    pub version: i64,
+
+    /// Human readable description, taken from the rest of the file name.
+    pub description: String,
+}
+
+impl std::fmt::Display for PendingMigration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.version, self.description)
+    }
+}
+
+/// Snapshot of the database schema compared with the embedded migrations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationStatus {
+    /// Versions recorded as applied in the database, in ascending order.
+    pub applied: Vec<i64>,
+
+    /// Migrations known to the binary that are not yet applied, in ascending order.
+    pub pending: Vec<PendingMigration>,
+}
+
+impl MigrationStatus {
+    /// Compare the database against the embedded [`MIGRATOR`].
+    ///
+    /// This is read only: the SQLx bookkeeping table is not created when it is
+    /// missing, every migration is simply reported as pending.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DatabaseError::Sqlx`] or [`DatabaseError::Migration`] if the
+    /// migration table cannot be read.
+    pub async fn check(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<Self> {
+        Self::check_with(&MIGRATOR, pool).await
+    }
+
+    /// Compare the database against an arbitrary `migrator`.
+    ///
+    /// See [`MigrationStatus::check`].
+    #[tracing::instrument(name = "Check migration status", skip(migrator, pool), err)]
+    pub async fn check_with(
+        migrator: &sqlx::migrate::Migrator,
+        pool: &sqlx::Pool<sqlx::Sqlite>,
+    ) -> DatabaseResult<Self> {
+        let mut connection = pool.acquire().await?;
+
+        let table_exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?)",
+        )
+        .bind(MIGRATIONS_TABLE)
+        .fetch_one(&mut *connection)
+        .await?;
+
+        let mut applied: Vec<i64> = if table_exists {
+            connection
+                .list_applied_migrations()
+                .await?
+                .into_iter()
+                .map(|migration| migration.version)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        applied.sort_unstable();
+
+        let pending = migrator
+            .iter()
+            .filter(|migration| migration.migration_type.is_up_migration())
+            .filter(|migration| applied.binary_search(&migration.version).is_err())
+            .map(|migration| PendingMigration {
+                version: migration.version,
+                description: migration.description.to_string(),
+            })
+            .collect();
+
+        Ok(Self { applied, pending })
+    }
+
+    /// Returns `true` when there are no pending migrations.
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Fail with [`DatabaseError::PendingMigrations`] if any migration is pending.
+    ///
+    /// The error message lists each pending migration so operators know what
+    /// needs to be run.
+    pub fn ensure_up_to_date(&self) -> DatabaseResult<()> {
+        if self.is_up_to_date() {
+            return Ok(());
+        }
+
+        let pending = self
+            .pending
+            .iter()
+            .map(PendingMigration::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Err(DatabaseError::PendingMigrations(format!(
+            "{} not applied [{}]; run the migrations before starting the server or disable `server.require_migrated`",
+            self.pending.len(),
+            pending
+        )))
+    }
+}
+
+/// Apply any pending embedded migrations to the database.
+///
+/// # Errors
+///
+/// Returns [`DatabaseError::Migration`] if a migration fails to apply.
+#[tracing::instrument(name = "Run database migrations", skip(pool), err)]
+pub async fn run_migrations(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+    MIGRATOR.run(pool).await?;
+    Ok(())
+}
+
+/// Bring the schema in line with the binary according to the startup policy.
+///
+/// When `require_migrated` is `true` the schema is only checked and startup
+/// fails if anything is pending. Otherwise pending migrations are applied.
+///
+/// # Errors
+///
+/// Returns [`DatabaseError::PendingMigrations`] when `require_migrated` is set
+/// and the schema is behind, or any error from checking or applying migrations.
+pub async fn prepare_schema(
+    pool: &sqlx::Pool<sqlx::Sqlite>,
+    require_migrated: bool,
+) -> DatabaseResult<()> {
+    if require_migrated {
+        let status = MigrationStatus::check(pool).await?;
+        status.ensure_up_to_date()?;
+        tracing::info!("Database schema is up to date ({} migrations applied)", status.applied.len());
+        return Ok(());
+    }
+
+    run_migrations(pool).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn memory_pool() -> sqlx::SqlitePool {
+        sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_check_reports_all_pending_on_empty_database() {
+        let pool = memory_pool().await;
+
+        let status = MigrationStatus::check(&pool).await.unwrap();
+
+        assert!(status.applied.is_empty());
+        assert_eq!(status.pending.len(), MIGRATOR.iter().count());
+        assert!(!status.is_up_to_date());
+    }
+
+    #[tokio::test]
+    async fn test_check_does_not_create_migrations_table() {
+        let pool = memory_pool().await;
+
+        MigrationStatus::check(&pool).await.unwrap();
+
+        let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE name = ?")
+            .bind(MIGRATIONS_TABLE)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(tables, 0);
+    }
+
+    #[tokio::test]
+    async fn test_check_is_up_to_date_after_running_migrations() {
+        let pool = memory_pool().await;
+
+        run_migrations(&pool).await.unwrap();
+        let status = MigrationStatus::check(&pool).await.unwrap();
+
+        assert!(status.is_up_to_date());
+        assert_eq!(status.applied.len(), MIGRATOR.iter().count());
+        assert!(status.ensure_up_to_date().is_ok());
+    }
+
+    #[test]
+    fn test_ensure_up_to_date_lists_pending_migrations() {
+        let status = MigrationStatus {
+            applied: vec![1],
+            pending: vec![
+                PendingMigration { version: 2, description: "add accounts".to_string() },
+                PendingMigration { version: 3, description: "add transactions".to_string() },
+            ],
+        };
+
+        let err = status.ensure_up_to_date().unwrap_err();
+        assert!(matches!(err, DatabaseError::PendingMigrations(_)));
+
+        let message = err.to_string();
+        assert!(message.contains("2 not applied"));
+        assert!(message.contains("2 (add accounts), 3 (add transactions)"));
+        assert!(message.contains("require_migrated"));
+    }
+}
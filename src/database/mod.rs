@@ -60,6 +60,12 @@ pub use error::DatabaseError;
 /// ```
 pub use error::DatabaseResult;
 
+/// Credential scrubbing for connection strings embedded in error messages.
+///
+/// Replaces the `user:password` segment of any `scheme://...@host` URL with a
+/// placeholder so connection errors can be logged and displayed safely.
+pub use error::{redact_connection_string, REDACTED_CREDENTIALS};
+
 mod pool;
 /// Database connection pool wrapper.
 ///
@@ -68,7 +74,26 @@ mod pool;
 /// SQLite and PostgreSQL through the `sqlx::any` driver.
 ///
 /// See [`pool`] module for detailed API documentation and examples.
-pub use pool::DatabasePool;
+pub use pool::{
+    checkpoint_wal, DatabasePool, DEFAULT_ACQUIRE_TIMEOUT, DEFAULT_CONNECT_ATTEMPTS, DEFAULT_CONNECT_BASE_DELAY,
+    DEFAULT_MAX_CONNECTIONS,
+};
+
+mod migrations;
+/// Embedded schema migrations and startup migration policy.
+///
+/// [`MigrationStatus`] reports applied and pending migrations without changing
+/// the database, while [`prepare_schema`] either applies pending migrations or
+/// refuses to continue when `server.require_migrated` is set.
+///
+/// See [`migrations`] module for details.
+pub use migrations::{prepare_schema, run_migrations, MigrationStatus, PendingMigration, MIGRATOR};
+
+mod self_test;
+/// Optional startup check that the database takes a create/read/delete round trip.
+///
+/// See [`self_test`] module for details.
+pub use self_test::{run_self_test, SELF_TEST_CODE_PREFIX};
 
 mod categories;
 /// Financial category domain model.
@@ -78,5 +103,10 @@ mod categories;
 /// builder pattern support.
 ///
 /// See [`categories`] module for implementation details.
-pub use categories::Categories;
-pub use categories::CategoriesBuilder;
\ No newline at end of file
+pub use categories::{Categories, FieldChange};
+pub use categories::CategoriesBuilder;
+pub use categories::CategoryCache;
+pub use categories::CategoryPatch;
+pub use categories::CategoryMatchedBy;
+pub use categories::{CategoryNode, RecommendedFields};
+pub use categories::{BatchLimits, DuplicateCluster, InvalidStoredValue, RestoreSummary};
\ No newline at end of file
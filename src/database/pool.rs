@@ -55,13 +55,34 @@
 //!     .connect()
 //!     .await?;
 //!
-//! // Close the pool gracefully
+//! // Flush the write-ahead log into the main file, then close the pool
+//! db.checkpoint().await?;
 //! db.close().await?;
 //! # Ok(())
 //! # }
 //! ```
 //!
-use crate::database::{DatabaseError, DatabaseResult};
+use std::time::Duration;
+
+use crate::database::{redact_connection_string, DatabaseError, DatabaseResult, MigrationStatus};
+
+/// Connection attempts made at startup before giving up.
+pub const DEFAULT_CONNECT_ATTEMPTS: u32 = 5;
+
+/// Delay before the first connection retry, doubled for each later retry.
+pub const DEFAULT_CONNECT_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Connections the pool opens at most, unless set with
+/// [`DatabasePool::with_max_connections`].
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 10;
+
+/// Time to wait for a free connection before a query fails with
+/// `sqlx::Error::PoolTimedOut`, unless set with
+/// [`DatabasePool::with_acquire_timeout`].
+pub const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest delay between two connection attempts.
+const MAX_CONNECT_DELAY: Duration = Duration::from_secs(30);
 
 /// Lightweight wrapper around a SQLx `Sqlite` pool that carries its URL and
 /// provides convenience helpers for connecting and extracting the pool.
@@ -79,7 +100,8 @@ use crate::database::{DatabaseError, DatabaseResult};
 /// 1. Create with [`DatabasePool::new`]
 /// 2. Connect with [`DatabasePool::connect`]
 /// 3. Access pool via [`DatabasePool::get_pool`] or [`DatabasePool::into_pool`]
-/// 4. Optionally close with [`DatabasePool::close`]
+/// 4. Optionally checkpoint with [`DatabasePool::checkpoint`] and close with
+///    [`DatabasePool::close`]
 ///
 /// # Examples
 ///
@@ -99,6 +121,8 @@ use crate::database::{DatabaseError, DatabaseResult};
 #[derive(Debug)]
 pub struct DatabasePool {
   url: String,
+  max_connections: u32,
+  acquire_timeout: Duration,
   pool: Option<sqlx::SqlitePool>,
 }
 
@@ -127,16 +151,56 @@ impl DatabasePool {
   /// let db = DatabasePool::new("sqlite:test.db");
   /// ```
   pub fn new(url: &str) -> Self {
-    tracing::info!(url = %url, "New database pool created");
+    tracing::info!(url = %redact_connection_string(url), "New database pool created");
 
     DatabasePool {
       url: url.to_string(),
+      max_connections: DEFAULT_MAX_CONNECTIONS,
+      acquire_timeout: DEFAULT_ACQUIRE_TIMEOUT,
       pool: None,
     }
   }
 
+  /// Set the most connections the pool may open, bounding the load a busy
+  /// server puts on the database. A value of `0` is treated as `1`.
+  ///
+  /// Takes effect on the next [`connect`](Self::connect).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// let db = DatabasePool::new("sqlite::memory:").with_max_connections(4);
+  /// ```
+  pub fn with_max_connections(mut self, max_connections: u32) -> Self {
+    self.max_connections = max_connections.max(1);
+    self
+  }
+
+  /// Set how long a query waits for a free connection when every connection
+  /// is in use, before failing with `sqlx::Error::PoolTimedOut`.
+  ///
+  /// Takes effect on the next [`connect`](Self::connect).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::time::Duration;
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// let db = DatabasePool::new("sqlite::memory:").with_acquire_timeout(Duration::from_secs(5));
+  /// ```
+  pub fn with_acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+    self.acquire_timeout = acquire_timeout;
+    self
+  }
+
   /// Establish a connection pool and run basic setup.
   ///
+  /// A single connection attempt is made; use
+  /// [`connect_with_retry`](Self::connect_with_retry) to retry failures.
+  ///
   /// This method performs several initialization steps:
   /// 1. Ensures SQLx "sqlite" drivers are installed (prevents runtime panics)
   /// 2. Establishes the connection pool
@@ -168,30 +232,173 @@ impl DatabasePool {
   /// # Ok(())
   /// # }
   /// ```
-  pub async fn connect(mut self) -> DatabaseResult<Self> {
-    // Ensure the SQLx "sqlite" driver is installed.
+  pub async fn connect(self) -> DatabaseResult<Self> {
+    self.connect_with_retry(1, Duration::ZERO).await
+  }
+
+  /// Establish a connection pool, retrying failed attempts with exponential
+  /// backoff.
+  ///
+  /// Like [`connect`](Self::connect), but a failed attempt is retried up to
+  /// `max_attempts` attempts in total, so a database that is not ready yet at
+  /// startup does not stop the server. The wait before retry `n` is
+  /// `base_delay * 2^(n - 1)`, capped at 30 seconds, with up to half of it
+  /// replaced by random jitter so restarting instances do not retry in step.
+  /// A `max_attempts` of `0` is treated as `1`.
+  ///
+  /// # Errors
+  ///
+  /// Returns the [`DatabaseError::Connection`] from the last attempt if every
+  /// attempt fails.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use std::time::Duration;
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+  /// let db = DatabasePool::new("sqlite:ledger.db")
+  ///     .connect_with_retry(5, Duration::from_millis(250))
+  ///     .await?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn connect_with_retry(mut self, max_attempts: u32, base_delay: Duration) -> DatabaseResult<Self> {
+    // Building the options also ensures the SQLx "sqlite" driver is installed.
     // This prevents the "No drivers installed" panic from sqlx.
-    sqlx::sqlite::SqlitePoolOptions::new();
-
-    // Build the connection pool. Map any sqlx error to DatabaseError::Sqlx
-    // to preserve the original error and make higher-level handling easier.
-    let pool = sqlx::SqlitePool::connect(&self.url)
-      .await
-      .map_err(|e| {
-        // Connection failures are mapped to `Connection` to make it clear
-        // the error occurred while establishing a connection.
-        tracing::error!(error = %e, url = %self.url, "Connection error: failed to establish database pool");
-        DatabaseError::Connection(e.to_string())
-      })?;
+    let options = sqlx::sqlite::SqlitePoolOptions::new()
+      .max_connections(self.max_connections)
+      .acquire_timeout(self.acquire_timeout);
+
+    let max_attempts = max_attempts.max(1);
+    let mut attempt = 1;
+    let pool = loop {
+      match options.clone().connect(&self.url).await {
+        Ok(pool) => break pool,
+        Err(e) if attempt < max_attempts => {
+          let delay = backoff_delay(base_delay, attempt);
+          tracing::warn!(
+            error = %redact_connection_string(&e.to_string()),
+            url = %redact_connection_string(&self.url),
+            attempt,
+            max_attempts,
+            delay_ms = delay.as_millis() as u64,
+            "Database connection attempt failed, retrying"
+          );
+          tokio::time::sleep(delay).await;
+          attempt += 1;
+        }
+        Err(e) => {
+          // Connection failures are mapped to `Connection` to make it clear
+          // the error occurred while establishing a connection.
+          tracing::error!(
+            error = %redact_connection_string(&e.to_string()),
+            url = %redact_connection_string(&self.url),
+            attempt,
+            max_attempts,
+            "Connection error: failed to establish database pool"
+          );
+          return Err(DatabaseError::connection(e.to_string()));
+        }
+      }
+    };
 
     self.pool = Some(pool);
 
-    tracing::info!("Database connection established");
+    tracing::info!(attempt, "Database connection established");
 
-    
     Ok(self)
   }
 
+  /// Report which embedded migrations have been applied and which are pending.
+  ///
+  /// This only reads the database; nothing is migrated. See
+  /// [`MigrationStatus::check`] for details.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`DatabaseError::Connection`] if the pool is not connected, or any
+  /// error raised while reading the migrations table.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+  /// let db = DatabasePool::new("sqlite::memory:")
+  ///     .connect()
+  ///     .await?;
+  ///
+  /// let status = db.migration_status().await?;
+  /// println!("{} migrations pending", status.pending.len());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn migration_status(&self) -> DatabaseResult<MigrationStatus> {
+    MigrationStatus::check(self.get_pool()?).await
+  }
+
+  /// Checkpoint the write-ahead log into the main database file.
+  ///
+  /// See [`checkpoint_wal`]. Call this before [`close`](Self::close) so the main
+  /// database file is current for backups.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`DatabaseError::Connection`] if the pool is not connected, or
+  /// [`DatabaseError::Sqlx`] if the checkpoint fails.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+  /// let mut db = DatabasePool::new("sqlite:ledger.db")
+  ///     .connect()
+  ///     .await?;
+  ///
+  /// db.checkpoint().await?;
+  /// db.close().await?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn checkpoint(&self) -> DatabaseResult<()> {
+    checkpoint_wal(self.get_pool()?).await
+  }
+
+  /// Check the database answers queries by running `SELECT 1`.
+  ///
+  /// Intended for readiness probes, which can report the service as not
+  /// serving while the database is unreachable.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`DatabaseError::Connection`] if the pool is not connected, or
+  /// [`DatabaseError::Sqlx`] if the query fails (for example because the pool
+  /// was closed elsewhere).
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use personal_ledger_backend::database::DatabasePool;
+  ///
+  /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+  /// let db = DatabasePool::new("sqlite::memory:")
+  ///     .connect()
+  ///     .await?;
+  ///
+  /// db.ping().await?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn ping(&self) -> DatabaseResult<()> {
+    sqlx::query("SELECT 1").execute(self.get_pool()?).await?;
+    Ok(())
+  }
+
   /// Borrow the inner connection pool if connected.
   ///
   /// This method provides a reference to the underlying SQLx pool without
@@ -327,7 +534,7 @@ impl DatabasePool {
     if let Some(pool) = self.pool.take() {
       // `close` does not return a Result; it performs an orderly shutdown.
       pool.close().await;
-      tracing::info!(url = %self.url, "Database pool closed");
+      tracing::info!(url = %redact_connection_string(&self.url), "Database pool closed");
       Ok(())
     } else {
       Err(DatabaseError::Connection("Database pool is not connected".into()))
@@ -335,6 +542,53 @@ impl DatabasePool {
   }
 }
 
+/// The wait before connection retry `attempt`: exponential backoff from
+/// `base_delay`, capped at [`MAX_CONNECT_DELAY`], with up to half of it
+/// replaced by random jitter.
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+  let backoff = base_delay
+    .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    .min(MAX_CONNECT_DELAY);
+  let half = backoff / 2;
+  half + half.mul_f64(rand::random::<f64>())
+}
+
+/// Run `PRAGMA wal_checkpoint(TRUNCATE)` so every committed write-ahead log
+/// frame is copied into the main database file and the `-wal` file is
+/// truncated to zero bytes.
+///
+/// This is a no-op when the database is not in WAL journal mode (for example
+/// in-memory databases). A checkpoint blocked by another connection is logged
+/// rather than treated as an error, as the log is still checkpointed later.
+///
+/// # Errors
+///
+/// Returns [`DatabaseError::Sqlx`] if the journal mode cannot be read or the
+/// checkpoint fails.
+#[tracing::instrument(name = "Checkpoint write-ahead log", skip(pool), err)]
+pub async fn checkpoint_wal(pool: &sqlx::SqlitePool) -> DatabaseResult<()> {
+  let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode").fetch_one(pool).await?;
+  if !journal_mode.eq_ignore_ascii_case("wal") {
+    tracing::debug!("Journal mode is '{}', skipping WAL checkpoint", journal_mode);
+    return Ok(());
+  }
+
+  let (busy, log_frames, checkpointed_frames): (i64, i64, i64) =
+    sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)").fetch_one(pool).await?;
+
+  if busy != 0 {
+    tracing::warn!(
+      "WAL checkpoint blocked by another connection ({} of {} frames checkpointed)",
+      checkpointed_frames,
+      log_frames
+    );
+  } else {
+    tracing::info!("WAL checkpoint complete ({} frames checkpointed)", checkpointed_frames);
+  }
+
+  Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,6 +674,37 @@ mod tests {
         assert!(pool_ref.is_ok());
     }
 
+    #[test]
+    fn test_pool_limits_default_and_builders() {
+        let pool = DatabasePool::new("sqlite::memory:");
+        assert_eq!(pool.max_connections, DEFAULT_MAX_CONNECTIONS);
+        assert_eq!(pool.acquire_timeout, DEFAULT_ACQUIRE_TIMEOUT);
+
+        let pool = pool.with_max_connections(0).with_acquire_timeout(Duration::from_secs(2));
+        assert_eq!(pool.max_connections, 1);
+        assert_eq!(pool.acquire_timeout, Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_times_out_when_max_connections_in_use() {
+        let db = DatabasePool::new("sqlite::memory:")
+            .with_max_connections(1)
+            .with_acquire_timeout(Duration::from_millis(100))
+            .connect()
+            .await
+            .unwrap();
+        let pool = db.get_pool().unwrap();
+        assert_eq!(pool.options().get_max_connections(), 1);
+
+        let held = pool.acquire().await.unwrap();
+        let result = pool.acquire().await;
+        assert!(matches!(result, Err(sqlx::Error::PoolTimedOut)), "got {:?}", result.map(|_| ()));
+
+        // The connection is available again once released
+        drop(held);
+        assert!(pool.acquire().await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_connect_with_invalid_url() {
         let pool = DatabasePool::new("invalid://bad_url");
@@ -434,6 +719,55 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_connect_with_retry_gives_up_after_max_attempts() {
+        let pool = DatabasePool::new("sqlite:/nonexistent-directory/ledger.db");
+        let result = pool.connect_with_retry(3, Duration::from_millis(1)).await;
+
+        assert!(matches!(result, Err(DatabaseError::Connection(_))));
+        logs_assert(|lines: &[&str]| {
+            let retries = lines.iter().filter(|line| line.contains("Database connection attempt failed")).count();
+            match retries {
+                2 => Ok(()),
+                n => Err(format!("expected 2 retries, got {}", n)),
+            }
+        });
+        assert!(logs_contain("attempt=3"));
+    }
+
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn test_connect_makes_a_single_attempt() {
+        let result = DatabasePool::new("sqlite:/nonexistent-directory/ledger.db").connect().await;
+
+        assert!(matches!(result, Err(DatabaseError::Connection(_))));
+        assert!(!logs_contain("Database connection attempt failed"));
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_retry_connects() {
+        let connected_pool = DatabasePool::new("sqlite::memory:")
+            .connect_with_retry(DEFAULT_CONNECT_ATTEMPTS, DEFAULT_CONNECT_BASE_DELAY)
+            .await
+            .unwrap();
+
+        assert!(connected_pool.ping().await.is_ok());
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_with_jitter_and_cap() {
+        let base_delay = Duration::from_millis(100);
+        for attempt in 1..=4 {
+            let backoff = base_delay * 2u32.pow(attempt - 1);
+            let delay = backoff_delay(base_delay, attempt);
+            assert!(delay >= backoff / 2 && delay <= backoff, "attempt {}: {:?}", attempt, delay);
+        }
+
+        assert!(backoff_delay(base_delay, u32::MAX) <= MAX_CONNECT_DELAY);
+        assert_eq!(backoff_delay(Duration::ZERO, 3), Duration::ZERO);
+    }
+
     #[tokio::test]
     async fn test_get_pool_after_connect() {
         let pool = DatabasePool::new("sqlite::memory:");
@@ -468,6 +802,27 @@ mod tests {
         assert_eq!(row.0, 1);
     }
 
+    #[tokio::test]
+    async fn test_ping_after_connect() {
+        let connected_pool = DatabasePool::new("sqlite::memory:").connect().await.unwrap();
+
+        assert!(connected_pool.ping().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_ping_fails_after_close() {
+        let mut connected_pool = DatabasePool::new("sqlite::memory:").connect().await.unwrap();
+        connected_pool.close().await.unwrap();
+
+        assert!(matches!(connected_pool.ping().await, Err(DatabaseError::Connection(_))));
+
+        // A pool closed through a shared handle fails the query itself
+        let connected_pool = DatabasePool::new("sqlite::memory:").connect().await.unwrap();
+        connected_pool.get_pool().unwrap().close().await;
+
+        assert!(matches!(connected_pool.ping().await, Err(DatabaseError::Sqlx(_))));
+    }
+
     #[tokio::test]
     async fn test_close_after_connect() {
         let pool = DatabasePool::new("sqlite::memory:");
@@ -508,6 +863,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_migration_status_when_not_connected() {
+        let pool = DatabasePool::new("sqlite::memory:");
+        let result = pool.migration_status().await;
+
+        assert!(matches!(result, Err(DatabaseError::Connection(_))));
+    }
+
+    #[tokio::test]
+    async fn test_migration_status_after_connect_reports_pending() {
+        let pool = DatabasePool::new("sqlite::memory:");
+        let connected_pool = pool.connect().await.unwrap();
+
+        let status = connected_pool.migration_status().await.unwrap();
+        assert!(!status.is_up_to_date());
+    }
+
     #[test]
     fn test_database_pool_with_fake_urls() {
         // Use fake crate to generate random test URLs
@@ -592,6 +964,62 @@ mod tests {
         assert_eq!(format!("{}", err2), "Error connecting to the database: Database pool is not connected");
     }
 
+    #[tokio::test]
+    async fn test_checkpoint_when_not_connected() {
+        let pool = DatabasePool::new("sqlite::memory:");
+        let result = pool.checkpoint().await;
+
+        assert!(matches!(result, Err(DatabaseError::Connection(_))));
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_is_noop_without_wal() {
+        let pool = DatabasePool::new("sqlite::memory:").connect().await.unwrap();
+
+        assert!(pool.checkpoint().await.is_ok());
+    }
+
+    #[sqlx::test]
+    async fn test_checkpoint_truncates_wal_file() {
+        let random_name: String = (10..20).fake();
+        let temp_dir = std::env::temp_dir().join(format!("ledger_checkpoint_{}", random_name));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let db_path = temp_dir.join("ledger.db");
+        let wal_path = temp_dir.join("ledger.db-wal");
+
+        let url = format!("sqlite://{}?mode=rwc", db_path.display());
+        let mut db = DatabasePool::new(&url).connect().await.unwrap();
+        let pool = db.get_pool().unwrap();
+
+        sqlx::query("PRAGMA journal_mode = WAL").execute(pool).await.unwrap();
+        sqlx::query("CREATE TABLE entries (id INTEGER PRIMARY KEY, note TEXT)")
+            .execute(pool)
+            .await
+            .unwrap();
+        for _ in 0..20 {
+            let note: String = (50..100).fake();
+            sqlx::query("INSERT INTO entries (note) VALUES (?)")
+                .bind(note)
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        assert!(std::fs::metadata(&wal_path).unwrap().len() > 0, "Writes should grow the WAL file");
+
+        db.checkpoint().await.unwrap();
+        assert_eq!(std::fs::metadata(&wal_path).unwrap().len(), 0);
+
+        // Checkpointed rows live in the main database file
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM entries")
+            .fetch_one(db.get_pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(count, 20);
+
+        db.close().await.unwrap();
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
     #[tokio::test]
     async fn test_connect_creates_sqlite_database_if_not_exists() {
         // This test verifies that SQLite databases are created automatically
@@ -0,0 +1,90 @@
+//! # Startup Self-Test
+//!
+//! This module provides an optional check, run before the server accepts
+//! traffic, that the database behind the pool is usable end to end. It goes
+//! through the same model functions as the services: a temporary category is
+//! inserted, read back by id and deleted. A wrong database file, a schema the
+//! queries do not match, or a read-only volume all fail here rather than on
+//! the first client request.
+//!
+//! The temporary category's code starts with [`SELF_TEST_CODE_PREFIX`], so a
+//! record left behind by a self-test that failed at the delete step is easy to
+//! recognise and remove.
+
+use crate::database::{self, DatabaseError, DatabaseResult};
+use crate::domain;
+
+/// Code prefix of the temporary category written by [`run_self_test`].
+pub const SELF_TEST_CODE_PREFIX: &str = "SELF-TEST.";
+
+/// Insert, read back and delete a temporary category.
+///
+/// # Errors
+///
+/// Returns [`DatabaseError::SelfTest`] naming the step that failed and why.
+#[tracing::instrument(name = "Run startup self-test", skip(pool), err)]
+pub async fn run_self_test(pool: &sqlx::Pool<sqlx::Sqlite>) -> DatabaseResult<()> {
+    let id = domain::RowID::new();
+    let category = database::CategoriesBuilder::new()
+        .with_id(id)
+        .with_code(format!("{}{}", SELF_TEST_CODE_PREFIX, id))
+        .with_name("Startup self-test (safe to delete)")
+        .with_category_type(domain::CategoryTypes::Expense)
+        .with_is_active(false)
+        .build()
+        .map_err(|e| self_test_error("build", e))?;
+
+    category.insert(pool).await.map_err(|e| self_test_error("insert", e))?;
+
+    match database::Categories::find_by_id(id, pool).await {
+        Ok(Some(found)) if found.code == category.code => {}
+        Ok(_) => return Err(self_test_error("read", "the inserted category was not found")),
+        Err(e) => return Err(self_test_error("read", e)),
+    }
+
+    database::Categories::delete_by_id(id, pool)
+        .await
+        .map_err(|e| self_test_error("delete", e))?;
+
+    tracing::info!("Startup self-test passed");
+    Ok(())
+}
+
+fn self_test_error(step: &str, error: impl std::fmt::Display) -> DatabaseError {
+    DatabaseError::SelfTest(format!("{} step failed: {}", step, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_self_test_passes_and_cleans_up(pool: sqlx::SqlitePool) {
+        run_self_test(&pool).await.unwrap();
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM categories")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn test_self_test_fails_without_schema() {
+        // A fresh database with no migrations applied, as with a wrong database path
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let err = run_self_test(&pool).await.unwrap_err();
+        assert!(matches!(err, DatabaseError::SelfTest(_)));
+        assert!(err.to_string().contains("insert step failed"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_self_test_fails_on_closed_pool() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        pool.close().await;
+
+        let err = run_self_test(&pool).await.unwrap_err();
+        assert!(err.to_string().starts_with("Startup self-test failed: insert step failed"), "{}", err);
+    }
+}
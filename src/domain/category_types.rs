@@ -12,6 +12,8 @@
 //! - **Expense**: Money spent (groceries, utilities, entertainment)
 //! - **Equity**: Net worth (assets minus liabilities)
 
+use super::HexColor;
+
 /// Represents the fundamental accounting categories for financial transactions.
 ///
 /// These categories follow the standard accounting equation:
@@ -31,8 +33,11 @@
 #[derive(
     Debug,
     Clone,
+    Copy,
     Default,
     PartialEq,
+    Eq,
+    Hash,
     PartialOrd,
     serde::Deserialize,
     serde::Serialize,
@@ -55,6 +60,39 @@ pub enum CategoryTypes {
     Liability,
 }
 
+/// The side of a double-entry ledger on which a category type normally increases.
+///
+/// Debit-normal categories (assets and expenses) grow with debits, while
+/// credit-normal categories (liabilities, equity and income) grow with credits.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::domain::{CategoryTypes, NormalBalance};
+///
+/// assert_eq!(CategoryTypes::Asset.normal_balance(), NormalBalance::Debit);
+/// assert_eq!(CategoryTypes::Income.normal_balance(), NormalBalance::Credit);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum NormalBalance {
+    /// Balance increases with debits (left side of the ledger).
+    Debit,
+
+    /// Balance increases with credits (right side of the ledger).
+    Credit,
+}
+
+impl NormalBalance {
+    /// Returns the opposite side of the ledger.
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        match self {
+            NormalBalance::Debit => NormalBalance::Credit,
+            NormalBalance::Credit => NormalBalance::Debit,
+        }
+    }
+}
+
 /// Error type for CategoryTypes parsing operations.
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum CategoryTypesError {
@@ -63,6 +101,17 @@ pub enum CategoryTypesError {
     InvalidCategoryType(String),
 }
 
+impl CategoryTypesError {
+    /// Stable reason code for clients to branch on, e.g. `CATEGORY_TYPE_INVALID`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CategoryTypesError::InvalidCategoryType(_) => "CATEGORY_TYPE_INVALID",
+        }
+    }
+}
+
 impl std::fmt::Display for CategoryTypes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
@@ -123,6 +172,101 @@ impl CategoryTypes {
         }
     }
 
+    /// Returns the side of the ledger on which this category type increases.
+    ///
+    /// Follows the accounting equation: assets and expenses are debit-normal,
+    /// while liabilities, equity and income are credit-normal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::{CategoryTypes, NormalBalance};
+    ///
+    /// assert_eq!(CategoryTypes::Expense.normal_balance(), NormalBalance::Debit);
+    /// assert_eq!(CategoryTypes::Liability.normal_balance(), NormalBalance::Credit);
+    /// ```
+    pub fn normal_balance(&self) -> NormalBalance {
+        match self {
+            CategoryTypes::Asset | CategoryTypes::Expense => NormalBalance::Debit,
+            CategoryTypes::Liability | CategoryTypes::Equity | CategoryTypes::Income => {
+                NormalBalance::Credit
+            }
+        }
+    }
+
+    /// Returns the numeric account code range reserved for this category type.
+    ///
+    /// Follows the traditional chart of accounts numbering scheme, so
+    /// generated codes sort in balance sheet then income statement order.
+    ///
+    /// | Type      | Range     |
+    /// |-----------|-----------|
+    /// | Asset     | 1000–1999 |
+    /// | Liability | 2000–2999 |
+    /// | Equity    | 3000–3999 |
+    /// | Income    | 4000–4999 |
+    /// | Expense   | 5000–5999 |
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// assert_eq!(CategoryTypes::Asset.code_range(), 1000..=1999);
+    /// assert!(CategoryTypes::Expense.code_range().contains(&5100));
+    /// ```
+    pub fn code_range(&self) -> std::ops::RangeInclusive<u32> {
+        match self {
+            CategoryTypes::Asset => 1000..=1999,
+            CategoryTypes::Liability => 2000..=2999,
+            CategoryTypes::Equity => 3000..=3999,
+            CategoryTypes::Income => 4000..=4999,
+            CategoryTypes::Expense => 5000..=5999,
+        }
+    }
+
+    /// Returns the colour given to new categories of this type when none is chosen.
+    ///
+    /// Income is green and expenses red, as on a statement; assets, liabilities
+    /// and equity get blue, orange and purple.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// assert_eq!(CategoryTypes::Income.default_color().as_str(), "#43A047");
+    /// assert_eq!(CategoryTypes::Expense.default_color().as_str(), "#E53935");
+    /// ```
+    pub fn default_color(&self) -> HexColor {
+        match self {
+            CategoryTypes::Asset => HexColor::from_rgb(0x1E, 0x88, 0xE5),
+            CategoryTypes::Liability => HexColor::from_rgb(0xFB, 0x8C, 0x00),
+            CategoryTypes::Equity => HexColor::from_rgb(0x8E, 0x24, 0xAA),
+            CategoryTypes::Income => HexColor::from_rgb(0x43, 0xA0, 0x47),
+            CategoryTypes::Expense => HexColor::from_rgb(0xE5, 0x39, 0x35),
+        }
+    }
+
+    /// Returns the icon name given to new categories of this type when none is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// assert_eq!(CategoryTypes::Expense.default_icon(), "shopping-cart");
+    /// ```
+    pub fn default_icon(&self) -> &'static str {
+        match self {
+            CategoryTypes::Asset => "wallet",
+            CategoryTypes::Liability => "credit-card",
+            CategoryTypes::Equity => "scale",
+            CategoryTypes::Income => "trending-up",
+            CategoryTypes::Expense => "shopping-cart",
+        }
+    }
+
     /// Returns all valid category types as a slice.
     ///
     /// Useful for validation, UI dropdowns, or iteration.
@@ -146,6 +290,21 @@ impl CategoryTypes {
         ]
     }
 
+    /// Returns the string value of every category type, in [`all`](Self::all) order.
+    ///
+    /// These are the values accepted wherever a category type is given as text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::CategoryTypes;
+    ///
+    /// assert_eq!(CategoryTypes::variants(), ["asset", "liability", "income", "expense", "equity"]);
+    /// ```
+    pub fn variants() -> Vec<&'static str> {
+        Self::all().iter().map(CategoryTypes::as_str).collect()
+    }
+
     /// Create a random CategoryTypes variant for testing.
     ///
     /// This method randomly selects one of the five category types using
@@ -165,8 +324,8 @@ impl CategoryTypes {
 
         // Get all category types and randomly select one
         let all_types = Self::all();
-        let random_index: usize = (0..all_types.len()).fake();
-        all_types[random_index].clone()
+        let random_index: usize = crate::mock_rng::with_rng(|rng| (0..all_types.len()).fake_with_rng(rng));
+        all_types[random_index]
     }
 
     /// Convert this CategoryTypes to the corresponding RPC CategoryType enum value as i32.
@@ -193,6 +352,29 @@ impl CategoryTypes {
         }
     }
 
+    /// Parse a value read from the `category_type` column.
+    ///
+    /// Used when decoding rows, so a value no variant matches, e.g. after a bad
+    /// migration, fails with a message naming the value, the column and the
+    /// values expected, rather than an opaque decode error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the message for a value that is not a category type.
+    pub fn from_stored(value: &str) -> Result<Self, String> {
+        use std::str::FromStr;
+
+        CategoryTypes::from_str(value).map_err(|_| {
+            let expected: Vec<&str> = CategoryTypes::all().iter().map(CategoryTypes::as_str).collect();
+            format!(
+                "Invalid value '{}' in the category_type column, expected one of: {}. \
+                 Correct the row or check the last migration",
+                value,
+                expected.join(", ")
+            )
+        })
+    }
+
     /// Convert from the protobuf i32 enum value to CategoryTypes.
     ///
     /// # Examples
@@ -238,8 +420,7 @@ impl sqlx::Type<sqlx::Sqlite> for CategoryTypes {
 impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for CategoryTypes {
     fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         let s = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
-        use std::str::FromStr;
-        Ok(CategoryTypes::from_str(&s).map_err(|e| format!("Invalid category type in DB: {}", e))?)
+        Ok(CategoryTypes::from_stored(&s)?)
     }
 }
 
@@ -259,9 +440,8 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for CategoryTypes {
 /// in the database will result in a decoding error.
 impl<'r> sqlx::Decode<'r, sqlx::Any> for CategoryTypes {
     fn decode(value: sqlx::any::AnyValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
-        use std::str::FromStr;
         let s = <String as sqlx::Decode<sqlx::Any>>::decode(value)?;
-        Ok(CategoryTypes::from_str(&s).map_err(|e| format!("Invalid category type in database: {}", e))?)
+        Ok(CategoryTypes::from_stored(&s)?)
     }
 }
 
@@ -328,6 +508,39 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(CategoryTypesError::InvalidCategoryType("savings".to_string()).code(), "CATEGORY_TYPE_INVALID");
+    }
+
+    #[test]
+    fn test_from_stored() {
+        assert_eq!(CategoryTypes::from_stored("income").unwrap(), CategoryTypes::Income);
+
+        let message = CategoryTypes::from_stored("savings").unwrap_err();
+        assert_eq!(
+            message,
+            "Invalid value 'savings' in the category_type column, expected one of: \
+             asset, liability, income, expense, equity. Correct the row or check the last migration"
+        );
+    }
+
+    #[test]
+    fn test_code_range() {
+        assert_eq!(CategoryTypes::Asset.code_range(), 1000..=1999);
+        assert_eq!(CategoryTypes::Liability.code_range(), 2000..=2999);
+        assert_eq!(CategoryTypes::Equity.code_range(), 3000..=3999);
+        assert_eq!(CategoryTypes::Income.code_range(), 4000..=4999);
+        assert_eq!(CategoryTypes::Expense.code_range(), 5000..=5999);
+
+        // Ranges never overlap
+        for a in CategoryTypes::all() {
+            for b in CategoryTypes::all().iter().filter(|b| *b != a) {
+                assert!(!b.code_range().contains(a.code_range().start()), "{:?} overlaps {:?}", a, b);
+            }
+        }
+    }
+
     #[test]
     fn test_as_str() {
         assert_eq!(CategoryTypes::Asset.as_str(), "asset");
@@ -380,6 +593,17 @@ mod tests {
         assert!(all_types.contains(&CategoryTypes::Equity));
     }
 
+    #[test]
+    fn test_variants_parse_back() {
+        use std::str::FromStr;
+
+        let variants = CategoryTypes::variants();
+        assert_eq!(variants.len(), CategoryTypes::all().len());
+        for (variant, category_type) in variants.iter().zip(CategoryTypes::all()) {
+            assert_eq!(CategoryTypes::from_str(variant), Ok(*category_type));
+        }
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(CategoryTypes::default(), CategoryTypes::Expense);
@@ -460,4 +684,34 @@ mod tests {
         assert!(CategoryTypes::from_rpc_i32(-1).is_err());
         assert!(CategoryTypes::from_rpc_i32(999).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normal_balance() {
+        assert_eq!(CategoryTypes::Asset.normal_balance(), NormalBalance::Debit);
+        assert_eq!(CategoryTypes::Expense.normal_balance(), NormalBalance::Debit);
+        assert_eq!(CategoryTypes::Liability.normal_balance(), NormalBalance::Credit);
+        assert_eq!(CategoryTypes::Equity.normal_balance(), NormalBalance::Credit);
+        assert_eq!(CategoryTypes::Income.normal_balance(), NormalBalance::Credit);
+    }
+
+    #[test]
+    fn test_normal_balance_opposite() {
+        assert_eq!(NormalBalance::Debit.opposite(), NormalBalance::Credit);
+        assert_eq!(NormalBalance::Credit.opposite(), NormalBalance::Debit);
+    }
+
+    #[test]
+    fn test_default_color_and_icon() {
+        for category_type in CategoryTypes::all() {
+            let color = category_type.default_color();
+            assert_eq!(HexColor::parse(color.as_str()).unwrap(), color, "{}", category_type);
+            assert!(!category_type.default_icon().is_empty(), "{}", category_type);
+        }
+
+        // Every type is told apart by its colour
+        let mut colors: Vec<_> = CategoryTypes::all().iter().map(CategoryTypes::default_color).collect();
+        colors.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        colors.dedup();
+        assert_eq!(colors.len(), CategoryTypes::all().len());
+    }
+}
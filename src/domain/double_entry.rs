@@ -0,0 +1,256 @@
+//! # Double Entry Domain Type
+//!
+//! This module defines [`DoubleEntry`], a validated set of ledger line items
+//! whose debits and credits are equal. It is the building block for future
+//! transactions: an entry can only be constructed if it keeps the accounting
+//! equation (Assets = Liabilities + Equity) in balance.
+//!
+//! ## Line Item Semantics
+//!
+//! Each [`LineItem`] names the [`CategoryTypes`] it affects and a signed
+//! [`Money`] amount. A positive amount increases the category's balance, so it
+//! is posted on the category's [`NormalBalance`] side; a negative amount is
+//! posted on the opposite side. For example, buying groceries with cash is an
+//! expense of `+50.00` (debit) and an asset of `-50.00` (credit).
+
+use super::{CategoryTypes, Money, MoneyError, NormalBalance};
+
+/// A single line of a double entry: an amount applied to a category type.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct LineItem {
+    /// The category type whose balance this line changes.
+    pub category_type: CategoryTypes,
+
+    /// Signed change to the category balance; positive increases it.
+    pub amount: Money,
+}
+
+impl LineItem {
+    /// Creates a line item for the given category type and amount.
+    #[must_use]
+    pub fn new(category_type: CategoryTypes, amount: Money) -> Self {
+        Self { category_type, amount }
+    }
+
+    /// Returns the side of the ledger this line is posted on.
+    ///
+    /// Positive amounts post to the category's normal balance side and
+    /// negative amounts to the opposite side.
+    #[must_use]
+    pub fn side(&self) -> NormalBalance {
+        let normal = self.category_type.normal_balance();
+        if self.amount.is_negative() {
+            normal.opposite()
+        } else {
+            normal
+        }
+    }
+}
+
+/// Errors that can occur when validating a [`DoubleEntry`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DoubleEntryError {
+    /// The entry contained no line items.
+    #[error("Double entry must contain at least one line item")]
+    Empty,
+
+    /// Total debits did not equal total credits.
+    #[error("Double entry is not balanced: debits {debits} != credits {credits}")]
+    Imbalanced {
+        /// Sum of all debit postings.
+        debits: Money,
+        /// Sum of all credit postings.
+        credits: Money,
+    },
+
+    /// Summing the line items overflowed.
+    #[error("Double entry totals overflowed: {0}")]
+    Money(#[from] MoneyError),
+}
+
+/// A balanced set of line items, validated at construction time.
+///
+/// Use [`DoubleEntry::new`] to create an entry; it is immutable afterwards so a
+/// `DoubleEntry` value is always balanced.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::domain::{CategoryTypes, DoubleEntry, LineItem, Money};
+///
+/// // Receive a salary payment into a bank account
+/// let entry = DoubleEntry::new(vec![
+///     LineItem::new(CategoryTypes::Asset, Money::from_minor_units(250_000)),
+///     LineItem::new(CategoryTypes::Income, Money::from_minor_units(250_000)),
+/// ])?;
+/// assert_eq!(entry.total_debits(), entry.total_credits());
+/// # Ok::<(), personal_ledger_backend::domain::DoubleEntryError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DoubleEntry {
+    lines: Vec<LineItem>,
+    total_debits: Money,
+    total_credits: Money,
+}
+
+impl DoubleEntry {
+    /// Validates `lines` and builds a balanced entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DoubleEntryError::Empty`] if there are no line items,
+    /// [`DoubleEntryError::Imbalanced`] if debits do not equal credits, or
+    /// [`DoubleEntryError::Money`] if the totals overflow.
+    pub fn new(lines: Vec<LineItem>) -> Result<Self, DoubleEntryError> {
+        if lines.is_empty() {
+            return Err(DoubleEntryError::Empty);
+        }
+
+        let mut debits = Money::ZERO;
+        let mut credits = Money::ZERO;
+
+        for line in &lines {
+            let amount = line.amount.abs()?;
+            match line.side() {
+                NormalBalance::Debit => debits = debits.checked_add(amount)?,
+                NormalBalance::Credit => credits = credits.checked_add(amount)?,
+            }
+        }
+
+        if debits != credits {
+            return Err(DoubleEntryError::Imbalanced { debits, credits });
+        }
+
+        Ok(Self {
+            lines,
+            total_debits: debits,
+            total_credits: credits,
+        })
+    }
+
+    /// Returns the validated line items.
+    #[must_use]
+    pub fn lines(&self) -> &[LineItem] {
+        &self.lines
+    }
+
+    /// Returns the sum of all debit postings.
+    #[must_use]
+    pub fn total_debits(&self) -> Money {
+        self.total_debits
+    }
+
+    /// Returns the sum of all credit postings.
+    ///
+    /// Always equal to [`DoubleEntry::total_debits`].
+    #[must_use]
+    pub fn total_credits(&self) -> Money {
+        self.total_credits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(category_type: CategoryTypes, minor_units: i64) -> LineItem {
+        LineItem::new(category_type, Money::from_minor_units(minor_units))
+    }
+
+    #[test]
+    fn test_line_item_side_follows_normal_balance() {
+        assert_eq!(line(CategoryTypes::Asset, 100).side(), NormalBalance::Debit);
+        assert_eq!(line(CategoryTypes::Asset, -100).side(), NormalBalance::Credit);
+        assert_eq!(line(CategoryTypes::Income, 100).side(), NormalBalance::Credit);
+        assert_eq!(line(CategoryTypes::Liability, -100).side(), NormalBalance::Debit);
+    }
+
+    #[test]
+    fn test_balanced_entry() {
+        // Pay for groceries (expense) with a credit card (liability)
+        let amount = Money::mock();
+        let entry = DoubleEntry::new(vec![
+            LineItem::new(CategoryTypes::Expense, amount),
+            LineItem::new(CategoryTypes::Liability, amount),
+        ])
+        .expect("entry should balance");
+
+        assert_eq!(entry.total_debits(), amount);
+        assert_eq!(entry.total_credits(), amount);
+        assert_eq!(entry.lines().len(), 2);
+    }
+
+    #[test]
+    fn test_balanced_entry_with_multiple_lines() {
+        // Split a 100.00 purchase across cash and a credit card
+        let entry = DoubleEntry::new(vec![
+            line(CategoryTypes::Expense, 10_000),
+            line(CategoryTypes::Asset, -4_000),
+            line(CategoryTypes::Liability, 6_000),
+        ])
+        .expect("entry should balance");
+
+        assert_eq!(entry.total_debits(), Money::from_minor_units(10_000));
+        assert_eq!(entry.total_credits(), Money::from_minor_units(10_000));
+    }
+
+    #[test]
+    fn test_imbalanced_entry() {
+        let result = DoubleEntry::new(vec![
+            line(CategoryTypes::Asset, 10_000),
+            line(CategoryTypes::Income, 9_999),
+        ]);
+
+        assert_eq!(
+            result,
+            Err(DoubleEntryError::Imbalanced {
+                debits: Money::from_minor_units(10_000),
+                credits: Money::from_minor_units(9_999),
+            })
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Double entry is not balanced: debits 100.00 != credits 99.99"
+        );
+    }
+
+    #[test]
+    fn test_same_side_lines_are_imbalanced() {
+        // Two debit-normal increases cannot balance each other
+        let result = DoubleEntry::new(vec![
+            line(CategoryTypes::Asset, 500),
+            line(CategoryTypes::Expense, 500),
+        ]);
+
+        assert!(matches!(result, Err(DoubleEntryError::Imbalanced { .. })));
+    }
+
+    #[test]
+    fn test_zero_sum_entry() {
+        // All-zero lines post nothing to either side and are trivially balanced
+        let entry = DoubleEntry::new(vec![
+            line(CategoryTypes::Asset, 0),
+            line(CategoryTypes::Income, 0),
+        ])
+        .expect("zero entry should balance");
+
+        assert!(entry.total_debits().is_zero());
+        assert!(entry.total_credits().is_zero());
+    }
+
+    #[test]
+    fn test_empty_entry() {
+        assert_eq!(DoubleEntry::new(Vec::new()), Err(DoubleEntryError::Empty));
+    }
+
+    #[test]
+    fn test_overflowing_entry() {
+        let result = DoubleEntry::new(vec![
+            line(CategoryTypes::Asset, i64::MAX),
+            line(CategoryTypes::Asset, 1),
+            line(CategoryTypes::Income, 1),
+        ]);
+
+        assert_eq!(result, Err(DoubleEntryError::Money(MoneyError::Overflow)));
+    }
+}
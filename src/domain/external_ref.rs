@@ -0,0 +1,262 @@
+//! # External Reference Domain Type
+//!
+//! This module defines [`ExternalRef`], an immutable, validated identifier that a
+//! third-party tool (a bank import, a YNAB migration and so on) uses for one of
+//! our records. Storing it lets imports recognise records they created before
+//! instead of duplicating them.
+//!
+//! An external reference is a bounded string of printable ASCII characters.
+//! Leading and trailing whitespace is trimmed; spaces inside the identifier are
+//! kept, as some tools use them.
+
+/// Maximum number of characters in an external reference.
+pub const EXTERNAL_REF_MAX_LENGTH: usize = 128;
+
+/// Regular expression matched by every parsed external reference, for clients
+/// validating input.
+pub const EXTERNAL_REF_PATTERN: &str = "^[!-~]([ -~]*[!-~])?$";
+
+/// A validated identifier assigned to a record by an external system.
+///
+/// Use [`ExternalRef::parse`] to create instances. The internal string is
+/// guaranteed to be non-empty, at most [`EXTERNAL_REF_MAX_LENGTH`] characters,
+/// free of surrounding whitespace, and made up of printable ASCII only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ExternalRef(String);
+
+/// Errors that can occur when parsing an [`ExternalRef`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ExternalRefError {
+    /// The input was empty or whitespace only.
+    #[error("External reference cannot be empty")]
+    Empty,
+    /// The input was longer than [`EXTERNAL_REF_MAX_LENGTH`].
+    #[error("External reference must be at most {EXTERNAL_REF_MAX_LENGTH} characters, got {0}")]
+    TooLong(usize),
+    /// The input contained characters outside printable ASCII.
+    #[error("External reference must contain only printable ASCII characters: {0}")]
+    InvalidCharacters(String),
+}
+
+impl ExternalRefError {
+    /// Stable reason code for clients to branch on, e.g. `EXTERNAL_ID_EMPTY`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ExternalRefError::Empty => "EXTERNAL_ID_EMPTY",
+            ExternalRefError::TooLong(_) => "EXTERNAL_ID_TOO_LONG",
+            ExternalRefError::InvalidCharacters(_) => "EXTERNAL_ID_BAD_CHARACTERS",
+        }
+    }
+}
+
+impl ExternalRef {
+    /// Parses a string into an [`ExternalRef`] after trimming surrounding
+    /// whitespace and validating its length and characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExternalRefError`] if the value is empty, too long, or contains
+    /// characters other than printable ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::ExternalRef;
+    ///
+    /// let external_id = ExternalRef::parse("  ynab:cat-42 ")?;
+    /// assert_eq!(external_id.as_str(), "ynab:cat-42");
+    /// # Ok::<(), personal_ledger_backend::domain::ExternalRefError>(())
+    /// ```
+    pub fn parse<S: AsRef<str>>(input: S) -> Result<Self, ExternalRefError> {
+        let input = input.as_ref().trim();
+        if input.is_empty() {
+            return Err(ExternalRefError::Empty);
+        }
+
+        if !input.chars().all(|c| c.is_ascii_graphic() || c == ' ') {
+            return Err(ExternalRefError::InvalidCharacters(input.to_string()));
+        }
+
+        // Printable ASCII is one byte per character
+        if input.len() > EXTERNAL_REF_MAX_LENGTH {
+            return Err(ExternalRefError::TooLong(input.len()));
+        }
+
+        Ok(ExternalRef(input.to_string()))
+    }
+
+    /// Gets the external reference as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Converts the external reference into its owned String representation.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Convenience helper used in validation contexts.
+    #[must_use]
+    pub fn is_valid<S: AsRef<str>>(input: S) -> bool {
+        Self::parse(input).is_ok()
+    }
+
+    /// Generates a random external reference for testing scenarios.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use rand::Rng;
+
+        let suffix: String = crate::mock_rng::with_rng(|rng| {
+            rng.sample_iter(&rand::distr::Alphanumeric)
+                .take(16)
+                .map(char::from)
+                .collect()
+        });
+
+        ExternalRef::parse(format!("mock:{}", suffix)).expect("mock external reference should be valid")
+    }
+
+    /// Generates a random optional external reference for testing scenarios.
+    #[cfg(test)]
+    pub fn mock_with_option() -> Option<Self> {
+        use fake::Fake;
+        use fake::faker::boolean::en::Boolean;
+
+        let is_some: bool = crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)); // 50% chance of Some
+        is_some.then(Self::mock)
+    }
+}
+
+impl std::fmt::Display for ExternalRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for ExternalRef {
+    type Err = ExternalRefError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExternalRef::parse(s)
+    }
+}
+
+impl From<ExternalRef> for String {
+    fn from(value: ExternalRef) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for ExternalRef {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<&str> for ExternalRef {
+    type Error = ExternalRefError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        ExternalRef::parse(value)
+    }
+}
+
+// SQLx trait implementations ensure the reference can be stored as TEXT.
+impl sqlx::Type<sqlx::Sqlite> for ExternalRef {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for ExternalRef {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let raw = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(ExternalRef::parse(raw).map_err(|e| format!("Invalid external reference in database: {}", e))?)
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for ExternalRef {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.0.clone(), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(ExternalRefError::Empty.code(), "EXTERNAL_ID_EMPTY");
+        assert_eq!(ExternalRefError::TooLong(999).code(), "EXTERNAL_ID_TOO_LONG");
+        assert_eq!(ExternalRefError::InvalidCharacters("é".to_string()).code(), "EXTERNAL_ID_BAD_CHARACTERS");
+    }
+
+    #[test]
+    fn parse_trims_surrounding_whitespace() {
+        let external_id = ExternalRef::parse("  bank:12345\t").unwrap();
+        assert_eq!(external_id.as_str(), "bank:12345");
+    }
+
+    #[test]
+    fn parse_keeps_inner_spaces_and_punctuation() {
+        let external_id = ExternalRef::parse("YNAB Category #7/{a-b}").unwrap();
+        assert_eq!(external_id.to_string(), "YNAB Category #7/{a-b}");
+    }
+
+    #[test]
+    fn parse_rejects_empty() {
+        assert_eq!(ExternalRef::parse(""), Err(ExternalRefError::Empty));
+        assert_eq!(ExternalRef::parse("   "), Err(ExternalRefError::Empty));
+    }
+
+    #[test]
+    fn parse_rejects_non_printable_ascii() {
+        for input in ["tab\tinside", "line\nbreak", "café", "null\0byte"] {
+            let err = ExternalRef::parse(input).unwrap_err();
+            assert!(matches!(err, ExternalRefError::InvalidCharacters(_)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_enforces_max_length() {
+        let longest = "x".repeat(EXTERNAL_REF_MAX_LENGTH);
+        assert!(ExternalRef::is_valid(&longest));
+
+        let too_long = "x".repeat(EXTERNAL_REF_MAX_LENGTH + 1);
+        assert_eq!(
+            ExternalRef::parse(too_long),
+            Err(ExternalRefError::TooLong(EXTERNAL_REF_MAX_LENGTH + 1))
+        );
+    }
+
+    #[test]
+    fn from_str_and_try_from_agree() {
+        let parsed: ExternalRef = "import-9".parse().unwrap();
+        assert_eq!(ExternalRef::try_from("import-9").unwrap(), parsed);
+        assert_eq!(String::from(parsed), "import-9");
+    }
+
+    #[test]
+    fn mock_is_valid() {
+        for _ in 0..10 {
+            assert!(ExternalRef::is_valid(ExternalRef::mock().as_str()));
+        }
+    }
+
+    #[test]
+    fn serde_is_transparent() {
+        let external_id = ExternalRef::parse("bank:1").unwrap();
+        let json = serde_json::to_string(&external_id).unwrap();
+        assert_eq!(json, "\"bank:1\"");
+        assert_eq!(serde_json::from_str::<ExternalRef>(&json).unwrap(), external_id);
+    }
+}
@@ -5,6 +5,10 @@
 //! parsing utilities, strongly typed access to channel values, helpers for random
 //! generation in tests, and trait implementations needed across the code base.
 
+/// Regular expression matched by every parsed colour in canonical form, for
+/// clients validating input.
+pub const HEX_COLOR_PATTERN: &str = "^#[0-9A-F]{6}$";
+
 /// Represents a web-style hexadecimal RGB colour in canonical `#RRGGBB` form.
 ///
 /// Use [`HexColor::parse`] or [`HexColor::from_rgb`] to create instances. The
@@ -20,22 +24,39 @@ pub enum HexColorError {
     /// The input was empty or whitespace only.
     #[error("Hex colour cannot be empty")]
     Empty,
-    /// The input length after removing an optional leading `#` was not six.
-    #[error("Hex colour must contain exactly six hexadecimal digits: {0}")]
+    /// The input length after removing an optional leading `#` was not three or six.
+    #[error("Hex colour must contain three or six hexadecimal digits: {0}")]
     InvalidLength(String),
     /// The input contained non-hexadecimal characters.
     #[error("Hex colour contains invalid characters: {0}")]
     InvalidCharacters(String),
 }
 
+impl HexColorError {
+    /// Stable reason code for clients to branch on, e.g. `COLOR_BAD_FORMAT`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            HexColorError::Empty => "COLOR_EMPTY",
+            HexColorError::InvalidLength(_) => "COLOR_BAD_LENGTH",
+            HexColorError::InvalidCharacters(_) => "COLOR_BAD_FORMAT",
+        }
+    }
+}
+
 impl HexColor {
     /// Parses a string into a [`HexColor`] after validating format and
     /// normalising to uppercase `#RRGGBB` form.
     ///
+    /// The CSS `#RGB` shorthand is accepted and expanded by doubling each
+    /// digit, so `#f00` is stored as `#FF0000`.
+    ///
     /// # Errors
     ///
     /// Returns [`HexColorError`] if the value is empty, the number of
-    /// hexadecimal digits is not six, or the string contains non-hex digits.
+    /// hexadecimal digits is not three or six, or the string contains non-hex
+    /// digits.
     ///
     /// # Examples
     ///
@@ -44,6 +65,9 @@ impl HexColor {
     ///
     /// let colour = HexColor::parse("#ff8800")?;
     /// assert_eq!(colour.as_str(), "#FF8800");
+    ///
+    /// let shorthand = HexColor::parse("#f80")?;
+    /// assert_eq!(shorthand, colour);
     /// # Ok::<(), personal_ledger_backend::domain::HexColorError>(())
     /// ```
     pub fn parse<S: AsRef<str>>(input: S) -> Result<Self, HexColorError> {
@@ -53,7 +77,7 @@ impl HexColor {
         }
 
         let digits = input.strip_prefix('#').unwrap_or(input);
-        if digits.len() != 6 {
+        if digits.len() != 3 && digits.len() != 6 {
             return Err(HexColorError::InvalidLength(input.to_string()));
         }
 
@@ -61,7 +85,14 @@ impl HexColor {
             return Err(HexColorError::InvalidCharacters(input.to_string()));
         }
 
-        let value = u32::from_str_radix(digits, 16).expect("validated hex digits");
+        // Expand `RGB` shorthand to `RRGGBB`
+        let digits: String = if digits.len() == 3 {
+            digits.chars().flat_map(|c| [c, c]).collect()
+        } else {
+            digits.to_string()
+        };
+
+        let value = u32::from_str_radix(&digits, 16).expect("validated hex digits");
         let canonical = format!("#{:06X}", value);
         Ok(HexColor(canonical))
     }
@@ -126,7 +157,7 @@ impl HexColor {
         use fake::Fake;
         use fake::faker::color::en::HexColor as FakeHex;
 
-        let value: String = FakeHex().fake();
+        let value: String = crate::mock_rng::with_rng(|rng| FakeHex().fake_with_rng(rng));
         HexColor::parse(value).expect("fake hex colour should be valid")
     }
 
@@ -136,7 +167,7 @@ impl HexColor {
         use fake::Fake;
         use fake::faker::boolean::en::Boolean;
 
-        let is_some: bool = Boolean(50).fake(); // 50% chance of Some
+        let is_some: bool = crate::mock_rng::with_rng(|rng| Boolean(50).fake_with_rng(rng)); // 50% chance of Some
         if is_some {
             Some(Self::mock())
         } else {
@@ -226,6 +257,13 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for HexColor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(HexColorError::Empty.code(), "COLOR_EMPTY");
+        assert_eq!(HexColorError::InvalidLength("#12".to_string()).code(), "COLOR_BAD_LENGTH");
+        assert_eq!(HexColorError::InvalidCharacters("#GG0000".to_string()).code(), "COLOR_BAD_FORMAT");
+    }
+
     #[test]
     fn parse_valid_colour_with_hash() {
         let colour = HexColor::parse("#ff0000").unwrap();
@@ -243,8 +281,27 @@ mod tests {
 
     #[test]
     fn parse_rejects_invalid_length() {
-        let err = HexColor::parse("123").unwrap_err();
-        assert!(matches!(err, HexColorError::InvalidLength(_)));
+        for input in ["12", "#1234", "#12345", "1234567"] {
+            let err = HexColor::parse(input).unwrap_err();
+            assert!(matches!(err, HexColorError::InvalidLength(_)), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_expands_shorthand() {
+        assert_eq!(HexColor::parse("#f00").unwrap().as_str(), "#FF0000");
+        assert_eq!(HexColor::parse("#ABC").unwrap().as_str(), "#AABBCC");
+        assert_eq!(HexColor::parse("123").unwrap().as_str(), "#112233");
+        assert_eq!(HexColor::parse("#FF5733").unwrap().as_str(), "#FF5733");
+
+        assert_eq!(HexColor::parse("#f00").unwrap(), HexColor::parse("#FF0000").unwrap());
+        assert_eq!(HexColor::parse("#abc").unwrap(), HexColor::parse("#aabbcc").unwrap());
+    }
+
+    #[test]
+    fn parse_rejects_invalid_shorthand_characters() {
+        let err = HexColor::parse("#FG0").unwrap_err();
+        assert!(matches!(err, HexColorError::InvalidCharacters(_)));
     }
 
     #[test]
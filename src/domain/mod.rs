@@ -13,6 +13,10 @@
 //! - [`RowID`] - Time-ordered UUID v7 identifiers for database rows
 //! - [`UrlSlug`] - URL-safe identifiers for web-friendly resource names
 //! - [`HexColor`] - Validated hexadecimal RGB colour representation
+//! - [`ExternalRef`] - Identifiers assigned to records by third-party tools
+//! - [`Tag`] - Free-form lowercase labels attached to records
+//! - [`Money`] - Exact monetary amounts held in minor units
+//! - [`DoubleEntry`] - Balanced ledger line items (debits equal credits)
 //!
 //! ## Design Principles
 //!
@@ -42,7 +46,7 @@ pub use row_id::{RowID, RowIDError};
 /// These categories follow the standard accounting equation and provide
 /// type-safe classification for all financial operations.
 mod category_types;
-pub use category_types::{CategoryTypes, CategoryTypesError};
+pub use category_types::{CategoryTypes, CategoryTypesError, NormalBalance};
 
 mod url_slug;
 /// URL-safe slug type for human-readable, SEO-friendly identifiers.
@@ -51,7 +55,7 @@ mod url_slug;
 /// converting them to lowercase, alphanumeric strings with hyphens. Used
 /// throughout the application for creating readable URLs and resource names
 /// that are both user-friendly and search engine optimized.
-pub use url_slug::{UrlSlug, UrlSlugError};
+pub use url_slug::{UrlSlug, UrlSlugError, URL_SLUG_PATTERN};
 
 mod hex_color;
 /// Hexadecimal RGB colour type for validated colour values.
@@ -60,4 +64,34 @@ mod hex_color;
 /// providing convenient access to individual RGB channels. Useful for
 /// theming, categorisation, and any feature that requires precise colour
 /// handling across the application.
-pub use hex_color::{HexColor, HexColorError};
\ No newline at end of file
+pub use hex_color::{HexColor, HexColorError, HEX_COLOR_PATTERN};
+
+mod external_ref;
+/// Identifier assigned to a record by an external system.
+///
+/// [`ExternalRef`] holds a bounded, printable-ASCII identifier from a bank
+/// import or another budgeting tool, letting imports recognise records they
+/// have already created.
+pub use external_ref::{ExternalRef, ExternalRefError, EXTERNAL_REF_MAX_LENGTH, EXTERNAL_REF_PATTERN};
+
+mod tag;
+/// Free-form label attached to a record.
+///
+/// [`Tag`] holds a short, lowercase label such as `tax-deductible`, letting
+/// users group categories beyond their single [`CategoryTypes`].
+pub use tag::{Tag, TagError, TAG_MAX_LENGTH, TAG_PATTERN};
+
+mod money;
+/// Monetary amount type using integer minor units.
+///
+/// [`Money`] stores amounts as a whole number of cents so ledger arithmetic is
+/// exact, and every operation is checked for overflow.
+pub use money::{Money, MoneyError};
+
+mod double_entry;
+/// Double-entry validation for ledger line items.
+///
+/// [`DoubleEntry`] accepts line items against category types and only
+/// constructs when total debits equal total credits under each category's
+/// [`NormalBalance`], enforcing the accounting equation at the type level.
+pub use double_entry::{DoubleEntry, DoubleEntryError, LineItem};
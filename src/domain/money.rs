@@ -0,0 +1,191 @@
+//! # Money Domain Type
+//!
+//! This module defines [`Money`], an immutable monetary amount stored as a whole
+//! number of minor units (for example cents). Using integers avoids the rounding
+//! errors of floating point arithmetic, and all arithmetic is checked so an
+//! overflow surfaces as an error rather than a silently wrong balance.
+
+/// Number of minor units in one major unit (cents per dollar).
+pub const MINOR_UNITS_PER_MAJOR: i64 = 100;
+
+/// A signed monetary amount held in minor units.
+///
+/// Positive amounts increase a balance and negative amounts decrease it. The
+/// currency is implied by the ledger; `Money` only guarantees exact arithmetic.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::domain::Money;
+///
+/// let amount = Money::from_minor_units(12_345);
+/// assert_eq!(amount.to_string(), "123.45");
+/// assert_eq!(Money::from_major_units(5).unwrap().minor_units(), 500);
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(transparent)]
+pub struct Money(i64);
+
+/// Errors that can occur when constructing or combining [`Money`] values.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MoneyError {
+    /// The result does not fit in the underlying integer representation.
+    #[error("Money amount overflowed")]
+    Overflow,
+}
+
+impl Money {
+    /// The zero amount.
+    pub const ZERO: Money = Money(0);
+
+    /// Creates an amount from a number of minor units (e.g. cents).
+    #[must_use]
+    pub const fn from_minor_units(minor_units: i64) -> Self {
+        Money(minor_units)
+    }
+
+    /// Creates an amount from a number of major units (e.g. dollars).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the amount cannot be represented.
+    pub fn from_major_units(major_units: i64) -> Result<Self, MoneyError> {
+        major_units
+            .checked_mul(MINOR_UNITS_PER_MAJOR)
+            .map(Money)
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Returns the amount as a number of minor units.
+    #[must_use]
+    pub const fn minor_units(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns `true` if the amount is exactly zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if the amount is below zero.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns the absolute value of the amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] for the minimum representable amount.
+    pub fn abs(&self) -> Result<Self, MoneyError> {
+        self.0.checked_abs().map(Money).ok_or(MoneyError::Overflow)
+    }
+
+    /// Adds two amounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the sum cannot be represented.
+    pub fn checked_add(&self, other: Money) -> Result<Self, MoneyError> {
+        self.0.checked_add(other.0).map(Money).ok_or(MoneyError::Overflow)
+    }
+
+    /// Subtracts `other` from this amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the difference cannot be represented.
+    pub fn checked_sub(&self, other: Money) -> Result<Self, MoneyError> {
+        self.0.checked_sub(other.0).map(Money).ok_or(MoneyError::Overflow)
+    }
+
+    /// Generates a random positive amount for testing scenarios.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use fake::Fake;
+
+        Money(crate::mock_rng::with_rng(|rng| (1..1_000_000).fake_with_rng(rng)))
+    }
+}
+
+impl std::fmt::Display for Money {
+    /// Formats the amount in major units with two decimal places (e.g. `-12.05`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let magnitude = self.0.unsigned_abs();
+        let per_major = MINOR_UNITS_PER_MAJOR as u64;
+        write!(f, "{}{}.{:02}", sign, magnitude / per_major, magnitude % per_major)
+    }
+}
+
+impl From<i64> for Money {
+    fn from(minor_units: i64) -> Self {
+        Money::from_minor_units(minor_units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_minor_units_round_trip() {
+        let amount = Money::from_minor_units(1_234);
+        assert_eq!(amount.minor_units(), 1_234);
+    }
+
+    #[test]
+    fn test_from_major_units() {
+        assert_eq!(Money::from_major_units(12), Ok(Money::from_minor_units(1_200)));
+        assert_eq!(Money::from_major_units(i64::MAX), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_display_formats_major_and_minor_units() {
+        assert_eq!(Money::from_minor_units(0).to_string(), "0.00");
+        assert_eq!(Money::from_minor_units(5).to_string(), "0.05");
+        assert_eq!(Money::from_minor_units(12_345).to_string(), "123.45");
+        assert_eq!(Money::from_minor_units(-1_205).to_string(), "-12.05");
+        assert_eq!(Money::from_minor_units(i64::MIN).to_string(), "-92233720368547758.08");
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Money::from_minor_units(150);
+        let b = Money::from_minor_units(50);
+        assert_eq!(a.checked_add(b), Ok(Money::from_minor_units(200)));
+        assert_eq!(b.checked_sub(a), Ok(Money::from_minor_units(-100)));
+        assert_eq!(Money::from_minor_units(i64::MAX).checked_add(b), Err(MoneyError::Overflow));
+        assert_eq!(Money::from_minor_units(i64::MIN).checked_sub(b), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_sign_helpers() {
+        assert!(Money::ZERO.is_zero());
+        assert!(Money::from_minor_units(-1).is_negative());
+        assert!(!Money::from_minor_units(1).is_negative());
+        assert_eq!(Money::from_minor_units(-42).abs(), Ok(Money::from_minor_units(42)));
+        assert_eq!(Money::from_minor_units(i64::MIN).abs(), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_mock_is_positive() {
+        for _ in 0..10 {
+            assert!(Money::mock().minor_units() > 0);
+        }
+    }
+}
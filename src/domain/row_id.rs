@@ -69,7 +69,7 @@
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(
-    Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct RowID(uuid::Uuid);
 
@@ -206,6 +206,14 @@ impl Ord for RowID {
     }
 }
 
+/// Clock sequence shared by every [`RowID::new`] call in the process.
+///
+/// [`uuid::ContextV7`] fills the bits after the millisecond timestamp with a
+/// counter, incremented for each UUID within the same millisecond and reseeded
+/// randomly when the millisecond changes, so ids never repeat or go backwards
+/// however fast they are created.
+static ROW_ID_CONTEXT: std::sync::Mutex<uuid::ContextV7> = std::sync::Mutex::new(uuid::ContextV7::new());
+
 impl RowID {
     /// Create a new RowID using UUID v7 with current timestamp.
     ///
@@ -213,6 +221,9 @@ impl RowID {
     /// ordered by creation time, making them suitable for chronological sorting.
     /// This is the primary method for creating new identifiers.
     ///
+    /// Ids come from a process-wide monotonic counter context, so successive
+    /// calls return strictly increasing ids even within one millisecond.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -223,7 +234,7 @@ impl RowID {
     /// assert!(id1 < id2); // id1 was created before id2
     /// ```
     pub fn new() -> Self {
-        let row_id = uuid::Uuid::now_v7();
+        let row_id = uuid::Uuid::new_v7(uuid::Timestamp::now(&ROW_ID_CONTEXT));
         Self(row_id)
     }
 
@@ -252,12 +263,37 @@ impl RowID {
         let ts = uuid::Timestamp::from_unix(
             uuid::NoContext,
             timestamp.timestamp() as u64,
-            timestamp.timestamp_nanos_opt().unwrap() as u32,
+            timestamp.timestamp_subsec_nanos(),
         );
         let row_id = uuid::Uuid::new_v7(ts);
         Self(row_id)
     }
 
+    /// The creation time embedded in the UUID v7, to millisecond precision.
+    ///
+    /// UUID v7 stores the Unix time in milliseconds in its first 48 bits, so
+    /// this recovers when the id was generated without a separate column.
+    ///
+    /// **Warning**: RowIDs that are not v7, such as those created with
+    /// [`from_i64()`](Self::from_i64), return a meaningless time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{DateTime, Utc};
+    /// use personal_ledger_backend::domain::RowID;
+    ///
+    /// let timestamp = DateTime::parse_from_rfc3339("2023-01-01T12:30:45.123Z")
+    ///     .unwrap()
+    ///     .with_timezone(&Utc);
+    /// let id = RowID::from_timestamp(timestamp);
+    /// assert_eq!(id.timestamp(), timestamp);
+    /// ```
+    pub fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        let millis = self.0.as_bytes()[..6].iter().fold(0i64, |millis, byte| (millis << 8) | i64::from(*byte));
+        chrono::DateTime::from_timestamp_millis(millis).expect("48-bit millisecond timestamp is in range")
+    }
+
     /// Convert the RowID into its underlying UUID.
     ///
     /// This consumes the RowID and returns the wrapped UUID v7.
@@ -536,19 +572,9 @@ impl RowID {
 
         // Generate random DateTime after UNIX time epoch (00:00:00 UTC on 1 January 1970)
         let random_datetime: DateTime<Utc> =
-            DateTimeAfter(chrono::DateTime::UNIX_EPOCH).fake();
+            crate::mock_rng::with_rng(|rng| DateTimeAfter(chrono::DateTime::UNIX_EPOCH).fake_with_rng(rng));
 
-        // Convert datetime to a UUID timestamp
-        let random_uuid_timestamp: uuid::Timestamp = uuid::Timestamp::from_unix(
-            uuid::NoContext,
-            random_datetime.timestamp() as u64,
-            random_datetime.timestamp_nanos_opt().unwrap() as u32,
-        );
-
-        // Generate Uuid V7
-        let row_id = uuid::Uuid::new_v7(random_uuid_timestamp);
-
-        Self(row_id)
+        Self::mock_from_datetime(random_datetime)
     }
 
     /// Create a mock RowID with a specific timestamp for testing.
@@ -577,15 +603,14 @@ impl RowID {
     /// ```
     #[cfg(test)]
     pub fn mock_from_datetime(date_time: chrono::DateTime<chrono::Utc>) -> Self {
-        // Convert datetime to a UUID timestamp
-        let uuid_timestamp: uuid::Timestamp = uuid::Timestamp::from_unix(
-            uuid::NoContext,
-            date_time.timestamp() as u64,
-            date_time.timestamp_nanos_opt().unwrap() as u32,
-        );
+        use rand::Rng;
+
+        // Draw the random bits from the mock generator, so they can be replayed
+        let random_bytes: [u8; 10] = crate::mock_rng::with_rng(|rng| rng.random());
 
         // Generate Uuid V7
-        let row_id = uuid::Uuid::new_v7(uuid_timestamp);
+        let row_id = uuid::Builder::from_unix_timestamp_millis(date_time.timestamp_millis() as u64, &random_bytes)
+            .into_uuid();
 
         Self(row_id)
     }
@@ -729,10 +754,30 @@ pub enum RowIDError {
     TypeCast(String),
 }
 
+impl RowIDError {
+    /// Stable reason code for clients to branch on, e.g. `ID_BAD_FORMAT`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RowIDError::InvalidUuid(_) => "ID_BAD_FORMAT",
+            RowIDError::InvalidVersion(_) => "ID_BAD_VERSION",
+            RowIDError::TypeCast(_) => "ID_TYPE_CAST",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(RowIDError::InvalidUuid("nope".to_string()).code(), "ID_BAD_FORMAT");
+        assert_eq!(RowIDError::InvalidVersion(4).code(), "ID_BAD_VERSION");
+        assert_eq!(RowIDError::TypeCast("-1".to_string()).code(), "ID_TYPE_CAST");
+    }
+
     #[test]
     fn test_row_id_creation() {
         let id = RowID::new();
@@ -746,6 +791,16 @@ mod tests {
         assert!(id1 < id2);
     }
 
+    #[test]
+    fn test_row_id_new_is_strictly_increasing_in_tight_loop() {
+        let ids: Vec<RowID> = (0..10_000).map(|_| RowID::new()).collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]), "RowIDs should be strictly increasing");
+
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len(), "RowIDs should be unique");
+    }
+
     #[test]
     fn test_row_id_display() {
         let id = RowID::new();
@@ -922,7 +977,30 @@ mod tests {
         let timestamp = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
         let id = RowID::from_timestamp(timestamp);
         assert_eq!(id.0.get_version_num(), 7);
-        // Note: We can't easily test the exact timestamp due to UUID v7 encoding
+        assert_eq!(id.timestamp(), timestamp);
+    }
+
+    #[test]
+    fn test_timestamp_round_trips_to_the_millisecond() {
+        use chrono::{DateTime, TimeDelta, Utc};
+        let timestamp = DateTime::parse_from_rfc3339("2024-06-30T23:59:59.987654321Z").unwrap().with_timezone(&Utc);
+
+        let id = RowID::from_timestamp(timestamp);
+        assert_eq!(id.timestamp(), DateTime::parse_from_rfc3339("2024-06-30T23:59:59.987Z").unwrap());
+        assert!(timestamp - id.timestamp() < TimeDelta::milliseconds(1));
+
+        let mocked = RowID::mock_from_datetime(timestamp);
+        assert_eq!(mocked.timestamp(), id.timestamp());
+    }
+
+    #[test]
+    fn test_timestamp_of_new_is_now() {
+        use chrono::{TimeDelta, Utc};
+        let before = Utc::now() - TimeDelta::milliseconds(1);
+        let id = RowID::new();
+        let after = Utc::now();
+
+        assert!(id.timestamp() >= before && id.timestamp() <= after, "{} not in {}..{}", id.timestamp(), before, after);
     }
 
     #[test]
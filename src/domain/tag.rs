@@ -0,0 +1,245 @@
+//! # Tag Domain Type
+//!
+//! This module defines [`Tag`], an immutable, validated free-form label such as
+//! `tax-deductible` or `recurring`. A category can carry any number of tags,
+//! unlike its single [`CategoryTypes`](super::CategoryTypes).
+//!
+//! A tag is lowercase ASCII letters, digits and hyphens, starting and ending
+//! with a letter or digit. Input is trimmed and lowercased before validation,
+//! so `" Recurring "` and `"recurring"` are the same tag.
+
+/// Maximum number of characters in a tag.
+pub const TAG_MAX_LENGTH: usize = 32;
+
+/// Regular expression matched by every parsed tag, for clients validating input.
+pub const TAG_PATTERN: &str = "^[a-z0-9]([a-z0-9-]*[a-z0-9])?$";
+
+/// A validated, lowercase label attached to a record.
+///
+/// Use [`Tag::parse`] to create instances. The internal string is guaranteed
+/// to be non-empty, at most [`TAG_MAX_LENGTH`] characters, and made up of
+/// `a-z`, `0-9` and inner hyphens only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Tag(String);
+
+/// Errors that can occur when parsing a [`Tag`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TagError {
+    /// The input was empty or whitespace only.
+    #[error("Tag cannot be empty")]
+    Empty,
+    /// The input was longer than [`TAG_MAX_LENGTH`].
+    #[error("Tag must be at most {TAG_MAX_LENGTH} characters, got {0}")]
+    TooLong(usize),
+    /// The input contained characters other than letters, digits and inner hyphens.
+    #[error("Tag must contain only letters, digits and inner hyphens: {0}")]
+    InvalidCharacters(String),
+}
+
+impl TagError {
+    /// Stable reason code for clients to branch on, e.g. `TAG_EMPTY`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            TagError::Empty => "TAG_EMPTY",
+            TagError::TooLong(_) => "TAG_TOO_LONG",
+            TagError::InvalidCharacters(_) => "TAG_BAD_CHARACTERS",
+        }
+    }
+}
+
+impl Tag {
+    /// Parses a string into a [`Tag`] after trimming and lowercasing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TagError`] if the value is empty, too long, contains characters
+    /// other than ASCII letters, digits and hyphens, or starts or ends with a
+    /// hyphen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::Tag;
+    ///
+    /// let tag = Tag::parse("  Tax-Deductible ")?;
+    /// assert_eq!(tag.as_str(), "tax-deductible");
+    /// # Ok::<(), personal_ledger_backend::domain::TagError>(())
+    /// ```
+    pub fn parse<S: AsRef<str>>(input: S) -> Result<Self, TagError> {
+        let input = input.as_ref().trim().to_ascii_lowercase();
+        if input.is_empty() {
+            return Err(TagError::Empty);
+        }
+
+        let valid_characters = input.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid_characters || input.starts_with('-') || input.ends_with('-') {
+            return Err(TagError::InvalidCharacters(input));
+        }
+
+        // Validated ASCII is one byte per character
+        if input.len() > TAG_MAX_LENGTH {
+            return Err(TagError::TooLong(input.len()));
+        }
+
+        Ok(Tag(input))
+    }
+
+    /// Gets the tag as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Converts the tag into its owned String representation.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// Convenience helper used in validation contexts.
+    #[must_use]
+    pub fn is_valid<S: AsRef<str>>(input: S) -> bool {
+        Self::parse(input).is_ok()
+    }
+
+    /// Generates a random tag for testing scenarios.
+    #[cfg(test)]
+    pub fn mock() -> Self {
+        use rand::Rng;
+
+        let suffix: String = crate::mock_rng::with_rng(|rng| {
+            rng.sample_iter(&rand::distr::Alphanumeric)
+                .take(12)
+                .map(char::from)
+                .collect()
+        });
+
+        Tag::parse(format!("mock-{}", suffix)).expect("mock tag should be valid")
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for Tag {
+    type Err = TagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::parse(s)
+    }
+}
+
+impl From<Tag> for String {
+    fn from(value: Tag) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for Tag {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<&str> for Tag {
+    type Error = TagError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Tag::parse(value)
+    }
+}
+
+impl TryFrom<String> for Tag {
+    type Error = TagError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Tag::parse(value)
+    }
+}
+
+// SQLx trait implementations ensure the tag can be stored as TEXT.
+impl sqlx::Type<sqlx::Sqlite> for Tag {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for Tag {
+    fn decode(value: sqlx::sqlite::SqliteValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+        let raw = <String as sqlx::Decode<sqlx::Sqlite>>::decode(value)?;
+        Ok(Tag::parse(raw).map_err(|e| format!("Invalid tag in database: {}", e))?)
+    }
+}
+
+impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for Tag {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Sqlite as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.0.clone(), buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(TagError::Empty.code(), "TAG_EMPTY");
+        assert_eq!(TagError::TooLong(99).code(), "TAG_TOO_LONG");
+        assert_eq!(TagError::InvalidCharacters("a b".to_string()).code(), "TAG_BAD_CHARACTERS");
+    }
+
+    #[test]
+    fn parse_trims_and_lowercases() {
+        let tag = Tag::parse("  Tax-Deductible\t").unwrap();
+        assert_eq!(tag.as_str(), "tax-deductible");
+        assert_eq!(tag, Tag::parse("tax-deductible").unwrap());
+    }
+
+    #[test]
+    fn parse_rejects_empty() {
+        assert_eq!(Tag::parse(""), Err(TagError::Empty));
+        assert_eq!(Tag::parse("   "), Err(TagError::Empty));
+    }
+
+    #[test]
+    fn parse_rejects_invalid_characters() {
+        for input in ["two words", "under_score", "café", "-leading", "trailing-", "a/b"] {
+            let err = Tag::parse(input).unwrap_err();
+            assert!(matches!(err, TagError::InvalidCharacters(_)), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_enforces_max_length() {
+        assert!(Tag::is_valid("x".repeat(TAG_MAX_LENGTH)));
+        assert_eq!(
+            Tag::parse("x".repeat(TAG_MAX_LENGTH + 1)),
+            Err(TagError::TooLong(TAG_MAX_LENGTH + 1))
+        );
+    }
+
+    #[test]
+    fn mock_is_valid() {
+        for _ in 0..10 {
+            assert!(Tag::is_valid(Tag::mock().as_str()));
+        }
+    }
+
+    #[test]
+    fn serde_validates_on_deserialize() {
+        let tag = Tag::parse("recurring").unwrap();
+        let json = serde_json::to_string(&tag).unwrap();
+        assert_eq!(json, "\"recurring\"");
+        assert_eq!(serde_json::from_str::<Tag>(&json).unwrap(), tag);
+        assert!(serde_json::from_str::<Tag>("\"not a tag\"").is_err());
+    }
+}
@@ -24,6 +24,9 @@
 
 use std::fmt;
 
+/// Regular expression matched by every parsed slug, for clients validating input.
+pub const URL_SLUG_PATTERN: &str = "^[a-z0-9]+(-[a-z0-9]+)*$";
+
 /// Represents a URL-safe slug string.
 ///
 /// A slug is a string that contains only lowercase letters, numbers, and hyphens,
@@ -52,6 +55,20 @@ pub enum UrlSlugError {
     ConsecutiveHyphens(String),
 }
 
+impl UrlSlugError {
+    /// Stable reason code for clients to branch on, e.g. `SLUG_EMPTY`.
+    ///
+    /// Unlike the message, the code does not change between releases.
+    pub fn code(&self) -> &'static str {
+        match self {
+            UrlSlugError::InvalidCharacters(_) => "SLUG_BAD_CHARACTERS",
+            UrlSlugError::EmptySlug => "SLUG_EMPTY",
+            UrlSlugError::StartsOrEndsWithHyphen(_) => "SLUG_EDGE_HYPHEN",
+            UrlSlugError::ConsecutiveHyphens(_) => "SLUG_CONSECUTIVE_HYPHENS",
+        }
+    }
+}
+
 impl UrlSlug {
     /// Parse a string into a URL-safe slug.
     ///
@@ -134,6 +151,34 @@ impl UrlSlug {
         self.0.len()
     }
 
+    /// Make `base` unique by appending `-2`, `-3`, ... until `exists`
+    /// reports the slug is free.
+    ///
+    /// `base` itself is returned unchanged when it is free. `exists` must
+    /// eventually report a suffixed slug as free, or this never returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use personal_ledger_backend::domain::UrlSlug;
+    ///
+    /// let taken = ["groceries", "groceries-2"];
+    /// let base = UrlSlug::parse("Groceries")?;
+    /// let slug = UrlSlug::make_unique(&base, |slug| taken.contains(&slug.as_str()));
+    /// assert_eq!(slug.as_str(), "groceries-3");
+    /// # Ok::<(), personal_ledger_backend::domain::UrlSlugError>(())
+    /// ```
+    pub fn make_unique(base: &UrlSlug, exists: impl Fn(&UrlSlug) -> bool) -> UrlSlug {
+        if !exists(base) {
+            return base.clone();
+        }
+
+        (2u64..)
+            .map(|suffix| UrlSlug(format!("{}-{}", base.0, suffix)))
+            .find(|slug| !exists(slug))
+            .expect("a free suffix is found before the counter overflows")
+    }
+
     /// Clean a string to make it URL-safe.
     ///
     /// This is the internal cleaning logic used by `parse()`.
@@ -256,6 +301,33 @@ impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for UrlSlug {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(UrlSlugError::InvalidCharacters("a_b".to_string()).code(), "SLUG_BAD_CHARACTERS");
+        assert_eq!(UrlSlugError::EmptySlug.code(), "SLUG_EMPTY");
+        assert_eq!(UrlSlugError::StartsOrEndsWithHyphen("-a".to_string()).code(), "SLUG_EDGE_HYPHEN");
+        assert_eq!(UrlSlugError::ConsecutiveHyphens("a--b".to_string()).code(), "SLUG_CONSECUTIVE_HYPHENS");
+    }
+
+    #[test]
+    fn test_make_unique_returns_free_base() {
+        let base = UrlSlug::parse("groceries").unwrap();
+        let slug = UrlSlug::make_unique(&base, |_| false);
+        assert_eq!(slug, base);
+    }
+
+    #[test]
+    fn test_make_unique_suffixes_until_free() {
+        let base = UrlSlug::parse("groceries").unwrap();
+
+        let slug = UrlSlug::make_unique(&base, |slug| slug.as_str() == "groceries");
+        assert_eq!(slug.as_str(), "groceries-2");
+
+        let taken = ["groceries", "groceries-2"];
+        let slug = UrlSlug::make_unique(&base, |slug| taken.contains(&slug.as_str()));
+        assert_eq!(slug.as_str(), "groceries-3");
+    }
+
     #[test]
     fn test_parse_basic() {
         let slug = UrlSlug::parse("Hello World").unwrap();
@@ -10,6 +10,7 @@
 //! - `Database`: Errors from database operations (SQLx)
 //! - `Config`: Configuration loading and parsing errors
 //! - `Io`: File system and I/O operations
+//! - `Bind`: Server listener binding failures, naming the address
 //! - `Validation`: Data validation errors
 //! - `Authentication`: JWT and authentication-related errors
 //! - `Internal`: Internal server errors and unexpected conditions
@@ -89,6 +90,15 @@ pub enum LedgerError {
     #[error("Address parsing error: {0}")]
     AddrParse(#[from] std::net::AddrParseError),
 
+    /// A server listener could not be bound to a configured address
+    #[error("Failed to bind server to {address}: {source}")]
+    Bind {
+        /// The address that could not be bound
+        address: std::net::SocketAddr,
+        /// The underlying bind failure
+        source: std::io::Error,
+    },
+
     /// Environment variable errors
     #[error("Environment variable error: {0}")]
     Env(#[from] std::env::VarError),
@@ -211,6 +221,10 @@ impl From<LedgerError> for tonic::Status {
             LedgerError::AddrParse(_) => {
                 tonic::Status::invalid_argument("Invalid network address format")
             }
+            LedgerError::Bind { address, source } => {
+                tracing::error!(%address, ?source, "Failed to bind server address");
+                tonic::Status::internal("I/O error occurred")
+            }
             LedgerError::Env(e) => {
                 tracing::error!(?e, "Environment variable error");
                 tonic::Status::internal("Environment configuration error")
@@ -394,6 +408,15 @@ mod tests {
         assert_eq!(status.code(), Code::Internal);
         assert!(status.message().contains("I/O error"));
 
+        // Test Bind variant
+        let bind_err = LedgerError::Bind {
+            address: "127.0.0.1:50059".parse().unwrap(),
+            source: io::Error::new(io::ErrorKind::AddrInUse, "Address in use"),
+        };
+        assert_eq!(bind_err.to_string(), "Failed to bind server to 127.0.0.1:50059: Address in use");
+        let status: Status = bind_err.into();
+        assert_eq!(status.code(), Code::Internal);
+
         // Test AddrParse variant
         let addr_parse_result: Result<std::net::SocketAddr, std::net::AddrParseError> = "invalid:address:99999".parse();
         if let Err(addr_parse_err) = addr_parse_result {
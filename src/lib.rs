@@ -31,6 +31,10 @@ pub mod domain;
 /// Error types and handling utilities.
 mod error;
 
+/// Seeded randomness for the test mock generators.
+#[cfg(test)]
+pub(crate) mod mock_rng;
+
 // Re-Exports for cleaner code
 /// Common error and result types used across the crate and binary.
 pub use error::{LedgerError, LedgerResult};
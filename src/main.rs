@@ -16,11 +16,30 @@ async fn main() -> LedgerResult<()> {
     #[allow(clippy::let_unit_value)]
     let _telemetry_guard = telemetry::init(log_level)?;
     tracing::info!("Starting tracing at level '{:?}'", log_level);
+    tracing::info!("Loaded configuration: {}", ledger_config.redacted_summary());
 
     // Initialize the database connection pool and run migrations
     let database_url = ledger_config.server.database_url()?;
-    let database_pool = database::DatabasePool::new(&database_url);
-    let database = database_pool.connect().await?;
+    let mut database_pool = database::DatabasePool::new(&database_url);
+    if let Some(max_connections) = ledger_config.server.database_max_connections {
+        database_pool = database_pool.with_max_connections(max_connections);
+    }
+    if let Some(acquire_timeout_secs) = ledger_config.server.database_acquire_timeout_secs {
+        database_pool = database_pool.with_acquire_timeout(std::time::Duration::from_secs(acquire_timeout_secs));
+    }
+    // Retry so a database that is still starting up does not stop the server
+    let database = database_pool
+        .connect_with_retry(database::DEFAULT_CONNECT_ATTEMPTS, database::DEFAULT_CONNECT_BASE_DELAY)
+        .await?;
+
+    // Apply pending migrations, or refuse to start if the deploy requires them
+    // to have been run already
+    database::prepare_schema(database.get_pool()?, ledger_config.server.require_migrated).await?;
+
+    // Fail fast if the database cannot take a write and read back before serving
+    if ledger_config.server.startup_self_test {
+        database::run_self_test(database.get_pool()?).await?;
+    }
 
     let tonic_server = server::TonicServer::new(database.into_pool()?, ledger_config).await?;
 
@@ -0,0 +1,71 @@
+//! # Seeded Randomness for Mocks
+//!
+//! Every `mock` generator in `domain` and `database` draws its randomness from
+//! [`with_rng`], so the data a test generated can be generated again.
+//!
+//! Each test thread gets its own generator, seeded from the
+//! [`TEST_SEED_ENV`] environment variable when it is set and from a random seed
+//! otherwise. The seed is written to stderr the first time a thread uses it,
+//! and the test harness shows that output for a failing test, so the failure
+//! can be replayed with:
+//!
+//! ```text
+//! LEDGER_TEST_SEED=<seed> cargo test <test name>
+//! ```
+//!
+//! Code that runs on other threads, such as tasks on a multi-threaded Tokio
+//! runtime, draws from those threads' own generators.
+
+use std::cell::RefCell;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Environment variable holding the seed for the mock generators.
+pub const TEST_SEED_ENV: &str = "LEDGER_TEST_SEED";
+
+thread_local! {
+    static MOCK_RNG: RefCell<StdRng> = RefCell::new(StdRng::seed_from_u64(initial_seed()));
+}
+
+/// The seed from [`TEST_SEED_ENV`], or a random one when it is not set.
+fn initial_seed() -> u64 {
+    let seed = match std::env::var(TEST_SEED_ENV) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("{} must be an unsigned integer, got {:?}", TEST_SEED_ENV, value)),
+        Err(_) => rand::random(),
+    };
+    eprintln!("Mock data seed: {}={}", TEST_SEED_ENV, seed);
+    seed
+}
+
+/// Run `f` with this thread's mock generator.
+pub fn with_rng<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    MOCK_RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Restart this thread's mock generator from `seed`.
+pub fn reseed(seed: u64) {
+    MOCK_RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_reseed_replays_the_same_values() {
+        reseed(42);
+        let first: [u64; 4] = with_rng(|rng| rng.random());
+        reseed(42);
+        let second: [u64; 4] = with_rng(|rng| rng.random());
+        reseed(43);
+        let third: [u64; 4] = with_rng(|rng| rng.random());
+
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+    }
+}
@@ -27,6 +27,16 @@ mod proto {
 /// File descriptor set for gRPC reflection.
 pub use proto::FILE_DESCRIPTOR_SET;
 
+/// Decode [`FILE_DESCRIPTOR_SET`], for tools that inspect the API's services
+/// and messages without the `.proto` sources.
+///
+/// # Errors
+///
+/// Returns a decode error if the embedded bytes are not a valid descriptor set.
+pub fn file_descriptor_set() -> Result<prost_types::FileDescriptorSet, prost::DecodeError> {
+    prost::Message::decode(FILE_DESCRIPTOR_SET)
+}
+
 /// gRPC client for the `UtilitiesService`.
 pub use proto::utilities_service_client::UtilitiesServiceClient;
 
@@ -36,6 +46,12 @@ pub use proto::utilities_service_server::{UtilitiesService, UtilitiesServiceServ
 /// Request and response message types for the Ping endpoint.
 pub use proto::{PingRequest, PingResponse};
 
+/// Request and response message types for the GetFeatures endpoint.
+pub use proto::{GetFeaturesRequest, GetFeaturesResponse};
+
+/// Request and event message types for the EventStream endpoint.
+pub use proto::{EventStreamRequest, ServerEvent};
+
 /// gRPC client for the `CategoriesService`.
 pub use proto::categories_service_client::CategoriesServiceClient;
 
@@ -50,12 +66,19 @@ pub use proto::{
     CategoryGetByCodeRequest, CategoryGetByCodeResponse,
     CategoryGetBySlugRequest, CategoryGetBySlugResponse,
     CategoriesListRequest, CategoriesListResponse,
+    CategoriesListStreamRequest, CategoriesListStreamResponse,
     CategoryUpdateRequest, CategoryUpdateResponse,
+    CategoryFieldChange, CategoryUpdatePreviewRequest, CategoryUpdatePreviewResponse,
     CategoriesCreateBatchRequest, CategoriesCreateBatchResponse,
+    CategoryValidationError, CategoriesValidateBatchRequest, CategoriesValidateBatchResponse,
     CategoryDeleteRequest, CategoryDeleteResponse,
     CategoriesDeleteBatchRequest, CategoriesDeleteBatchResponse,
     CategoryActivateRequest, CategoryActivateResponse,
     CategoryDeactivateRequest, CategoryDeactivateResponse,
+    CategoriesExportCsvRequest, CategoriesExportCsvChunk,
+    CategoryDisplayOrder, CategoriesReorderRequest, CategoriesReorderResponse,
+    CategoryNode, CategoriesTreeRequest, CategoriesTreeResponse,
+    CategoryFieldRule, GetCategorySchemaRequest, GetCategorySchemaResponse,
 };
 
 /// Google protobuf types used in the API.
@@ -0,0 +1,272 @@
+//! # Admin HTTP Server
+//!
+//! This module provides a small plain HTTP server that runs alongside the gRPC
+//! server, so load balancers, orchestrators and Prometheus can probe and scrape
+//! the backend without speaking gRPC. It is started on `ServerConfig.admin_port`
+//! and serves:
+//!
+//! - `GET /healthz`: `200` while the process is running
+//! - `GET /readyz`: `200` when every [`server::HealthChecker`] check passes,
+//!   `503` otherwise, with one line of detail per check. The database check
+//!   fails once shutdown has started closing the pool
+//! - `GET /metrics`: the shared [`telemetry::Metrics`] in the Prometheus text format
+//! - `GET /descriptor`: the compiled protobuf `FileDescriptorSet` served to
+//!   gRPC reflection, [`rpc::FILE_DESCRIPTOR_SET`], for documentation
+//!   generators such as `buf` or `protoc` plugins
+//!
+//! When `ServerConfig.enable_json_api` is set it also serves the
+//! [`server::json_api_router`] endpoints under `/api/categories`.
+//!
+//! The admin server shares the database pool, request counters and stream cap
+//! with the gRPC [`server::Router`], so both sides report the same state.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use tokio::net as TokioNet;
+
+use crate::{rpc, server, telemetry, LedgerResult};
+
+/// How long `/readyz` and `/metrics` wait for the database to answer.
+pub const DATABASE_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Content type of the Prometheus text exposition format.
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// File name suggested for the descriptor set download, in `buf`'s binary format.
+const DESCRIPTOR_FILE_NAME: &str = "personal_ledger_descriptor.binpb";
+
+/// State shared between the admin endpoints and the gRPC services.
+#[derive(Debug, Clone)]
+pub struct AdminState {
+    database_pool: Arc<sqlx::SqlitePool>,
+    metrics: Arc<telemetry::Metrics>,
+    stream_limit: server::StreamLimitLayer,
+    health_checker: server::HealthChecker,
+    json_api: Option<server::JsonApiState>,
+}
+
+impl AdminState {
+    /// Create the admin state from its parts, checking readiness of the database.
+    pub fn new(
+        database_pool: Arc<sqlx::SqlitePool>,
+        metrics: Arc<telemetry::Metrics>,
+        stream_limit: server::StreamLimitLayer,
+    ) -> Self {
+        let health_checker = server::HealthChecker::with_database(Arc::clone(&database_pool));
+        Self { database_pool, metrics, stream_limit, health_checker, json_api: None }
+    }
+
+    /// Replace the readiness checks run by `/readyz`.
+    pub fn with_health_checker(mut self, health_checker: server::HealthChecker) -> Self {
+        self.health_checker = health_checker;
+        self
+    }
+
+    /// Also serve the JSON API through `json_api`.
+    pub fn with_json_api(mut self, json_api: server::JsonApiState) -> Self {
+        self.json_api = Some(json_api);
+        self
+    }
+
+    /// Share the database pool, metrics, stream cap and JSON API of a gRPC router.
+    pub fn from_router(router: &server::Router) -> Self {
+        let state = Self::new(router.database_pool(), router.metrics(), router.stream_limit().clone());
+        match router.json_api() {
+            Some(json_api) => state.with_json_api(json_api.clone()),
+            None => state,
+        }
+    }
+
+    /// Returns `true` if the database answers a trivial query in time.
+    pub async fn database_up(&self) -> bool {
+        server::database_check(&self.database_pool).await.healthy
+    }
+}
+
+/// Build the axum router serving the admin endpoints, and the JSON API when
+/// the state has one.
+pub fn admin_router(state: AdminState) -> axum::Router {
+    let json_api = state.json_api.clone();
+    let router = axum::Router::new()
+        .route("/healthz", axum::routing::get(healthz))
+        .route("/readyz", axum::routing::get(readyz))
+        .route("/metrics", axum::routing::get(metrics))
+        .route("/descriptor", axum::routing::get(descriptor))
+        .with_state(state);
+
+    match json_api {
+        Some(json_api) => router.merge(server::json_api_router(json_api)),
+        None => router,
+    }
+}
+
+async fn healthz() -> impl IntoResponse {
+    (StatusCode::OK, "ok")
+}
+
+async fn readyz(State(state): State<AdminState>) -> impl IntoResponse {
+    let report = state.health_checker.check().await;
+    let status = if report.is_healthy() {
+        StatusCode::OK
+    } else {
+        tracing::warn!("Readiness checks failing: {}", report.failing().join(", "));
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, report.render())
+}
+
+async fn metrics(State(state): State<AdminState>) -> impl IntoResponse {
+    let body = state
+        .metrics
+        .render(state.database_up().await, state.stream_limit.available_streams());
+    ([(header::CONTENT_TYPE, METRICS_CONTENT_TYPE)], body)
+}
+
+async fn descriptor() -> impl IntoResponse {
+    (
+        [
+            (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}\"", DESCRIPTOR_FILE_NAME)),
+        ],
+        rpc::FILE_DESCRIPTOR_SET,
+    )
+}
+
+/// The admin HTTP server, bound but not yet serving.
+pub struct AdminServer {
+    listener: TokioNet::TcpListener,
+    state: AdminState,
+}
+
+impl AdminServer {
+    /// Bind the admin server to `address`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LedgerError::Bind`](crate::LedgerError::Bind) if the address
+    /// cannot be bound.
+    pub async fn bind(address: core::net::SocketAddr, state: AdminState) -> LedgerResult<Self> {
+        let listener = TokioNet::TcpListener::bind(address)
+            .await
+            .map_err(|source| crate::LedgerError::Bind { address, source })?;
+        tracing::debug!("Admin server bound to {}", listener.local_addr()?);
+
+        Ok(Self { listener, state })
+    }
+
+    /// Get the local address the admin server is bound to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `std::io::Error` if the listener's local address cannot be retrieved.
+    pub fn local_addr(&self) -> Result<core::net::SocketAddr, std::io::Error> {
+        self.listener.local_addr()
+    }
+
+    /// Serve the admin endpoints until the task is dropped or aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if accepting connections fails fatally.
+    pub async fn run(self) -> LedgerResult<()> {
+        tracing::info!("Admin server listening on {}", self.local_addr()?);
+        axum::serve(self.listener, admin_router(self.state)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    async fn state(pool: sqlx::SqlitePool) -> AdminState {
+        let stream_limit = server::StreamLimitLayer::new(4, server::StreamingMethods::default());
+        AdminState::new(Arc::new(pool), Arc::new(telemetry::Metrics::default()), stream_limit)
+    }
+
+    async fn get(state: AdminState, path: &str) -> (StatusCode, String) {
+        let request = axum::http::Request::builder().uri(path).body(axum::body::Body::empty()).unwrap();
+        let response = admin_router(state).oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_descriptor_lists_services() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let request = axum::http::Request::builder().uri("/descriptor").body(axum::body::Body::empty()).unwrap();
+        let response = admin_router(state(pool).await).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/octet-stream");
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let descriptor_set: prost_types::FileDescriptorSet = prost::Message::decode(bytes).unwrap();
+        assert_eq!(descriptor_set, rpc::file_descriptor_set().unwrap());
+
+        let services: Vec<&str> = descriptor_set
+            .file
+            .iter()
+            .flat_map(|file| file.service.iter().map(|service| service.name()))
+            .collect();
+        assert!(services.contains(&"CategoriesService"), "{:?}", services);
+        assert!(services.contains(&"UtilitiesService"), "{:?}", services);
+    }
+
+    #[tokio::test]
+    async fn test_healthz_is_ok() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let (status, body) = get(state(pool).await, "/healthz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_reports_shared_state() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let state = state(pool).await;
+        state.metrics.record_grpc_request();
+
+        let (status, body) = get(state, "/metrics").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("\nledger_grpc_requests_total 1\n"));
+        assert!(body.contains("\nledger_database_up 1\n"));
+        assert!(body.contains("\nledger_streams_available 4\n"));
+    }
+
+    #[tokio::test]
+    async fn test_readyz_reports_failing_check() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let checker = server::HealthChecker::with_database(Arc::new(pool.clone()))
+            .register("stub", || async { server::CheckResult::unhealthy("stubbed out") });
+        let state = state(pool).await.with_health_checker(checker);
+
+        let (status, body) = get(state, "/readyz").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body, "not ready\ndatabase: healthy (query answered)\nstub: unhealthy (stubbed out)\n");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_is_not_found() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let (status, _) = get(state(pool).await, "/nope").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn test_json_api_only_served_when_enabled(pool: sqlx::SqlitePool) {
+        let (status, _) = get(state(pool.clone()).await, "/api/categories").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let categories =
+            crate::services::CategoriesService::new(Arc::new(pool.clone()), Arc::new(crate::LedgerConfig::default()));
+        let state = state(pool).await.with_json_api(server::JsonApiState::new(categories));
+        let (status, body) = get(state, "/api/categories").await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        assert!(body.contains("\"total_count\":0"), "{}", body);
+    }
+}
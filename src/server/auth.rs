@@ -0,0 +1,169 @@
+//! # Bearer Token Authentication
+//!
+//! This module provides [`AuthInterceptor`], which checks the static bearer
+//! token configured in `ServerConfig.auth_token` on every request to the
+//! application services. Requests without an `authorization: Bearer <token>`
+//! header, or with a different token, are rejected with
+//! `tonic::Code::Unauthenticated`.
+//!
+//! The router applies it to the Utilities and Categories services and the
+//! admin server's JSON API only, so health checks and reflection keep working
+//! for probes and tooling that do not hold the token. With no token
+//! configured the interceptor lets every request through.
+
+use std::sync::Arc;
+
+use secrecy::ExposeSecret;
+
+use crate::config::ServerConfig;
+
+/// Metadata key carrying the bearer token.
+pub const AUTHORIZATION_HEADER: &str = "authorization";
+
+/// Message returned to clients that did not send a bearer token.
+pub const MISSING_TOKEN_MESSAGE: &str = "Missing bearer token";
+
+/// Message returned to clients whose bearer token does not match.
+pub const INVALID_TOKEN_MESSAGE: &str = "Invalid bearer token";
+
+/// Interceptor that rejects requests without the configured bearer token.
+#[derive(Clone, Default)]
+pub struct AuthInterceptor {
+    token: Option<Arc<str>>,
+}
+
+impl std::fmt::Debug for AuthInterceptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthInterceptor")
+            .field("enabled", &self.is_enabled())
+            .finish()
+    }
+}
+
+impl AuthInterceptor {
+    /// Create an interceptor requiring `token`.
+    ///
+    /// `None`, or a blank token, disables the check.
+    pub fn new(token: Option<&str>) -> Self {
+        let token = token.map(str::trim).filter(|token| !token.is_empty()).map(Arc::from);
+        Self { token }
+    }
+
+    /// Create an interceptor requiring the configured `auth_token`.
+    pub fn from_config(config: &ServerConfig) -> Self {
+        Self::new(config.auth_token.as_ref().map(|token| token.expose_secret()))
+    }
+
+    /// Returns `true` if requests must carry a token.
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some()
+    }
+}
+
+impl tonic::service::Interceptor for AuthInterceptor {
+    fn call(&mut self, request: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        let Some(expected) = &self.token else {
+            return Ok(request);
+        };
+
+        let Some(presented) = bearer_token(&request) else {
+            tracing::warn!("Rejecting request from {:?}: missing bearer token", request.remote_addr());
+            return Err(tonic::Status::unauthenticated(MISSING_TOKEN_MESSAGE));
+        };
+
+        if !constant_time_eq(presented.as_bytes(), expected.as_bytes()) {
+            tracing::warn!("Rejecting request from {:?}: invalid bearer token", request.remote_addr());
+            return Err(tonic::Status::unauthenticated(INVALID_TOKEN_MESSAGE));
+        }
+
+        Ok(request)
+    }
+}
+
+/// The token from an `authorization: Bearer <token>` header, if present.
+fn bearer_token<T>(request: &tonic::Request<T>) -> Option<&str> {
+    let value = request.metadata().get(AUTHORIZATION_HEADER)?.to_str().ok()?;
+    let (scheme, token) = value.trim().split_once(' ')?;
+    scheme.eq_ignore_ascii_case("bearer").then(|| token.trim())
+}
+
+/// Compare tokens without exiting early on the first differing byte, so the
+/// time taken does not reveal how much of a guess was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::service::Interceptor;
+
+    const TOKEN: &str = "s3cret-token";
+
+    fn request(authorization: Option<&str>) -> tonic::Request<()> {
+        let mut request = tonic::Request::new(());
+        if let Some(value) = authorization {
+            request.metadata_mut().insert(AUTHORIZATION_HEADER, value.parse().unwrap());
+        }
+        request
+    }
+
+    fn rejection(interceptor: &mut AuthInterceptor, authorization: Option<&str>) -> Option<tonic::Status> {
+        interceptor.call(request(authorization)).err()
+    }
+
+    #[test]
+    fn test_accepts_matching_token() {
+        let mut interceptor = AuthInterceptor::new(Some(TOKEN));
+        assert!(interceptor.is_enabled());
+
+        assert!(rejection(&mut interceptor, Some("Bearer s3cret-token")).is_none());
+        assert!(rejection(&mut interceptor, Some("bearer s3cret-token")).is_none());
+    }
+
+    #[test]
+    fn test_rejects_missing_token() {
+        let mut interceptor = AuthInterceptor::new(Some(TOKEN));
+
+        let status = rejection(&mut interceptor, None).unwrap();
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+        assert_eq!(status.message(), MISSING_TOKEN_MESSAGE);
+
+        // A token under another scheme is not a bearer token
+        let status = rejection(&mut interceptor, Some("Basic s3cret-token")).unwrap();
+        assert_eq!(status.message(), MISSING_TOKEN_MESSAGE);
+    }
+
+    #[test]
+    fn test_rejects_wrong_token() {
+        let mut interceptor = AuthInterceptor::new(Some(TOKEN));
+
+        for authorization in ["Bearer wrong", "Bearer s3cret-token-2", "Bearer s3cret"] {
+            let status = rejection(&mut interceptor, Some(authorization)).unwrap();
+            assert_eq!(status.code(), tonic::Code::Unauthenticated);
+            assert_eq!(status.message(), INVALID_TOKEN_MESSAGE);
+        }
+    }
+
+    #[test]
+    fn test_disabled_without_token() {
+        for token in [None, Some(""), Some("   ")] {
+            let mut interceptor = AuthInterceptor::new(token);
+            assert!(!interceptor.is_enabled());
+            assert!(rejection(&mut interceptor, None).is_none());
+        }
+    }
+
+    #[test]
+    fn test_from_config() {
+        let config = ServerConfig {
+            auth_token: Some(secrecy::SecretString::from(TOKEN)),
+            ..ServerConfig::default()
+        };
+        let interceptor = AuthInterceptor::from_config(&config);
+        assert!(interceptor.is_enabled());
+        assert!(!format!("{:?}", interceptor).contains(TOKEN));
+
+        assert!(!AuthInterceptor::from_config(&ServerConfig::default()).is_enabled());
+    }
+}
@@ -0,0 +1,393 @@
+//! # Per-Connection Request Limit
+//!
+//! This module closes a connection once it has served a configured number of
+//! requests, so a long-lived client reconnects and is rebalanced instead of
+//! holding one connection, and whatever it has leaked, forever.
+//!
+//! - [`ConnectionLimitLayer`] counts the requests on each connection and
+//!   rejects the ones past the limit with `tonic::Code::Unavailable`
+//! - [`LimitedConnection`] wraps each accepted TCP stream and ends it once
+//!   its limit is reached, no request on it is still in flight and the last
+//!   response has been flushed
+//!
+//! Requests are matched to their connection by the local and peer addresses
+//! tonic records for every request, so the connect info seen by the rest of
+//! the server, including the TLS check, is unchanged.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures_util::future::BoxFuture;
+use futures_util::task::AtomicWaker;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tonic::transport::server::{Connected, TcpConnectInfo, TlsConnectInfo};
+
+/// Message returned to clients whose request is past their connection's limit.
+pub const CONNECTION_LIMIT_MESSAGE: &str = "Connection request limit reached, reconnect and retry";
+
+/// Local and peer address identifying one accepted connection.
+type ConnectionKey = (SocketAddr, SocketAddr);
+
+/// Request counts for one connection.
+#[derive(Debug, Default)]
+struct ConnectionBudget {
+    served: AtomicU64,
+    in_flight: AtomicUsize,
+    closed: AtomicBool,
+    reader: AtomicWaker,
+}
+
+impl ConnectionBudget {
+    /// Count a new request, returning `false` if it is past `limit`.
+    fn admit(&self, limit: u64) -> bool {
+        if self.served.fetch_add(1, Ordering::AcqRel) >= limit {
+            return false;
+        }
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        true
+    }
+
+    /// Mark a request finished, closing the connection if it was the last
+    /// one allowed.
+    fn finish(&self, limit: u64) {
+        let remaining = self.in_flight.fetch_sub(1, Ordering::AcqRel) - 1;
+        if remaining == 0 && self.served.load(Ordering::Acquire) >= limit {
+            self.close();
+        }
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.reader.wake();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+}
+
+type Registry = Arc<Mutex<HashMap<ConnectionKey, Arc<ConnectionBudget>>>>;
+
+/// Tower layer limiting the number of requests served per connection.
+///
+/// The same layer must wrap the accepted streams with
+/// [`ConnectionLimitLayer::accept`] and the services, so both share one
+/// registry of connections. Clone it to share.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionLimitLayer {
+    limit: Option<u64>,
+    connections: Registry,
+}
+
+impl ConnectionLimitLayer {
+    /// Create a layer closing connections after `max_requests_per_connection`
+    /// requests.
+    ///
+    /// A limit of `0` disables it.
+    pub fn new(max_requests_per_connection: u64) -> Self {
+        Self {
+            limit: (max_requests_per_connection > 0).then_some(max_requests_per_connection),
+            connections: Registry::default(),
+        }
+    }
+
+    /// The configured limit, or `None` when connections are not limited.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Number of open connections being counted.
+    pub fn tracked_connections(&self) -> usize {
+        self.connections.lock().expect("connection registry poisoned").len()
+    }
+
+    /// Wrap an accepted stream so it closes once its limit is reached.
+    ///
+    /// Streams are passed through untouched when the limit is disabled or
+    /// their addresses cannot be read.
+    pub fn accept(&self, stream: TcpStream) -> LimitedConnection {
+        let key = match (self.limit, stream.local_addr(), stream.peer_addr()) {
+            (Some(_), Ok(local), Ok(peer)) => Some((local, peer)),
+            _ => None,
+        };
+
+        let budget = key.map(|key| {
+            let budget = Arc::new(ConnectionBudget::default());
+            self.connections
+                .lock()
+                .expect("connection registry poisoned")
+                .insert(key, Arc::clone(&budget));
+            (key, budget)
+        });
+
+        LimitedConnection {
+            inner: stream,
+            budget,
+            connections: Arc::clone(&self.connections),
+            written_after_close: false,
+            drained: false,
+        }
+    }
+
+    fn budget_for<B>(&self, request: &http::Request<B>) -> Option<Arc<ConnectionBudget>> {
+        let extensions = request.extensions();
+        let info = extensions
+            .get::<TcpConnectInfo>()
+            .or_else(|| extensions.get::<TlsConnectInfo<TcpConnectInfo>>().map(|tls| tls.get_ref()))?;
+        let key = (info.local_addr()?, info.remote_addr()?);
+
+        self.connections
+            .lock()
+            .expect("connection registry poisoned")
+            .get(&key)
+            .cloned()
+    }
+}
+
+impl<S> tower::Layer<S> for ConnectionLimitLayer {
+    type Service = ConnectionLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ConnectionLimit {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// Service produced by [`ConnectionLimitLayer`].
+#[derive(Debug, Clone)]
+pub struct ConnectionLimit<S> {
+    inner: S,
+    layer: ConnectionLimitLayer,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for ConnectionLimit<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let (Some(limit), Some(budget)) = (self.layer.limit, self.layer.budget_for(&request)) else {
+            let future = inner.call(request);
+            return Box::pin(async move { Ok(future.await?.map(tonic::body::Body::new)) });
+        };
+
+        if !budget.admit(limit) {
+            tracing::debug!("Rejecting {}: connection request limit reached", request.uri().path());
+            let response = tonic::Status::unavailable(CONNECTION_LIMIT_MESSAGE).into_http();
+            return Box::pin(async move { Ok(response) });
+        }
+
+        // Counted as in flight until the response body is finished or dropped
+        let guard = InFlightGuard { budget, limit };
+        let future = inner.call(request);
+        Box::pin(async move {
+            let response = future.await?;
+            Ok(response.map(|body| {
+                let body = tonic::body::Body::new(body);
+                tonic::body::Body::new(InFlightBody { body, _guard: guard })
+            }))
+        })
+    }
+}
+
+/// Marks a request finished on its connection when dropped.
+struct InFlightGuard {
+    budget: Arc<ConnectionBudget>,
+    limit: u64,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.budget.finish(self.limit);
+    }
+}
+
+/// Response body that keeps its request in flight until it is finished or dropped.
+struct InFlightBody {
+    body: tonic::body::Body,
+    _guard: InFlightGuard,
+}
+
+impl http_body::Body for InFlightBody {
+    type Data = bytes::Bytes;
+    type Error = tonic::Status;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        Pin::new(&mut self.body).poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.body.size_hint()
+    }
+}
+
+/// Accepted TCP stream that reads as closed once its connection's limit is
+/// reached, so the server drops the connection.
+#[derive(Debug)]
+pub struct LimitedConnection {
+    inner: TcpStream,
+    budget: Option<(ConnectionKey, Arc<ConnectionBudget>)>,
+    connections: Registry,
+    /// Bytes were written after the budget closed.
+    written_after_close: bool,
+    /// The writes after the budget closed are flushed, so reads can end.
+    drained: bool,
+}
+
+impl LimitedConnection {
+    fn is_closed(&self) -> bool {
+        self.budget.as_ref().is_some_and(|(_, budget)| budget.is_closed())
+    }
+
+    fn record_write<T>(&mut self, written: Poll<std::io::Result<T>>) -> Poll<std::io::Result<T>> {
+        if matches!(written, Poll::Ready(Ok(_))) && self.is_closed() {
+            self.written_after_close = true;
+        }
+        written
+    }
+}
+
+impl Connected for LimitedConnection {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.inner.connect_info()
+    }
+}
+
+impl AsyncRead for LimitedConnection {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        if let Some((_, budget)) = &self.budget {
+            // Register before checking, so a flush in between still wakes us
+            budget.reader.register(cx.waker());
+            if self.drained {
+                return Poll::Ready(Ok(()));
+            }
+        }
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for LimitedConnection {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        let written = Pin::new(&mut self.inner).poll_write(cx, buf);
+        self.record_write(written)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let flushed = Pin::new(&mut self.inner).poll_flush(cx);
+        // The last response is only on the wire once it is flushed, so the
+        // connection ends on the read after that flush rather than on close
+        if matches!(flushed, Poll::Ready(Ok(()))) && self.written_after_close && !self.drained {
+            self.drained = true;
+            if let Some((_, budget)) = &self.budget {
+                budget.reader.wake();
+            }
+        }
+        flushed
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        let written = Pin::new(&mut self.inner).poll_write_vectored(cx, bufs);
+        self.record_write(written)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+}
+
+impl Drop for LimitedConnection {
+    fn drop(&mut self) {
+        if let Some((key, budget)) = &self.budget {
+            let mut connections = self.connections.lock().expect("connection registry poisoned");
+            // Only remove our own entry, the address pair may have been reused
+            if connections.get(key).is_some_and(|current| Arc::ptr_eq(current, budget)) {
+                connections.remove(key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_closes_after_last_allowed_request_finishes() {
+        let budget = ConnectionBudget::default();
+
+        assert!(budget.admit(2));
+        assert!(budget.admit(2));
+        assert!(!budget.admit(2));
+
+        budget.finish(2);
+        assert!(!budget.is_closed(), "a request is still in flight");
+        budget.finish(2);
+        assert!(budget.is_closed());
+    }
+
+    #[test]
+    fn test_disabled_limit() {
+        let layer = ConnectionLimitLayer::new(0);
+        assert_eq!(layer.limit(), None);
+        assert_eq!(ConnectionLimitLayer::new(3).limit(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_accept_tracks_connection_until_dropped() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).await.unwrap();
+        let (stream, _) = listener.accept().await.unwrap();
+
+        let layer = ConnectionLimitLayer::new(1);
+        let connection = layer.accept(stream);
+        assert_eq!(layer.tracked_connections(), 1);
+
+        let info = connection.connect_info();
+        assert_eq!(info.remote_addr(), client.local_addr().ok());
+
+        drop(connection);
+        assert_eq!(layer.tracked_connections(), 0);
+    }
+}
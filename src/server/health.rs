@@ -0,0 +1,192 @@
+//! # Readiness Checks
+//!
+//! This module aggregates the dependency checks behind the admin `/readyz`
+//! endpoint. A [`HealthChecker`] holds named checks, each an async function
+//! returning a [`CheckResult`]; running it produces a [`HealthReport`] that is
+//! healthy only when every check is, and keeps each check's detail so an
+//! unhealthy report names the culprit.
+//!
+//! The database is the first registered check, see
+//! [`HealthChecker::with_database`]. New dependencies register their own check
+//! next to it.
+
+use std::sync::Arc;
+
+use futures_util::future::BoxFuture;
+
+/// Outcome of a single readiness check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// Whether the dependency is usable.
+    pub healthy: bool,
+
+    /// A short human readable explanation.
+    pub detail: String,
+}
+
+impl CheckResult {
+    /// A passing check with an explanation.
+    pub fn healthy(detail: impl Into<String>) -> Self {
+        Self { healthy: true, detail: detail.into() }
+    }
+
+    /// A failing check with an explanation.
+    pub fn unhealthy(detail: impl Into<String>) -> Self {
+        Self { healthy: false, detail: detail.into() }
+    }
+}
+
+/// Aggregate outcome of every registered check, in registration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The name and result of each check.
+    pub checks: Vec<(String, CheckResult)>,
+}
+
+impl HealthReport {
+    /// Returns `true` if every check passed.
+    pub fn is_healthy(&self) -> bool {
+        self.checks.iter().all(|(_, result)| result.healthy)
+    }
+
+    /// Names of the checks that failed.
+    pub fn failing(&self) -> Vec<&str> {
+        self.checks
+            .iter()
+            .filter(|(_, result)| !result.healthy)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Render the report as plain text: `ready` or `not ready`, then one
+    /// `name: healthy|unhealthy (detail)` line per check.
+    pub fn render(&self) -> String {
+        let mut body = String::from(if self.is_healthy() { "ready\n" } else { "not ready\n" });
+        for (name, result) in &self.checks {
+            let status = if result.healthy { "healthy" } else { "unhealthy" };
+            body.push_str(&format!("{}: {} ({})\n", name, status, result.detail));
+        }
+        body
+    }
+}
+
+type Check = Arc<dyn Fn() -> BoxFuture<'static, CheckResult> + Send + Sync>;
+
+/// A set of named readiness checks.
+#[derive(Clone, Default)]
+pub struct HealthChecker {
+    checks: Vec<(String, Check)>,
+}
+
+impl std::fmt::Debug for HealthChecker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<_> = self.checks.iter().map(|(name, _)| name.as_str()).collect();
+        f.debug_struct("HealthChecker").field("checks", &names).finish()
+    }
+}
+
+impl HealthChecker {
+    /// Create a checker with no checks, which always reports healthy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a checker whose first check is the database, named `database`.
+    pub fn with_database(database_pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self::new().register("database", move || {
+            let database_pool = Arc::clone(&database_pool);
+            async move { database_check(&database_pool).await }
+        })
+    }
+
+    /// Add a named check, run after the checks registered before it.
+    pub fn register<F, Fut>(mut self, name: impl Into<String>, check: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CheckResult> + Send + 'static,
+    {
+        self.checks.push((name.into(), Arc::new(move || Box::pin(check()))));
+        self
+    }
+
+    /// Run every check concurrently and collect the results.
+    pub async fn check(&self) -> HealthReport {
+        let results = futures_util::future::join_all(self.checks.iter().map(|(_, check)| check())).await;
+        let checks = self
+            .checks
+            .iter()
+            .map(|(name, _)| name.clone())
+            .zip(results)
+            .collect();
+        HealthReport { checks }
+    }
+}
+
+/// Check that the database answers a trivial query within
+/// [`DATABASE_CHECK_TIMEOUT`](super::DATABASE_CHECK_TIMEOUT).
+pub async fn database_check(database_pool: &sqlx::SqlitePool) -> CheckResult {
+    if database_pool.is_closed() {
+        return CheckResult::unhealthy("connection pool is closed");
+    }
+
+    let query = sqlx::query("SELECT 1").execute(database_pool);
+    match tokio::time::timeout(super::DATABASE_CHECK_TIMEOUT, query).await {
+        Ok(Ok(_)) => CheckResult::healthy("query answered"),
+        Ok(Err(e)) => {
+            tracing::warn!("Database readiness check failed: {}", e);
+            CheckResult::unhealthy("query failed")
+        }
+        Err(_) => {
+            tracing::warn!("Database readiness check timed out");
+            CheckResult::unhealthy("query timed out")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_failing_check_makes_report_unhealthy() {
+        let checker = HealthChecker::new()
+            .register("cache", || async { CheckResult::healthy("warm") })
+            .register("bank-feed", || async { CheckResult::unhealthy("connection refused") });
+
+        let report = checker.check().await;
+        assert!(!report.is_healthy());
+        assert_eq!(report.failing(), vec!["bank-feed"]);
+        assert_eq!(report.checks[0], ("cache".to_string(), CheckResult::healthy("warm")));
+        assert_eq!(
+            report.render(),
+            "not ready\ncache: healthy (warm)\nbank-feed: unhealthy (connection refused)\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_passing_checks_make_report_healthy() {
+        let checker = HealthChecker::new()
+            .register("cache", || async { CheckResult::healthy("warm") })
+            .register("bank-feed", || async { CheckResult::healthy("connected") });
+
+        let report = checker.check().await;
+        assert!(report.is_healthy());
+        assert!(report.failing().is_empty());
+        assert!(report.render().starts_with("ready\n"));
+    }
+
+    #[tokio::test]
+    async fn test_database_check_follows_pool_state() {
+        let pool = Arc::new(sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap());
+        let checker = HealthChecker::with_database(Arc::clone(&pool));
+
+        let report = checker.check().await;
+        assert!(report.is_healthy());
+        assert_eq!(report.checks[0].0, "database");
+
+        pool.close().await;
+        let report = checker.check().await;
+        assert_eq!(report.failing(), vec!["database"]);
+        assert_eq!(report.checks[0].1.detail, "connection pool is closed");
+    }
+}
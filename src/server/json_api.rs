@@ -0,0 +1,429 @@
+//! # JSON API
+//!
+//! This module provides a JSON over HTTP view of category CRUD for tools that
+//! do not speak gRPC, such as scripts and browser dashboards. It is merged
+//! into the admin server when `ServerConfig.enable_json_api` is set and serves:
+//!
+//! - `POST /api/categories`: create a category, `201` with the created category
+//! - `GET /api/categories`: list categories, paged with `offset`, `limit`
+//!   (default [`DEFAULT_LIST_LIMIT`]) and `page_token` query parameters
+//! - `GET /api/categories/{id}`: get a category
+//! - `PATCH /api/categories/{id}`: update the fields present in the body
+//! - `DELETE /api/categories/{id}`: delete a category, `204` on success
+//!
+//! Every endpoint calls the same [`services::CategoriesService`] handler as
+//! the gRPC method, so validation, events and localized timestamps behave the
+//! same. Categories are rendered with the proto field names, `category_type`
+//! as its lowercase name (e.g. `expense`) and timestamps as RFC 3339. Errors
+//! come back as `{"code": ..., "message": ...}`, plus the validation
+//! `reason` code when there is one, with the gRPC status code mapped to the
+//! closest HTTP status.
+//!
+//! When `ServerConfig.auth_token` is set, every endpoint requires the same
+//! `Authorization: Bearer <token>` header as the gRPC services, checked by
+//! the same [`server::AuthInterceptor`].
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, SecondsFormat};
+use tonic_types::StatusExt;
+
+use crate::rpc::CategoriesService as _;
+use crate::{domain, rpc, server, services};
+
+/// A category as sent and received by the JSON API.
+///
+/// `created_on`, `updated_on` and their localized renderings are filled in
+/// responses and ignored in requests.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CategoryJson {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub url_slug: Option<String>,
+    pub category_type: String,
+    pub color: Option<String>,
+    pub icon: Option<String>,
+    pub is_active: bool,
+    pub display_order: i64,
+    pub created_on: Option<String>,
+    pub updated_on: Option<String>,
+    pub created_on_local: Option<String>,
+    pub updated_on_local: Option<String>,
+}
+
+impl Default for CategoryJson {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            code: String::new(),
+            name: String::new(),
+            description: None,
+            url_slug: None,
+            category_type: String::new(),
+            color: None,
+            icon: None,
+            // New categories are active unless the body says otherwise
+            is_active: true,
+            display_order: 0,
+            created_on: None,
+            updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
+        }
+    }
+}
+
+impl From<rpc::Category> for CategoryJson {
+    fn from(category: rpc::Category) -> Self {
+        let category_type = domain::CategoryTypes::from_rpc_i32(category.category_type)
+            .map(|category_type| category_type.as_str().to_string())
+            .unwrap_or_default();
+
+        Self {
+            id: category.id,
+            code: category.code,
+            name: category.name,
+            description: category.description,
+            url_slug: category.url_slug,
+            category_type,
+            color: category.color,
+            icon: category.icon,
+            is_active: category.is_active,
+            display_order: category.display_order,
+            created_on: category.created_on.as_ref().and_then(timestamp_to_rfc3339),
+            updated_on: category.updated_on.as_ref().and_then(timestamp_to_rfc3339),
+            created_on_local: category.created_on_local,
+            updated_on_local: category.updated_on_local,
+        }
+    }
+}
+
+impl TryFrom<CategoryJson> for rpc::Category {
+    type Error = tonic::Status;
+
+    /// Convert a request body to a proto category. An empty `category_type`
+    /// is left unspecified, for updates that do not change it.
+    fn try_from(category: CategoryJson) -> Result<Self, Self::Error> {
+        let category_type = if category.category_type.is_empty() {
+            rpc::CategoryTypes::Unspecified as i32
+        } else {
+            category
+                .category_type
+                .parse::<domain::CategoryTypes>()
+                .map_err(|e| tonic::Status::invalid_argument(e.to_string()))?
+                .to_rpc_i32()
+        };
+
+        Ok(Self {
+            id: category.id,
+            code: category.code,
+            name: category.name,
+            description: category.description,
+            url_slug: category.url_slug,
+            category_type,
+            color: category.color,
+            icon: category.icon,
+            is_active: category.is_active,
+            display_order: category.display_order,
+            ..Default::default()
+        })
+    }
+}
+
+/// A page of categories returned by `GET /api/categories`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CategoriesListJson {
+    pub categories: Vec<CategoryJson>,
+    pub total_count: i32,
+    pub next_page_token: Option<String>,
+}
+
+/// Page size of `GET /api/categories` when the query does not set `limit`.
+pub const DEFAULT_LIST_LIMIT: i32 = 100;
+
+/// Query parameters of `GET /api/categories`.
+#[derive(Debug, serde::Deserialize)]
+#[serde(default)]
+struct ListQuery {
+    offset: i32,
+    limit: i32,
+    page_token: Option<String>,
+}
+
+impl Default for ListQuery {
+    fn default() -> Self {
+        Self { offset: 0, limit: DEFAULT_LIST_LIMIT, page_token: None }
+    }
+}
+
+/// Error body returned for a failed request.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ErrorJson {
+    /// The gRPC status code name, e.g. `NotFound`.
+    pub code: String,
+    pub message: String,
+
+    /// Stable reason code of a broken validation rule, e.g. `SLUG_EMPTY`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Map a gRPC status code to the closest HTTP status.
+pub fn http_status(code: tonic::Code) -> StatusCode {
+    match code {
+        tonic::Code::Ok => StatusCode::OK,
+        tonic::Code::InvalidArgument | tonic::Code::OutOfRange | tonic::Code::FailedPrecondition => {
+            StatusCode::BAD_REQUEST
+        }
+        tonic::Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+        tonic::Code::PermissionDenied => StatusCode::FORBIDDEN,
+        tonic::Code::NotFound => StatusCode::NOT_FOUND,
+        tonic::Code::AlreadyExists | tonic::Code::Aborted => StatusCode::CONFLICT,
+        tonic::Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+        tonic::Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+        tonic::Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        tonic::Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// A gRPC status returned from a JSON endpoint.
+struct JsonApiError(tonic::Status);
+
+impl From<tonic::Status> for JsonApiError {
+    fn from(status: tonic::Status) -> Self {
+        Self(status)
+    }
+}
+
+impl IntoResponse for JsonApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorJson {
+            code: format!("{:?}", self.0.code()),
+            message: self.0.message().to_string(),
+            reason: self.0.get_error_details().error_info().map(|info| info.reason.clone()),
+        };
+        (http_status(self.0.code()), Json(body)).into_response()
+    }
+}
+
+type JsonApiResult<T> = Result<T, JsonApiError>;
+
+/// State of the JSON endpoints: the categories service they call and the
+/// bearer token check in front of it.
+#[derive(Clone)]
+pub struct JsonApiState {
+    categories: Arc<services::CategoriesService>,
+    auth: server::AuthInterceptor,
+}
+
+impl std::fmt::Debug for JsonApiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonApiState").finish_non_exhaustive()
+    }
+}
+
+impl JsonApiState {
+    /// Serve the JSON API through `categories`.
+    pub fn new(categories: services::CategoriesService) -> Self {
+        Self { categories: Arc::new(categories), auth: server::AuthInterceptor::default() }
+    }
+
+    /// Require the bearer token checked by `auth` on every endpoint.
+    pub fn with_auth(mut self, auth: server::AuthInterceptor) -> Self {
+        self.auth = auth;
+        self
+    }
+}
+
+/// Build the axum router serving the JSON API.
+pub fn json_api_router(state: JsonApiState) -> axum::Router {
+    axum::Router::new()
+        .route("/api/categories", axum::routing::get(list_categories).post(create_category))
+        .route(
+            "/api/categories/{id}",
+            axum::routing::get(get_category).patch(update_category).delete(delete_category),
+        )
+        .route_layer(axum::middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+/// Reject requests without the configured bearer token, as the gRPC services do.
+async fn require_token(
+    State(state): State<JsonApiState>,
+    request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> Response {
+    let metadata = tonic::metadata::MetadataMap::from_headers(request.headers().clone());
+    let grpc_request = tonic::Request::from_parts(metadata, tonic::Extensions::default(), ());
+
+    match tonic::service::Interceptor::call(&mut state.auth.clone(), grpc_request) {
+        Ok(_) => next.run(request).await,
+        Err(status) => JsonApiError(status).into_response(),
+    }
+}
+
+async fn create_category(State(state): State<JsonApiState>, body: Bytes) -> JsonApiResult<impl IntoResponse> {
+    let category: CategoryJson = parse_body(&body)?;
+    let request = rpc::CategoryCreateRequest { category: Some(category.try_into()?) };
+
+    let response = state.categories.category_create(tonic::Request::new(request)).await?;
+    Ok((StatusCode::CREATED, Json(category_json(response.into_inner().category)?)))
+}
+
+async fn list_categories(
+    State(state): State<JsonApiState>,
+    Query(query): Query<ListQuery>,
+) -> JsonApiResult<Json<CategoriesListJson>> {
+    let request = rpc::CategoriesListRequest {
+        offset: query.offset,
+        limit: query.limit,
+        page_token: query.page_token,
+        ..Default::default()
+    };
+
+    let response = state.categories.categories_list(tonic::Request::new(request)).await?.into_inner();
+    Ok(Json(CategoriesListJson {
+        categories: response.categories.into_iter().map(CategoryJson::from).collect(),
+        total_count: response.total_count,
+        next_page_token: response.next_page_token,
+    }))
+}
+
+async fn get_category(State(state): State<JsonApiState>, Path(id): Path<String>) -> JsonApiResult<Json<CategoryJson>> {
+    let request = rpc::CategoryGetRequest { id, read_mask: None };
+
+    let response = state.categories.category_get(tonic::Request::new(request)).await?;
+    Ok(Json(category_json(response.into_inner().category)?))
+}
+
+/// Update the fields present in the body; the update mask is the body's keys.
+async fn update_category(
+    State(state): State<JsonApiState>,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> JsonApiResult<Json<CategoryJson>> {
+    let fields: serde_json::Map<String, serde_json::Value> = parse_body(&body)?;
+    let paths = fields.keys().filter(|field| field.as_str() != "id").cloned().collect();
+    let category: CategoryJson = serde_json::from_value(serde_json::Value::Object(fields))
+        .map_err(|e| tonic::Status::invalid_argument(format!("Invalid JSON body: {}", e)))?;
+
+    let request = rpc::CategoryUpdateRequest {
+        id,
+        category: Some(category.try_into()?),
+        update_mask: Some(prost_types::FieldMask { paths }),
+    };
+
+    let response = state.categories.category_update(tonic::Request::new(request)).await?;
+    Ok(Json(category_json(response.into_inner().category)?))
+}
+
+async fn delete_category(State(state): State<JsonApiState>, Path(id): Path<String>) -> JsonApiResult<StatusCode> {
+    let request = rpc::CategoryDeleteRequest { id };
+
+    let response = state.categories.category_delete(tonic::Request::new(request)).await?;
+    if response.into_inner().rows_deleted == 0 {
+        return Err(tonic::Status::not_found("Category not found").into());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn parse_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, tonic::Status> {
+    serde_json::from_slice(body).map_err(|e| tonic::Status::invalid_argument(format!("Invalid JSON body: {}", e)))
+}
+
+fn category_json(category: Option<rpc::Category>) -> Result<CategoryJson, tonic::Status> {
+    category
+        .map(CategoryJson::from)
+        .ok_or_else(|| tonic::Status::internal("Response did not include a category"))
+}
+
+fn timestamp_to_rfc3339(timestamp: &rpc::Timestamp) -> Option<String> {
+    let utc = DateTime::from_timestamp(timestamp.seconds, u32::try_from(timestamp.nanos).ok()?)?;
+    Some(utc.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    async fn request(pool: sqlx::SqlitePool, method: &str, path: &str, body: &str) -> (StatusCode, String) {
+        let service = services::CategoriesService::new(Arc::new(pool), Arc::new(crate::LedgerConfig::default()));
+        let request = axum::http::Request::builder()
+            .method(method)
+            .uri(path)
+            .body(axum::body::Body::from(body.to_string()))
+            .unwrap();
+        let response = json_api_router(JsonApiState::new(service)).oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[test]
+    fn test_category_json_round_trips_rpc_category() {
+        let category: rpc::Category = crate::database::Categories::mock().into();
+        let json = CategoryJson::from(category.clone());
+
+        assert_eq!(json.category_type.parse::<domain::CategoryTypes>().unwrap().to_rpc_i32(), category.category_type);
+        assert!(json.created_on.as_deref().unwrap().ends_with('Z'));
+
+        let back = rpc::Category::try_from(json).unwrap();
+        assert_eq!(back.id, category.id);
+        assert_eq!(back.code, category.code);
+        assert_eq!(back.category_type, category.category_type);
+        assert_eq!(back.created_on, None);
+    }
+
+    #[test]
+    fn test_category_json_rejects_unknown_type() {
+        let json = CategoryJson { category_type: "savings".to_string(), ..Default::default() };
+        let status = rpc::Category::try_from(json).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_http_status_mapping() {
+        assert_eq!(http_status(tonic::Code::InvalidArgument), StatusCode::BAD_REQUEST);
+        assert_eq!(http_status(tonic::Code::NotFound), StatusCode::NOT_FOUND);
+        assert_eq!(http_status(tonic::Code::AlreadyExists), StatusCode::CONFLICT);
+        assert_eq!(http_status(tonic::Code::Unavailable), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(http_status(tonic::Code::Internal), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[sqlx::test]
+    async fn test_invalid_body_is_bad_request(pool: sqlx::SqlitePool) {
+        let (status, body) = request(pool, "POST", "/api/categories", "{not json").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let error: ErrorJson = serde_json::from_str(&body).unwrap();
+        assert_eq!(error.code, "InvalidArgument");
+        assert!(error.message.starts_with("Invalid JSON body"), "{}", error.message);
+    }
+
+    #[sqlx::test]
+    async fn test_validation_error_includes_reason(pool: sqlx::SqlitePool) {
+        let body = r##"{"code": "BAD.COLOR", "name": "Bad color", "category_type": "expense", "color": "#GG0000"}"##;
+        let (status, body) = request(pool, "POST", "/api/categories", body).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let error: ErrorJson = serde_json::from_str(&body).unwrap();
+        assert_eq!(error.reason.as_deref(), Some("COLOR_BAD_FORMAT"));
+    }
+
+    #[sqlx::test]
+    async fn test_get_missing_category_is_not_found(pool: sqlx::SqlitePool) {
+        let path = format!("/api/categories/{}", domain::RowID::new());
+        let (status, body) = request(pool, "GET", &path, "").await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{}", body);
+    }
+}
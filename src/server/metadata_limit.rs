@@ -0,0 +1,113 @@
+//! # Request Metadata Limits
+//!
+//! This module caps how much metadata a request may carry, so a client cannot
+//! send huge metadata maps to waste memory and CPU in every layer and handler
+//! that inspects them.
+//!
+//! [`MetadataLimitInterceptor`] counts a request's metadata entries and the
+//! total bytes of their names and values, and rejects requests over either
+//! `ServerConfig.max_metadata_entries` or `ServerConfig.max_metadata_bytes`
+//! with `tonic::Code::InvalidArgument`. It runs before any service interceptor
+//! or handler.
+//!
+//! The HTTP/2 transport bounds the header block it decodes; these limits are
+//! the tighter, gRPC-level policy on top of it.
+
+use crate::config::ServerConfig;
+
+/// Interceptor rejecting requests whose metadata is over the configured limits.
+///
+/// A limit of `0` disables that check, so the interceptor can be installed
+/// unconditionally.
+#[derive(Debug, Clone, Copy)]
+pub struct MetadataLimitInterceptor {
+    max_entries: usize,
+    max_bytes: usize,
+}
+
+impl MetadataLimitInterceptor {
+    /// Create an interceptor allowing at most `max_entries` metadata entries and
+    /// `max_bytes` bytes of metadata names and values per request.
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        Self { max_entries, max_bytes }
+    }
+
+    /// Create an interceptor from `max_metadata_entries` and `max_metadata_bytes`.
+    pub fn from_config(server_config: &ServerConfig) -> Self {
+        Self::new(server_config.max_metadata_entries, server_config.max_metadata_bytes)
+    }
+}
+
+impl tonic::service::Interceptor for MetadataLimitInterceptor {
+    fn call(&mut self, request: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        let headers = request.metadata().as_ref();
+
+        let entries = headers.len();
+        if self.max_entries > 0 && entries > self.max_entries {
+            tracing::warn!("Rejecting request with {} metadata entries from {:?}", entries, request.remote_addr());
+            return Err(tonic::Status::invalid_argument(format!(
+                "Request metadata has {} entries, the limit is {}",
+                entries, self.max_entries
+            )));
+        }
+
+        let bytes: usize = headers.iter().map(|(name, value)| name.as_str().len() + value.len()).sum();
+        if self.max_bytes > 0 && bytes > self.max_bytes {
+            tracing::warn!("Rejecting request with {} bytes of metadata from {:?}", bytes, request.remote_addr());
+            return Err(tonic::Status::invalid_argument(format!(
+                "Request metadata is {} bytes, the limit is {}",
+                bytes, self.max_bytes
+            )));
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::service::Interceptor;
+
+    fn request(entries: usize, value_len: usize) -> tonic::Request<()> {
+        let mut request = tonic::Request::new(());
+        let value: tonic::metadata::MetadataValue<_> = "x".repeat(value_len).parse().unwrap();
+        for i in 0..entries {
+            let name: tonic::metadata::MetadataKey<_> = format!("x-entry-{:02}", i).parse().unwrap();
+            request.metadata_mut().insert(name, value.clone());
+        }
+        request
+    }
+
+    #[test]
+    fn test_request_within_limits_passes() {
+        let mut interceptor = MetadataLimitInterceptor::new(4, 100);
+        assert!(interceptor.call(request(4, 10)).is_ok());
+    }
+
+    #[test]
+    fn test_too_many_entries_are_rejected() {
+        let mut interceptor = MetadataLimitInterceptor::new(4, 0);
+
+        let status = interceptor.call(request(5, 1)).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Request metadata has 5 entries, the limit is 4");
+    }
+
+    #[test]
+    fn test_oversized_metadata_is_rejected() {
+        // Each entry is 10 name bytes ("x-entry-00") plus its value
+        let mut interceptor = MetadataLimitInterceptor::new(0, 100);
+        assert!(interceptor.call(request(5, 9)).is_ok());
+
+        let status = interceptor.call(request(5, 11)).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Request metadata is 105 bytes, the limit is 100");
+    }
+
+    #[test]
+    fn test_zero_limits_disable_checks() {
+        let mut interceptor = MetadataLimitInterceptor::new(0, 0);
+        assert!(interceptor.call(request(200, 100)).is_ok());
+    }
+}
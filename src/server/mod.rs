@@ -15,7 +15,12 @@
 //!
 //! - **`Router`**: Composes and manages gRPC services (reflection, health, utilities)
 //! - **`TonicServer`**: High-level server abstraction combining Router with network binding
-//! - **TLS Support**: Secure communication capabilities (future extension)
+//! - **TLS Support**: Optional TLS, with plaintext requests rejected when `require_tls` is set
+//! - **Authentication**: Optional static bearer token on the application services
+//! - **Trace Context**: Request spans join the caller's W3C `traceparent` trace
+//! - **Metadata Limits**: Requests with oversized metadata are rejected before any handler
+//! - **Connection Limits**: Connections are closed after a configured number of requests
+//! - **JSON API**: Optional category CRUD as JSON on the admin port
 //!
 //! ## Key Features
 //!
@@ -71,7 +76,8 @@
 //!
 //! ## Security Considerations
 //!
-//! - TLS support is available through the `tls` module (currently internal)
+//! - TLS is configured through `ServerConfig`; set `require_tls` to refuse to start
+//!   without it and to reject plaintext requests with `PermissionDenied`
 //! - Health endpoints should be properly secured in production environments
 //! - Consider rate limiting and authentication for public-facing services
 //!
@@ -81,19 +87,98 @@
 //! - Connection pooling and reuse is handled automatically by the underlying Tonic framework
 //! - Health checks are lightweight and designed for frequent polling by load balancers
 
+mod admin;
+/// Plain HTTP admin server for health probes and metrics scraping.
+///
+/// Serves `/healthz`, `/readyz` and `/metrics` on `ServerConfig.admin_port`,
+/// sharing state with the gRPC [`Router`].
+pub use admin::{admin_router, AdminServer, AdminState, DATABASE_CHECK_TIMEOUT};
+
+mod auth;
+/// Static bearer token check on the application services.
+///
+/// [`AuthInterceptor`] rejects requests without `ServerConfig.auth_token` in
+/// their `authorization` metadata with `Unauthenticated`.
+pub use auth::{AuthInterceptor, AUTHORIZATION_HEADER, INVALID_TOKEN_MESSAGE, MISSING_TOKEN_MESSAGE};
+
+mod connection_limit;
+/// Closes connections after `ServerConfig.max_requests_per_connection` requests.
+///
+/// [`ConnectionLimitLayer`] rejects requests past the limit with `Unavailable`
+/// and ends the connection once its last allowed request finishes.
+pub use connection_limit::{ConnectionLimit, ConnectionLimitLayer, LimitedConnection, CONNECTION_LIMIT_MESSAGE};
+
+mod health;
+/// Aggregated readiness checks behind the admin `/readyz` endpoint.
+///
+/// [`HealthChecker`] runs named checks, the database first, and reports the
+/// overall status with per-check detail.
+pub use health::{database_check, CheckResult, HealthChecker, HealthReport};
+
+mod json_api;
+/// Category CRUD as JSON over HTTP, served by the admin server.
+///
+/// Enabled by `ServerConfig.enable_json_api`, [`json_api_router`] maps
+/// `/api/categories` requests onto the gRPC category handlers.
+pub use json_api::{http_status, json_api_router, CategoriesListJson, CategoryJson, ErrorJson, JsonApiState, DEFAULT_LIST_LIMIT};
+
+mod metadata_limit;
+/// Limits on the metadata a request may carry.
+///
+/// [`MetadataLimitInterceptor`] rejects requests over
+/// `ServerConfig.max_metadata_entries` or `max_metadata_bytes` with
+/// `InvalidArgument`.
+pub use metadata_limit::MetadataLimitInterceptor;
+
+mod request_log;
+/// Per-RPC logging of method, peer, status code and duration.
+///
+/// [`RequestLogLayer`] records the values on a `grpc_call` span and logs
+/// once the response finishes.
+pub use request_log::{RequestLog, RequestLogLayer};
+
 mod router;
 /// Service router for composing and managing gRPC services.
 ///
 /// The Router handles the composition of multiple gRPC services including
 /// reflection, health checking, and application-specific utilities.
 /// It provides a clean abstraction for service management and health reporting.
-pub use router::Router;
+pub use router::{Router, RouterBuilder, TonicRouter};
+
+mod stream_limit;
+/// Tower layer capping concurrent streaming RPCs.
+///
+/// Streams past `ServerConfig.max_concurrent_streams` are rejected with
+/// `ResourceExhausted`; unary RPCs are not counted.
+pub use stream_limit::{StreamLimit, StreamLimitLayer, StreamingMethods, STREAM_LIMIT_MESSAGE};
+
+mod timeout;
+/// Tower layer failing slow requests with `DeadlineExceeded`.
+///
+/// [`MethodTimeoutLayer`] applies `ServerConfig.request_timeout_secs`, or the
+/// method's override from `ServerConfig.method_timeouts`.
+pub use timeout::{MethodTimeout, MethodTimeoutLayer};
 
 mod tls;
-// TLS configuration and utilities (internal module).
-//
-// This module provides TLS/SSL support for secure gRPC communications.
-// Currently internal but may be exposed in future versions.
+/// TLS configuration and enforcement.
+///
+/// [`server_tls_config`] loads the configured certificate and key, and
+/// [`RequireTlsInterceptor`] rejects plaintext requests when TLS is required.
+pub use tls::{server_tls_config, RequireTlsInterceptor, TLS_REQUIRED_MESSAGE};
+
+mod trace_context;
+/// W3C Trace Context propagation.
+///
+/// [`TraceContextLayer`] links each request span to the caller's
+/// `traceparent`, and [`TraceContext::inject`] propagates it to outgoing calls.
+pub use trace_context::{TraceContext, TraceContextLayer, TraceContextService, TRACEPARENT_HEADER, TRACESTATE_HEADER};
+
+mod transport;
+/// HTTP/2 window sizes and keepalive timeouts for accepted connections.
+///
+/// [`TransportSettings`] validates the `ServerConfig` values and applies them
+/// to the tonic server builder.
+pub use transport::{TransportSettings, MAX_HTTP2_WINDOW_SIZE};
 
 mod tonic;
 /// High-level Tonic gRPC server abstraction.
@@ -0,0 +1,249 @@
+//! # RPC Request Logging
+//!
+//! This module provides [`RequestLogLayer`], which logs one `info` event per
+//! RPC with the full method path, the peer address, the resulting
+//! `tonic::Code` and how long the call took. The values are recorded on a
+//! `grpc_call` span, so the subscriber installed by [`crate::telemetry::init`]
+//! prints them as structured fields alongside any events the handler emits.
+//!
+//! The status of a successful call, and of a stream failing part way, is only
+//! known once its trailers are sent, so the event is emitted when the response
+//! body finishes. A response body dropped before then, such as a stream the
+//! client abandoned, is logged as `Cancelled`.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use futures_util::future::BoxFuture;
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tracing::Instrument;
+
+/// Tower layer logging every RPC's method, peer, status code and duration.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestLogLayer;
+
+impl<S> tower::Layer<S> for RequestLogLayer {
+    type Service = RequestLog<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestLog { inner }
+    }
+}
+
+/// Service produced by [`RequestLogLayer`].
+#[derive(Debug, Clone)]
+pub struct RequestLog<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for RequestLog<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let span = tracing::info_span!(
+            "grpc_call",
+            method = %request.uri().path(),
+            peer = tracing::field::Empty,
+            code = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        );
+        if let Some(peer) = peer_addr(&request) {
+            span.record("peer", tracing::field::display(peer));
+        }
+
+        let call = CallLog { span: span.clone(), started: Instant::now() };
+        let future = span.in_scope(|| inner.call(request));
+        Box::pin(
+            async move {
+                let response = match future.await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        call.finish(tonic::Code::Unknown);
+                        return Err(e);
+                    }
+                };
+
+                // Errors returned by the handler arrive as a trailers-only
+                // response, with the status in the headers
+                if let Some(status) = tonic::Status::from_header_map(response.headers()) {
+                    call.finish(status.code());
+                    return Ok(response.map(tonic::body::Body::new));
+                }
+
+                Ok(response.map(|body| {
+                    let body = tonic::body::Body::new(body);
+                    tonic::body::Body::new(RequestLogBody { body, call: Some(call) })
+                }))
+            }
+            .instrument(span),
+        )
+    }
+}
+
+impl<S: tonic::server::NamedService> tonic::server::NamedService for RequestLog<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+/// The peer address of the connection a request arrived on, if known.
+fn peer_addr<B>(request: &http::Request<B>) -> Option<std::net::SocketAddr> {
+    let extensions = request.extensions();
+    extensions
+        .get::<TcpConnectInfo>()
+        .or_else(|| extensions.get::<TlsConnectInfo<TcpConnectInfo>>().map(|tls| tls.get_ref()))?
+        .remote_addr()
+}
+
+/// The span and start time of an RPC still to be logged.
+struct CallLog {
+    span: tracing::Span,
+    started: Instant,
+}
+
+impl CallLog {
+    /// Record the status code and duration on the span and log the call.
+    fn finish(self, code: tonic::Code) {
+        let elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+        self.span.record("code", tracing::field::debug(code));
+        self.span.record("elapsed_ms", elapsed_ms);
+        self.span.in_scope(|| tracing::info!("Finished RPC"));
+    }
+}
+
+/// Response body that logs the call once its trailers are sent.
+struct RequestLogBody {
+    body: tonic::body::Body,
+    call: Option<CallLog>,
+}
+
+impl RequestLogBody {
+    fn finish(&mut self, code: tonic::Code) {
+        if let Some(call) = self.call.take() {
+            call.finish(code);
+        }
+    }
+}
+
+impl http_body::Body for RequestLogBody {
+    type Data = bytes::Bytes;
+    type Error = tonic::Status;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        let frame = std::task::ready!(Pin::new(&mut self.body).poll_frame(cx));
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(trailers) = frame.trailers_ref() {
+                    let code = tonic::Status::from_header_map(trailers).map_or(tonic::Code::Unknown, |status| status.code());
+                    self.finish(code);
+                }
+            }
+            Some(Err(status)) => self.finish(status.code()),
+            None => self.finish(tonic::Code::Unknown),
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.body.size_hint()
+    }
+}
+
+impl Drop for RequestLogBody {
+    fn drop(&mut self) {
+        self.finish(tonic::Code::Cancelled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use tower::{Layer, ServiceExt};
+    use tracing_test::traced_test;
+
+    const PING_PATH: &str = "/personal_ledger.UtilitiesService/Ping";
+
+    fn utilities_server() -> crate::rpc::UtilitiesServiceServer<crate::services::UtilitiesService> {
+        let ledger_config = std::sync::Arc::new(crate::LedgerConfig::default());
+        crate::rpc::UtilitiesServiceServer::new(crate::services::UtilitiesService::new(ledger_config))
+    }
+
+    /// A gRPC request for `path` carrying one empty message.
+    fn grpc_request(path: &str) -> http::Request<tonic::body::Body> {
+        let frame = bytes::Bytes::from_static(&[0, 0, 0, 0, 0]);
+        http::Request::builder()
+            .method(http::Method::POST)
+            .uri(path)
+            .header(http::header::CONTENT_TYPE, "application/grpc")
+            .header("te", "trailers")
+            .body(tonic::body::Body::new(http_body_util::Full::new(frame)))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_ping_is_logged_with_method_and_code() {
+        let service = RequestLogLayer.layer(utilities_server());
+
+        let response = service.oneshot(grpc_request(PING_PATH)).await.unwrap();
+        let trailers = response.into_body().collect().await.unwrap().trailers().cloned().unwrap();
+        assert_eq!(tonic::Status::from_header_map(&trailers).unwrap().code(), tonic::Code::Ok);
+
+        assert!(logs_contain("grpc_call{"));
+        assert!(logs_contain(&format!("method={}", PING_PATH)));
+        assert!(logs_contain("code=Ok"));
+        assert!(logs_contain("elapsed_ms="));
+        assert!(logs_contain("Finished RPC"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_unknown_method_is_logged_with_its_code() {
+        let service = RequestLogLayer.layer(utilities_server());
+
+        let response = service.oneshot(grpc_request("/personal_ledger.UtilitiesService/Missing")).await.unwrap();
+        drop(response);
+
+        assert!(logs_contain("method=/personal_ledger.UtilitiesService/Missing"));
+        assert!(logs_contain("code=Unimplemented"));
+        assert!(!logs_contain("code=Cancelled"));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_dropped_response_is_logged_as_cancelled() {
+        let service = RequestLogLayer.layer(utilities_server());
+
+        let response = service.oneshot(grpc_request(PING_PATH)).await.unwrap();
+        drop(response);
+
+        assert!(logs_contain("code=Cancelled"));
+    }
+}
@@ -5,6 +5,10 @@
 //! The router is responsible for:
 //! - Initializing and registering all gRPC services (reflection, health, utilities, categories)
 //! - Managing service health status via a health reporter
+//! - Capping concurrent streaming RPCs across the application services
+//! - Closing connections after `ServerConfig.max_requests_per_connection` requests
+//! - Applying the TLS configuration and rejecting plaintext requests when TLS is required
+//! - Requiring the configured bearer token on the utilities and categories services
 //! - Providing access to the underlying tonic router for advanced composition
 //!
 //! ## Usage
@@ -28,6 +32,16 @@
 //! server.run().await?;
 //! ```
 //!
+//! Use [`Router::builder`] to turn off reflection, or to serve extra services
+//! behind the same layers:
+//!
+//! ```rust,no_run
+//! let router = Router::builder(pool.into_pool()?, config)
+//!     .with_reflection(false)
+//!     .build()
+//!     .await?;
+//! ```
+//!
 //! For advanced scenarios, access the underlying tonic router:
 //!
 //! ```rust,no_run
@@ -39,7 +53,10 @@
 //!
 //! The router automatically configures these services:
 //! - **Health Service**: gRPC health checking
-//! - **Reflection Service**: gRPC server reflection for debugging
+//! - **Reflection Service**: gRPC server reflection for debugging, unless
+//!   turned off with [`RouterBuilder::with_reflection`]. When
+//!   `ServerConfig.reflection_optional` is set, the router starts without it
+//!   if the descriptor set fails to build
 //! - **Utilities Service**: General utility endpoints
 //! - **Categories Service**: Category management endpoints
 //!
@@ -49,14 +66,58 @@
 
 use tonic::transport::Server;
 use tonic_reflection::server as TonicRefelectionServer;
-use crate::{rpc, services, LedgerConfig, LedgerResult};
+use tower::Layer;
+use crate::{rpc, server, services, telemetry, LedgerConfig, LedgerResult};
+
+/// Tonic router with the server-wide [`server::TraceContextLayer`],
+/// [`server::RequestLogLayer`], [`server::ConnectionLimitLayer`], [`server::MetadataLimitInterceptor`], [`server::RequireTlsInterceptor`],
+/// [`telemetry::MetricsLayer`] and [`server::MethodTimeoutLayer`] layers applied.
+pub type TonicRouter = tonic::transport::server::Router<
+    tower::layer::util::Stack<
+        server::MethodTimeoutLayer,
+        tower::layer::util::Stack<
+            telemetry::MetricsLayer,
+            tower::layer::util::Stack<
+                tonic::service::InterceptorLayer<server::RequireTlsInterceptor>,
+                tower::layer::util::Stack<
+                    tonic::service::InterceptorLayer<server::MetadataLimitInterceptor>,
+                    tower::layer::util::Stack<
+                        server::ConnectionLimitLayer,
+                        tower::layer::util::Stack<
+                            server::RequestLogLayer,
+                            tower::layer::util::Stack<server::TraceContextLayer, tower::layer::util::Identity>,
+                        >,
+                    >,
+                >,
+            >,
+        >,
+    >,
+>;
 
 pub struct Router {
     /// The underlying tonic router that manages gRPC services.
-    router: tonic::transport::server::Router,
+    router: TonicRouter,
 
     /// Health reporter for managing service health status.
     health_reporter: tonic_health::server::HealthReporter,
+
+    /// Shared database pool, kept so the server can close it on shutdown.
+    database_pool: std::sync::Arc<sqlx::SqlitePool>,
+
+    /// Request counters, shared with the admin `/metrics` endpoint.
+    metrics: std::sync::Arc<telemetry::Metrics>,
+
+    /// Streaming cap shared by the services, kept to report free slots.
+    stream_limit: server::StreamLimitLayer,
+
+    /// Per-connection request limit, shared with the server accepting connections.
+    connection_limit: server::ConnectionLimitLayer,
+
+    /// Category lookup cache, shared by every writer of categories.
+    category_cache: crate::database::CategoryCache,
+
+    /// JSON API state for the admin server, when `enable_json_api` is set.
+    json_api: Option<server::JsonApiState>,
 }
 
 impl Router {
@@ -87,7 +148,8 @@ impl Router {
     /// # Errors
     ///
     /// This method will return an error if:
-    /// - The reflection service cannot be configured
+    /// - The reflection service cannot be configured and
+    ///   `ServerConfig.reflection_optional` is not set
     /// - Database connections cannot be established
     /// - Service registration fails
     ///
@@ -103,45 +165,34 @@ impl Router {
     /// let router = Router::new(pool.into_pool()?, config).await?;
     /// ```
     pub async fn new(database_pool: sqlx::SqlitePool, ledger_config: LedgerConfig) -> LedgerResult<Self> {
-        let database_pool_arc = std::sync::Arc::new(database_pool);
-        let ledger_config_arc = std::sync::Arc::new(ledger_config);
-        
-        // Build reflections service
-        let reflections_service = TonicRefelectionServer::Builder::configure()
-            .register_encoded_file_descriptor_set(rpc::FILE_DESCRIPTOR_SET)
-            .build_v1()?;
-
-        // Build utilities service/server
-        let utility_service = services::UtilitiesService::default();
-        
-        let utility_server = rpc::UtilitiesServiceServer::new(utility_service);
-
-        // Build Category service/server
-        let categories_service = services::CategoriesService::new(database_pool_arc, ledger_config_arc);
-        
-        let categories_server = rpc::CategoriesServiceServer::new(categories_service);
-
-        // Build health service
-        let (health_reporter, health_service) = tonic_health::server::health_reporter();
-        health_reporter
-            .set_serving::<rpc::UtilitiesServiceServer<services::UtilitiesService>>()
-            .await;
-        health_reporter
-            .set_serving::<rpc::CategoriesServiceServer<services::CategoriesService>>()
-            .await;
-
-        // Build router
-        let router = Server::builder()
-            .add_service(health_service)
-            .add_service(reflections_service)
-            .add_service(utility_server)
-            .add_service(categories_server);
+        Self::builder(database_pool, ledger_config).build().await
+    }
 
-        tracing::info!("Router initialised successfully with all services");
-        Ok(Router {
-            router,
-            health_reporter,
-        })
+    /// Start building a router, with reflection enabled and no extra services.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::{database, LedgerConfig, server::Router};
+    ///
+    /// let config = LedgerConfig::parse()?;
+    /// let pool = database::DatabasePool::new(&config.server.database_url()?)
+    ///     .connect().await?;
+    ///
+    /// // Keep the service list private in production
+    /// let router = Router::builder(pool.into_pool()?, config)
+    ///     .with_reflection(false)
+    ///     .build()
+    ///     .await?;
+    /// ```
+    pub fn builder(database_pool: sqlx::SqlitePool, ledger_config: LedgerConfig) -> RouterBuilder {
+        RouterBuilder {
+            database_pool,
+            ledger_config,
+            reflection: true,
+            descriptor_sets: vec![rpc::FILE_DESCRIPTOR_SET],
+            services: Vec::new(),
+        }
     }
 
     /// Get a reference to the health reporter for health status management.
@@ -151,6 +202,42 @@ impl Router {
         &self.health_reporter
     }
 
+    /// Shared handle to the database pool used by the services.
+    ///
+    /// Closing this pool makes the services reject new requests with
+    /// `tonic::Code::Unavailable`.
+    pub fn database_pool(&self) -> std::sync::Arc<sqlx::SqlitePool> {
+        std::sync::Arc::clone(&self.database_pool)
+    }
+
+    /// Request counters recorded by the gRPC services.
+    pub fn metrics(&self) -> std::sync::Arc<telemetry::Metrics> {
+        std::sync::Arc::clone(&self.metrics)
+    }
+
+    /// The streaming cap layer shared by the services.
+    pub fn stream_limit(&self) -> &server::StreamLimitLayer {
+        &self.stream_limit
+    }
+
+    /// The per-connection request limit applied to every request.
+    ///
+    /// Connections must be accepted through [`server::ConnectionLimitLayer::accept`]
+    /// for the limit to apply.
+    pub fn connection_limit(&self) -> &server::ConnectionLimitLayer {
+        &self.connection_limit
+    }
+
+    /// The category lookup cache shared by the services.
+    pub fn category_cache(&self) -> &crate::database::CategoryCache {
+        &self.category_cache
+    }
+
+    /// State for the admin server's JSON API, when it is enabled.
+    pub fn json_api(&self) -> Option<&server::JsonApiState> {
+        self.json_api.as_ref()
+    }
+
     /// Set the health status for a specific service.
     ///
     /// # Arguments
@@ -175,29 +262,410 @@ impl Router {
     /// Consume the Router and return the inner tonic router.
     ///
     /// This is useful for advanced composition or testing.
-    pub fn into_inner(self) -> tonic::transport::server::Router {
+    pub fn into_inner(self) -> TonicRouter {
         self.router
     }
 }
 
+/// Adds one extra service to the tonic router, see [`RouterBuilder::with_service`].
+type AddService = Box<dyn FnOnce(TonicRouter) -> TonicRouter + Send>;
+
+/// Builder for a [`Router`], created by [`Router::builder`].
+///
+/// Reflection is enabled by default and describes the application services.
+/// Extra services are served behind the same server-wide layers as the
+/// application services and are reported as serving by the health service.
+pub struct RouterBuilder {
+    database_pool: sqlx::SqlitePool,
+    ledger_config: LedgerConfig,
+    reflection: bool,
+    descriptor_sets: Vec<&'static [u8]>,
+    services: Vec<(&'static str, AddService)>,
+}
+
+impl std::fmt::Debug for RouterBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouterBuilder")
+            .field("reflection", &self.reflection)
+            .field("descriptor_sets", &self.descriptor_sets.len())
+            .field("services", &self.services.iter().map(|(name, _)| *name).collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl RouterBuilder {
+    /// Serve the gRPC reflection service, `true` by default. Deployments that
+    /// do not want to list their services to any client can turn it off.
+    pub fn with_reflection(mut self, enabled: bool) -> Self {
+        self.reflection = enabled;
+        self
+    }
+
+    /// Register an encoded `FileDescriptorSet` with the reflection service,
+    /// so services added with [`with_service`](Self::with_service) can be
+    /// described too.
+    pub fn with_file_descriptor_set(mut self, descriptor_set: &'static [u8]) -> Self {
+        self.descriptor_sets.push(descriptor_set);
+        self
+    }
+
+    /// Serve an extra gRPC service alongside the application services.
+    ///
+    /// The service name must not clash with a service the router already
+    /// serves.
+    pub fn with_service<S>(mut self, service: S) -> Self
+    where
+        S: tower::Service<http::Request<tonic::body::Body>, Error = std::convert::Infallible>
+            + tonic::server::NamedService
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Response: axum::response::IntoResponse,
+        S::Future: Send + 'static,
+    {
+        self.services.push((S::NAME, Box::new(move |router: TonicRouter| router.add_service(service))));
+        self
+    }
+
+    /// Build the router with all required services.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Router::new`].
+    pub async fn build(self) -> LedgerResult<Router> {
+        let database_pool = self.database_pool;
+        let ledger_config = self.ledger_config;
+
+        // Cap concurrent streaming RPCs, shared across the application services
+        let stream_limit = server::StreamLimitLayer::new(
+            ledger_config.server.max_concurrent_streams,
+            server::StreamingMethods::from_file_descriptor_set(rpc::FILE_DESCRIPTOR_SET)?,
+        );
+
+        // Fails when TLS is required but not configured
+        let tls_config = server::server_tls_config(&ledger_config.server)?;
+        let require_tls = server::RequireTlsInterceptor::new(ledger_config.server.require_tls);
+        let metadata_limit = server::MetadataLimitInterceptor::from_config(&ledger_config.server);
+        let connection_limit = server::ConnectionLimitLayer::new(ledger_config.server.max_requests_per_connection);
+        let auth = server::AuthInterceptor::from_config(&ledger_config.server);
+
+        // Fails when a window size or the idle timeout is out of range
+        let transport_settings = server::TransportSettings::from_config(&ledger_config.server)?;
+
+        // Fails when a request timeout is zero or a method override is malformed
+        let method_timeout = server::MethodTimeoutLayer::from_config(&ledger_config.server)?;
+
+        // Fails when the display timezone is not an IANA name
+        ledger_config.server.display_tz()?;
+
+        // The JSON API is served by the admin server, so it needs its port
+        if ledger_config.server.enable_json_api && ledger_config.server.admin_port.is_none() {
+            return Err(crate::LedgerError::configuration("enable_json_api requires admin_port to be set"));
+        }
+
+        // Build reflections service, when enabled, optionally carrying on without it
+        let reflections_service = if self.reflection {
+            let reflection_builder = self.descriptor_sets.iter().fold(
+                TonicRefelectionServer::Builder::configure(),
+                |reflection_builder, descriptor_set| reflection_builder.register_encoded_file_descriptor_set(descriptor_set),
+            );
+            match reflection_builder.build_v1() {
+                Ok(reflections_service) => Some(reflections_service),
+                Err(e) if ledger_config.server.reflection_optional => {
+                    tracing::warn!("Starting without gRPC reflection, failed to build the service: {}", e);
+                    None
+                }
+                Err(e) => return Err(e.into()),
+            }
+        } else {
+            tracing::info!("gRPC reflection disabled");
+            None
+        };
+
+        let database_pool_arc = std::sync::Arc::new(database_pool);
+        let ledger_config_arc = std::sync::Arc::new(ledger_config);
+
+        // Changes published by the services, streamed by `EventStream`
+        let event_bus = services::EventBus::default();
+
+        // One cache for every categories service and the reactivation sweep,
+        // so a write through any of them invalidates it
+        let category_cache = crate::database::CategoryCache::new(ledger_config_arc.server.category_cache_size);
+
+        // Build utilities service/server
+        let utility_service = services::UtilitiesService::new(ledger_config_arc.clone())
+            .with_event_bus(event_bus.clone());
+
+        // Reject utility requests without the bearer token, when one is
+        // configured, and with `Unavailable` once the pool is closing
+        let utility_server = tonic::service::InterceptorLayer::new(auth.clone()).layer(
+            rpc::UtilitiesServiceServer::with_interceptor(
+                utility_service,
+                services::ShutdownInterceptor::new(database_pool_arc.clone()),
+            ),
+        );
+
+        // The JSON API calls its own categories service on the same pool and
+        // bus, behind the same bearer token check
+        let json_api = ledger_config_arc.server.enable_json_api.then(|| {
+            server::JsonApiState::new(
+                services::CategoriesService::new(database_pool_arc.clone(), ledger_config_arc.clone())
+                    .with_event_bus(event_bus.clone())
+                    .with_category_cache(category_cache.clone()),
+            )
+            .with_auth(auth.clone())
+        });
+
+        // Build Category service/server
+        let categories_service = services::CategoriesService::new(database_pool_arc.clone(), ledger_config_arc)
+            .with_event_bus(event_bus)
+            .with_category_cache(category_cache.clone());
+
+        // Reject category requests without the bearer token, when one is
+        // configured, and with `Unavailable` once the pool is closing
+        let categories_server = tonic::service::InterceptorLayer::new(auth).layer(stream_limit.layer(
+            rpc::CategoriesServiceServer::with_interceptor(
+                categories_service,
+                services::ShutdownInterceptor::new(database_pool_arc.clone()),
+            ),
+        ));
+
+        // Build health service
+        let (health_reporter, health_service) = tonic_health::server::health_reporter();
+        health_reporter
+            .set_serving::<rpc::UtilitiesServiceServer<services::UtilitiesService>>()
+            .await;
+        health_reporter
+            .set_serving::<rpc::CategoriesServiceServer<services::CategoriesService>>()
+            .await;
+        for (name, _) in &self.services {
+            health_reporter
+                .set_service_status(*name, tonic_health::ServingStatus::Serving)
+                .await;
+        }
+
+        // Build the router. Every request passes through the layers in order:
+        // joining the caller's trace, logging the call's outcome, counting
+        // requests per connection, rejecting oversized metadata, rejecting
+        // plaintext when TLS is required, recording metrics, and failing
+        // requests past their timeout
+        let mut server_builder = transport_settings.apply(Server::builder());
+        if let Some(tls_config) = tls_config {
+            // tonic parses the certificate and key here; its error only says
+            // "transport error", so surface the underlying cause instead
+            server_builder = server_builder.tls_config(tls_config).map_err(|e| {
+                let cause = std::error::Error::source(&e).map_or_else(|| e.to_string(), ToString::to_string);
+                crate::config::ConfigError::Validation(format!("Invalid TLS certificate or private key: {}", cause))
+            })?;
+        }
+        let metrics = std::sync::Arc::new(telemetry::Metrics::default());
+        let mut router = server_builder
+            .layer(server::TraceContextLayer)
+            .layer(server::RequestLogLayer)
+            .layer(connection_limit.clone())
+            .layer(tonic::service::InterceptorLayer::new(metadata_limit))
+            .layer(tonic::service::InterceptorLayer::new(require_tls))
+            .layer(telemetry::MetricsLayer::new(std::sync::Arc::clone(&metrics)))
+            .layer(method_timeout)
+            .add_service(health_service)
+            .add_optional_service(reflections_service)
+            .add_service(utility_server)
+            .add_service(categories_server);
+        for (_, add_service) in self.services {
+            router = add_service(router);
+        }
+
+        tracing::info!("Router initialised successfully with all services");
+        Ok(Router {
+            router,
+            health_reporter,
+            database_pool: database_pool_arc,
+            metrics,
+            stream_limit,
+            connection_limit,
+            category_cache,
+            json_api,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    /// Test error handling for Router::new when reflection fails
+    /// Descriptor set bytes that fail to decode, to make reflection fail
+    const INVALID_DESCRIPTOR_SET: &[u8] = &[0xff, 0xff, 0xff];
+
+    /// Serve `router` on a local port and return a channel connected to it.
+    async fn serve(router: Router) -> tonic::transport::Channel {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+        tokio::spawn(router.into_inner().serve_with_incoming(incoming));
+
+        tonic::transport::Channel::from_shared(format!("http://{}", address))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap()
+    }
+
+    /// Ask the v1 reflection service for the names of the services it serves.
+    async fn list_reflected_services(channel: tonic::transport::Channel) -> Result<Vec<String>, tonic::Status> {
+        use tonic_reflection::pb::v1::{
+            server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+            server_reflection_response::MessageResponse, ServerReflectionRequest,
+        };
+
+        let request = ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(MessageRequest::ListServices(String::new())),
+        };
+        let mut responses = ServerReflectionClient::new(channel)
+            .server_reflection_info(tokio_stream::once(request))
+            .await?
+            .into_inner();
+        match responses.message().await?.and_then(|response| response.message_response) {
+            Some(MessageResponse::ListServicesResponse(list)) => {
+                Ok(list.service.into_iter().map(|service| service.name).collect())
+            }
+            other => panic!("unexpected reflection response: {:?}", other),
+        }
+    }
+
+    /// Test that a reflection build failure aborts startup by default
     #[tokio::test]
     async fn test_router_new_reflection_error() {
-        // Create in-memory database pool for testing
         let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
         let ledger_config = LedgerConfig::default();
-        
-        // Simulate an error by passing an invalid file descriptor set
-        // This requires temporarily overriding rpc::FILE_DESCRIPTOR_SET
-        // For demonstration, we expect Router::new() to succeed, but in real cases,
-        // you would mock or patch the reflection builder to fail.
-        // This test is a placeholder for error path coverage.
-        let result = Router::new(database_pool, ledger_config).await;
-        assert!(result.is_ok(), "Router::new() should succeed with valid descriptor set");
+        assert!(!ledger_config.server.reflection_optional);
+
+        let result =
+            Router::builder(database_pool, ledger_config).with_file_descriptor_set(INVALID_DESCRIPTOR_SET).build().await;
+        assert!(matches!(result, Err(crate::LedgerError::TonicReflection(_))));
+    }
+
+    /// Test that the server starts without reflection when it is optional
+    #[tokio::test]
+    async fn test_router_reflection_optional_starts_without_reflection() {
+        use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+        use tonic::server::NamedService;
+
+        let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.server.reflection_optional = true;
+
+        let router = Router::builder(database_pool, ledger_config)
+            .with_file_descriptor_set(INVALID_DESCRIPTOR_SET)
+            .build()
+            .await
+            .expect("router should start without reflection");
+
+        // Serve the router and ask the health service about each application service
+        let channel = serve(router).await;
+        let mut health_client = HealthClient::new(channel.clone());
+        for service in [
+            <rpc::CategoriesServiceServer<services::CategoriesService> as NamedService>::NAME,
+            <rpc::UtilitiesServiceServer<services::UtilitiesService> as NamedService>::NAME,
+        ] {
+            let response = health_client
+                .check(HealthCheckRequest { service: service.to_string() })
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(response.status, ServingStatus::Serving as i32, "{}", service);
+        }
+
+        // The categories service is routed, even though this database has no schema
+        let mut categories_client = rpc::CategoriesServiceClient::new(channel);
+        let status = categories_client
+            .categories_list(rpc::CategoriesListRequest { limit: 1, ..Default::default() })
+            .await
+            .unwrap_err();
+        assert_ne!(status.code(), tonic::Code::Unimplemented);
+    }
+
+    /// Test that the router is only served without reflection when disabled
+    #[tokio::test]
+    async fn test_router_builder_with_reflection_toggle() {
+        let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let router = Router::builder(database_pool.clone(), LedgerConfig::default()).build().await.unwrap();
+        let services = list_reflected_services(serve(router).await).await.unwrap();
+        assert!(services.iter().any(|name| name == "personal_ledger.CategoriesService"), "{:?}", services);
+
+        let router = Router::builder(database_pool, LedgerConfig::default())
+            .with_reflection(false)
+            .build()
+            .await
+            .unwrap();
+        let status = list_reflected_services(serve(router).await).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unimplemented);
+    }
+
+    /// Test that an extra service is routed and reported as serving
+    #[tokio::test]
+    async fn test_router_builder_with_service() {
+        use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+        use tonic_reflection::pb::v1alpha::{
+            server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+            ServerReflectionRequest,
+        };
+
+        let reflection_v1alpha = TonicRefelectionServer::Builder::configure()
+            .register_encoded_file_descriptor_set(rpc::FILE_DESCRIPTOR_SET)
+            .build_v1alpha()
+            .unwrap();
+        let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let router = Router::builder(database_pool, LedgerConfig::default())
+            .with_service(reflection_v1alpha)
+            .build()
+            .await
+            .unwrap();
+        let channel = serve(router).await;
+
+        let response = HealthClient::new(channel.clone())
+            .check(HealthCheckRequest { service: "grpc.reflection.v1alpha.ServerReflection".to_string() })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.status, ServingStatus::Serving as i32);
+
+        let request = ServerReflectionRequest {
+            host: String::new(),
+            message_request: Some(MessageRequest::ListServices(String::new())),
+        };
+        let mut responses = ServerReflectionClient::new(channel)
+            .server_reflection_info(tokio_stream::once(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(responses.message().await.unwrap().is_some());
+    }
+
+    /// Test that an unknown display timezone fails router startup
+    #[tokio::test]
+    async fn test_router_rejects_unknown_display_timezone() {
+        let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.server.display_timezone = Some("Sydney".to_string());
+
+        assert!(Router::new(database_pool, ledger_config).await.is_err());
+    }
+
+    /// Test that the JSON API cannot be enabled without an admin port
+    #[tokio::test]
+    async fn test_router_json_api_requires_admin_port() {
+        let database_pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.server.enable_json_api = true;
+
+        let result = Router::new(database_pool.clone(), ledger_config.clone()).await;
+        assert!(matches!(result, Err(crate::LedgerError::Config(_))));
+
+        ledger_config.server.admin_port = Some(0);
+        let router = Router::new(database_pool, ledger_config).await.unwrap();
+        assert!(router.json_api().is_some());
     }
 
     /// Test error handling for set_service_health with an invalid service type
@@ -253,7 +721,7 @@ mod tests {
         // Verify that we get a tonic Router back
         // We can't easily test the exact services without integration testing,
         // but we can verify the type is correct
-        let _router_type: tonic::transport::server::Router = inner_router;
+        let _router_type: TonicRouter = inner_router;
     }
 
     /// Test that multiple router instances can be created independently
@@ -0,0 +1,313 @@
+//! # Streaming Concurrency Limit
+//!
+//! This module provides a tower layer that caps how many streaming RPCs the
+//! server handles at once. Streaming responses such as the CSV export can stay
+//! open for a long time, so a few clients opening many of them could exhaust
+//! server resources. Unary RPCs pass straight through and are not counted.
+//!
+//! - [`StreamingMethods`] lists the gRPC paths that stream, read from the
+//!   service descriptors so new streaming RPCs are covered automatically
+//! - [`StreamLimitLayer`] wraps a service and rejects streams past the cap
+//!   with `tonic::Code::ResourceExhausted`
+//!
+//! A stream holds its slot until the response body is finished or dropped,
+//! so a client disconnecting mid-stream frees its slot immediately.
+
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_util::future::BoxFuture;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{LedgerError, LedgerResult};
+
+/// Message returned to clients whose stream is rejected by the cap.
+pub const STREAM_LIMIT_MESSAGE: &str = "Too many concurrent streams, try again later";
+
+/// The set of gRPC method paths (`/package.Service/Method`) that stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamingMethods(HashSet<String>);
+
+impl StreamingMethods {
+    /// Collect every client or server streaming method from an encoded
+    /// `FileDescriptorSet`, such as [`rpc::FILE_DESCRIPTOR_SET`](crate::rpc::FILE_DESCRIPTOR_SET).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LedgerError::Internal`] if the descriptor set cannot be decoded.
+    pub fn from_file_descriptor_set(encoded: &[u8]) -> LedgerResult<Self> {
+        use prost::Message;
+
+        let descriptor_set = prost_types::FileDescriptorSet::decode(encoded)
+            .map_err(|e| LedgerError::internal(format!("Failed to decode file descriptor set: {}", e)))?;
+
+        let mut paths = HashSet::new();
+        for file in &descriptor_set.file {
+            let package = file.package();
+            for service in &file.service {
+                let service_name = if package.is_empty() {
+                    service.name().to_string()
+                } else {
+                    format!("{}.{}", package, service.name())
+                };
+
+                for method in &service.method {
+                    if method.client_streaming() || method.server_streaming() {
+                        paths.insert(format!("/{}/{}", service_name, method.name()));
+                    }
+                }
+            }
+        }
+
+        Ok(Self(paths))
+    }
+
+    /// Returns `true` if `path` is a streaming method.
+    pub fn contains(&self, path: &str) -> bool {
+        self.0.contains(path)
+    }
+
+    /// Returns the number of streaming methods.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no streaming methods.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for StreamingMethods {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Tower layer capping concurrent streaming RPCs.
+///
+/// Clone the layer to share one cap across several services.
+#[derive(Debug, Clone)]
+pub struct StreamLimitLayer {
+    permits: Option<Arc<Semaphore>>,
+    methods: Arc<StreamingMethods>,
+}
+
+impl StreamLimitLayer {
+    /// Create a layer allowing at most `max_concurrent_streams` streams at once.
+    ///
+    /// A cap of `0` disables the limit.
+    pub fn new(max_concurrent_streams: usize, methods: StreamingMethods) -> Self {
+        let permits = (max_concurrent_streams > 0).then(|| Arc::new(Semaphore::new(max_concurrent_streams)));
+
+        Self {
+            permits,
+            methods: Arc::new(methods),
+        }
+    }
+
+    /// Number of streams that can still be opened, or `None` when uncapped.
+    pub fn available_streams(&self) -> Option<usize> {
+        self.permits.as_ref().map(|permits| permits.available_permits())
+    }
+}
+
+impl<S> tower::Layer<S> for StreamLimitLayer {
+    type Service = StreamLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        StreamLimit {
+            inner,
+            permits: self.permits.clone(),
+            methods: Arc::clone(&self.methods),
+        }
+    }
+}
+
+/// Service produced by [`StreamLimitLayer`].
+#[derive(Debug, Clone)]
+pub struct StreamLimit<S> {
+    inner: S,
+    permits: Option<Arc<Semaphore>>,
+    methods: Arc<StreamingMethods>,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for StreamLimit<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let permits = match &self.permits {
+            Some(permits) if self.methods.contains(request.uri().path()) => permits,
+            _ => {
+                let future = inner.call(request);
+                return Box::pin(async move { Ok(future.await?.map(tonic::body::Body::new)) });
+            }
+        };
+
+        let permit = match Arc::clone(permits).try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                tracing::warn!("Rejecting stream {}: concurrent stream limit reached", request.uri().path());
+                let response = tonic::Status::resource_exhausted(STREAM_LIMIT_MESSAGE).into_http();
+                return Box::pin(async move { Ok(response) });
+            }
+        };
+
+        let future = inner.call(request);
+        Box::pin(async move {
+            let response = future.await?;
+            Ok(response.map(|body| {
+                let body = tonic::body::Body::new(body);
+                tonic::body::Body::new(PermitBody { body, _permit: permit })
+            }))
+        })
+    }
+}
+
+impl<S: tonic::server::NamedService> tonic::server::NamedService for StreamLimit<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+/// Response body that holds a stream slot until it is finished or dropped.
+struct PermitBody {
+    body: tonic::body::Body,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl http_body::Body for PermitBody {
+    type Data = bytes::Bytes;
+    type Error = tonic::Status;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        Pin::new(&mut self.body).poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.body.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, Service};
+
+    const STREAM_PATH: &str = "/personal_ledger.CategoriesService/CategoriesExportCsv";
+    const UNARY_PATH: &str = "/personal_ledger.CategoriesService/CategoryGet";
+
+    /// Inner service that answers every request with a non-empty body.
+    #[derive(Clone)]
+    struct EchoService;
+
+    impl tower::Service<http::Request<()>> for EchoService {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: http::Request<()>) -> Self::Future {
+            let body = tonic::body::Body::new(http_body_util::Full::new(bytes::Bytes::from_static(b"chunk")));
+            std::future::ready(Ok(http::Response::new(body)))
+        }
+    }
+
+    fn request(path: &str) -> http::Request<()> {
+        http::Request::builder().uri(path).body(()).unwrap()
+    }
+
+    fn grpc_status(response: &http::Response<tonic::body::Body>) -> Option<tonic::Code> {
+        tonic::Status::from_header_map(response.headers()).map(|status| status.code())
+    }
+
+    #[test]
+    fn test_streaming_methods_from_descriptor_set() {
+        let methods = StreamingMethods::from_file_descriptor_set(crate::rpc::FILE_DESCRIPTOR_SET).unwrap();
+
+        assert!(methods.contains(STREAM_PATH));
+        assert!(!methods.contains(UNARY_PATH));
+        assert!(!methods.contains("/personal_ledger.UtilitiesService/Ping"));
+    }
+
+    #[test]
+    fn test_streaming_methods_rejects_invalid_descriptor_set() {
+        let result = StreamingMethods::from_file_descriptor_set(&[0xff, 0xff, 0xff]);
+        assert!(matches!(result, Err(LedgerError::Internal(_))));
+    }
+
+    #[tokio::test]
+    async fn test_stream_past_cap_is_rejected_until_slot_frees() {
+        let layer = StreamLimitLayer::new(1, StreamingMethods::from_iter([STREAM_PATH]));
+        let mut service = layer.layer(EchoService);
+
+        let first = service.call(request(STREAM_PATH)).await.unwrap();
+        assert_eq!(grpc_status(&first), None);
+        assert_eq!(layer.available_streams(), Some(0));
+
+        let rejected = service.call(request(STREAM_PATH)).await.unwrap();
+        assert_eq!(grpc_status(&rejected), Some(tonic::Code::ResourceExhausted));
+
+        // Finishing the first stream frees its slot
+        drop(first);
+        assert_eq!(layer.available_streams(), Some(1));
+        let second = service.call(request(STREAM_PATH)).await.unwrap();
+        assert_eq!(grpc_status(&second), None);
+    }
+
+    #[tokio::test]
+    async fn test_unary_requests_are_not_counted() {
+        let layer = StreamLimitLayer::new(1, StreamingMethods::from_iter([STREAM_PATH]));
+        let mut service = layer.layer(EchoService);
+
+        let _stream = service.call(request(STREAM_PATH)).await.unwrap();
+        for _ in 0..3 {
+            let unary = service.call(request(UNARY_PATH)).await.unwrap();
+            assert_eq!(grpc_status(&unary), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zero_cap_disables_limit() {
+        let layer = StreamLimitLayer::new(0, StreamingMethods::from_iter([STREAM_PATH]));
+        let mut service = layer.layer(EchoService);
+        assert_eq!(layer.available_streams(), None);
+
+        let mut streams = Vec::new();
+        for _ in 0..5 {
+            let response = service.call(request(STREAM_PATH)).await.unwrap();
+            assert_eq!(grpc_status(&response), None);
+            streams.push(response);
+        }
+    }
+}
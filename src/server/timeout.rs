@@ -0,0 +1,249 @@
+//! # Request Timeouts
+//!
+//! This module provides a tower layer that fails requests taking too long with
+//! `tonic::Code::DeadlineExceeded`. One global limit suits most RPCs, but not
+//! all: a streaming export needs far longer than a `Ping`. So besides
+//! `ServerConfig.request_timeout_secs`, [`MethodTimeoutLayer`] reads
+//! per-method overrides from `ServerConfig.method_timeouts`, matched on the
+//! gRPC path (`/package.Service/Method`).
+//!
+//! The timeout covers the time until the handler returns its response. A
+//! streaming response that has started is not cut off mid-stream.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+use crate::config::{ConfigError, ServerConfig};
+use crate::LedgerResult;
+
+/// Tower layer failing requests that run past their method's timeout.
+///
+/// The default layer sets no timeouts.
+#[derive(Debug, Clone, Default)]
+pub struct MethodTimeoutLayer {
+    default_timeout: Option<Duration>,
+    method_timeouts: Arc<HashMap<String, Duration>>,
+}
+
+impl MethodTimeoutLayer {
+    /// Create a layer applying `method_timeouts` by gRPC path, and
+    /// `default_timeout` to every other method.
+    pub fn new(default_timeout: Option<Duration>, method_timeouts: HashMap<String, Duration>) -> Self {
+        Self {
+            default_timeout,
+            method_timeouts: Arc::new(method_timeouts),
+        }
+    }
+
+    /// Read and validate `request_timeout_secs` and `method_timeouts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] (as a `LedgerError::Config`) if a
+    /// timeout is `0`, or a `method_timeouts` entry is not
+    /// `/package.Service/Method=seconds`.
+    pub fn from_config(server_config: &ServerConfig) -> LedgerResult<Self> {
+        if server_config.request_timeout_secs == Some(0) {
+            return Err(ConfigError::Validation("request_timeout_secs must be greater than 0".to_string()).into());
+        }
+
+        let mut method_timeouts = HashMap::new();
+        let entries = server_config.method_timeouts.as_deref().unwrap_or_default();
+        for entry in entries.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (path, secs) = parse_method_timeout(entry).ok_or_else(|| {
+                ConfigError::Validation(format!(
+                    "method_timeouts entry '{}' must be /package.Service/Method=seconds with seconds greater than 0",
+                    entry
+                ))
+            })?;
+            method_timeouts.insert(path.to_string(), Duration::from_secs(secs));
+        }
+
+        Ok(Self::new(
+            server_config.request_timeout_secs.map(Duration::from_secs),
+            method_timeouts,
+        ))
+    }
+
+    /// The timeout applied to the method at `path`, if any.
+    pub fn timeout_for(&self, path: &str) -> Option<Duration> {
+        self.method_timeouts.get(path).copied().or(self.default_timeout)
+    }
+}
+
+/// Split a `/package.Service/Method=seconds` entry into its path and seconds.
+fn parse_method_timeout(entry: &str) -> Option<(&str, u64)> {
+    let (path, secs) = entry.split_once('=')?;
+    let path = path.trim();
+    let secs: u64 = secs.trim().parse().ok()?;
+
+    let (service, method) = path.strip_prefix('/')?.split_once('/')?;
+    if service.is_empty() || method.is_empty() || method.contains('/') || secs == 0 {
+        return None;
+    }
+
+    Some((path, secs))
+}
+
+impl<S> tower::Layer<S> for MethodTimeoutLayer {
+    type Service = MethodTimeout<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MethodTimeout {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// Service produced by [`MethodTimeoutLayer`].
+#[derive(Debug, Clone)]
+pub struct MethodTimeout<S> {
+    inner: S,
+    layer: MethodTimeoutLayer,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for MethodTimeout<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let path = request.uri().path().to_string();
+        let timeout = self.layer.timeout_for(&path);
+        let future = inner.call(request);
+
+        Box::pin(async move {
+            let Some(timeout) = timeout else {
+                return Ok(future.await?.map(tonic::body::Body::new));
+            };
+
+            match tokio::time::timeout(timeout, future).await {
+                Ok(response) => Ok(response?.map(tonic::body::Body::new)),
+                Err(_) => {
+                    tracing::warn!("Request {} timed out after {:?}", path, timeout);
+                    Ok(tonic::Status::deadline_exceeded(format!("Request exceeded its {:?} timeout", timeout))
+                        .into_http())
+                }
+            }
+        })
+    }
+}
+
+impl<S: tonic::server::NamedService> tonic::server::NamedService for MethodTimeout<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, Service};
+
+    const EXPORT_PATH: &str = "/personal_ledger.CategoriesService/CategoriesExportCsv";
+    const PING_PATH: &str = "/personal_ledger.UtilitiesService/Ping";
+
+    /// Inner service that answers every request after `delay`.
+    #[derive(Clone)]
+    struct SlowService {
+        delay: Duration,
+    }
+
+    impl tower::Service<http::Request<()>> for SlowService {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: http::Request<()>) -> Self::Future {
+            let delay = self.delay;
+            Box::pin(async move {
+                tokio::time::sleep(delay).await;
+                Ok(http::Response::new(tonic::body::Body::empty()))
+            })
+        }
+    }
+
+    async fn call(layer: &MethodTimeoutLayer, path: &str, delay: Duration) -> Option<tonic::Code> {
+        let mut service = layer.layer(SlowService { delay });
+        let request = http::Request::builder().uri(path).body(()).unwrap();
+        let response = service.call(request).await.unwrap();
+        tonic::Status::from_header_map(response.headers()).map(|status| status.code())
+    }
+
+    #[tokio::test]
+    async fn test_override_outlasts_global_timeout() {
+        let layer = MethodTimeoutLayer::new(
+            Some(Duration::from_millis(50)),
+            HashMap::from([(EXPORT_PATH.to_string(), Duration::from_secs(5))]),
+        );
+
+        // The overridden method is not cut off at the global 50 ms
+        assert_eq!(call(&layer, EXPORT_PATH, Duration::from_millis(200)).await, None);
+
+        // Other methods still are
+        assert_eq!(
+            call(&layer, PING_PATH, Duration::from_millis(200)).await,
+            Some(tonic::Code::DeadlineExceeded)
+        );
+        assert_eq!(call(&layer, PING_PATH, Duration::ZERO).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_no_timeouts_by_default() {
+        let layer = MethodTimeoutLayer::from_config(&ServerConfig::default()).unwrap();
+        assert_eq!(layer.timeout_for(PING_PATH), None);
+        assert_eq!(call(&layer, PING_PATH, Duration::from_millis(100)).await, None);
+    }
+
+    #[test]
+    fn test_from_config_parses_method_timeouts() {
+        let config = ServerConfig {
+            method_timeouts: Some(format!(" {} = 300 , {}=1,", EXPORT_PATH, PING_PATH)),
+            ..ServerConfig::default()
+        };
+        let layer = MethodTimeoutLayer::from_config(&config).unwrap();
+
+        assert_eq!(layer.timeout_for(EXPORT_PATH), Some(Duration::from_secs(300)));
+        assert_eq!(layer.timeout_for(PING_PATH), Some(Duration::from_secs(1)));
+        assert_eq!(layer.timeout_for("/personal_ledger.CategoriesService/CategoryGet"), None);
+    }
+
+    #[test]
+    fn test_from_config_rejects_invalid_entries() {
+        for method_timeouts in ["Ping=5", "/personal_ledger.UtilitiesService/Ping", "/a/b=0", "/a/b=soon", "/a=5"] {
+            let config = ServerConfig {
+                method_timeouts: Some(method_timeouts.to_string()),
+                ..ServerConfig::default()
+            };
+            assert!(MethodTimeoutLayer::from_config(&config).is_err(), "{}", method_timeouts);
+        }
+
+        let config = ServerConfig { request_timeout_secs: Some(0), ..ServerConfig::default() };
+        assert!(MethodTimeoutLayer::from_config(&config).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+//! # TLS Support
+//!
+//! This module turns the TLS settings in [`ServerConfig`] into the tonic
+//! transport configuration, and optionally enforces that every request arrived
+//! over TLS.
+//!
+//! - [`server_tls_config`] loads the PEM certificate and key when TLS is enabled
+//! - [`RequireTlsInterceptor`] rejects plaintext requests with
+//!   `tonic::Code::PermissionDenied` when `require_tls` is set
+//!
+//! With TLS enabled, the transport already refuses plaintext handshakes. The
+//! interceptor checks each request's connection info as well, so a request can
+//! never reach a service over plaintext even if the transport is misconfigured.
+
+use tonic::transport::server::{TcpConnectInfo, TlsConnectInfo};
+use tonic::transport::{Identity, ServerTlsConfig};
+
+use crate::config::{ConfigError, ServerConfig};
+use crate::LedgerResult;
+
+/// Message returned to clients whose plaintext request is rejected.
+pub const TLS_REQUIRED_MESSAGE: &str = "TLS is required";
+
+/// Build the tonic TLS configuration from the server settings.
+///
+/// # Returns
+///
+/// Returns `Ok(None)` when TLS is disabled, or the server identity loaded from
+/// the configured certificate and key files.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::Validation`] (as a `LedgerError::Config`) if the TLS
+/// settings are inconsistent (see [`ServerConfig::tls_files`]) or if the
+/// certificate or key file cannot be read.
+pub fn server_tls_config(server_config: &ServerConfig) -> LedgerResult<Option<ServerTlsConfig>> {
+    let Some((cert_path, key_path)) = server_config.tls_files()? else {
+        return Ok(None);
+    };
+
+    let read_pem = |kind: &str, path: &std::path::Path| {
+        std::fs::read(path).map_err(|e| {
+            ConfigError::Validation(format!("Failed to read TLS {} '{}': {}", kind, path.display(), e))
+        })
+    };
+    let cert = read_pem("certificate", cert_path)?;
+    let key = read_pem("private key", key_path)?;
+
+    tracing::info!("TLS enabled with certificate '{}'", cert_path.display());
+    Ok(Some(ServerTlsConfig::new().identity(Identity::from_pem(cert, key))))
+}
+
+/// Interceptor that rejects requests which did not arrive over TLS.
+///
+/// When constructed with `required = false` every request passes through, so
+/// the interceptor can be installed unconditionally.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireTlsInterceptor {
+    required: bool,
+}
+
+impl RequireTlsInterceptor {
+    /// Create an interceptor that enforces TLS when `required` is `true`.
+    pub fn new(required: bool) -> Self {
+        Self { required }
+    }
+}
+
+impl tonic::service::Interceptor for RequireTlsInterceptor {
+    fn call(&mut self, request: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        if !self.required {
+            return Ok(request);
+        }
+
+        // The transport only records TLS connection info for TLS connections
+        if request.extensions().get::<TlsConnectInfo<TcpConnectInfo>>().is_none() {
+            tracing::warn!("Rejecting plaintext request from {:?}", request.remote_addr());
+            return Err(tonic::Status::permission_denied(TLS_REQUIRED_MESSAGE));
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tonic::service::Interceptor;
+
+    #[test]
+    fn test_server_tls_config_disabled() {
+        let config = ServerConfig::default();
+        assert!(server_tls_config(&config).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_server_tls_config_required_without_tls_fails() {
+        let config = ServerConfig { require_tls: true, ..ServerConfig::default() };
+        let result = server_tls_config(&config);
+        assert!(matches!(result, Err(crate::LedgerError::Config(ConfigError::Validation(_)))));
+    }
+
+    #[test]
+    fn test_server_tls_config_missing_files_fails() {
+        let config = ServerConfig {
+            tls_enabled: true,
+            tls_cert_path: Some(PathBuf::from("/nonexistent/cert.pem")),
+            tls_key_path: Some(PathBuf::from("/nonexistent/key.pem")),
+            ..ServerConfig::default()
+        };
+
+        let err = server_tls_config(&config).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/cert.pem"));
+    }
+
+    #[test]
+    fn test_interceptor_rejects_plaintext_when_required() {
+        let mut interceptor = RequireTlsInterceptor::new(true);
+
+        let status = interceptor.call(tonic::Request::new(())).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+        assert_eq!(status.message(), TLS_REQUIRED_MESSAGE);
+    }
+
+    #[test]
+    fn test_interceptor_passes_plaintext_when_not_required() {
+        let mut interceptor = RequireTlsInterceptor::new(false);
+        assert!(interceptor.call(tonic::Request::new(())).is_ok());
+    }
+}
@@ -2,15 +2,18 @@
 //!
 //! This module provides the `TonicServer` struct, which offers a high-level abstraction for running Tonic gRPC servers.
 //!
-//! The `TonicServer` combines a `Router` (which manages gRPC services) with a `TcpListener` to provide
-//! enhanced control over server lifecycle and connection handling. This design is particularly beneficial
+//! The `TonicServer` combines a `Router` (which manages gRPC services) with one `TcpListener` per
+//! configured bind address to provide enhanced control over server lifecycle and connection handling. This design is particularly beneficial
 //! for integration testing scenarios where random port binding and controlled server startup are essential.
 //!
 //! ## Key Features
 //!
 //! - **Random Port Binding**: Set port to 0 for automatic OS-assigned ports (ideal for testing)
+//! - **Multiple Bind Addresses**: Listen on several addresses (e.g. IPv4 and IPv6) as one server
 //! - **Flexible Serving**: Support for both address-based and stream-based serving
 //! - **Health Management**: Integration with gRPC health checking services
+//! - **Admin Endpoint**: Optional plain HTTP `/healthz`, `/readyz` and `/metrics` on `admin_port`
+//! - **Reactivation Sweep**: Background task reactivating categories whose `reactivate_on` has passed
 //! - **Integration Testing**: Designed for easy testing with controlled server lifecycles
 //!
 //! ## Usage
@@ -42,9 +45,10 @@
 //! Personal Ledger backend server components.
 use core::net;
 use tokio::net as TokioNet;
+use futures_util::StreamExt;
 use tokio_stream::wrappers::TcpListenerStream;
 
-use crate::{server, LedgerResult};
+use crate::{database, rpc, server, services, LedgerResult};
 
 /// TonicServer provides a higher-level abstraction for running a Tonic gRPC server.
 /// It combines a Router with a TcpListener for more control over server lifecycle,
@@ -69,17 +73,22 @@ pub struct TonicServer {
     /// The router containing all configured gRPC services (reflection, health, utilities).
     /// This field is public to allow direct access for advanced use cases.
     pub router: server::Router,
-    /// The TCP listener bound to the server address.
+    /// The TCP listeners, one per configured bind address, in configuration order.
     /// This field is public to allow direct access for advanced networking scenarios.
-    pub listener: TokioNet::TcpListener,
+    pub listeners: Vec<TokioNet::TcpListener>,
+    /// How long to keep rejecting requests with `Unavailable` once shutdown begins.
+    pub shutdown_grace_period: std::time::Duration,
+    /// The admin HTTP server, when `admin_port` is configured. It runs for as
+    /// long as the gRPC server does.
+    pub admin: Option<server::AdminServer>,
 }
 
 impl TonicServer {
     /// Create a new TonicServer instance with the given address.
     ///
     /// This method initializes a new router with all required services and binds a TCP listener
-    /// to the specified address. If the port is set to 0, the operating system will assign
-    /// a random available port.
+    /// to each configured address. If the port is set to 0, the operating system will assign
+    /// a random available port to each listener.
     ///
     /// # Arguments
     ///
@@ -93,7 +102,9 @@ impl TonicServer {
     ///
     /// Returns an error if:
     /// - Router initialization fails (service configuration issues)
-    /// - TCP listener binding fails (address already in use, permission denied, etc.)
+    /// - The configured addresses cannot be parsed
+    /// - Any TCP listener fails to bind ([`LedgerError::Bind`](crate::LedgerError::Bind) names
+    ///   the address, e.g. when it is already in use or IPv6 is unavailable)
     ///
     /// # Examples
     ///
@@ -108,20 +119,90 @@ impl TonicServer {
         let router = server::Router::new(database_pool, ledger_config.clone()).await?;
         tracing::debug!("New tonic server router created");
 
-        let address = ledger_config.server.address()?;
-        let listener = TokioNet::TcpListener::bind(address).await?;
-        tracing::debug!("Tonic server bound to {}", listener.local_addr()?);
+        let addresses = ledger_config.server.addresses()?;
+        let mut listeners = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let listener = TokioNet::TcpListener::bind(address)
+                .await
+                .map_err(|source| crate::LedgerError::Bind { address, source })?;
+            tracing::debug!("Tonic server bound to {}", listener.local_addr()?);
+            listeners.push(listener);
+        }
+
+        // The admin server listens on the primary address, next to gRPC
+        let admin = match ledger_config.server.admin_port {
+            Some(admin_port) => {
+                let address = net::SocketAddr::new(ledger_config.server.address()?.ip(), admin_port);
+                Some(server::AdminServer::bind(address, server::AdminState::from_router(&router)).await?)
+            }
+            None => None,
+        };
 
-        let tonic_server = Self { router, listener };
+        let shutdown_grace_period = ledger_config.server.shutdown_grace_period();
+
+        let tonic_server = Self { router, listeners, shutdown_grace_period, admin };
         tracing::debug!("New Tonic server instance created.");
 
         Ok(tonic_server)
     }
 
+    /// Create a throwaway server for integration tests, backed by a migrated
+    /// in-memory SQLite database and bound to an OS-assigned port on localhost.
+    ///
+    /// The server uses the default configuration without an admin port. It is
+    /// returned unstarted with its address, so the caller decides how to run
+    /// and stop it, e.g. with [`run_with_shutdown`](Self::run_with_shutdown).
+    /// The database lives in a single connection and is dropped with the server.
+    ///
+    /// Only available in this crate's tests and with the `test-util` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be created or migrated, or the
+    /// server cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use personal_ledger_backend::server::TonicServer;
+    ///
+    /// # async fn example() -> personal_ledger_backend::LedgerResult<()> {
+    /// let (server, address) = TonicServer::test_instance().await?;
+    /// tokio::spawn(server.run());
+    /// println!("Test server on {}", address);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(any(test, feature = "test-util"))]
+    pub async fn test_instance() -> LedgerResult<(Self, net::SocketAddr)> {
+        // Every connection to `sqlite::memory:` opens its own database, so keep exactly one
+        let database_pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .min_connections(1)
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect("sqlite::memory:")
+            .await
+            .map_err(|e| database::DatabaseError::connection(e.to_string()))?;
+        database::run_migrations(&database_pool).await?;
+
+        let mut ledger_config = crate::LedgerConfig::default();
+        ledger_config.server.address = "127.0.0.1".to_string();
+        ledger_config.server.port = 0;
+        ledger_config.server.admin_port = None;
+
+        let server = Self::new(database_pool, ledger_config).await?;
+        let address = server.local_addr()?;
+        tracing::debug!("Test server instance bound to {}", address);
+
+        Ok((server, address))
+    }
+
     /// Get the local address that the server is bound to.
     ///
-    /// This method returns the actual socket address that the TCP listener is bound to,
-    /// which is useful when the server was created with port 0 (auto-assignment).
+    /// This method returns the actual socket address that the first (primary) TCP listener
+    /// is bound to, which is useful when the server was created with port 0 (auto-assignment).
+    /// Use [`TonicServer::local_addrs`] to get the address of every listener.
     ///
     /// # Returns
     ///
@@ -140,7 +221,40 @@ impl TonicServer {
     /// println!("Server bound to: {}", addr);
     /// ```
     pub fn local_addr(&self) -> Result<net::SocketAddr, std::io::Error> {
-        self.listener.local_addr()
+        match self.listeners.first() {
+            Some(listener) => listener.local_addr(),
+            None => Err(std::io::Error::new(std::io::ErrorKind::NotConnected, "Tonic server has no listeners")),
+        }
+    }
+
+    /// Get the local addresses of every listener, in configuration order.
+    ///
+    /// When the server was created with port 0 each listener has its own
+    /// OS-assigned port.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `std::io::Error` if any listener's local address cannot be retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let server = TonicServer::new(pool, config).await?;
+    /// for addr in server.local_addrs()? {
+    ///     println!("Server bound to: {}", addr);
+    /// }
+    /// ```
+    pub fn local_addrs(&self) -> Result<Vec<net::SocketAddr>, std::io::Error> {
+        self.listeners.iter().map(TokioNet::TcpListener::local_addr).collect()
+    }
+
+    /// Get the local address of the admin HTTP server, if it is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `std::io::Error` if the admin listener's local address cannot be retrieved.
+    pub fn admin_addr(&self) -> Result<Option<net::SocketAddr>, std::io::Error> {
+        self.admin.as_ref().map(server::AdminServer::local_addr).transpose()
     }
 
     /// Get a formatted string representation of the server address.
@@ -168,9 +282,9 @@ impl TonicServer {
         Ok(format!("{}:{}", addr.ip(), addr.port()))
     }
 
-    /// Run the server using the bound listener.
+    /// Run the server using the bound listeners.
     ///
-    /// This method starts the gRPC server using the pre-bound TCP listener. It consumes
+    /// This method starts the gRPC server using the pre-bound TCP listeners. It consumes
     /// the `TonicServer` instance and runs until the server is shut down or an error occurs.
     /// The server will log its listening address and begin accepting connections.
     ///
@@ -194,13 +308,122 @@ impl TonicServer {
     /// This method consumes `self` and will run indefinitely until interrupted.
     /// For testing scenarios, consider using `serve_with_incoming()` with a controlled stream.
     pub async fn run(self) -> LedgerResult<()> {
-        let addr_string = self.address_string()?;
+        self.run_with_shutdown(async {
+            if let Err(e) = tokio::signal::ctrl_c().await {
+                tracing::error!("Failed to listen for shutdown signal: {}", e);
+                std::future::pending::<()>().await;
+            }
+        })
+        .await
+    }
 
-        let incoming = TcpListenerStream::new(self.listener);
-        let router = self.router;
-        router.into_inner().serve_with_incoming(incoming).await?;
+    /// Run the server until `signal` resolves, then shut down gracefully.
+    ///
+    /// Connections accepted by every listener are merged into a single stream
+    /// and served by the same router.
+    ///
+    /// When the signal fires the services are marked as not serving, so load
+    /// balancers stop routing to them, and requests keep being served for the
+    /// configured `shutdown_grace_period`. The transport then stops accepting
+    /// connections and waits for in-flight requests to complete. Only once
+    /// they have is the database checkpointed and the pool closed, so a
+    /// request that needs another connection part way through still gets one.
+    ///
+    /// # Arguments
+    ///
+    /// * `signal` - Future that resolves when shutdown should begin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails to start or encounters a fatal error during operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// let server = TonicServer::new(pool, config).await?;
+    /// let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    /// tokio::spawn(server.run_with_shutdown(async { let _ = shutdown_rx.await; }));
+    /// shutdown_tx.send(()).ok();
+    /// ```
+    pub async fn run_with_shutdown<F>(self, signal: F) -> LedgerResult<()>
+    where
+        F: core::future::Future<Output = ()> + Send + 'static,
+    {
+        let addr_string = self
+            .local_addrs()?
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // Merge the accept streams of every listener into one served stream,
+        // counting the requests on each accepted connection
+        let connection_limit = self.router.connection_limit().clone();
+        let incoming = futures_util::stream::select_all(
+            self.listeners.into_iter().map(TcpListenerStream::new),
+        )
+        .map(move |stream| stream.map(|stream| connection_limit.accept(stream)));
+        let database_pool = self.router.database_pool();
+        let health_reporter = self.router.health_reporter().clone();
+        let grace_period = self.shutdown_grace_period;
+
+        let shutdown = async move {
+            signal.await;
+            tracing::info!("Shutdown requested, reporting services as not serving");
+
+            health_reporter
+                .set_not_serving::<rpc::UtilitiesServiceServer<services::UtilitiesService>>()
+                .await;
+            health_reporter
+                .set_not_serving::<rpc::CategoriesServiceServer<services::CategoriesService>>()
+                .await;
+
+            // Give load balancers the grace period to notice before
+            // connections are refused
+            tokio::time::sleep(grace_period).await;
+            tracing::info!("Stopping transport, draining in-flight requests");
+        };
+
+        // The admin server stops with the gRPC server, so `/readyz` reports
+        // the shutdown until the transport stops
+        let admin_task = self.admin.map(|admin| {
+            tokio::spawn(async move {
+                if let Err(e) = admin.run().await {
+                    tracing::error!("Admin server error: {}", e);
+                }
+            })
+        });
+
+        let sweep_task = tokio::spawn(services::run_reactivation_sweep(
+            self.router.database_pool(),
+            self.router.category_cache().clone(),
+            services::REACTIVATION_SWEEP_INTERVAL,
+        ));
 
         tracing::info!("Tonic server listening on {}", addr_string);
+        let served = self
+            .router
+            .into_inner()
+            .serve_with_incoming_shutdown(incoming, shutdown)
+            .await;
+        if let Some(admin_task) = admin_task {
+            admin_task.abort();
+        }
+        sweep_task.abort();
+
+        // In-flight requests have completed, so nothing needs the pool any
+        // more. Checkpoint first so the main database file is current for
+        // backups.
+        if !database_pool.is_closed()
+            && let Err(e) = database::checkpoint_wal(&database_pool).await
+        {
+            tracing::warn!("Failed to checkpoint database before shutdown: {}", e);
+        }
+        database_pool.close().await;
+        tracing::info!("Database pool closed");
+        served?;
+
+        tracing::info!("Tonic server on {} shut down", addr_string);
         Ok(())
     }
 
@@ -582,5 +805,124 @@ mod tests {
         let _router = &server.router;
         // The router should be properly initialized
     }
-}
 
+    /// Returns `true` when the IPv6 loopback can be bound in this environment.
+    fn ipv6_loopback_available() -> bool {
+        std::net::TcpListener::bind("[::1]:0").is_ok()
+    }
+
+    /// Test binding loopback IPv4 and IPv6 and pinging through each listener
+    #[sqlx::test]
+    async fn test_tonic_server_binds_every_address(database_pool: sqlx::SqlitePool) {
+        if !ipv6_loopback_available() {
+            eprintln!("Skipping dual stack bind test: IPv6 loopback is unavailable");
+            return;
+        }
+
+        let mut ledger_config = crate::LedgerConfig::default();
+        ledger_config.server.address = "127.0.0.1, ::1".to_string();
+        ledger_config.server.port = 0; // Use port 0 for auto-assignment
+        ledger_config.server.shutdown_grace_period_secs = 0;
+        let server = TonicServer::new(database_pool, ledger_config).await.unwrap();
+
+        let addrs = server.local_addrs().unwrap();
+        assert_eq!(addrs.len(), 2, "One listener per configured address");
+        assert!(addrs[0].is_ipv4());
+        assert!(addrs[1].is_ipv6());
+        assert_eq!(server.local_addr().unwrap(), addrs[0]);
+
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(server.run_with_shutdown(async {
+            let _ = shutdown_rx.await;
+        }));
+
+        for addr in &addrs {
+            let mut client = rpc::UtilitiesServiceClient::connect(format!("http://{}", addr))
+                .await
+                .unwrap_or_else(|e| panic!("Should connect via {}: {}", addr, e));
+            let response = client.ping(rpc::PingRequest {}).await;
+            assert!(response.is_ok(), "Ping via {} should succeed", addr);
+        }
+
+        shutdown_tx.send(()).unwrap();
+        let _ = handle.await;
+    }
+
+    /// Test run_with_shutdown returns Ok once its signal fires
+    #[sqlx::test]
+    async fn test_run_with_shutdown_resolves_ok(database_pool: sqlx::SqlitePool) {
+        let mut ledger_config = crate::LedgerConfig::default();
+        ledger_config.server.address = "127.0.0.1".to_string();
+        ledger_config.server.port = 0;
+        ledger_config.server.shutdown_grace_period_secs = 0;
+        let server = TonicServer::new(database_pool, ledger_config).await.unwrap();
+
+        let signal = tokio::time::sleep(std::time::Duration::from_millis(50));
+        let result = tokio::time::timeout(std::time::Duration::from_secs(5), server.run_with_shutdown(signal))
+            .await
+            .expect("Server should stop after the shutdown signal");
+        assert!(result.is_ok(), "{:?}", result);
+    }
+
+    /// Test the ephemeral test instance serves on its own migrated database
+    #[tokio::test]
+    async fn test_tonic_server_test_instance_is_ready() {
+        use tonic::server::NamedService;
+        use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+
+        let (server, address) = TonicServer::test_instance().await.unwrap();
+        assert!(address.ip().is_loopback());
+        assert_ne!(address.port(), 0);
+
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let handle = tokio::spawn(server.run_with_shutdown(async {
+            let _ = shutdown_rx.await;
+        }));
+
+        let channel = tonic::transport::Channel::from_shared(format!("http://{}", address))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+
+        let mut utilities_client = rpc::UtilitiesServiceClient::new(channel.clone());
+        assert!(utilities_client.ping(rpc::PingRequest {}).await.is_ok());
+
+        let service = <rpc::CategoriesServiceServer<services::CategoriesService> as NamedService>::NAME;
+        let response = HealthClient::new(channel.clone())
+            .check(HealthCheckRequest { service: service.to_string() })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.status, ServingStatus::Serving as i32);
+
+        // Migrations were applied, so the empty table can be listed
+        let listed = rpc::CategoriesServiceClient::new(channel)
+            .categories_list(rpc::CategoriesListRequest { limit: 1, ..Default::default() })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(listed.categories.is_empty());
+
+        shutdown_tx.send(()).unwrap();
+        let _ = handle.await;
+    }
+
+    /// Test a bind failure names the address that could not be bound
+    #[sqlx::test]
+    async fn test_tonic_server_new_reports_bind_failure(database_pool: sqlx::SqlitePool) {
+        // Hold a port so the server cannot bind to it
+        let occupied = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let occupied_addr = occupied.local_addr().unwrap();
+
+        let mut ledger_config = crate::LedgerConfig::default();
+        ledger_config.server.port = occupied_addr.port();
+        let result = TonicServer::new(database_pool, ledger_config).await;
+
+        match result {
+            Err(crate::LedgerError::Bind { address, .. }) => assert_eq!(address, occupied_addr),
+            Err(e) => panic!("Expected a bind error, got {}", e),
+            Ok(_) => panic!("Binding an occupied port should fail"),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! # W3C Trace Context Propagation
+//!
+//! This module links the backend's request spans to the caller's trace, so a
+//! single trace follows a request across the gateway and into the backend. It
+//! follows the [W3C Trace Context](https://www.w3.org/TR/trace-context/) format:
+//!
+//! ```text
+//! traceparent: 00-<trace id, 32 hex>-<parent span id, 16 hex>-<flags, 2 hex>
+//! ```
+//!
+//! - [`TraceContext`] parses and formats `traceparent` / `tracestate`
+//! - [`TraceContextLayer`] opens a `grpc_request` span for every request,
+//!   carrying the propagated `trace_id` and `parent_span_id`, and stores the
+//!   request's own [`TraceContext`] in the request extensions
+//!
+//! Requests without a valid `traceparent` start a new trace. Handlers making
+//! outgoing calls read the context with [`TraceContext::from_request`] and
+//! [`TraceContext::inject`] it into the outgoing metadata, so the downstream
+//! service becomes a child of this request's span.
+
+use std::task::{Context, Poll};
+
+use tracing::Instrument;
+
+/// Header carrying the trace id, parent span id and flags.
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// Header carrying vendor-specific trace state, passed through unchanged.
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+/// Trace flags marking a trace as sampled.
+const SAMPLED_FLAG: u8 = 0x01;
+
+/// The trace a request belongs to and the span that represents it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceContext {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    flags: u8,
+    tracestate: Option<String>,
+}
+
+impl TraceContext {
+    /// Start a new, sampled trace.
+    pub fn root() -> Self {
+        Self {
+            trace_id: random_non_zero(),
+            span_id: random_non_zero(),
+            flags: SAMPLED_FLAG,
+            tracestate: None,
+        }
+    }
+
+    /// Parse a `traceparent` header value.
+    ///
+    /// Returns `None` for malformed values, the reserved version `ff` and
+    /// all-zero trace or span ids, as the specification requires.
+    pub fn parse(traceparent: &str) -> Option<Self> {
+        let mut parts = traceparent.trim().split('-');
+        let version: [u8; 1] = parse_hex(parts.next()?)?;
+        let trace_id: [u8; 16] = parse_hex(parts.next()?)?;
+        let span_id: [u8; 8] = parse_hex(parts.next()?)?;
+        let [flags] = parse_hex::<1>(parts.next()?)?;
+
+        // Version 00 has exactly four fields; later versions may append more
+        let valid_version = match version[0] {
+            0x00 => parts.next().is_none(),
+            0xff => false,
+            _ => true,
+        };
+        if !valid_version || trace_id == [0; 16] || span_id == [0; 8] {
+            return None;
+        }
+
+        Some(Self { trace_id, span_id, flags, tracestate: None })
+    }
+
+    /// Read the trace context from request headers, if a valid `traceparent` is present.
+    pub fn from_headers(headers: &http::HeaderMap) -> Option<Self> {
+        let traceparent = headers.get(TRACEPARENT_HEADER)?.to_str().ok()?;
+        let mut context = Self::parse(traceparent)?;
+        context.tracestate = headers
+            .get(TRACESTATE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.trim().is_empty())
+            .map(str::to_string);
+
+        Some(context)
+    }
+
+    /// The context [`TraceContextLayer`] stored for a request.
+    pub fn from_request<T>(request: &tonic::Request<T>) -> Option<&Self> {
+        request.extensions().get::<Self>()
+    }
+
+    /// A new span in the same trace, keeping the flags and trace state.
+    pub fn child(&self) -> Self {
+        Self { span_id: random_non_zero(), ..self.clone() }
+    }
+
+    /// The trace id as 32 lowercase hex characters.
+    pub fn trace_id(&self) -> String {
+        to_hex(&self.trace_id)
+    }
+
+    /// The span id as 16 lowercase hex characters.
+    pub fn span_id(&self) -> String {
+        to_hex(&self.span_id)
+    }
+
+    /// Whether the caller asked for this trace to be recorded.
+    pub fn is_sampled(&self) -> bool {
+        self.flags & SAMPLED_FLAG != 0
+    }
+
+    /// Vendor trace state received with the context, if any.
+    pub fn tracestate(&self) -> Option<&str> {
+        self.tracestate.as_deref()
+    }
+
+    /// Format as a version `00` `traceparent` header value.
+    pub fn traceparent(&self) -> String {
+        format!("00-{}-{}-{:02x}", self.trace_id(), self.span_id(), self.flags)
+    }
+
+    /// Write `traceparent` and `tracestate` into outgoing gRPC metadata,
+    /// making this span the parent of the downstream call.
+    pub fn inject(&self, metadata: &mut tonic::metadata::MetadataMap) {
+        if let Ok(traceparent) = self.traceparent().parse() {
+            metadata.insert(TRACEPARENT_HEADER, traceparent);
+        }
+        if let Some(tracestate) = self.tracestate.as_deref().and_then(|value| value.parse().ok()) {
+            metadata.insert(TRACESTATE_HEADER, tracestate);
+        }
+    }
+}
+
+fn random_non_zero<const N: usize>() -> [u8; N] {
+    loop {
+        let bytes: [u8; N] = rand::random();
+        if bytes != [0; N] {
+            return bytes;
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parse exactly `N` bytes of lowercase hex; the specification rejects uppercase.
+fn parse_hex<const N: usize>(value: &str) -> Option<[u8; N]> {
+    if value.len() != N * 2 || !value.bytes().all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+
+    let mut bytes = [0; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Tower layer opening a `grpc_request` span linked to the caller's trace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TraceContextLayer;
+
+impl<S> tower::Layer<S> for TraceContextLayer {
+    type Service = TraceContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceContextService { inner }
+    }
+}
+
+/// Service produced by [`TraceContextLayer`].
+#[derive(Debug, Clone)]
+pub struct TraceContextService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> tower::Service<http::Request<ReqBody>> for TraceContextService<S>
+where
+    S: tower::Service<http::Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = tracing::instrument::Instrumented<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: http::Request<ReqBody>) -> Self::Future {
+        let parent = TraceContext::from_headers(request.headers());
+        let context = parent.as_ref().map_or_else(TraceContext::root, TraceContext::child);
+
+        let span = tracing::info_span!(
+            "grpc_request",
+            method = %request.uri().path(),
+            trace_id = %context.trace_id(),
+            span_id = %context.span_id(),
+            parent_span_id = tracing::field::Empty,
+        );
+        if let Some(parent) = &parent {
+            span.record("parent_span_id", tracing::field::display(parent.span_id()));
+        }
+
+        request.extensions_mut().insert(context);
+        let future = span.in_scope(|| self.inner.call(request));
+        future.instrument(span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::{Layer, ServiceExt};
+    use tracing_test::traced_test;
+
+    const TRACEPARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    fn request(headers: &[(&str, &str)]) -> http::Request<()> {
+        let mut builder = http::Request::builder().uri("/personal_ledger.CategoriesService/CategoryGet");
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_round_trips_traceparent() {
+        let context = TraceContext::parse(TRACEPARENT).unwrap();
+        assert_eq!(context.trace_id(), "0af7651916cd43dd8448eb211c80319c");
+        assert_eq!(context.span_id(), "b7ad6b7169203331");
+        assert!(context.is_sampled());
+        assert_eq!(context.traceparent(), TRACEPARENT);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_traceparent() {
+        for traceparent in [
+            "",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331",
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra",
+            "ff-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01",
+            "00-00000000000000000000000000000000-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319c-0000000000000000-01",
+            "00-0AF7651916CD43DD8448EB211C80319C-b7ad6b7169203331-01",
+            "00-0af7651916cd43dd8448eb211c80319-b7ad6b7169203331-01",
+        ] {
+            assert_eq!(TraceContext::parse(traceparent), None, "{:?}", traceparent);
+        }
+
+        // Future versions may carry extra fields
+        assert!(TraceContext::parse("01-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01-extra").is_some());
+    }
+
+    #[test]
+    fn test_child_keeps_trace_and_state() {
+        let headers = request(&[(TRACEPARENT_HEADER, TRACEPARENT), (TRACESTATE_HEADER, "vendor=value")]);
+        let parent = TraceContext::from_headers(headers.headers()).unwrap();
+        let child = parent.child();
+
+        assert_eq!(child.trace_id(), parent.trace_id());
+        assert_ne!(child.span_id(), parent.span_id());
+        assert_eq!(child.tracestate(), Some("vendor=value"));
+    }
+
+    #[test]
+    fn test_inject_writes_outgoing_metadata() {
+        let headers = request(&[(TRACEPARENT_HEADER, TRACEPARENT), (TRACESTATE_HEADER, "vendor=value")]);
+        let context = TraceContext::from_headers(headers.headers()).unwrap().child();
+
+        let mut metadata = tonic::metadata::MetadataMap::new();
+        context.inject(&mut metadata);
+
+        let traceparent = metadata.get(TRACEPARENT_HEADER).unwrap().to_str().unwrap();
+        assert_eq!(TraceContext::parse(traceparent).unwrap().span_id(), context.span_id());
+        assert_eq!(metadata.get(TRACESTATE_HEADER).unwrap(), "vendor=value");
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_span_carries_propagated_trace_id() {
+        let service = TraceContextLayer.layer(tower::service_fn(|request: http::Request<()>| async move {
+            tracing::info!("handling request");
+            let context = request.extensions().get::<TraceContext>().cloned().unwrap();
+            Ok::<_, std::convert::Infallible>(context)
+        }));
+
+        let context = service.oneshot(request(&[(TRACEPARENT_HEADER, TRACEPARENT)])).await.unwrap();
+
+        assert_eq!(context.trace_id(), "0af7651916cd43dd8448eb211c80319c");
+        assert!(logs_contain("grpc_request{"));
+        assert!(logs_contain("trace_id=0af7651916cd43dd8448eb211c80319c"));
+        assert!(logs_contain("parent_span_id=b7ad6b7169203331"));
+        assert!(logs_contain(&format!("span_id={}", context.span_id())));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_without_traceparent_starts_new_trace() {
+        let service = TraceContextLayer.layer(tower::service_fn(|request: http::Request<()>| async move {
+            tracing::info!("handling request");
+            Ok::<_, std::convert::Infallible>(request.extensions().get::<TraceContext>().cloned().unwrap())
+        }));
+
+        let context = service.oneshot(request(&[(TRACEPARENT_HEADER, "garbage")])).await.unwrap();
+
+        assert!(context.is_sampled());
+        assert!(logs_contain(&format!("trace_id={}", context.trace_id())));
+        assert!(!logs_contain("parent_span_id="));
+    }
+}
@@ -0,0 +1,128 @@
+//! # Transport Tuning
+//!
+//! This module turns the connection settings in [`ServerConfig`] into tonic
+//! transport options, so idle or slow peers cannot tie up server resources:
+//!
+//! - HTTP/2 initial connection and stream flow control windows
+//! - HTTP/2 keepalive pings that close connections whose peer stops answering
+//!
+//! [`TransportSettings`] is computed and validated once at startup and then
+//! applied to the tonic `Server` builder.
+
+use tonic::transport::Server;
+
+use crate::config::{ConfigError, ServerConfig};
+use crate::LedgerResult;
+
+/// Largest flow control window HTTP/2 allows, `2^31 - 1` bytes.
+pub const MAX_HTTP2_WINDOW_SIZE: u32 = (1 << 31) - 1;
+
+/// Connection settings applied to the tonic `Server` builder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransportSettings {
+    /// Initial HTTP/2 connection window, or the transport default.
+    pub initial_connection_window_size: Option<u32>,
+
+    /// Initial HTTP/2 stream window, or the transport default.
+    pub initial_stream_window_size: Option<u32>,
+
+    /// Keepalive ping interval and acknowledgement timeout, or no pings.
+    pub idle_timeout: Option<std::time::Duration>,
+}
+
+impl TransportSettings {
+    /// Read and validate the transport settings from the server configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::Validation`] (as a `LedgerError::Config`) if a
+    /// window size is `0` or above [`MAX_HTTP2_WINDOW_SIZE`], or the idle
+    /// timeout is `0`.
+    pub fn from_config(server_config: &ServerConfig) -> LedgerResult<Self> {
+        for (name, size) in [
+            ("http2_initial_connection_window_size", server_config.http2_initial_connection_window_size),
+            ("http2_initial_stream_window_size", server_config.http2_initial_stream_window_size),
+        ] {
+            if let Some(size) = size
+                && !(1..=MAX_HTTP2_WINDOW_SIZE).contains(&size)
+            {
+                return Err(ConfigError::Validation(format!(
+                    "{} must be between 1 and {} bytes, got {}",
+                    name, MAX_HTTP2_WINDOW_SIZE, size
+                ))
+                .into());
+            }
+        }
+
+        if server_config.connection_idle_timeout_secs == Some(0) {
+            return Err(ConfigError::Validation("connection_idle_timeout_secs must be greater than 0".to_string()).into());
+        }
+
+        Ok(Self {
+            initial_connection_window_size: server_config.http2_initial_connection_window_size,
+            initial_stream_window_size: server_config.http2_initial_stream_window_size,
+            idle_timeout: server_config.connection_idle_timeout_secs.map(std::time::Duration::from_secs),
+        })
+    }
+
+    /// Apply the settings to a tonic server builder.
+    pub fn apply<L>(&self, server: Server<L>) -> Server<L> {
+        server
+            .initial_connection_window_size(self.initial_connection_window_size)
+            .initial_stream_window_size(self.initial_stream_window_size)
+            .http2_keepalive_interval(self.idle_timeout)
+            .http2_keepalive_timeout(self.idle_timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_keeps_transport_defaults() {
+        let settings = TransportSettings::from_config(&ServerConfig::default()).unwrap();
+        assert_eq!(settings, TransportSettings::default());
+    }
+
+    #[test]
+    fn test_configured_values_are_applied() {
+        let config = ServerConfig {
+            http2_initial_connection_window_size: Some(4 * 1024 * 1024),
+            http2_initial_stream_window_size: Some(1024 * 1024),
+            connection_idle_timeout_secs: Some(30),
+            ..ServerConfig::default()
+        };
+
+        let settings = TransportSettings::from_config(&config).unwrap();
+        assert_eq!(
+            settings,
+            TransportSettings {
+                initial_connection_window_size: Some(4 * 1024 * 1024),
+                initial_stream_window_size: Some(1024 * 1024),
+                idle_timeout: Some(std::time::Duration::from_secs(30)),
+            }
+        );
+
+        // The builder accepts the settings
+        let _server = settings.apply(Server::builder());
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let invalid = [
+            ServerConfig { http2_initial_connection_window_size: Some(0), ..ServerConfig::default() },
+            ServerConfig { http2_initial_stream_window_size: Some(MAX_HTTP2_WINDOW_SIZE + 1), ..ServerConfig::default() },
+            ServerConfig { connection_idle_timeout_secs: Some(0), ..ServerConfig::default() },
+        ];
+
+        for config in invalid {
+            let result = TransportSettings::from_config(&config);
+            assert!(
+                matches!(result, Err(crate::LedgerError::Config(ConfigError::Validation(_)))),
+                "{:?}",
+                result
+            );
+        }
+    }
+}
@@ -6,7 +6,7 @@
 //! - Activating a category by setting is_active = true
 //! - Proper error handling for not found cases and database errors
 
-use crate::{database, rpc};
+use crate::{database, rpc, services};
 
 /// Handle the category activation logic for the gRPC service.
 ///
@@ -42,16 +42,19 @@ pub async fn activate_category(
     let updated_category = match database::Categories::update_active_status(category_id, true, service.database_ref()).await {
         Ok(category) => category,
         Err(database::DatabaseError::NotFound(_)) => {
-            return Err(tonic::Status::not_found(format!("Category with ID '{}' not found", activate_request.id)));
+            return Err(services::not_found_status("Category", "ID", &activate_request.id));
         }
         Err(db_error) => {
             tracing::error!("Failed to activate category {}: {}", activate_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to activate category"));
+            return Err(services::database_error_status(&db_error, "Failed to activate category"));
         }
     };
 
+    service.category_cache().invalidate(updated_category.id);
+    service.publish_event(services::EventKind::Updated, updated_category.id.to_string());
+
     // Convert to RPC category and return response
-    let rpc_category: rpc::Category = updated_category.into();
+    let rpc_category = service.localize(updated_category.into());
     let response = rpc::CategoryActivateResponse {
         category: Some(rpc_category),
     };
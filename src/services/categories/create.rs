@@ -6,91 +6,245 @@
 //! - Conversion from gRPC `CategoryCreateRequest` to domain/database `Category`
 //! - Validation and error handling for all fields
 //! - The async service handlers for single and batch category creation, used by the gRPC service
+//! - A validate-only batch handler reporting every invalid field, not just the first
+//! - Keeping a client-supplied id when `ServerConfig.accept_client_ids` is set
 //! - Comprehensive unit tests for all conversion and validation logic
 //!
 //! The core business logic is abstracted here to keep the gRPC service layer clean
 //! and to enable easier testing and maintenance.
 
-use crate::{database, domain, rpc, services::ServiceError};
+use crate::{database, domain, rpc, services::{self, ServiceError}};
 use chrono::Utc;
 
 /// Convert a gRPC `CategoryCreateRequest` into a domain/database `Category`.
 ///
 /// This implementation performs all necessary validation and conversion of fields,
 /// ensuring that required fields are present and valid, and that optional fields
-/// are handled correctly. Any validation or parsing errors are returned as a
-/// `ServiceError`.
+/// are normalised as described in [`services::normalize_optional`]. A missing colour
+/// or icon takes the category type's default. Any validation or parsing errors are
+/// returned as a `ServiceError`.
+///
+/// An unknown category type is an error; see [`category_from_create_request`]
+/// to apply the server's `strict_enums` setting instead.
 impl TryFrom<rpc::CategoryCreateRequest> for database::Categories {
     type Error = ServiceError;
 
     fn try_from(value: rpc::CategoryCreateRequest) -> Result<Self, Self::Error> {
-        let category = value.category.ok_or_else(|| ServiceError::validation(
-            "Category field is required in CategoryCreateRequest"
-        ))?;
+        category_from_create_request(value, true)
+    }
+}
 
-        // Generate new ID for the category
-        let id = domain::RowID::new();
+/// Convert a gRPC `CategoryCreateRequest` into a database category, as the
+/// [`TryFrom`] conversion does.
+///
+/// # Arguments
+/// * `value` - The incoming create request
+/// * `strict_enums` - Whether an unknown category type is an error, see [`services::parse_enum_i32`]
+///
+/// # Errors
+/// Returns a validation error for the first invalid field.
+pub fn category_from_create_request(
+    value: rpc::CategoryCreateRequest,
+    strict_enums: bool,
+) -> Result<database::Categories, ServiceError> {
+    let category = value.category.ok_or_else(|| ServiceError::validation(
+        "Category field is required in CategoryCreateRequest"
+    ))?;
+
+    // Generate new ID for the category
+    let id = domain::RowID::new();
+
+    // Validate and parse required fields
+    let code = if category.code.trim().is_empty() {
+        return Err(ServiceError::rule_violation(services::REASON_CODE_REQUIRED, "Category code is required and cannot be empty"));
+    } else {
+        category.code
+    };
 
-        // Validate and parse required fields
-        let code = if category.code.trim().is_empty() {
-            return Err(ServiceError::validation("Category code is required and cannot be empty"));
-        } else {
-            category.code
-        };
+    let name = if category.name.trim().is_empty() {
+        return Err(ServiceError::rule_violation(services::REASON_NAME_REQUIRED, "Category name is required and cannot be empty"));
+    } else {
+        category.name
+    };
 
-        let name = if category.name.trim().is_empty() {
-            return Err(ServiceError::validation("Category name is required and cannot be empty"));
-        } else {
-            category.name
-        };
+    // Description is free text, so only a blank value is dropped
+    let description = services::normalize_optional_text(category.description);
+
+    // Parse optional URL slug
+    let url_slug = services::normalize_optional(category.url_slug)
+        .map(domain::UrlSlug::parse)
+        .transpose()?;
+
+    // Parse category type
+    let category_type: domain::CategoryTypes = services::parse_enum_i32(category.category_type, strict_enums)?;
+
+    // Parse optional color, falling back to the category type's default
+    let color = services::normalize_optional(category.color)
+        .map(domain::HexColor::parse)
+        .transpose()?
+        .unwrap_or_else(|| category_type.default_color());
+
+    let icon = services::normalize_optional(category.icon)
+        .unwrap_or_else(|| category_type.default_icon().to_string());
+
+    // Default to active for new categories
+    let is_active = category.is_active;
+
+    // Set timestamps
+    let created_on = Utc::now();
+    let updated_on = created_on;
+
+    Ok(database::Categories {
+        id,
+        code,
+        name,
+        description,
+        url_slug,
+        category_type,
+        color: Some(color),
+        icon: Some(icon),
+        is_active,
+        display_order: category.display_order,
+        external_id: None,
+        reactivate_on: None,
+        parent_id: None,
+        created_on,
+        updated_on,
+        deleted_on: None,
+    })
+}
 
-        // Parse optional description
-        let description = category.description.filter(|s| !s.trim().is_empty());
+/// Parse the id a client sent with a new category.
+///
+/// # Arguments
+/// * `id` - The `id` field of the incoming category
+///
+/// # Returns
+/// * `Ok(None)` when `id` is blank, so the server generates one
+/// * `Ok(Some(id))` when `id` is a UUID v7
+///
+/// # Errors
+/// Returns a validation error if `id` is not a UUID v7.
+pub fn parse_client_id(id: &str) -> Result<Option<domain::RowID>, ServiceError> {
+    let id = id.trim();
+    if id.is_empty() {
+        return Ok(None);
+    }
 
-        // Parse optional URL slug
-        let url_slug = if let Some(slug) = category.url_slug.filter(|s| !s.trim().is_empty()) {
-            Some(domain::UrlSlug::parse(slug)?)
-        } else {
-            None
-        };
+    id.parse::<domain::RowID>()
+        .map(Some)
+        .map_err(|e| ServiceError::rule_violation(e.code(), format!("Invalid category id '{}': {}", id, e)))
+}
 
-        // Parse category type
-        let category_type = domain::CategoryTypes::from_rpc_i32(category.category_type)
-            .map_err(|e| ServiceError::validation(&e))?;
+/// Check that `adding` more categories stay within `max_categories`.
+///
+/// The count and the insert are separate statements, so concurrent creates
+/// can overshoot the cap by the size of the other requests.
+async fn ensure_capacity(service: &super::CategoriesService, adding: usize) -> Result<(), tonic::Status> {
+    let Some(max_categories) = service.max_categories() else {
+        return Ok(());
+    };
 
-        // Parse optional color
-        let color = if let Some(color_str) = category.color.filter(|s| !s.trim().is_empty()) {
-            Some(domain::HexColor::parse(color_str)?)
-        } else {
-            None
-        };
+    let stored = database::Categories::count(service.database_ref()).await.map_err(|db_error| {
+        tracing::error!("Failed to count categories: {}", db_error);
+        services::database_error_status(&db_error, "Failed to count categories")
+    })?;
+
+    if stored as usize + adding > max_categories {
+        return Err(tonic::Status::resource_exhausted(format!(
+            "Creating {} categories would exceed the limit of {} ({} stored)",
+            adding, max_categories, stored
+        )));
+    }
+
+    Ok(())
+}
+
+/// Replace the server-generated id of a new category with the client's.
+///
+/// Does nothing unless the server accepts client ids and `client_id` is not
+/// blank. A client id already in use is rejected with `AlreadyExists`.
+async fn assign_client_id(
+    service: &super::CategoriesService,
+    category: &mut database::Categories,
+    client_id: &str,
+) -> Result<(), tonic::Status> {
+    if !service.accept_client_ids() {
+        return Ok(());
+    }
 
-        // Parse optional icon
-        let icon = category.icon.filter(|s| !s.trim().is_empty());
-
-        // Default to active for new categories
-        let is_active = category.is_active;
-
-        // Set timestamps
-        let created_on = Utc::now();
-        let updated_on = created_on;
-
-        Ok(database::Categories {
-            id,
-            code,
-            name,
-            description,
-            url_slug,
-            category_type,
-            color,
-            icon,
-            is_active,
-            created_on,
-            updated_on,
-        })
+    let Some(id) = parse_client_id(client_id).map_err(|e| tonic::Status::invalid_argument(e.to_string()))? else {
+        return Ok(());
+    };
+
+    match database::Categories::find_by_id(id, service.database_ref()).await {
+        Ok(None) => {
+            category.id = id;
+            Ok(())
+        }
+        Ok(Some(_)) => Err(tonic::Status::already_exists(format!("Category with ID '{}' already exists", id))),
+        Err(db_error) => {
+            tracing::error!("Failed to check category id {}: {}", id, db_error);
+            Err(services::database_error_status(&db_error, "Failed to create category"))
+        }
     }
 }
 
+/// Reject a new category whose code is already used by another category.
+///
+/// Soft-deleted categories keep their code, as the unique constraint on
+/// `code` covers every row. The constraint still backs this up for
+/// concurrent creates, but failing here lets clients tell a duplicate apart
+/// from a real failure.
+async fn ensure_code_available(service: &super::CategoriesService, code: &str) -> Result<(), tonic::Status> {
+    match database::Categories::code_in_use(code, service.database_ref()).await {
+        Ok(false) => Ok(()),
+        Ok(true) => Err(tonic::Status::already_exists(format!("Category with code '{}' already exists", code))),
+        Err(db_error) => {
+            tracing::error!("Failed to check category code {}: {}", code, db_error);
+            Err(services::database_error_status(&db_error, "Failed to create category"))
+        }
+    }
+}
+
+/// Check every field of a category, without stopping at the first error.
+///
+/// This applies the same rules as the conversion into a database category,
+/// but collects a `(field, message)` pair for each invalid field so a client
+/// can fix them all at once.
+///
+/// # Arguments
+/// * `category` - The category to check
+/// * `strict_enums` - Whether an unknown category type is an error, see [`services::parse_enum_i32`]
+///
+/// # Returns
+/// * The invalid fields and why, in field order. Empty when the category is valid.
+pub fn category_field_errors(category: &rpc::Category, strict_enums: bool) -> Vec<(&'static str, String)> {
+    let mut errors = Vec::new();
+
+    if category.code.trim().is_empty() {
+        errors.push(("code", "Category code is required and cannot be empty".to_string()));
+    }
+
+    if category.name.trim().is_empty() {
+        errors.push(("name", "Category name is required and cannot be empty".to_string()));
+    }
+
+    if let Some(Err(e)) = services::normalize_optional(category.url_slug.clone()).map(domain::UrlSlug::parse) {
+        errors.push(("url_slug", e.to_string()));
+    }
+
+    if let Err(e) = services::parse_enum_i32::<domain::CategoryTypes>(category.category_type, strict_enums) {
+        errors.push(("category_type", e.to_string()));
+    }
+
+    if let Some(Err(e)) = services::normalize_optional(category.color.clone()).map(domain::HexColor::parse) {
+        errors.push(("color", e.to_string()));
+    }
+
+    errors
+}
+
 /// Handle the category creation logic for the gRPC service.
 ///
 /// This function performs:
@@ -112,36 +266,41 @@ pub async fn create_category(
 ) -> Result<tonic::Response<rpc::CategoryCreateResponse>, tonic::Status> {
     // Extract the inner request
     let create_request = request.into_inner();
+    let client_id = create_request.category.as_ref().map(|category| category.id.clone()).unwrap_or_default();
 
     // Convert the request to a database category
-    let category = match database::Categories::try_from(create_request) {
+    let mut category = match category_from_create_request(create_request, service.strict_enums()) {
         Ok(category) => category,
         Err(service_error) => {
-            // Convert ServiceError to tonic::Status
-            let status_code = match service_error.http_status_code() {
-                400 => tonic::Code::InvalidArgument,
-                401 => tonic::Code::Unauthenticated,
-                404 => tonic::Code::NotFound,
-                422 => tonic::Code::FailedPrecondition,
-                500 => tonic::Code::Internal,
-                502 => tonic::Code::Unavailable,
-                _ => tonic::Code::Internal,
-            };
-            return Err(tonic::Status::new(status_code, service_error.to_string()));
+            return Err(service_error.to_status());
         }
     };
 
+    assign_client_id(service, &mut category, &client_id).await?;
+    ensure_code_available(service, &category.code).await?;
+    ensure_capacity(service, 1).await?;
+
     // Insert the category into the database
     let inserted_category = match category.insert(service.database_ref()).await {
         Ok(category) => category,
+        Err(db_error) if db_error.is_unique_violation() => {
+            tracing::warn!("Category conflicts with an existing category: {}", db_error);
+            return Err(tonic::Status::already_exists(format!(
+                "Category '{}' conflicts with an existing category: code, name, url_slug and external_id must be unique",
+                category.code
+            )));
+        }
         Err(db_error) => {
             tracing::error!("Failed to insert category: {}", db_error);
-            return Err(tonic::Status::internal("Failed to create category"));
+            return Err(services::database_error_status(&db_error, "Failed to create category"));
         }
     };
 
+    service.category_cache().invalidate(inserted_category.id);
+    service.publish_event(services::EventKind::Created, inserted_category.id.to_string());
+
     // Convert the database category back to RPC format
-    let rpc_category: rpc::Category = inserted_category.into();
+    let rpc_category = service.localize(inserted_category.into());
 
     // Create the response
     let response = rpc::CategoryCreateResponse {
@@ -191,45 +350,71 @@ pub async fn create_batch_categories(
     // Convert each RPC category to database category
     let mut db_categories = Vec::with_capacity(batch_request.categories.len());
 
+    let mut client_ids = std::collections::HashSet::new();
+
     for (index, rpc_category) in batch_request.categories.into_iter().enumerate() {
+        let client_id = rpc_category.id.clone();
+
         // Create a CategoryCreateRequest for each category
         let create_request = rpc::CategoryCreateRequest {
             category: Some(rpc_category),
         };
 
-        // Convert to database category using the existing TryFrom implementation
-        match database::Categories::try_from(create_request) {
-            Ok(db_category) => db_categories.push(db_category),
+        // Convert to database category as a single create would
+        match category_from_create_request(create_request, service.strict_enums()) {
+            Ok(mut db_category) => {
+                assign_client_id(service, &mut db_category, &client_id)
+                    .await
+                    .map_err(|status| tonic::Status::new(status.code(), format!("Category at index {}: {}", index, status.message())))?;
+
+                // Generated ids are unique, so only a repeated client id can collide
+                if !client_ids.insert(db_category.id) {
+                    return Err(tonic::Status::already_exists(format!(
+                        "Category at index {}: Category with ID '{}' appears more than once in the batch",
+                        index, db_category.id
+                    )));
+                }
+                db_categories.push(db_category);
+            }
             Err(service_error) => {
                 // Include the index in the error message for better debugging
                 let error_msg = format!("Category at index {}: {}", index, service_error);
-                let status_code = match service_error.http_status_code() {
-                    400 => tonic::Code::InvalidArgument,
-                    401 => tonic::Code::Unauthenticated,
-                    404 => tonic::Code::NotFound,
-                    422 => tonic::Code::FailedPrecondition,
-                    500 => tonic::Code::Internal,
-                    502 => tonic::Code::Unavailable,
-                    _ => tonic::Code::Internal,
-                };
-                return Err(tonic::Status::new(status_code, error_msg));
+                return Err(service_error.to_status_with_message(error_msg));
             }
         }
     }
 
-    // Insert all categories in a batch using the database's insert_many method
-    let inserted_categories = match database::Categories::insert_many(&db_categories, service.database_ref()).await {
+    // Reject the whole batch if it does not fit under the cap
+    ensure_capacity(service, db_categories.len()).await?;
+
+    // Insert all categories in one transaction within the configured time budget
+    let inserted_categories = match database::Categories::insert_many_with_limits(
+        &db_categories,
+        service.batch_limits(),
+        service.database_ref(),
+    )
+    .await
+    {
         Ok(categories) => categories,
+        Err(database::DatabaseError::Timeout(msg)) => {
+            tracing::error!("Batch insert of categories timed out: {}", msg);
+            return Err(tonic::Status::deadline_exceeded(msg));
+        }
         Err(db_error) => {
             tracing::error!("Failed to batch insert categories: {}", db_error);
-            return Err(tonic::Status::internal("Failed to create categories in batch"));
+            return Err(services::database_error_status(&db_error, "Failed to create categories in batch"));
         }
     };
 
+    for category in &inserted_categories {
+        service.category_cache().invalidate(category.id);
+        service.publish_event(services::EventKind::Created, category.id.to_string());
+    }
+
     // Convert database categories back to RPC format
     let rpc_categories: Vec<rpc::Category> = inserted_categories
         .into_iter()
-        .map(|category| category.into())
+        .map(|category| service.localize(category.into()))
         .collect();
 
     // Create the response
@@ -241,6 +426,55 @@ pub async fn create_batch_categories(
     Ok(tonic::Response::new(response))
 }
 
+/// Handle the validate-only batch logic for the gRPC service.
+///
+/// This function performs:
+/// - Checking every field of every category in the batch
+/// - Collecting all errors with their index, rather than stopping at the first
+///
+/// Nothing is written to the database, and checks that need it, such as
+/// unique codes, are left to the create call.
+///
+/// # Arguments
+/// * `_service` - Reference to the `CategoriesService`
+/// * `request` - The incoming gRPC batch validate request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoriesValidateBatchResponse>)` with every error found
+/// * `Err(tonic::Status)` if the batch is empty
+pub async fn validate_batch_categories(
+    service: &super::CategoriesService,
+    request: tonic::Request<rpc::CategoriesValidateBatchRequest>,
+) -> Result<tonic::Response<rpc::CategoriesValidateBatchResponse>, tonic::Status> {
+    // Extract the inner request
+    let validate_request = request.into_inner();
+
+    // Validate that we have categories to check
+    if validate_request.categories.is_empty() {
+        return Err(tonic::Status::invalid_argument("No categories provided for batch validation"));
+    }
+
+    let mut errors = Vec::new();
+    let mut valid_count = 0;
+
+    for (index, rpc_category) in validate_request.categories.iter().enumerate() {
+        let field_errors = category_field_errors(rpc_category, service.strict_enums());
+        if field_errors.is_empty() {
+            valid_count += 1;
+        }
+
+        errors.extend(field_errors.into_iter().map(|(field, message)| rpc::CategoryValidationError {
+            index: index as i32,
+            field: field.to_string(),
+            message,
+        }));
+    }
+
+    // Create the response
+    let response = rpc::CategoriesValidateBatchResponse { errors, valid_count };
+
+    Ok(tonic::Response::new(response))
+}
 
 #[cfg(test)]
 mod tests {
@@ -261,8 +495,11 @@ mod tests {
                 color: Some("#FF5733".to_string()),
                 icon: Some("shopping-cart".to_string()),
                 is_active: true,
+                display_order: 0,
                 created_on: None, // Will be ignored
                 updated_on: None, // Will be ignored
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
@@ -294,8 +531,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: false,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
@@ -308,8 +548,8 @@ mod tests {
         assert!(category.description.is_none());
         assert!(category.url_slug.is_none());
         assert_eq!(category.category_type, domain::CategoryTypes::Asset);
-        assert!(category.color.is_none());
-        assert!(category.icon.is_none());
+        assert_eq!(category.color, Some(domain::CategoryTypes::Asset.default_color()));
+        assert_eq!(category.icon.as_deref(), Some(domain::CategoryTypes::Asset.default_icon()));
         assert!(!category.is_active);
     }
 
@@ -322,7 +562,7 @@ mod tests {
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test error handling for empty code field
@@ -339,14 +579,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test error handling for whitespace-only name field
@@ -363,14 +606,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test error handling for invalid category type values
@@ -387,14 +633,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test error handling for invalid URL slug values
@@ -411,14 +660,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test error handling for invalid hex color values
@@ -435,14 +687,17 @@ mod tests {
                 color: Some("INVALID".to_string()),
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test that empty/whitespace-only optional fields become None
@@ -459,8 +714,11 @@ mod tests {
                 color: Some("".to_string()), // Empty string should become None
                 icon: Some("   ".to_string()), // Whitespace should become None
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
@@ -470,8 +728,9 @@ mod tests {
         let category = result.unwrap();
         assert!(category.description.is_none());
         assert!(category.url_slug.is_none());
-        assert!(category.color.is_none());
-        assert!(category.icon.is_none());
+        // Blank colour and icon fall back to the type's defaults
+        assert_eq!(category.color, Some(domain::CategoryTypes::Expense.default_color()));
+        assert_eq!(category.icon.as_deref(), Some(domain::CategoryTypes::Expense.default_icon()));
     }
 
     /// Test error handling for whitespace-only code field
@@ -488,14 +747,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test conversion for all valid category type enum values
@@ -521,8 +783,11 @@ mod tests {
                     color: None,
                     icon: None,
                     is_active: true,
+                    display_order: 0,
                     created_on: None,
                     updated_on: None,
+                    created_on_local: None,
+                    updated_on_local: None,
                 }),
             };
 
@@ -549,14 +814,17 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
         let result = database::Categories::try_from(request);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ServiceError::Validation(_)));
+        assert!(result.unwrap_err().is_validation());
     }
 
     /// Test hex color validation for valid and invalid formats
@@ -578,8 +846,11 @@ mod tests {
                     color: Some(color.to_string()),
                     icon: None,
                     is_active: true,
+                    display_order: 0,
                     created_on: None,
                     updated_on: None,
+                    created_on_local: None,
+                    updated_on_local: None,
                 }),
             };
 
@@ -603,8 +874,11 @@ mod tests {
                     color: Some(color.to_string()),
                     icon: None,
                     is_active: true,
+                    display_order: 0,
                     created_on: None,
                     updated_on: None,
+                    created_on_local: None,
+                    updated_on_local: None,
                 }),
             };
 
@@ -627,8 +901,11 @@ mod tests {
                 color: Some("  \n\t  ".to_string()), // Mixed whitespace
                 icon: Some("\t\r\n".to_string()), // Mixed whitespace
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
@@ -638,8 +915,8 @@ mod tests {
         let category = result.unwrap();
         assert!(category.description.is_none(), "Mixed whitespace description should become None");
         assert!(category.url_slug.is_none(), "Mixed whitespace URL slug should become None");
-        assert!(category.color.is_none(), "Mixed whitespace color should become None");
-        assert!(category.icon.is_none(), "Mixed whitespace icon should become None");
+        assert_eq!(category.color, Some(domain::CategoryTypes::Expense.default_color()), "Mixed whitespace color should use the default");
+        assert_eq!(category.icon.as_deref(), Some(domain::CategoryTypes::Expense.default_icon()), "Mixed whitespace icon should use the default");
     }
 
     /// Test that non-empty optional fields with whitespace are preserved correctly
@@ -654,10 +931,13 @@ mod tests {
                 url_slug: Some("  valid-slug  ".to_string()), // Leading/trailing whitespace preserved
                 category_type: rpc::CategoryTypes::Expense as i32,
                 color: Some("#FF5733".to_string()),
-                icon: Some("  shopping-cart  ".to_string()), // Leading/trailing whitespace preserved
+                icon: Some("  shopping-cart  ".to_string()), // Leading/trailing whitespace trimmed
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             }),
         };
 
@@ -668,7 +948,59 @@ mod tests {
         assert_eq!(category.description, Some("  Valid description  ".to_string()));
         assert_eq!(category.url_slug.as_ref().unwrap().as_str(), "valid-slug"); // Slug gets cleaned
         assert_eq!(category.color.as_ref().unwrap().as_str(), "#FF5733");
-        assert_eq!(category.icon, Some("  shopping-cart  ".to_string()));
+        assert_eq!(category.icon, Some("shopping-cart".to_string()));
+    }
+
+    /// Pin the normalisation policy for every optional field
+    #[test]
+    fn test_try_from_normalizes_each_optional_field() {
+        fn convert(description: &str, url_slug: &str, color: &str, icon: &str) -> database::Categories {
+            let request = rpc::CategoryCreateRequest {
+                category: Some(rpc::Category {
+                    code: "NORM".to_string(),
+                    name: "Normalised".to_string(),
+                    description: Some(description.to_string()),
+                    url_slug: Some(url_slug.to_string()),
+                    category_type: rpc::CategoryTypes::Expense as i32,
+                    color: Some(color.to_string()),
+                    icon: Some(icon.to_string()),
+                    ..Default::default()
+                }),
+            };
+            database::Categories::try_from(request).expect("conversion should succeed")
+        }
+
+        // Blank values are absent for every field, and colour and icon take
+        // the category type's defaults
+        for blank in ["", " ", "\t\r\n"] {
+            let category = convert(blank, blank, blank, blank);
+            assert_eq!(category.description, None);
+            assert_eq!(category.url_slug, None);
+            assert_eq!(category.color, Some(domain::CategoryTypes::Expense.default_color()));
+            assert_eq!(category.icon.as_deref(), Some(domain::CategoryTypes::Expense.default_icon()));
+        }
+
+        let category = convert(" Two\n lines ", " groceries ", " #00ff00 ", " cart ");
+        // Description is the free text exception and is kept as sent
+        assert_eq!(category.description.as_deref(), Some(" Two\n lines "));
+        assert_eq!(category.url_slug.as_ref().map(|s| s.as_str()), Some("groceries"));
+        assert_eq!(category.color.as_ref().map(|c| c.as_str()), Some("#00FF00"));
+        assert_eq!(category.icon.as_deref(), Some("cart"));
+
+        // Absent values stay absent, except colour and icon which take defaults
+        let request = rpc::CategoryCreateRequest {
+            category: Some(rpc::Category {
+                code: "NORM".to_string(),
+                name: "Normalised".to_string(),
+                category_type: rpc::CategoryTypes::Expense as i32,
+                ..Default::default()
+            }),
+        };
+        let category = database::Categories::try_from(request).unwrap();
+        assert_eq!(category.description, None);
+        assert_eq!(category.url_slug, None);
+        assert_eq!(category.color, Some(domain::CategoryTypes::Expense.default_color()));
+        assert_eq!(category.icon.as_deref(), Some(domain::CategoryTypes::Expense.default_icon()));
     }
 
     /// Test successful batch creation with multiple valid categories
@@ -687,8 +1019,11 @@ mod tests {
                 color: Some("#FF5733".to_string()),
                 icon: Some("utensils".to_string()),
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
             rpc::Category {
                 id: "".to_string(),
@@ -700,8 +1035,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
         ];
 
@@ -739,8 +1077,11 @@ mod tests {
                 color: Some("#4A90E2".to_string()),
                 icon: Some("bolt".to_string()),
                 is_active: false, // Test inactive category
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
         ];
 
@@ -767,8 +1108,11 @@ mod tests {
                 color: Some("#FF5733".to_string()),
                 icon: Some("utensils".to_string()),
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
             rpc::Category {
                 id: "".to_string(),
@@ -780,8 +1124,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
         ];
 
@@ -822,8 +1169,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
             rpc::Category {
                 id: "".to_string(),
@@ -835,8 +1185,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
             rpc::Category {
                 id: "".to_string(),
@@ -848,8 +1201,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
         ];
 
@@ -872,7 +1228,7 @@ mod tests {
 
         assert_eq!(errors.len(), 1, "Expected one error");
         assert_eq!(errors[0].0, 1, "Error should be at index 1");
-        assert!(matches!(errors[0].1, ServiceError::Validation(_)));
+        assert!(errors[0].1.is_validation());
         assert_eq!(db_categories.len(), 1, "Only first valid category should be converted");
         assert_eq!(db_categories[0].code, "VALID");
     }
@@ -891,8 +1247,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
             rpc::Category {
                 id: "".to_string(),
@@ -904,8 +1263,11 @@ mod tests {
                 color: None,
                 icon: None,
                 is_active: true,
+                display_order: 0,
                 created_on: None,
                 updated_on: None,
+                created_on_local: None,
+                updated_on_local: None,
             },
         ];
 
@@ -928,7 +1290,60 @@ mod tests {
 
         assert_eq!(errors.len(), 1, "Expected one error on first invalid");
         assert_eq!(errors[0].0, 0, "Error should be at index 0");
-        assert!(matches!(errors[0].1, ServiceError::Validation(_)));
+        assert!(errors[0].1.is_validation());
         assert!(db_categories.is_empty(), "No categories should be converted");
     }
-}
\ No newline at end of file
+
+    /// Test that every invalid field of a category is reported, not just the first
+    #[test]
+    fn test_field_errors_reports_every_invalid_field() {
+        let category = rpc::Category {
+            code: " ".to_string(),
+            name: "".to_string(),
+            url_slug: Some("!!!".to_string()),
+            category_type: 999,
+            color: Some("blue".to_string()),
+            ..Default::default()
+        };
+
+        let fields: Vec<&str> = category_field_errors(&category, true).into_iter().map(|(field, _)| field).collect();
+        assert_eq!(fields, vec!["code", "name", "url_slug", "category_type", "color"]);
+    }
+
+    /// Test that field checks agree with the create conversion
+    #[test]
+    fn test_field_errors_agree_with_conversion() {
+        let valid = rpc::Category {
+            code: "VALID".to_string(),
+            name: "Valid Category".to_string(),
+            url_slug: Some("valid-category".to_string()),
+            category_type: rpc::CategoryTypes::Expense as i32,
+            color: Some("#00aa00".to_string()),
+            ..Default::default()
+        };
+        let invalid_color = rpc::Category { color: Some("#GGGGGG".to_string()), ..valid.clone() };
+        let blank_slug = rpc::Category { url_slug: Some("  ".to_string()), ..valid.clone() };
+
+        for category in [valid, invalid_color, blank_slug] {
+            let converts = database::Categories::try_from(rpc::CategoryCreateRequest {
+                category: Some(category.clone()),
+            })
+            .is_ok();
+            assert_eq!(category_field_errors(&category, true).is_empty(), converts, "{:?}", category);
+        }
+    }
+
+    /// Test parsing client-supplied ids
+    #[test]
+    fn test_parse_client_id() {
+        assert_eq!(parse_client_id("").unwrap(), None);
+        assert_eq!(parse_client_id("   ").unwrap(), None);
+
+        let id = domain::RowID::new();
+        assert_eq!(parse_client_id(&format!(" {} ", id)).unwrap(), Some(id));
+
+        // Must be a UUID, and version 7
+        assert!(parse_client_id("not-a-uuid").unwrap_err().is_validation());
+        assert!(parse_client_id("550e8400-e29b-41d4-a716-446655440000").unwrap_err().is_validation());
+    }
+}
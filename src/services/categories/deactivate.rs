@@ -3,16 +3,19 @@
 //! This module provides the service logic for deactivating categories
 //! in the Personal Ledger backend. It includes:
 //!
-//! - Deactivating a category by setting is_active = false
+//! - Deactivating a category by setting is_active = false, found by id or code
+//! - Optionally scheduling the category to reactivate at `reactivate_on`
 //! - Proper error handling for not found cases and database errors
 
-use crate::{database, rpc};
+use crate::{database, rpc, services};
 
 /// Handle the category deactivation logic for the gRPC service.
 ///
 /// This function performs:
-/// - Parsing the ID from the request
-/// - Updating the category's active status to false in the database
+/// - Parsing the ID, or taking the code in its place, and the optional
+///   `reactivate_on` from the request
+/// - Updating the category's active status to false in the database, storing
+///   the reactivation date (a past date is rejected)
 /// - Converting the updated database category to gRPC response format
 /// - Proper error handling for not found cases and database errors
 ///
@@ -30,28 +33,75 @@ pub async fn deactivate_category(
     // Extract the inner request
     let deactivate_request = request.into_inner();
 
-    // Parse the ID from string to RowID
-    let category_id = match deactivate_request.id.parse::<crate::domain::RowID>() {
-        Ok(id) => id,
-        Err(_) => {
-            return Err(tonic::Status::invalid_argument("Invalid category ID format"));
+    // The category is named by its code or its ID, not both
+    let code = deactivate_request.code.as_deref();
+    if code.is_some() && !deactivate_request.id.is_empty() {
+        return Err(tonic::Status::invalid_argument("Set either id or code, not both"));
+    }
+
+    // Parse the ID from string to RowID, unless the code names the category
+    let target = match code {
+        Some(code) => DeactivateTarget::Code(code),
+        None => match deactivate_request.id.parse::<crate::domain::RowID>() {
+            Ok(id) => DeactivateTarget::Id(id),
+            Err(_) => {
+                return Err(tonic::Status::invalid_argument("Invalid category ID format"));
+            }
+        },
+    };
+
+    // Parse the optional reactivation date, which must be in the future
+    let reactivate_on = match deactivate_request.reactivate_on {
+        Some(timestamp) => {
+            let nanos = u32::try_from(timestamp.nanos).ok();
+            match nanos.and_then(|nanos| chrono::DateTime::from_timestamp(timestamp.seconds, nanos)) {
+                Some(reactivate_on) if reactivate_on > chrono::Utc::now() => Some(reactivate_on),
+                Some(_) => {
+                    return Err(tonic::Status::invalid_argument("reactivate_on must be in the future"));
+                }
+                None => {
+                    return Err(tonic::Status::invalid_argument("Invalid reactivate_on timestamp"));
+                }
+            }
         }
+        None => None,
     };
 
     // Update the category's active status to false
-    let updated_category = match database::Categories::update_active_status(category_id, false, service.database_ref()).await {
+    let pool = service.database_ref();
+    let result = match target {
+        DeactivateTarget::Id(category_id) => {
+            database::Categories::deactivate_until(category_id, reactivate_on, pool).await
+        }
+        DeactivateTarget::Code(code) if reactivate_on.is_none() => {
+            database::Categories::deactivate_by_code(code, pool).await
+        }
+        // Scheduling a reactivation needs the ID
+        DeactivateTarget::Code(code) => match database::Categories::find_by_code(code, pool).await {
+            Ok(Some(category)) => database::Categories::deactivate_until(category.id, reactivate_on, pool).await,
+            Ok(None) => Err(database::DatabaseError::NotFound(format!("Category with code {} not found", code))),
+            Err(db_error) => Err(db_error),
+        },
+    };
+    let updated_category = match result {
         Ok(category) => category,
         Err(database::DatabaseError::NotFound(_)) => {
-            return Err(tonic::Status::not_found(format!("Category with ID '{}' not found", deactivate_request.id)));
+            return Err(match code {
+                Some(code) => services::not_found_status("Category", "code", code),
+                None => services::not_found_status("Category", "ID", &deactivate_request.id),
+            });
         }
         Err(db_error) => {
-            tracing::error!("Failed to deactivate category {}: {}", deactivate_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to deactivate category"));
+            tracing::error!("Failed to deactivate category {}: {}", code.unwrap_or(&deactivate_request.id), db_error);
+            return Err(services::database_error_status(&db_error, "Failed to deactivate category"));
         }
     };
 
+    service.category_cache().invalidate(updated_category.id);
+    service.publish_event(services::EventKind::Updated, updated_category.id.to_string());
+
     // Convert to RPC category and return response
-    let rpc_category: rpc::Category = updated_category.into();
+    let rpc_category = service.localize(updated_category.into());
     let response = rpc::CategoryDeactivateResponse {
         category: Some(rpc_category),
     };
@@ -59,6 +109,12 @@ pub async fn deactivate_category(
     Ok(tonic::Response::new(response))
 }
 
+/// How a deactivate request names its category.
+enum DeactivateTarget<'a> {
+    Id(crate::domain::RowID),
+    Code(&'a str),
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -7,7 +7,7 @@
 //! - Deleting multiple categories in a batch operation
 //! - Proper error handling for not found cases and database errors
 
-use crate::{database, rpc};
+use crate::{database, rpc, services};
 
 /// Handle the category deletion logic for the gRPC service.
 ///
@@ -45,10 +45,15 @@ pub async fn delete_category(
         Err(database::DatabaseError::NotFound(_)) => 0, // Category not found, 0 rows deleted
         Err(db_error) => {
             tracing::error!("Failed to delete category {}: {}", delete_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to delete category"));
+            return Err(services::database_error_status(&db_error, "Failed to delete category"));
         }
     };
 
+    if rows_deleted > 0 {
+        service.category_cache().invalidate(category_id);
+        service.publish_event(services::EventKind::Deleted, category_id.to_string());
+    }
+
     // Return response with rows deleted count
     let response = rpc::CategoryDeleteResponse {
         rows_deleted: rows_deleted as i32,
@@ -100,20 +105,31 @@ pub async fn delete_categories_batch(
 
     // Delete the categories from the database
     let rows_deleted = match database::Categories::delete_many_by_id(&category_ids, service.database_ref()).await {
-        Ok(()) => category_ids.len() as i32, // All categories were successfully deleted
+        Ok(()) => {
+            // All categories were successfully deleted
+            for id in &category_ids {
+                service.category_cache().invalidate(*id);
+                service.publish_event(services::EventKind::Deleted, id.to_string());
+            }
+            category_ids.len() as i32
+        }
         Err(database::DatabaseError::NotFound(_msg)) => {
             // Some categories were not found - count how many were actually deleted
             // by attempting individual deletes and counting successes
             let mut actual_deleted = 0;
             for &id in &category_ids {
                 match database::Categories::delete_by_id(id, service.database_ref()).await {
-                    Ok(()) => actual_deleted += 1,
+                    Ok(()) => {
+                        service.category_cache().invalidate(id);
+                        service.publish_event(services::EventKind::Deleted, id.to_string());
+                        actual_deleted += 1;
+                    }
                     Err(database::DatabaseError::NotFound(_)) => {
                         // Category not found, skip it
                     }
                     Err(db_error) => {
                         tracing::error!("Failed to delete category {} during batch operation: {}", id, db_error);
-                        return Err(tonic::Status::internal("Failed to delete categories"));
+                        return Err(services::database_error_status(&db_error, "Failed to delete categories"));
                     }
                 }
             }
@@ -121,7 +137,7 @@ pub async fn delete_categories_batch(
         }
         Err(db_error) => {
             tracing::error!("Failed to delete categories batch: {}", db_error);
-            return Err(tonic::Status::internal("Failed to delete categories"));
+            return Err(services::database_error_status(&db_error, "Failed to delete categories"));
         }
     };
 
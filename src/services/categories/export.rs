@@ -0,0 +1,242 @@
+//! # Category CSV Export Logic
+//!
+//! This module provides the service logic for exporting categories as CSV
+//! from the Personal Ledger backend. It includes:
+//!
+//! - Conversion of database categories into CSV records
+//! - A server-streaming handler that emits CSV chunks as rows are read
+//! - Proper error handling for database and serialisation failures
+//!
+//! Rows are read from a database stream and forwarded in small chunks, so
+//! neither the server nor the client has to hold the whole export in memory.
+//! The header row is always sent as the first chunk.
+
+use futures_util::TryStreamExt;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{config, database, rpc, services::{self, ServiceError}};
+
+/// Column headers for the category CSV export, in output order.
+pub const CATEGORIES_CSV_HEADER: [&str; 12] = [
+    "id",
+    "code",
+    "name",
+    "description",
+    "url_slug",
+    "category_type",
+    "color",
+    "icon",
+    "is_active",
+    "display_order",
+    "created_on",
+    "updated_on",
+];
+
+/// Number of category rows bundled into each streamed CSV chunk.
+pub const CATEGORIES_CSV_ROWS_PER_CHUNK: usize = 100;
+
+/// Capacity of the channel between the database reader task and the gRPC stream.
+const EXPORT_CHANNEL_CAPACITY: usize = 4;
+
+/// Stream type returned by the CSV export handler.
+pub type CategoriesExportCsvStream =
+    ReceiverStream<Result<rpc::CategoriesExportCsvChunk, tonic::Status>>;
+
+/// Convert a database category into a CSV record matching [`CATEGORIES_CSV_HEADER`].
+///
+/// Optional fields are written as empty strings and timestamps use RFC 3339.
+pub fn category_csv_record(category: &database::Categories) -> [String; 12] {
+    [
+        category.id.to_string(),
+        category.code.clone(),
+        category.name.clone(),
+        category.description.clone().unwrap_or_default(),
+        category.url_slug.as_ref().map(|s| s.to_string()).unwrap_or_default(),
+        category.category_type.to_string(),
+        category.color.as_ref().map(|c| c.to_string()).unwrap_or_default(),
+        category.icon.clone().unwrap_or_default(),
+        category.is_active.to_string(),
+        category.display_order.to_string(),
+        category.created_on.to_rfc3339(),
+        category.updated_on.to_rfc3339(),
+    ]
+}
+
+/// Serialise a set of CSV records into a single string chunk.
+///
+/// Quoting and escaping follow RFC 4180 via the `csv` crate.
+fn write_csv_chunk<I, R>(records: I) -> Result<String, ServiceError>
+where
+    I: IntoIterator<Item = R>,
+    R: IntoIterator,
+    R::Item: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    for record in records {
+        writer
+            .write_record(record)
+            .map_err(|e| ServiceError::internal(format!("Failed to write CSV record: {}", e)))?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| ServiceError::internal(format!("Failed to flush CSV writer: {}", e)))?;
+
+    String::from_utf8(bytes)
+        .map_err(|e| ServiceError::internal(format!("CSV output is not valid UTF-8: {}", e)))
+}
+
+/// Serialise `records` and send them down the export stream as one chunk.
+///
+/// Returns `false` when the export should stop, either because the client
+/// closed the stream or because serialisation failed (in which case an error
+/// status has already been sent).
+async fn send_csv_chunk(
+    tx: &tokio::sync::mpsc::Sender<Result<rpc::CategoriesExportCsvChunk, tonic::Status>>,
+    records: Vec<[String; 12]>,
+) -> bool {
+    let message = match write_csv_chunk(records) {
+        Ok(data) => Ok(rpc::CategoriesExportCsvChunk { data }),
+        Err(service_error) => {
+            tracing::error!("Failed to write CSV chunk: {}", service_error);
+            Err(tonic::Status::internal("Failed to export categories"))
+        }
+    };
+    let is_ok = message.is_ok();
+
+    if tx.send(message).await.is_err() {
+        tracing::debug!("CSV export stream closed by client");
+        return false;
+    }
+
+    is_ok
+}
+
+/// Handle the streaming category CSV export for the gRPC service.
+///
+/// This function performs:
+/// - Spawning a reader task that streams categories from the database
+/// - Sending the CSV header as the first chunk
+/// - Emitting subsequent chunks of up to [`CATEGORIES_CSV_ROWS_PER_CHUNK`] rows
+/// - Forwarding database or serialisation failures as a final error status
+///
+/// The reader stops early if the client drops the stream.
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `_request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoriesExportCsvStream>)` once the export has started
+/// * `Err(tonic::Status)` with `Unimplemented` when streaming is disabled
+/// * Stream items of `Err(tonic::Status)` on database or serialisation error
+pub async fn export_categories_csv(
+    service: &super::CategoriesService,
+    _request: tonic::Request<rpc::CategoriesExportCsvRequest>,
+) -> Result<tonic::Response<CategoriesExportCsvStream>, tonic::Status> {
+    service.require_feature(config::FEATURE_STREAMING)?;
+
+    let database_pool = service.database_pool();
+    let (tx, rx) = tokio::sync::mpsc::channel(EXPORT_CHANNEL_CAPACITY);
+
+    tokio::spawn(async move {
+        // The header is always the first chunk
+        let header: Vec<[String; 12]> = vec![CATEGORIES_CSV_HEADER.map(String::from)];
+        if !send_csv_chunk(&tx, header).await {
+            return;
+        }
+
+        let mut rows = database::Categories::fetch_all_stream(&database_pool);
+        let mut pending = Vec::with_capacity(CATEGORIES_CSV_ROWS_PER_CHUNK);
+        let mut exported = 0usize;
+
+        loop {
+            match rows.try_next().await {
+                Ok(Some(category)) => pending.push(category_csv_record(&category)),
+                Ok(None) => break,
+                Err(db_error) => {
+                    tracing::error!("Failed to read categories for CSV export: {}", db_error);
+                    let _ = tx.send(Err(services::database_error_status(&db_error, "Failed to export categories"))).await;
+                    return;
+                }
+            }
+
+            if pending.len() == CATEGORIES_CSV_ROWS_PER_CHUNK {
+                exported += pending.len();
+                if !send_csv_chunk(&tx, std::mem::take(&mut pending)).await {
+                    return;
+                }
+            }
+        }
+
+        if !pending.is_empty() {
+            exported += pending.len();
+            if !send_csv_chunk(&tx, pending).await {
+                return;
+            }
+        }
+
+        tracing::info!("Exported {} categories as CSV", exported);
+    });
+
+    Ok(tonic::Response::new(ReceiverStream::new(rx)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_csv_record_matches_header_order() {
+        let category = database::Categories::mock();
+        let record = category_csv_record(&category);
+
+        assert_eq!(record.len(), CATEGORIES_CSV_HEADER.len());
+        assert_eq!(record[0], category.id.to_string());
+        assert_eq!(record[1], category.code);
+        assert_eq!(record[2], category.name);
+        assert_eq!(record[5], category.category_type.to_string());
+        assert_eq!(record[8], category.is_active.to_string());
+        assert_eq!(record[9], category.display_order.to_string());
+        assert_eq!(record[10], category.created_on.to_rfc3339());
+    }
+
+    #[test]
+    fn test_category_csv_record_writes_empty_optional_fields() {
+        let mut category = database::Categories::mock();
+        category.description = None;
+        category.url_slug = None;
+        category.color = None;
+        category.icon = None;
+
+        let record = category_csv_record(&category);
+        assert_eq!(record[3], "");
+        assert_eq!(record[4], "");
+        assert_eq!(record[6], "");
+        assert_eq!(record[7], "");
+    }
+
+    #[test]
+    fn test_write_csv_chunk_escapes_special_characters() {
+        let chunk = write_csv_chunk([["a,b", "say \"hi\"", "line\nbreak"]]).unwrap();
+        assert_eq!(chunk, "\"a,b\",\"say \"\"hi\"\"\",\"line\nbreak\"\n");
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(chunk.as_bytes());
+        let parsed = reader.records().next().unwrap().unwrap();
+        assert_eq!(&parsed[0], "a,b");
+        assert_eq!(&parsed[1], "say \"hi\"");
+        assert_eq!(&parsed[2], "line\nbreak");
+    }
+
+    #[test]
+    fn test_write_csv_chunk_header() {
+        let chunk = write_csv_chunk([CATEGORIES_CSV_HEADER]).unwrap();
+        assert_eq!(
+            chunk,
+            "id,code,name,description,url_slug,category_type,color,icon,is_active,display_order,created_on,updated_on\n"
+        );
+    }
+}
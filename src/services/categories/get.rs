@@ -6,16 +6,18 @@
 //! - Getting a category by its unique ID
 //! - Getting a category by its unique code
 //! - Getting a category by its URL slug
+//! - Limiting the response to the fields in an optional read mask
 //! - Proper error handling for not found cases and database errors
 
-use crate::{database, rpc};
+use crate::{database, rpc, services};
 
 /// Handle the category retrieval by ID logic for the gRPC service.
 ///
 /// This function performs:
 /// - Parsing the ID from the request
 /// - Querying the database for the category
-/// - Converting the database category to gRPC response format
+/// - Converting the database category to gRPC response format, limited to
+///   the fields in the read mask when one is given
 /// - Proper error handling for not found cases and database errors
 ///
 /// # Arguments
@@ -24,13 +26,16 @@ use crate::{database, rpc};
 ///
 /// # Returns
 /// * `Ok(tonic::Response<CategoryGetResponse>)` on success
-/// * `Err(tonic::Status)` on not found or database error
+/// * `Err(tonic::Status)` on not found, an invalid read mask, or database error
 pub async fn get_category(
     service: &super::CategoriesService,
     request: tonic::Request<rpc::CategoryGetRequest>,
 ) -> Result<tonic::Response<rpc::CategoryGetResponse>, tonic::Status> {
     // Extract the inner request
-    let get_request = request.into_inner();
+    let mut get_request = request.into_inner();
+
+    // Validate the read mask before touching the database
+    let projection = super::CategoryProjection::from_read_mask(get_request.read_mask.take())?;
 
     // Parse the ID from string to RowID
     let category_id = match get_request.id.parse::<crate::domain::RowID>() {
@@ -40,20 +45,17 @@ pub async fn get_category(
         }
     };
 
-    // Query the database for the category
-    let category = match database::Categories::find_by_id(category_id, service.database_ref()).await {
-        Ok(Some(category)) => category,
-        Ok(None) => {
-            return Err(tonic::Status::not_found(format!("Category with ID '{}' not found", get_request.id)));
-        }
+    // Query the database for the category, through the cache when enabled
+    let category = match service.category_cache().find_by_id(category_id, service.database_ref()).await {
+        Ok(found) => services::require_found(found, "Category", "ID", &get_request.id)?,
         Err(db_error) => {
             tracing::error!("Failed to find category by ID {}: {}", get_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to retrieve category"));
+            return Err(services::database_error_status(&db_error, "Failed to retrieve category"));
         }
     };
 
     // Convert the database category to RPC format
-    let rpc_category: rpc::Category = category.into();
+    let rpc_category = projection.apply(service.localize(category.into()));
 
     // Create the response
     let response = rpc::CategoryGetResponse {
@@ -68,7 +70,8 @@ pub async fn get_category(
 /// This function performs:
 /// - Extracting the code from the request
 /// - Querying the database for the category by code
-/// - Converting the database category to gRPC response format
+/// - Converting the database category to gRPC response format, limited to
+///   the fields in the read mask when one is given
 /// - Proper error handling for not found cases and database errors
 ///
 /// # Arguments
@@ -77,13 +80,16 @@ pub async fn get_category(
 ///
 /// # Returns
 /// * `Ok(tonic::Response<CategoryGetByCodeResponse>)` on success
-/// * `Err(tonic::Status)` on not found or database error
+/// * `Err(tonic::Status)` on not found, an invalid read mask, or database error
 pub async fn get_category_by_code(
     service: &super::CategoriesService,
     request: tonic::Request<rpc::CategoryGetByCodeRequest>,
 ) -> Result<tonic::Response<rpc::CategoryGetByCodeResponse>, tonic::Status> {
     // Extract the inner request
-    let get_request = request.into_inner();
+    let mut get_request = request.into_inner();
+
+    // Validate the read mask before touching the database
+    let projection = super::CategoryProjection::from_read_mask(get_request.read_mask.take())?;
 
     // Validate that code is not empty
     if get_request.code.trim().is_empty() {
@@ -92,18 +98,15 @@ pub async fn get_category_by_code(
 
     // Query the database for the category
     let category = match database::Categories::find_by_code(&get_request.code, service.database_ref()).await {
-        Ok(Some(category)) => category,
-        Ok(None) => {
-            return Err(tonic::Status::not_found(format!("Category with code '{}' not found", get_request.code)));
-        }
+        Ok(found) => services::require_found(found, "Category", "code", &get_request.code)?,
         Err(db_error) => {
             tracing::error!("Failed to find category by code {}: {}", get_request.code, db_error);
-            return Err(tonic::Status::internal("Failed to retrieve category"));
+            return Err(services::database_error_status(&db_error, "Failed to retrieve category"));
         }
     };
 
     // Convert the database category to RPC format
-    let rpc_category: rpc::Category = category.into();
+    let rpc_category = projection.apply(service.localize(category.into()));
 
     // Create the response
     let response = rpc::CategoryGetByCodeResponse {
@@ -118,7 +121,8 @@ pub async fn get_category_by_code(
 /// This function performs:
 /// - Extracting and parsing the URL slug from the request
 /// - Querying the database for the category by slug
-/// - Converting the database category to gRPC response format
+/// - Converting the database category to gRPC response format, limited to
+///   the fields in the read mask when one is given
 /// - Proper error handling for not found cases and database errors
 ///
 /// # Arguments
@@ -127,13 +131,16 @@ pub async fn get_category_by_code(
 ///
 /// # Returns
 /// * `Ok(tonic::Response<CategoryGetBySlugResponse>)` on success
-/// * `Err(tonic::Status)` on not found or database error
+/// * `Err(tonic::Status)` on not found, an invalid read mask, or database error
 pub async fn get_category_by_slug(
     service: &super::CategoriesService,
     request: tonic::Request<rpc::CategoryGetBySlugRequest>,
 ) -> Result<tonic::Response<rpc::CategoryGetBySlugResponse>, tonic::Status> {
     // Extract the inner request
-    let get_request = request.into_inner();
+    let mut get_request = request.into_inner();
+
+    // Validate the read mask before touching the database
+    let projection = super::CategoryProjection::from_read_mask(get_request.read_mask.take())?;
 
     // Validate that slug is not empty
     if get_request.url_slug.trim().is_empty() {
@@ -150,18 +157,15 @@ pub async fn get_category_by_slug(
 
     // Query the database for the category
     let category = match database::Categories::find_by_url_slug(&url_slug, service.database_ref()).await {
-        Ok(Some(category)) => category,
-        Ok(None) => {
-            return Err(tonic::Status::not_found(format!("Category with slug '{}' not found", get_request.url_slug)));
-        }
+        Ok(found) => services::require_found(found, "Category", "slug", &get_request.url_slug)?,
         Err(db_error) => {
             tracing::error!("Failed to find category by slug {}: {}", get_request.url_slug, db_error);
-            return Err(tonic::Status::internal("Failed to retrieve category"));
+            return Err(services::database_error_status(&db_error, "Failed to retrieve category"));
         }
     };
 
     // Convert the database category to RPC format
-    let rpc_category: rpc::Category = category.into();
+    let rpc_category = projection.apply(service.localize(category.into()));
 
     // Create the response
     let response = rpc::CategoryGetBySlugResponse {
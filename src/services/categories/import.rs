@@ -0,0 +1,383 @@
+//! # Category Import Pre-validation
+//!
+//! This module checks the structure of a whole category import file before any
+//! row is processed, so a malformed file fails fast with one clear error
+//! instead of an error for every row. It includes:
+//!
+//! - [`ImportSchema`], describing the columns and envelope versions an import accepts
+//! - CSV header validation: every required column present, no unknown or
+//!   duplicated columns
+//! - JSON envelope validation: a supported `version` and a records array
+//! - [`restore_from_csv_diff`], restoring a CSV export while writing only the
+//!   rows that changed
+//!
+//! Structural problems are reported as [`ServiceError::MalformedImport`]. Row
+//! contents (codes, colours, category types) are validated separately, row by
+//! row, once the structure is known to be sound.
+
+use std::collections::HashMap;
+
+use crate::database;
+use crate::services::ServiceError;
+
+use super::CATEGORIES_CSV_HEADER;
+
+/// Columns every category import must provide.
+pub const CATEGORIES_IMPORT_REQUIRED_COLUMNS: [&str; 3] = ["code", "name", "category_type"];
+
+/// Current version of the category JSON import envelope.
+pub const CATEGORIES_IMPORT_ENVELOPE_VERSION: u32 = 1;
+
+/// The structure an import file must have.
+///
+/// Use [`ImportSchema::categories`] for category imports, or build a schema
+/// with other columns or versions, for example to accept an older envelope
+/// version while clients migrate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSchema {
+    /// Columns that must appear in a CSV header.
+    pub required_columns: &'static [&'static str],
+
+    /// Every column a CSV header may contain, including the required ones.
+    pub known_columns: &'static [&'static str],
+
+    /// Key of the records array in a JSON envelope.
+    pub records_key: &'static str,
+
+    /// JSON envelope versions this import understands.
+    pub supported_versions: std::ops::RangeInclusive<u32>,
+}
+
+impl ImportSchema {
+    /// Schema for category imports.
+    ///
+    /// Any column of the CSV export is accepted, so an export can be imported
+    /// again unchanged. The JSON envelope is
+    /// `{"version": 1, "categories": [...]}`.
+    pub fn categories() -> Self {
+        Self {
+            required_columns: &CATEGORIES_IMPORT_REQUIRED_COLUMNS,
+            known_columns: &CATEGORIES_CSV_HEADER,
+            records_key: "categories",
+            supported_versions: CATEGORIES_IMPORT_ENVELOPE_VERSION..=CATEGORIES_IMPORT_ENVELOPE_VERSION,
+        }
+    }
+
+    /// Check the header row of a CSV import.
+    ///
+    /// Column names are trimmed before comparison.
+    ///
+    /// # Returns
+    ///
+    /// Returns the trimmed column names in file order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServiceError::MalformedImport`] if the header is missing or
+    /// unreadable, lists a column twice or a column outside
+    /// [`known_columns`](Self::known_columns), or lacks any required column.
+    /// Every missing column is named in the one error.
+    pub fn validate_csv(&self, data: &[u8]) -> Result<Vec<String>, ServiceError> {
+        let mut reader = csv::ReaderBuilder::new().has_headers(true).from_reader(data);
+        let header: Vec<String> = reader
+            .headers()
+            .map_err(|e| ServiceError::malformed_import(format!("Cannot read CSV header: {}", e)))?
+            .iter()
+            .map(|column| column.trim().to_string())
+            .collect();
+
+        if header.iter().all(String::is_empty) {
+            return Err(ServiceError::malformed_import("CSV header row is missing"));
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        if let Some(duplicate) = header.iter().find(|column| !seen.insert(column.as_str())) {
+            return Err(ServiceError::malformed_import(format!("CSV column '{}' appears more than once", duplicate)));
+        }
+
+        let unknown: Vec<&str> = header
+            .iter()
+            .map(String::as_str)
+            .filter(|column| !self.known_columns.contains(column))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(ServiceError::malformed_import(format!("Unknown CSV column(s): {}", unknown.join(", "))));
+        }
+
+        let missing: Vec<&str> = self
+            .required_columns
+            .iter()
+            .copied()
+            .filter(|required| !header.iter().any(|column| column == required))
+            .collect();
+        if !missing.is_empty() {
+            return Err(ServiceError::malformed_import(format!(
+                "Missing required CSV column(s): {}",
+                missing.join(", ")
+            )));
+        }
+
+        Ok(header)
+    }
+
+    /// Check the envelope of a JSON import.
+    ///
+    /// # Returns
+    ///
+    /// Returns the records array, ready for row-by-row validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ServiceError::MalformedImport`] if the data is not a JSON
+    /// object, its `version` is missing or outside
+    /// [`supported_versions`](Self::supported_versions), or it has no records
+    /// array under [`records_key`](Self::records_key).
+    pub fn validate_json(&self, data: &str) -> Result<Vec<serde_json::Value>, ServiceError> {
+        let envelope: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| ServiceError::malformed_import(format!("Invalid JSON: {}", e)))?;
+        let serde_json::Value::Object(mut envelope) = envelope else {
+            return Err(ServiceError::malformed_import("JSON import must be an object envelope"));
+        };
+
+        let version = envelope
+            .get("version")
+            .ok_or_else(|| ServiceError::malformed_import("JSON envelope is missing 'version'"))?;
+        let version = version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| {
+                ServiceError::malformed_import(format!("JSON envelope version must be a positive integer, got {}", version))
+            })?;
+        if !self.supported_versions.contains(&version) {
+            return Err(ServiceError::malformed_import(format!(
+                "Unsupported JSON envelope version {}, supported versions are {} to {}",
+                version,
+                self.supported_versions.start(),
+                self.supported_versions.end()
+            )));
+        }
+
+        match envelope.remove(self.records_key) {
+            Some(serde_json::Value::Array(records)) => Ok(records),
+            Some(_) => Err(ServiceError::malformed_import(format!(
+                "JSON envelope '{}' must be an array",
+                self.records_key
+            ))),
+            None => Err(ServiceError::malformed_import(format!(
+                "JSON envelope is missing '{}'",
+                self.records_key
+            ))),
+        }
+    }
+}
+
+/// Restore categories from a CSV export, writing only the rows that changed.
+///
+/// The file must carry every column of [`CATEGORIES_CSV_HEADER`], in any
+/// order. Each row is compared with the stored category of the same `id`,
+/// ignoring timestamps; see [`database::Categories::restore_diff`].
+///
+/// # Errors
+///
+/// Returns [`ServiceError::MalformedImport`] if the header is not a full
+/// export header, [`ServiceError::Validation`] naming the first row that
+/// cannot be parsed, or a database error if the restore fails. Nothing is
+/// written unless every row parses and restores.
+pub async fn restore_from_csv_diff(
+    data: &[u8],
+    pool: &sqlx::SqlitePool,
+) -> Result<database::RestoreSummary, ServiceError> {
+    let schema = ImportSchema { required_columns: &CATEGORIES_CSV_HEADER, ..ImportSchema::categories() };
+    let header = schema.validate_csv(data)?;
+
+    let mut reader = csv::ReaderBuilder::new().has_headers(true).from_reader(data);
+    let mut categories = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let row = index + 1;
+        let record = record.map_err(|e| ServiceError::validation(format!("Row {}: {}", row, e)))?;
+        let fields: HashMap<&str, &str> = header.iter().map(String::as_str).zip(record.iter()).collect();
+        let category = parse_csv_category(&fields).map_err(|e| ServiceError::validation(format!("Row {}: {}", row, e)))?;
+        categories.push(category);
+    }
+
+    database::Categories::restore_diff(&categories, pool)
+        .await
+        .map_err(|e| match e {
+            database::DatabaseError::Sqlx(e) => ServiceError::Database(e),
+            other => ServiceError::internal(format!("Failed to restore categories: {}", other)),
+        })
+}
+
+/// Parse one CSV export row, keyed by column name.
+fn parse_csv_category(fields: &HashMap<&str, &str>) -> Result<database::Categories, String> {
+    let field = |name: &str| fields.get(name).copied().unwrap_or_default();
+    let optional = |name: &str| Some(field(name)).filter(|value| !value.is_empty());
+    let timestamp = |name: &str| {
+        chrono::DateTime::parse_from_rfc3339(field(name))
+            .map(|value| value.with_timezone(&chrono::Utc))
+            .map_err(|e| format!("invalid {}: {}", name, e))
+    };
+
+    Ok(database::Categories {
+        id: field("id").parse().map_err(|e| format!("invalid id: {}", e))?,
+        code: field("code").to_string(),
+        name: field("name").to_string(),
+        description: optional("description").map(str::to_string),
+        url_slug: optional("url_slug")
+            .map(str::parse)
+            .transpose()
+            .map_err(|e| format!("invalid url_slug: {}", e))?,
+        category_type: field("category_type")
+            .parse()
+            .map_err(|e| format!("invalid category_type: {}", e))?,
+        color: optional("color")
+            .map(str::parse)
+            .transpose()
+            .map_err(|e| format!("invalid color: {}", e))?,
+        icon: optional("icon").map(str::to_string),
+        is_active: field("is_active").parse().map_err(|e| format!("invalid is_active: {}", e))?,
+        display_order: field("display_order")
+            .parse()
+            .map_err(|e| format!("invalid display_order: {}", e))?,
+        external_id: None,
+        reactivate_on: None,
+        parent_id: None,
+        created_on: timestamp("created_on")?,
+        updated_on: timestamp("updated_on")?,
+        deleted_on: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::categories::category_csv_record;
+
+    fn malformed_message(result: Result<impl std::fmt::Debug, ServiceError>) -> String {
+        match result {
+            Err(ServiceError::MalformedImport(message)) => message,
+            other => panic!("expected a malformed import error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_csv_header_with_required_columns_is_accepted() {
+        let data = b" code ,name,category_type,color\nFOOD,Food,expense,#00FF00\n";
+        let header = ImportSchema::categories().validate_csv(data).unwrap();
+        assert_eq!(header, ["code", "name", "category_type", "color"]);
+    }
+
+    #[test]
+    fn test_csv_export_header_is_accepted() {
+        let data = format!("{}\n", CATEGORIES_CSV_HEADER.join(","));
+        assert!(ImportSchema::categories().validate_csv(data.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_csv_missing_columns_are_reported_together() {
+        // The rows are malformed too, but only the structural error is reported
+        let data = b"code,color\nFOOD,not-a-colour\n";
+        let message = malformed_message(ImportSchema::categories().validate_csv(data));
+        assert_eq!(message, "Missing required CSV column(s): name, category_type");
+    }
+
+    #[test]
+    fn test_csv_unknown_and_duplicate_columns_are_rejected() {
+        let message = malformed_message(ImportSchema::categories().validate_csv(b"code,name,category_type,colour\n"));
+        assert_eq!(message, "Unknown CSV column(s): colour");
+
+        let message = malformed_message(ImportSchema::categories().validate_csv(b"code,name,code,category_type\n"));
+        assert_eq!(message, "CSV column 'code' appears more than once");
+    }
+
+    #[test]
+    fn test_csv_without_header_is_rejected() {
+        let message = malformed_message(ImportSchema::categories().validate_csv(b""));
+        assert_eq!(message, "CSV header row is missing");
+    }
+
+    #[test]
+    fn test_json_supported_envelope_returns_records() {
+        let data = r#"{"version": 1, "categories": [{"code": "FOOD"}, {"code": "RENT"}]}"#;
+        let records = ImportSchema::categories().validate_json(data).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1]["code"], "RENT");
+    }
+
+    #[test]
+    fn test_json_unsupported_envelope_version_is_rejected() {
+        let data = r#"{"version": 2, "categories": []}"#;
+        let message = malformed_message(ImportSchema::categories().validate_json(data));
+        assert_eq!(message, "Unsupported JSON envelope version 2, supported versions are 1 to 1");
+
+        // A schema configured for the newer version accepts it
+        let schema = ImportSchema { supported_versions: 1..=2, ..ImportSchema::categories() };
+        assert!(schema.validate_json(data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_json_malformed_envelopes_are_rejected() {
+        let cases = [
+            ("not json", "Invalid JSON"),
+            ("[]", "JSON import must be an object envelope"),
+            (r#"{"categories": []}"#, "JSON envelope is missing 'version'"),
+            (r#"{"version": "1", "categories": []}"#, "JSON envelope version must be a positive integer"),
+            (r#"{"version": 1}"#, "JSON envelope is missing 'categories'"),
+            (r#"{"version": 1, "categories": {}}"#, "JSON envelope 'categories' must be an array"),
+        ];
+        for (data, expected) in cases {
+            let message = malformed_message(ImportSchema::categories().validate_json(data));
+            assert!(message.starts_with(expected), "{:?}: {}", data, message);
+        }
+    }
+
+    fn export_csv(categories: &[database::Categories]) -> Vec<u8> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(CATEGORIES_CSV_HEADER).unwrap();
+        for category in categories {
+            writer.write_record(category_csv_record(category)).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    #[sqlx::test]
+    async fn test_restore_from_csv_diff_writes_only_changed_rows(pool: sqlx::SqlitePool) {
+        let identical = database::Categories { name: "Identical".to_string(), ..database::Categories::mock() };
+        let identical = identical.insert(&pool).await.unwrap();
+        let changed = database::Categories {
+            code: format!("{}.CHANGED", identical.code),
+            name: "Changed".to_string(),
+            url_slug: None,
+            ..database::Categories::mock()
+        };
+        let changed = changed.insert(&pool).await.unwrap();
+        let renamed = database::Categories { name: format!("{} renamed", changed.name), ..changed.clone() };
+
+        let summary = restore_from_csv_diff(&export_csv(&[identical.clone(), renamed.clone()]), &pool)
+            .await
+            .unwrap();
+        assert_eq!(summary, database::RestoreSummary { created: 0, updated: 1, unchanged: 1 });
+
+        let stored = database::Categories::find_by_id(identical.id, &pool).await.unwrap().unwrap();
+        assert_eq!(stored.updated_on, identical.updated_on);
+
+        let stored = database::Categories::find_by_id(changed.id, &pool).await.unwrap().unwrap();
+        assert_eq!(stored.name, renamed.name);
+        assert!(stored.updated_on > changed.updated_on);
+    }
+
+    #[sqlx::test]
+    async fn test_restore_from_csv_diff_rejects_bad_rows(pool: sqlx::SqlitePool) {
+        let mut data = export_csv(&[database::Categories::mock()]);
+        data.extend_from_slice(b"not-an-id,CODE,Name,,,expense,,,true,0,2024-01-01T00:00:00Z,2024-01-01T00:00:00Z\n");
+
+        match restore_from_csv_diff(&data, &pool).await {
+            Err(ServiceError::Validation(message)) => assert!(message.starts_with("Row 2: invalid id"), "{}", message),
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+        assert!(database::Categories::find_all(&pool).await.unwrap().is_empty());
+
+        let error = restore_from_csv_diff(b"code,name,category_type\n", &pool).await.unwrap_err();
+        assert!(error.is_malformed_import());
+    }
+}
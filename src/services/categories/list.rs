@@ -4,17 +4,37 @@
 //! from the Personal Ledger backend. It includes:
 //!
 //! - Flexible category listing with filtering, sorting, and pagination
-//! - Support for filtering by category type and active status
+//! - Support for filtering by category type, active status and tag
+//! - Partial responses limited to the fields in an optional read mask
+//! - Opaque, signed page tokens for cursor pagination (see [`services::PageToken`])
+//! - A server-streaming listing of every category in creation order, read from
+//!   the database a page at a time so memory stays bounded
 //! - Proper error handling and response formatting
 
-use crate::{database, domain, rpc};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{config, database, domain, rpc, services};
+
+/// Number of categories read from the database per page of the list stream.
+pub const CATEGORIES_LIST_STREAM_PAGE_SIZE: i64 = 100;
+
+/// Capacity of the channel between the database reader task and the gRPC stream.
+const LIST_STREAM_CHANNEL_CAPACITY: usize = 16;
+
+/// Stream type returned by the streaming list handler.
+pub type CategoriesListStreamStream =
+    ReceiverStream<Result<rpc::CategoriesListStreamResponse, tonic::Status>>;
 
 /// Handle the category listing logic for the gRPC service.
 ///
 /// This function performs:
 /// - Parsing and validation of filter parameters from the request
 /// - Querying the database with flexible filtering, sorting, and pagination
-/// - Converting database categories to gRPC response format
+/// - Verifying the page token, when given, and continuing after the row it points at
+/// - Rejecting a page token combined with an offset, rather than preferring one
+/// - Converting database categories to gRPC response format, limited to the
+///   fields in the read mask, with a `next_page_token` when more categories
+///   follow
 /// - Proper error handling for database errors
 ///
 /// # Arguments
@@ -23,21 +43,26 @@ use crate::{database, domain, rpc};
 ///
 /// # Returns
 /// * `Ok(tonic::Response<CategoriesListResponse>)` on success
-/// * `Err(tonic::Status)` on database error
+/// * `Err(tonic::Status)` with `InvalidArgument` for invalid parameters, read
+///   masks or page tokens, `Unimplemented` for a tag filter when tags are disabled, or on
+///   database error
 pub async fn list_categories(
     service: &super::CategoriesService,
     request: tonic::Request<rpc::CategoriesListRequest>,
 ) -> Result<tonic::Response<rpc::CategoriesListResponse>, tonic::Status> {
     // Extract the inner request
-    let list_request = request.into_inner();
+    let mut list_request = request.into_inner();
+
+    // Validate the read mask before touching the database
+    let projection = super::CategoryProjection::from_read_mask(list_request.read_mask.take())?;
 
     // Parse optional filters
     let category_type_filter = match list_request.category_type {
         Some(ct) if ct != 0 => {
             // Try to convert the proto enum to domain enum
-            match domain::CategoryTypes::from_rpc_i32(ct) {
+            match services::parse_enum_i32::<domain::CategoryTypes>(ct, service.strict_enums()) {
                 Ok(domain_type) => Some(domain_type),
-                Err(_) => return Err(tonic::Status::invalid_argument("Invalid category type")),
+                Err(e) => return Err(e.to_status()),
             }
         }
         _ => None,
@@ -45,8 +70,19 @@ pub async fn list_categories(
 
     let is_active_filter = list_request.is_active;
 
+    let tag_filter = match services::normalize_optional(list_request.tag) {
+        Some(tag) => {
+            service.require_feature(config::FEATURE_TAGS)?;
+            match domain::Tag::parse(&tag) {
+                Ok(tag) => Some(tag),
+                Err(e) => return Err(tonic::Status::invalid_argument(format!("Invalid tag: {}", e))),
+            }
+        }
+        None => None,
+    };
+
     // Parse sorting parameters
-    let sort_by = list_request.sort_by.filter(|s| !s.trim().is_empty());
+    let sort_by = services::normalize_optional(list_request.sort_by);
 
     let sort_desc = list_request.sort_desc;
 
@@ -63,27 +99,62 @@ pub async fn list_categories(
         return Err(tonic::Status::invalid_argument("Limit cannot exceed 1000"));
     }
 
-    // Query the database with filters
-    let (categories, total_count) = match database::Categories::find_with_filters(
+    // Verify the page token before touching the database
+    let page = services::PageToken::parse(list_request.page_token.as_deref(), "page_token", service.cursor_key())?;
+    services::require_exclusive(&[("offset", list_request.offset != 0), ("page_token", !page.is_first())])?;
+
+    // Query the database with filters. With a page token the offset query is
+    // only used for the total count.
+    let (mut categories, total_count) = match database::Categories::find_with_filters(
         category_type_filter,
         is_active_filter,
+        tag_filter.as_ref(),
         sort_by.as_deref(),
         sort_desc,
         list_request.offset,
-        list_request.limit,
+        if page.is_first() { list_request.limit } else { 0 },
         service.database_ref(),
     ).await {
         Ok(result) => result,
         Err(db_error) => {
             tracing::error!("Failed to list categories: {}", db_error);
-            return Err(tonic::Status::internal("Failed to retrieve categories"));
+            return Err(services::database_error_status(&db_error, "Failed to retrieve categories"));
         }
     };
 
+    // Fetch one extra row after the cursor to learn whether another page follows
+    let has_more = match page.position() {
+        Some(after) => {
+            categories = match database::Categories::find_with_filters_after(
+                category_type_filter,
+                is_active_filter,
+                tag_filter.as_ref(),
+                after,
+                list_request.limit + 1,
+                service.database_ref(),
+            ).await {
+                Ok(categories) => categories,
+                Err(db_error) => {
+                    tracing::error!("Failed to list categories: {}", db_error);
+                    return Err(services::database_error_status(&db_error, "Failed to retrieve categories"));
+                }
+            };
+            let has_more = categories.len() > list_request.limit as usize;
+            categories.truncate(list_request.limit as usize);
+            has_more
+        }
+        None => i64::from(list_request.offset) + (categories.len() as i64) < i64::from(total_count),
+    };
+
+    let next_page_token = categories
+        .last()
+        .filter(|_| has_more)
+        .and_then(|last| services::PageToken::after(last.created_on, last.id).encode(service.cursor_key()));
+
     // Convert database categories to RPC format
     let rpc_categories: Vec<rpc::Category> = categories
         .into_iter()
-        .map(|category| category.into())
+        .map(|category| projection.apply(service.localize(category.into())))
         .collect();
 
     // Create the response
@@ -92,7 +163,84 @@ pub async fn list_categories(
         total_count,
         offset: list_request.offset,
         limit: list_request.limit,
+        next_page_token,
     };
 
     Ok(tonic::Response::new(response))
-}
\ No newline at end of file
+}
+
+/// Handle the streaming category listing for the gRPC service.
+///
+/// This function performs:
+/// - Spawning a reader task that pages through categories in `created_on`
+///   order, [`CATEGORIES_LIST_STREAM_PAGE_SIZE`] at a time
+/// - Sending each category as its own stream message
+/// - Forwarding a database failure as a final error status
+///
+/// Pages are keyed on the last category sent (see
+/// [`find_page_by_created`](database::Categories::find_page_by_created)), so
+/// at most one page is held in memory and the reader stops early if the
+/// client drops the stream.
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `_request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoriesListStreamStream>)` once the listing has started
+/// * `Err(tonic::Status)` with `Unimplemented` when streaming is disabled
+/// * Stream items of `Err(tonic::Status)` on database error
+pub async fn list_categories_stream(
+    service: &super::CategoriesService,
+    _request: tonic::Request<rpc::CategoriesListStreamRequest>,
+) -> Result<tonic::Response<CategoriesListStreamStream>, tonic::Status> {
+    service.require_feature(config::FEATURE_STREAMING)?;
+
+    let database_pool = service.database_pool();
+    let display_timezone = service.display_timezone();
+    let (tx, rx) = tokio::sync::mpsc::channel(LIST_STREAM_CHANNEL_CAPACITY);
+
+    tokio::spawn(async move {
+        let mut after = None;
+        let mut streamed = 0usize;
+
+        loop {
+            let page = match database::Categories::find_page_by_created(
+                after,
+                CATEGORIES_LIST_STREAM_PAGE_SIZE,
+                &database_pool,
+            )
+            .await
+            {
+                Ok(page) => page,
+                Err(db_error) => {
+                    tracing::error!("Failed to read categories for list stream: {}", db_error);
+                    let _ = tx.send(Err(services::database_error_status(&db_error, "Failed to list categories"))).await;
+                    return;
+                }
+            };
+
+            let is_last_page = page.len() < CATEGORIES_LIST_STREAM_PAGE_SIZE as usize;
+            after = page.last().map(|category| (category.created_on, category.id));
+
+            for category in page {
+                let category = services::localize_category(category.into(), display_timezone);
+                let message = rpc::CategoriesListStreamResponse { category: Some(category) };
+                if tx.send(Ok(message)).await.is_err() {
+                    tracing::debug!("Category list stream closed by client");
+                    return;
+                }
+                streamed += 1;
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        tracing::info!("Streamed {} categories", streamed);
+    });
+
+    Ok(tonic::Response::new(ReceiverStream::new(rx)))
+}
+
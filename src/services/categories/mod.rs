@@ -10,9 +10,16 @@
 //! - `create`: Category creation logic (single and batch)
 //! - `deactivate`: Category deactivation logic
 //! - `delete`: Category deletion logic (single and batch)
+//! - `export`: Streaming CSV export of all categories
 //! - `get`: Category retrieval logic (by ID, code, slug)
+//! - `import`: Import file pre-validation and diff-based restore from a CSV export
 //! - `list`: Category listing with filtering and pagination
+//! - `projection`: Partial responses limited to the fields in a read mask
+//! - `reactivate`: Background sweep reactivating categories whose `reactivate_on` has passed
+//! - `reorder`: Atomic display order updates for many categories
+//! - `schema`: Category types and field validation rules for clients
 //! - `service`: gRPC service trait implementation
+//! - `tree`: All categories nested by parent
 //! - `update`: Category update logic with field mask support
 //!
 //! ## Re-exports
@@ -24,9 +31,16 @@ mod activate;
 mod create;
 mod deactivate;
 mod delete;
+mod export;
 mod get;
+mod import;
 mod list;
+mod projection;
+mod reactivate;
+mod reorder;
+mod schema;
 mod service;
+mod tree;
 mod update;
 
 
@@ -35,7 +49,14 @@ pub use activate::*;
 pub use create::*;
 pub use deactivate::*;
 pub use delete::*;
+pub use export::*;
 pub use get::*;
+pub use import::*;
 pub use list::*;
+pub use projection::*;
+pub use reactivate::*;
+pub use reorder::*;
+pub use schema::*;
 pub use service::*;
+pub use tree::*;
 pub use update::*;
\ No newline at end of file
@@ -0,0 +1,145 @@
+//! # Category Response Projection
+//!
+//! This module provides partial responses for the category read endpoints in
+//! the Personal Ledger backend. A client rendering a compact list can send a
+//! `read_mask` naming the fields it needs, and every other field comes back
+//! empty. It includes:
+//!
+//! - Validating the read mask before any database work
+//! - Clearing the unrequested fields of a category
+//!
+//! The `id` is always populated, so a projected category can still be fetched
+//! in full or paged past. The localized renderings of `created_on` and
+//! `updated_on` follow the timestamp they render.
+
+use crate::rpc;
+use prost_types::FieldMask;
+
+/// Category fields that a read mask may name.
+pub const CATEGORY_READ_FIELDS: &[&str] = &[
+    "id",
+    "code",
+    "name",
+    "description",
+    "url_slug",
+    "category_type",
+    "color",
+    "icon",
+    "is_active",
+    "created_on",
+    "updated_on",
+    "display_order",
+];
+
+/// The set of category fields to populate in a response.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CategoryProjection {
+    /// Requested fields, or `None` to populate every field.
+    fields: Option<Vec<String>>,
+}
+
+impl CategoryProjection {
+    /// Build a projection from a request's optional read mask.
+    ///
+    /// A missing or empty mask populates every field.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidArgument` naming the first path that is not one of
+    /// [`CATEGORY_READ_FIELDS`].
+    pub fn from_read_mask(read_mask: Option<FieldMask>) -> Result<Self, tonic::Status> {
+        let paths = read_mask.map(|mask| mask.paths).unwrap_or_default();
+        if paths.is_empty() {
+            return Ok(Self::default());
+        }
+
+        if let Some(unknown) = paths.iter().find(|path| !CATEGORY_READ_FIELDS.contains(&path.as_str())) {
+            return Err(tonic::Status::invalid_argument(format!("Unknown field in read mask: {}", unknown)));
+        }
+
+        Ok(Self { fields: Some(paths) })
+    }
+
+    /// Returns `true` if `field` is populated by this projection.
+    pub fn includes(&self, field: &str) -> bool {
+        field == "id"
+            || self
+                .fields
+                .as_ref()
+                .is_none_or(|fields| fields.iter().any(|requested| requested == field))
+    }
+
+    /// Clear every field of `category` not included in this projection.
+    pub fn apply(&self, category: rpc::Category) -> rpc::Category {
+        if self.fields.is_none() {
+            return category;
+        }
+
+        let defaults = rpc::Category::default();
+        rpc::Category {
+            id: category.id,
+            code: if self.includes("code") { category.code } else { defaults.code },
+            name: if self.includes("name") { category.name } else { defaults.name },
+            description: if self.includes("description") { category.description } else { defaults.description },
+            url_slug: if self.includes("url_slug") { category.url_slug } else { defaults.url_slug },
+            category_type: if self.includes("category_type") { category.category_type } else { defaults.category_type },
+            color: if self.includes("color") { category.color } else { defaults.color },
+            icon: if self.includes("icon") { category.icon } else { defaults.icon },
+            is_active: if self.includes("is_active") { category.is_active } else { defaults.is_active },
+            created_on: if self.includes("created_on") { category.created_on } else { defaults.created_on },
+            updated_on: if self.includes("updated_on") { category.updated_on } else { defaults.updated_on },
+            display_order: if self.includes("display_order") { category.display_order } else { defaults.display_order },
+            created_on_local: if self.includes("created_on") { category.created_on_local } else { defaults.created_on_local },
+            updated_on_local: if self.includes("updated_on") { category.updated_on_local } else { defaults.updated_on_local },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database;
+
+    fn read_mask(paths: &[&str]) -> Option<FieldMask> {
+        Some(FieldMask { paths: paths.iter().map(|path| path.to_string()).collect() })
+    }
+
+    #[test]
+    fn test_missing_or_empty_mask_keeps_every_field() {
+        let category: rpc::Category = database::Categories::mock().into();
+
+        for mask in [None, read_mask(&[])] {
+            let projection = CategoryProjection::from_read_mask(mask).unwrap();
+            assert_eq!(projection.apply(category.clone()), category);
+        }
+    }
+
+    #[test]
+    fn test_mask_clears_unrequested_fields() {
+        let category: rpc::Category = database::Categories {
+            description: Some("Weekly shop".to_string()),
+            icon: Some("cart".to_string()),
+            ..database::Categories::mock()
+        }
+        .into();
+
+        let projection = CategoryProjection::from_read_mask(read_mask(&["name", "is_active"])).unwrap();
+        let projected = projection.apply(category.clone());
+
+        assert_eq!(projected.id, category.id);
+        assert_eq!(projected.name, category.name);
+        assert_eq!(projected.is_active, category.is_active);
+        assert!(projected.code.is_empty());
+        assert_eq!(projected.description, None);
+        assert_eq!(projected.icon, None);
+        assert_eq!(projected.color, None);
+        assert_eq!(projected.created_on, None);
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        let status = CategoryProjection::from_read_mask(read_mask(&["name", "colour"])).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("colour"));
+    }
+}
@@ -0,0 +1,83 @@
+//! # Scheduled Category Reactivation
+//!
+//! This module provides the background sweep that reactivates categories
+//! deactivated with a `reactivate_on` date, e.g. seasonal categories hidden
+//! until next year. It includes:
+//!
+//! - [`REACTIVATION_SWEEP_INTERVAL`], how often due categories are checked
+//! - [`run_reactivation_sweep`], the loop the server spawns alongside gRPC
+
+use std::sync::Arc;
+
+use crate::database;
+
+/// How often the server checks for categories due to be reactivated.
+pub const REACTIVATION_SWEEP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Reactivate due categories every `interval` until the pool is closed.
+///
+/// The first sweep runs immediately, so categories that fell due while the
+/// server was down are reactivated at startup. Sweep failures are logged and
+/// retried on the next tick. `category_cache` is cleared after a sweep that
+/// reactivated any category.
+pub async fn run_reactivation_sweep(
+    database_pool: Arc<sqlx::SqlitePool>,
+    category_cache: database::CategoryCache,
+    interval: std::time::Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        if database_pool.is_closed() {
+            tracing::debug!("Database pool closed, stopping reactivation sweep");
+            return;
+        }
+
+        match database::Categories::reactivate_due(chrono::Utc::now(), &database_pool).await {
+            // The sweep does not report which categories it reactivated
+            Ok(reactivated) if reactivated > 0 => category_cache.clear(),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Category reactivation sweep failed: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test]
+    async fn test_sweep_reactivates_due_categories(pool: sqlx::SqlitePool) {
+        let category = database::Categories::mock().insert(&pool).await.unwrap();
+        let past = chrono::Utc::now() - chrono::Duration::minutes(5);
+        database::Categories::deactivate_until(category.id, Some(past), &pool).await.unwrap();
+
+        // Cache the deactivated category, the sweep must not leave it stale
+        let category_cache = database::CategoryCache::new(8);
+        assert!(!category_cache.find_by_id(category.id, &pool).await.unwrap().unwrap().is_active);
+
+        let pool = Arc::new(pool);
+        let sweep = tokio::spawn(run_reactivation_sweep(
+            pool.clone(),
+            category_cache.clone(),
+            std::time::Duration::from_millis(10),
+        ));
+
+        let mut reactivated = false;
+        for _ in 0..100 {
+            let found = category_cache.find_by_id(category.id, &pool).await.unwrap().unwrap();
+            if found.is_active {
+                reactivated = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(reactivated, "category was not reactivated by the sweep");
+
+        // The sweep stops on its own once the pool is closed
+        pool.close().await;
+        tokio::time::timeout(std::time::Duration::from_secs(1), sweep).await.unwrap().unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+//! # Category Reorder Logic
+//!
+//! This module provides the service logic for reordering categories
+//! in the Personal Ledger backend. It includes:
+//!
+//! - Applying many `(id, display_order)` pairs in a single transaction
+//! - Rejecting the whole set when any category ID is unknown
+//! - Proper error handling for validation and database errors
+
+use crate::{database, rpc, services};
+
+/// Handle the category reorder logic for the gRPC service.
+///
+/// This function performs:
+/// - Parsing every ID in the request
+/// - Applying all display orders atomically in the database
+/// - Converting the reordered categories to gRPC response format
+/// - Proper error handling for invalid IDs, unknown categories and database errors
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoriesReorderResponse>)` on success
+/// * `Err(tonic::Status)` on validation, not found or database error
+pub async fn reorder_categories(
+    service: &super::CategoriesService,
+    request: tonic::Request<rpc::CategoriesReorderRequest>,
+) -> Result<tonic::Response<rpc::CategoriesReorderResponse>, tonic::Status> {
+    // Extract the inner request
+    let reorder_request = request.into_inner();
+
+    // Validate that we have categories to reorder
+    if reorder_request.orders.is_empty() {
+        return Err(tonic::Status::invalid_argument("No categories provided for reorder"));
+    }
+
+    // Parse all IDs from strings to RowIDs
+    let mut orders = Vec::with_capacity(reorder_request.orders.len());
+    for order in &reorder_request.orders {
+        match order.id.parse::<crate::domain::RowID>() {
+            Ok(id) => orders.push((id, order.display_order)),
+            Err(_) => {
+                return Err(tonic::Status::invalid_argument(format!("Invalid category ID format: {}", order.id)));
+            }
+        }
+    }
+
+    // Apply the new ordering in a single transaction
+    let reordered = match database::Categories::reorder(&orders, service.database_ref()).await {
+        Ok(categories) => categories,
+        Err(database::DatabaseError::NotFound(msg)) => {
+            return Err(tonic::Status::not_found(msg));
+        }
+        Err(database::DatabaseError::Validation(msg)) => {
+            return Err(tonic::Status::invalid_argument(msg));
+        }
+        Err(db_error) => {
+            tracing::error!("Failed to reorder categories: {}", db_error);
+            return Err(services::database_error_status(&db_error, "Failed to reorder categories"));
+        }
+    };
+
+    for category in &reordered {
+        service.category_cache().invalidate(category.id);
+    }
+
+    // Convert to RPC categories and return response
+    let response = rpc::CategoriesReorderResponse {
+        categories: reordered.into_iter().map(|category| service.localize(category.into())).collect(),
+    };
+
+    Ok(tonic::Response::new(response))
+}
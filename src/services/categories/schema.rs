@@ -0,0 +1,83 @@
+//! # Category Schema Logic
+//!
+//! This module provides the service logic for describing the category domain
+//! rules to clients in the Personal Ledger backend. It includes:
+//!
+//! - Every valid category type value
+//! - Maximum lengths and regular expressions for constrained fields
+//!
+//! The rules are read from the domain types that enforce them, so clients
+//! building forms never drift from what the server accepts.
+
+use crate::{domain, rpc};
+
+/// Build the validation rules for every constrained category field.
+///
+/// Patterns describe the stored, canonical form of a value. The server is more
+/// lenient on input: colours may omit `#` or use lowercase digits, and slugs
+/// and tags are cleaned before validation.
+pub fn category_field_rules() -> Vec<rpc::CategoryFieldRule> {
+    vec![
+        rpc::CategoryFieldRule {
+            field: "url_slug".to_string(),
+            max_length: None,
+            pattern: Some(domain::URL_SLUG_PATTERN.to_string()),
+        },
+        rpc::CategoryFieldRule {
+            field: "color".to_string(),
+            max_length: Some(7),
+            pattern: Some(domain::HEX_COLOR_PATTERN.to_string()),
+        },
+        rpc::CategoryFieldRule {
+            field: "external_id".to_string(),
+            max_length: Some(domain::EXTERNAL_REF_MAX_LENGTH as u32),
+            pattern: Some(domain::EXTERNAL_REF_PATTERN.to_string()),
+        },
+        rpc::CategoryFieldRule {
+            field: "tag".to_string(),
+            max_length: Some(domain::TAG_MAX_LENGTH as u32),
+            pattern: Some(domain::TAG_PATTERN.to_string()),
+        },
+    ]
+}
+
+/// Handle the category schema logic for the gRPC service.
+///
+/// # Arguments
+/// * `_service` - Reference to the `CategoriesService`
+/// * `_request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<GetCategorySchemaResponse>)` with the category types
+///   and field rules
+pub async fn get_category_schema(
+    _service: &super::CategoriesService,
+    _request: tonic::Request<rpc::GetCategorySchemaRequest>,
+) -> Result<tonic::Response<rpc::GetCategorySchemaResponse>, tonic::Status> {
+    let response = rpc::GetCategorySchemaResponse {
+        category_types: domain::CategoryTypes::variants().into_iter().map(String::from).collect(),
+        fields: category_field_rules(),
+    };
+
+    Ok(tonic::Response::new(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_rules_cover_each_field_once() {
+        let rules = category_field_rules();
+        let mut fields: Vec<_> = rules.iter().map(|rule| rule.field.as_str()).collect();
+        fields.sort();
+        fields.dedup();
+        assert_eq!(fields.len(), rules.len());
+    }
+
+    #[test]
+    fn test_color_rule_matches_canonical_length() {
+        let color = category_field_rules().into_iter().find(|rule| rule.field == "color").unwrap();
+        assert_eq!(color.max_length, Some(domain::HexColor::mock().as_str().len() as u32));
+    }
+}
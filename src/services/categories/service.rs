@@ -25,13 +25,14 @@
 
 use std::sync::Arc;
 
-use crate::{database, rpc, LedgerConfig};
+use crate::{config, database, rpc, services, LedgerConfig};
 use tonic;
 
 /// Service for handling category-related gRPC requests.
 ///
 /// Provides methods to interact with category data via gRPC, including CRUD operations
-/// and batch processing. It holds references to the database pool and ledger configuration.
+/// and batch processing. It holds references to the database pool and ledger configuration,
+/// and the key used to sign list page tokens.
 ///
 /// # Examples
 ///
@@ -51,8 +52,11 @@ use tonic;
 /// ```
 pub struct CategoriesService {
     database_pool: Arc<sqlx::SqlitePool>,
-    #[allow(dead_code)]
     ledger_config: Arc<LedgerConfig>,
+    cursor_key: services::CursorKey,
+    event_bus: services::EventBus,
+    display_timezone: Option<chrono_tz::Tz>,
+    category_cache: crate::database::CategoryCache,
 }
 
 impl CategoriesService {
@@ -82,7 +86,34 @@ impl CategoriesService {
     /// # }
     /// ```
     pub fn new(database_pool: Arc<sqlx::SqlitePool>, ledger_config: Arc<LedgerConfig>) -> Self {
-        Self { database_pool, ledger_config }
+        let cursor_key = services::CursorKey::from_config(&ledger_config.server);
+
+        // An invalid timezone fails router startup, see `ServerConfig::display_tz`
+        let display_timezone = ledger_config.server.display_tz().ok().flatten();
+
+        let category_cache = crate::database::CategoryCache::new(ledger_config.server.category_cache_size);
+
+        Self {
+            database_pool,
+            ledger_config,
+            cursor_key,
+            event_bus: services::EventBus::default(),
+            display_timezone,
+            category_cache,
+        }
+    }
+
+    /// Publish category changes on `event_bus`, shared with the `EventStream` handler.
+    pub fn with_event_bus(mut self, event_bus: services::EventBus) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// Look categories up through `category_cache`, shared with every other
+    /// writer so their changes invalidate it.
+    pub fn with_category_cache(mut self, category_cache: crate::database::CategoryCache) -> Self {
+        self.category_cache = category_cache;
+        self
     }
 
     /// Shorthand for reference to database pool.
@@ -110,6 +141,138 @@ impl CategoriesService {
     pub fn database_ref(&self) -> &sqlx::SqlitePool {
         &self.database_pool
     }
+
+    /// Shared handle to the database pool.
+    ///
+    /// Use this instead of [`database_ref`](Self::database_ref) when the pool has
+    /// to outlive the request, for example in a task feeding a streaming response.
+    ///
+    /// # Returns
+    ///
+    /// A cloned `Arc` pointing at the same SqlitePool.
+    pub fn database_pool(&self) -> Arc<sqlx::SqlitePool> {
+        Arc::clone(&self.database_pool)
+    }
+
+    /// Check that `feature` is enabled in the server configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `tonic::Code::Unimplemented` if the feature is disabled, see
+    /// [`config::feature_flags`].
+    pub fn require_feature(&self, feature: &str) -> Result<(), tonic::Status> {
+        if config::feature_flags(&self.ledger_config.server).get(feature).copied().unwrap_or(false) {
+            Ok(())
+        } else {
+            Err(tonic::Status::unimplemented(format!("Feature '{}' is disabled on this server", feature)))
+        }
+    }
+
+    /// Time budget and progress logging for batch writes, from the server configuration.
+    pub fn batch_limits(&self) -> crate::database::BatchLimits {
+        crate::database::BatchLimits::from_config(&self.ledger_config.server)
+    }
+
+    /// Cap on the number of stored categories, `None` when there is no cap.
+    pub fn max_categories(&self) -> Option<usize> {
+        Some(self.ledger_config.server.max_categories).filter(|max| *max > 0)
+    }
+
+    /// Whether unknown proto enum values are rejected, see [`services::parse_enum_i32`].
+    pub fn strict_enums(&self) -> bool {
+        self.ledger_config.server.strict_enums
+    }
+
+    /// Whether create requests may supply the category id, from the server configuration.
+    pub fn accept_client_ids(&self) -> bool {
+        self.ledger_config.server.accept_client_ids
+    }
+
+    /// Key used to sign and verify list page tokens.
+    pub fn cursor_key(&self) -> &services::CursorKey {
+        &self.cursor_key
+    }
+
+    /// Add renderings of the category's timestamps in the configured display
+    /// timezone, see [`services::localize_category`].
+    pub fn localize(&self, category: rpc::Category) -> rpc::Category {
+        services::localize_category(category, self.display_timezone)
+    }
+
+    /// Time zone categories are localized to, for handlers that localize
+    /// outside the request, such as in a task feeding a streaming response.
+    pub fn display_timezone(&self) -> Option<chrono_tz::Tz> {
+        self.display_timezone
+    }
+
+    /// Localize every category in a tree node, see [`localize`](Self::localize).
+    pub fn localize_node(&self, node: rpc::CategoryNode) -> rpc::CategoryNode {
+        services::localize_category_node(node, self.display_timezone)
+    }
+
+    /// Cache in front of category lookups by id. Handlers that write a
+    /// category must [`invalidate`](crate::database::CategoryCache::invalidate) it.
+    pub fn category_cache(&self) -> &crate::database::CategoryCache {
+        &self.category_cache
+    }
+
+    /// Run `f` in a database transaction, committing if it returns `Ok` and
+    /// rolling back if it returns `Err`.
+    ///
+    /// Lets a caller compose several writes, such as
+    /// [`Categories::insert_tx`](database::Categories::insert_tx),
+    /// [`update_tx`](database::Categories::update_tx) and
+    /// [`delete_by_id_tx`](database::Categories::delete_by_id_tx), into one
+    /// atomic unit. The closure returns a boxed future borrowing the
+    /// transaction:
+    ///
+    /// ```rust,no_run
+    /// # use personal_ledger_backend::{database, CategoriesService};
+    /// # async fn example(service: &CategoriesService, first: database::Categories, second: database::Categories) -> Result<(), database::DatabaseError> {
+    /// let (first, second) = service
+    ///     .with_transaction(|tx| Box::pin(async move {
+    ///         Ok((first.insert_tx(tx).await?, second.insert_tx(tx).await?))
+    ///     }))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Writes in the transaction bypass the per-id cache invalidation of the
+    /// handlers, so the category cache is cleared after a commit. No events
+    /// are published; callers publish their own once this returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f`, after rolling back, or a
+    /// [`DatabaseError`](database::DatabaseError) if the transaction cannot be
+    /// started, committed or rolled back.
+    pub async fn with_transaction<T, F>(&self, f: F) -> database::DatabaseResult<T>
+    where
+        F: for<'t> FnOnce(
+            &'t mut sqlx::Transaction<'static, sqlx::Sqlite>,
+        ) -> futures_util::future::BoxFuture<'t, database::DatabaseResult<T>>,
+    {
+        let mut tx = self.database_pool.begin().await?;
+
+        match f(&mut tx).await {
+            Ok(value) => {
+                tx.commit().await?;
+                self.category_cache.clear();
+                Ok(value)
+            }
+            Err(e) => {
+                tracing::warn!("Rolling back transaction: {}", e);
+                tx.rollback().await?;
+                Err(e)
+            }
+        }
+    }
+
+    /// Publish a change to the category `id` for `EventStream` subscribers.
+    pub fn publish_event(&self, kind: services::EventKind, id: impl Into<String>) {
+        self.event_bus.publish(services::DomainEvent::new(kind, services::EVENT_RESOURCE_CATEGORY, id));
+    }
 }
 
 /// Convert a database::Category into a Category Response message.
@@ -137,8 +300,13 @@ impl CategoriesService {
 ///     color: None,
 ///     icon: None,
 ///     is_active: true,
+///     display_order: 0,
+///     external_id: None,
+///     reactivate_on: None,
+///     parent_id: None,
 ///     created_on: Utc::now(),
 ///     updated_on: Utc::now(),
+///     deleted_on: None,
 /// };
 ///
 /// let rpc_category: Category = db_category.into();
@@ -158,6 +326,7 @@ impl From<database::Categories> for rpc::Category {
             color: category.color.map(|c| c.to_string()),
             icon: category.icon,
             is_active: category.is_active,
+            display_order: category.display_order,
             created_on: Some(Timestamp {
                 seconds: category.created_on.timestamp(),
                 nanos: category.created_on.timestamp_subsec_nanos() as i32,
@@ -166,12 +335,42 @@ impl From<database::Categories> for rpc::Category {
                 seconds: category.updated_on.timestamp(),
                 nanos: category.updated_on.timestamp_subsec_nanos() as i32,
             }),
+            // Set by `CategoriesService::localize` when a display timezone is configured
+            created_on_local: None,
+            updated_on_local: None,
+        }
+    }
+}
+
+/// Convert a database category node, and its children, into gRPC format.
+impl From<database::CategoryNode> for rpc::CategoryNode {
+    fn from(node: database::CategoryNode) -> Self {
+        Self {
+            category: Some(node.category.into()),
+            children: node.children.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Convert a field difference between two categories into gRPC format.
+impl From<database::FieldChange> for rpc::CategoryFieldChange {
+    fn from(change: database::FieldChange) -> Self {
+        Self {
+            field: change.field.to_string(),
+            old_value: change.old_value,
+            new_value: change.new_value,
         }
     }
 }
 
 #[tonic::async_trait]
 impl crate::rpc::CategoriesService for CategoriesService {
+    /// Server stream of CSV chunks produced by `categories_export_csv`.
+    type CategoriesExportCsvStream = crate::services::categories::CategoriesExportCsvStream;
+
+    /// Server stream of categories produced by `categories_list_stream`.
+    type CategoriesListStreamStream = crate::services::categories::CategoriesListStreamStream;
+
     /// Activate a category by setting its active status to true.
     ///
     /// # Arguments
@@ -220,6 +419,22 @@ impl crate::rpc::CategoriesService for CategoriesService {
         crate::services::categories::create_batch_categories(self, request).await
     }
 
+    /// Validate multiple categories without creating them.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request containing a list of categories to check.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response with every validation error found, or an error status.
+    async fn categories_validate_batch(
+        &self,
+        request: tonic::Request<crate::rpc::CategoriesValidateBatchRequest>,
+    ) -> Result<tonic::Response<crate::rpc::CategoriesValidateBatchResponse>, tonic::Status> {
+        crate::services::categories::validate_batch_categories(self, request).await
+    }
+
     /// Deactivate a category by setting its active status to false.
     ///
     /// # Arguments
@@ -268,6 +483,86 @@ impl crate::rpc::CategoriesService for CategoriesService {
         crate::services::categories::delete_categories_batch(self, request).await
     }
 
+    /// Stream all categories as CSV chunks, header first.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request to start the export.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response carrying a stream of CSV chunks or an error status.
+    async fn categories_export_csv(
+        &self,
+        request: tonic::Request<crate::rpc::CategoriesExportCsvRequest>,
+    ) -> Result<tonic::Response<Self::CategoriesExportCsvStream>, tonic::Status> {
+        crate::services::categories::export_categories_csv(self, request).await
+    }
+
+    /// Reorder many categories atomically by assigning new display orders.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request containing the `(id, display_order)` pairs.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response with the reordered categories or an error status.
+    async fn categories_reorder(
+        &self,
+        request: tonic::Request<crate::rpc::CategoriesReorderRequest>,
+    ) -> Result<tonic::Response<crate::rpc::CategoriesReorderResponse>, tonic::Status> {
+        crate::services::categories::reorder_categories(self, request).await
+    }
+
+    /// Preview a category update as a field diff, without saving it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request containing the update to preview.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response with the would-be category and its changed fields, or an error status.
+    async fn category_update_preview(
+        &self,
+        request: tonic::Request<crate::rpc::CategoryUpdatePreviewRequest>,
+    ) -> Result<tonic::Response<crate::rpc::CategoryUpdatePreviewResponse>, tonic::Status> {
+        crate::services::categories::preview_category_update(self, request).await
+    }
+
+    /// Return every category nested by parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request for the tree.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response with the top-level category nodes or an error status.
+    async fn categories_tree(
+        &self,
+        request: tonic::Request<crate::rpc::CategoriesTreeRequest>,
+    ) -> Result<tonic::Response<crate::rpc::CategoriesTreeResponse>, tonic::Status> {
+        crate::services::categories::categories_tree(self, request).await
+    }
+
+    /// Describe the valid category types and field validation rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request (empty).
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response with the category schema.
+    async fn get_category_schema(
+        &self,
+        request: tonic::Request<crate::rpc::GetCategorySchemaRequest>,
+    ) -> Result<tonic::Response<crate::rpc::GetCategorySchemaResponse>, tonic::Status> {
+        crate::services::categories::get_category_schema(self, request).await
+    }
+
     /// List categories with optional filtering and pagination.
     ///
     /// # Arguments
@@ -284,6 +579,22 @@ impl crate::rpc::CategoriesService for CategoriesService {
         crate::services::categories::list_categories(self, request).await
     }
 
+    /// Stream every category, one per message, in creation order.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The gRPC request to start the listing.
+    ///
+    /// # Returns
+    ///
+    /// A gRPC response carrying a stream of categories or an error status.
+    async fn categories_list_stream(
+        &self,
+        request: tonic::Request<crate::rpc::CategoriesListStreamRequest>,
+    ) -> Result<tonic::Response<Self::CategoriesListStreamStream>, tonic::Status> {
+        crate::services::categories::list_categories_stream(self, request).await
+    }
+
     /// Get a category by its unique ID.
     ///
     /// # Arguments
@@ -404,8 +715,13 @@ mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: chrono::Utc::now(),
             updated_on: chrono::Utc::now(),
+            deleted_on: None,
         };
 
         // Convert to RPC category
@@ -443,8 +759,13 @@ mod tests {
             color: Some(crate::domain::HexColor::parse("#FF5733").unwrap()),
             icon: Some("dollar-sign".to_string()),
             is_active: false,
+            display_order: 0,
+            external_id: None,
+            reactivate_on: None,
+            parent_id: None,
             created_on: now,
             updated_on: now,
+            deleted_on: None,
         };
 
         // Convert to RPC category
@@ -466,6 +787,30 @@ mod tests {
         assert!(rpc_category.updated_on.is_some());
     }
 
+    #[test]
+    fn test_from_database_category_to_rpc_category_known_timestamps_and_id() {
+        use chrono::TimeZone;
+
+        let created_on = chrono::Utc.with_ymd_and_hms(2024, 2, 29, 12, 34, 56).unwrap()
+            + chrono::Duration::nanoseconds(789_012_345);
+        let updated_on = chrono::Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let db_category = database::Categories { created_on, updated_on, ..database::Categories::mock() };
+
+        let rpc_category: rpc::Category = db_category.clone().into();
+
+        let created_ts = rpc_category.created_on.unwrap();
+        assert_eq!(created_ts.seconds, 1_709_210_096);
+        assert_eq!(created_ts.nanos, 789_012_345);
+        let updated_ts = rpc_category.updated_on.unwrap();
+        assert_eq!(updated_ts.seconds, 1_735_689_600);
+        assert_eq!(updated_ts.nanos, 0);
+
+        // The id is the canonical hyphenated UUID and parses back to the same row id
+        assert_eq!(rpc_category.id.len(), 36);
+        assert_eq!(rpc_category.id, rpc_category.id.to_lowercase());
+        assert_eq!(rpc_category.id.parse::<crate::domain::RowID>().unwrap(), db_category.id);
+    }
+
     #[tokio::test]
     async fn test_categories_service_new() {
         // Test that CategoriesService::new creates an instance correctly
@@ -489,4 +834,58 @@ mod tests {
         // Check that the references point to the same pool instance
         assert!(std::ptr::eq(db_ref, &*pool));
     }
+
+    #[sqlx::test]
+    async fn test_with_transaction_rolls_back_on_error(pool: sqlx::SqlitePool) {
+        let service = CategoriesService::new(Arc::new(pool.clone()), Arc::new(crate::LedgerConfig::default()));
+        let first = database::Categories::mock();
+        let second = database::Categories::mock();
+        let ids = [first.id, second.id];
+
+        let result: database::DatabaseResult<()> = service
+            .with_transaction(|tx| Box::pin(async move {
+                first.insert_tx(tx).await?;
+                second.insert_tx(tx).await?;
+                Err(database::DatabaseError::Validation("abandon the transaction".to_string()))
+            }))
+            .await;
+
+        assert!(matches!(result, Err(database::DatabaseError::Validation(_))));
+        for id in ids {
+            assert!(database::Categories::find_by_id(id, &pool).await.unwrap().is_none());
+        }
+    }
+
+    #[sqlx::test]
+    async fn test_with_transaction_commits_on_ok(pool: sqlx::SqlitePool) {
+        let service = CategoriesService::new(Arc::new(pool.clone()), Arc::new(crate::LedgerConfig::default()));
+        let kept = database::Categories::mock();
+        let removed = database::Categories::mock().insert(&pool).await.unwrap();
+
+        let inserted = service
+            .with_transaction(|tx| Box::pin(async move {
+                let inserted = kept.insert_tx(tx).await?;
+                let renamed = database::Categories { name: "Renamed In Transaction".to_string(), ..inserted };
+                let updated = renamed.update_tx(tx).await?;
+                database::Categories::delete_by_id_tx(removed.id, tx).await?;
+                Ok(updated)
+            }))
+            .await
+            .unwrap();
+
+        let found = database::Categories::find_by_id(inserted.id, &pool).await.unwrap().unwrap();
+        assert_eq!(found.name, "Renamed In Transaction");
+        assert!(database::Categories::find_by_id(removed.id, &pool).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_database_pool_shares_arc() {
+        let pool = Arc::new(sqlx::SqlitePool::connect_lazy("sqlite::memory:").unwrap());
+        let config = Arc::new(crate::LedgerConfig::default());
+        let service = CategoriesService::new(pool.clone(), config);
+
+        let shared = service.database_pool();
+        assert!(Arc::ptr_eq(&shared, &pool));
+        assert_eq!(Arc::strong_count(&pool), 3);
+    }
 }
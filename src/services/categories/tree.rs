@@ -0,0 +1,50 @@
+//! # Category Tree Logic
+//!
+//! This module provides the service logic for returning every category
+//! nested by parent in the Personal Ledger backend. It includes:
+//!
+//! - Loading the whole hierarchy in one query and nesting it in memory
+//! - Returning orphaned categories (missing parent) at the top level
+//! - Proper error handling for cyclic hierarchies and database errors
+
+use crate::{config, database, rpc, services};
+
+/// Handle the category tree logic for the gRPC service.
+///
+/// This function performs:
+/// - Loading all categories nested by `parent_id`
+/// - Converting the nodes to gRPC response format
+/// - Proper error handling for cyclic hierarchies and database errors
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `_request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoriesTreeResponse>)` on success
+/// * `Err(tonic::Status)` with `Unimplemented` when the hierarchy is disabled,
+///   or on a cyclic hierarchy or database error
+pub async fn categories_tree(
+    service: &super::CategoriesService,
+    _request: tonic::Request<rpc::CategoriesTreeRequest>,
+) -> Result<tonic::Response<rpc::CategoriesTreeResponse>, tonic::Status> {
+    service.require_feature(config::FEATURE_HIERARCHY)?;
+
+    let roots = match database::Categories::find_all_as_tree(service.database_ref()).await {
+        Ok(roots) => roots,
+        Err(database::DatabaseError::Validation(msg)) => {
+            tracing::error!("Cannot assemble category tree: {}", msg);
+            return Err(tonic::Status::failed_precondition(msg));
+        }
+        Err(db_error) => {
+            tracing::error!("Failed to load category tree: {}", db_error);
+            return Err(services::database_error_status(&db_error, "Failed to load category tree"));
+        }
+    };
+
+    let response = rpc::CategoriesTreeResponse {
+        roots: roots.into_iter().map(|node| service.localize_node(node.into())).collect(),
+    };
+
+    Ok(tonic::Response::new(response))
+}
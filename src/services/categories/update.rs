@@ -4,9 +4,10 @@
 //! in the Personal Ledger backend. It includes:
 //!
 //! - Partial and full category updates with field masking
+//! - Previewing an update as a field diff without saving it
 //! - Proper validation and error handling
 
-use crate::{database, rpc, services::ServiceError};
+use crate::{database, rpc, services::{self, ServiceError}};
 use prost_types::FieldMask;
 
 /// Handle the category update logic for the gRPC service.
@@ -33,13 +34,112 @@ pub async fn update_category(
     // Extract the inner request
     let update_request = request.into_inner();
 
+    let (_, updated_category) = load_and_apply_update(
+        service,
+        &update_request.id,
+        update_request.category,
+        update_request.update_mask,
+        "CategoryUpdateRequest",
+    )
+    .await?;
+
+    // Update the category in the database
+    let saved_category = match updated_category.update(service.database_ref()).await {
+        Ok(category) => category,
+        Err(database::DatabaseError::NotFound(_)) => {
+            return Err(services::not_found_status("Category", "ID", &update_request.id));
+        }
+        Err(database::DatabaseError::Validation(msg)) => {
+            return Err(tonic::Status::invalid_argument(msg));
+        }
+        Err(db_error) => {
+            tracing::error!("Failed to update category {}: {}", update_request.id, db_error);
+            return Err(services::database_error_status(&db_error, "Failed to update category"));
+        }
+    };
+
+    service.category_cache().invalidate(saved_category.id);
+    service.publish_event(services::EventKind::Updated, saved_category.id.to_string());
+
+    // Convert to RPC category and return response
+    let rpc_category = service.localize(saved_category.into());
+    let response = rpc::CategoryUpdateResponse {
+        category: Some(rpc_category),
+    };
+
+    Ok(tonic::Response::new(response))
+}
+
+/// Handle the category update preview logic for the gRPC service.
+///
+/// This function performs the same steps as [`update_category`] up to saving:
+/// - Retrieving the existing category and applying the field mask
+/// - Diffing the stored category against the updated one
+/// - Returning both without writing to the database
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `request` - The incoming gRPC request
+///
+/// # Returns
+/// * `Ok(tonic::Response<CategoryUpdatePreviewResponse>)` with the would-be
+///   category and its changed fields
+/// * `Err(tonic::Status)` on not found, validation error, or database error
+pub async fn preview_category_update(
+    service: &super::CategoriesService,
+    request: tonic::Request<rpc::CategoryUpdatePreviewRequest>,
+) -> Result<tonic::Response<rpc::CategoryUpdatePreviewResponse>, tonic::Status> {
+    let preview_request = request.into_inner();
+
+    let (existing_category, updated_category) = load_and_apply_update(
+        service,
+        &preview_request.id,
+        preview_request.category,
+        preview_request.update_mask,
+        "CategoryUpdatePreviewRequest",
+    )
+    .await?;
+
+    let changes = existing_category
+        .diff(&updated_category)
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let response = rpc::CategoryUpdatePreviewResponse {
+        category: Some(service.localize(updated_category.into())),
+        changes,
+    };
+
+    Ok(tonic::Response::new(response))
+}
+
+/// Retrieve a category and apply an update to a copy of it.
+///
+/// # Arguments
+/// * `service` - Reference to the `CategoriesService` (for DB access)
+/// * `id` - The category ID from the request
+/// * `category` - The new category data from the request
+/// * `update_mask` - Optional field mask specifying which fields to update
+/// * `request_name` - The request message name, for the missing category error
+///
+/// # Returns
+/// * `Ok((existing, updated))` with the stored category and the updated copy
+/// * `Err(tonic::Status)` on not found, validation error, or database error
+async fn load_and_apply_update(
+    service: &super::CategoriesService,
+    id: &str,
+    category: Option<rpc::Category>,
+    update_mask: Option<FieldMask>,
+    request_name: &str,
+) -> Result<(database::Categories, database::Categories), tonic::Status> {
     // Get the category data from the request first
-    let new_category_data = update_request.category.ok_or_else(|| {
-        tonic::Status::invalid_argument("Category field is required in CategoryUpdateRequest")
+    let new_category_data = category.ok_or_else(|| {
+        tonic::Status::invalid_argument(format!("Category field is required in {}", request_name))
     })?;
 
     // Parse the ID from string to RowID
-    let category_id = match update_request.id.parse::<crate::domain::RowID>() {
+    let category_id = match id.parse::<crate::domain::RowID>() {
         Ok(id) => id,
         Err(_) => {
             return Err(tonic::Status::invalid_argument("Invalid category ID format"));
@@ -48,56 +148,27 @@ pub async fn update_category(
 
     // Retrieve the existing category
     let existing_category = match database::Categories::find_by_id(category_id, service.database_ref()).await {
-        Ok(Some(category)) => category,
-        Ok(None) => {
-            return Err(tonic::Status::not_found(format!("Category with ID '{}' not found", update_request.id)));
-        }
+        Ok(found) => services::require_found(found, "Category", "ID", id)?,
         Err(db_error) => {
-            tracing::error!("Failed to find category {}: {}", update_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to retrieve category"));
+            tracing::error!("Failed to find category {}: {}", id, db_error);
+            return Err(services::database_error_status(&db_error, "Failed to retrieve category"));
         }
     };
 
     // Apply the updates based on the field mask
-    let updated_category = match apply_field_mask_updates(existing_category, new_category_data, update_request.update_mask) {
+    let updated_category = match apply_field_mask_updates(
+        existing_category.clone(),
+        new_category_data,
+        update_mask,
+        service.strict_enums(),
+    ) {
         Ok(category) => category,
         Err(service_error) => {
-            // Convert ServiceError to tonic::Status
-            let status_code = match service_error.http_status_code() {
-                400 => tonic::Code::InvalidArgument,
-                401 => tonic::Code::Unauthenticated,
-                404 => tonic::Code::NotFound,
-                422 => tonic::Code::FailedPrecondition,
-                500 => tonic::Code::Internal,
-                502 => tonic::Code::Unavailable,
-                _ => tonic::Code::Internal,
-            };
-            return Err(tonic::Status::new(status_code, service_error.to_string()));
+            return Err(service_error.to_status());
         }
     };
 
-    // Update the category in the database
-    let saved_category = match updated_category.update(service.database_ref()).await {
-        Ok(category) => category,
-        Err(database::DatabaseError::NotFound(_)) => {
-            return Err(tonic::Status::not_found(format!("Category with ID '{}' not found", update_request.id)));
-        }
-        Err(database::DatabaseError::Validation(msg)) => {
-            return Err(tonic::Status::invalid_argument(msg));
-        }
-        Err(db_error) => {
-            tracing::error!("Failed to update category {}: {}", update_request.id, db_error);
-            return Err(tonic::Status::internal("Failed to update category"));
-        }
-    };
-
-    // Convert to RPC category and return response
-    let rpc_category: rpc::Category = saved_category.into();
-    let response = rpc::CategoryUpdateResponse {
-        category: Some(rpc_category),
-    };
-
-    Ok(tonic::Response::new(response))
+    Ok((existing_category, updated_category))
 }
 
 /// Apply field mask updates to an existing category.
@@ -110,6 +181,7 @@ pub async fn update_category(
 /// * `existing` - The existing category from the database
 /// * `new_data` - The new category data from the request
 /// * `field_mask` - Optional field mask specifying which fields to update
+/// * `strict_enums` - Whether an unknown category type is an error, see [`services::parse_enum_i32`]
 ///
 /// # Returns
 /// * `Ok(database::Categories)` with the updated category
@@ -118,8 +190,10 @@ fn apply_field_mask_updates(
     mut existing: database::Categories,
     new_data: rpc::Category,
     field_mask: Option<FieldMask>,
+    strict_enums: bool,
 ) -> Result<database::Categories, ServiceError> {
-    // If no field mask is provided, update all fields
+    // If no field mask is provided, update all fields except display_order,
+    // which is only changed when named explicitly or through reorder
     let field_mask = field_mask.unwrap_or_else(|| FieldMask {
         paths: vec![
             "code".to_string(),
@@ -138,51 +212,41 @@ fn apply_field_mask_updates(
         match path.as_str() {
             "code" => {
                 if new_data.code.trim().is_empty() {
-                    return Err(ServiceError::validation("Category code cannot be empty"));
+                    return Err(ServiceError::rule_violation(services::REASON_CODE_REQUIRED, "Category code cannot be empty"));
                 }
                 existing.code = new_data.code.clone();
             }
             "name" => {
                 if new_data.name.trim().is_empty() {
-                    return Err(ServiceError::validation("Category name cannot be empty"));
+                    return Err(ServiceError::rule_violation(services::REASON_NAME_REQUIRED, "Category name cannot be empty"));
                 }
                 existing.name = new_data.name.clone();
             }
             "description" => {
-                existing.description = new_data.description.clone();
+                existing.description = services::normalize_optional_text(new_data.description.clone());
             }
             "url_slug" => {
-                existing.url_slug = if let Some(slug) = &new_data.url_slug {
-                    if slug.trim().is_empty() {
-                        None
-                    } else {
-                        Some(crate::domain::UrlSlug::parse(slug.clone())?)
-                    }
-                } else {
-                    None
-                };
+                existing.url_slug = services::normalize_optional(new_data.url_slug.clone())
+                    .map(crate::domain::UrlSlug::parse)
+                    .transpose()?;
             }
             "category_type" => {
-                existing.category_type = crate::domain::CategoryTypes::from_rpc_i32(new_data.category_type)
-                    .map_err(|e| ServiceError::validation(&e))?;
+                existing.category_type = services::parse_enum_i32(new_data.category_type, strict_enums)?;
             }
             "color" => {
-                existing.color = if let Some(color_str) = &new_data.color {
-                    if color_str.trim().is_empty() {
-                        None
-                    } else {
-                        Some(crate::domain::HexColor::parse(color_str.clone())?)
-                    }
-                } else {
-                    None
-                };
+                existing.color = services::normalize_optional(new_data.color.clone())
+                    .map(crate::domain::HexColor::parse)
+                    .transpose()?;
             }
             "icon" => {
-                existing.icon = new_data.icon.clone();
+                existing.icon = services::normalize_optional(new_data.icon.clone());
             }
             "is_active" => {
                 existing.is_active = new_data.is_active;
             }
+            "display_order" => {
+                existing.display_order = new_data.display_order;
+            }
             _ => {
                 return Err(ServiceError::validation(format!("Unknown field in update mask: {}", path)));
             }
@@ -217,12 +281,15 @@ mod tests {
             color: Some("#FF0000".to_string()),
             icon: Some("new-icon".to_string()),
             is_active: false,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         };
 
         // No field mask means full update
-        let result = apply_field_mask_updates(existing.clone(), new_data, None);
+        let result = apply_field_mask_updates(existing.clone(), new_data, None, true);
 
         assert!(result.is_ok());
         let updated = result.unwrap();
@@ -231,6 +298,24 @@ mod tests {
         assert_eq!(updated.description, Some("New description".to_string()));
         assert_eq!(updated.category_type, domain::CategoryTypes::Income);
         assert!(!updated.is_active);
+        // Display order is only changed when named in the mask
+        assert_eq!(updated.display_order, existing.display_order);
+    }
+
+    #[test]
+    fn test_apply_field_mask_updates_display_order() {
+        let existing = database::Categories::mock();
+        let new_data = rpc::Category {
+            display_order: existing.display_order + 5,
+            ..existing.clone().into()
+        };
+
+        let field_mask = FieldMask {
+            paths: vec!["display_order".to_string()],
+        };
+
+        let updated = apply_field_mask_updates(existing.clone(), new_data, Some(field_mask), true).unwrap();
+        assert_eq!(updated.display_order, existing.display_order + 5);
     }
 
     #[test]
@@ -249,8 +334,11 @@ mod tests {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         };
 
         // Field mask for only name
@@ -258,7 +346,7 @@ mod tests {
             paths: vec!["name".to_string()],
         };
 
-        let result = apply_field_mask_updates(existing.clone(), new_data, Some(field_mask));
+        let result = apply_field_mask_updates(existing.clone(), new_data, Some(field_mask), true);
 
         assert!(result.is_ok());
         let updated = result.unwrap();
@@ -280,7 +368,7 @@ mod tests {
             paths: vec!["code".to_string()],
         };
 
-        let result = apply_field_mask_updates(existing, new_data, Some(field_mask));
+        let result = apply_field_mask_updates(existing, new_data, Some(field_mask), true);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("code cannot be empty"));
     }
@@ -296,7 +384,7 @@ mod tests {
             paths: vec!["invalid_field".to_string()],
         };
 
-        let result = apply_field_mask_updates(existing, new_data, Some(field_mask));
+        let result = apply_field_mask_updates(existing, new_data, Some(field_mask), true);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Unknown field"));
     }
@@ -0,0 +1,300 @@
+//! # Page Cursors
+//!
+//! This module turns the sort key of the last row on a page, its `created_on`
+//! and [`RowID`](domain::RowID), into an opaque page token, and back again.
+//! The next page is read by comparing against that key directly, so a token
+//! keeps working after its row is deleted. Handing out raw keys would leak our
+//! internal ordering and let clients guess cursors, so a token is the URL-safe
+//! base64 encoding of:
+//!
+//! ```text
+//! version (1 byte) | created_on seconds (8 bytes) | created_on nanoseconds (4 bytes)
+//!     | row id (16 bytes) | HMAC-SHA256 tag (first 16 bytes)
+//! ```
+//!
+//! The tag is keyed by the server's `page_token_secret`, so a token that was
+//! forged, truncated or edited fails verification and is rejected with
+//! `tonic::Code::InvalidArgument`.
+//!
+//! List endpoints do not call [`CursorKey`] directly. They read their request
+//! parameter into a [`PageToken`], which treats a missing or blank token as the
+//! first page and names the parameter when a token is rejected.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use secrecy::ExposeSecret;
+use sha2::Sha256;
+
+use crate::{config::ServerConfig, domain};
+
+/// Version byte at the start of every page token.
+const CURSOR_VERSION: u8 = 2;
+
+/// Number of HMAC bytes kept in a page token.
+const TAG_LENGTH: usize = 16;
+
+/// Length in bytes of a decoded page token.
+const TOKEN_LENGTH: usize = 1 + 8 + 4 + 16 + TAG_LENGTH;
+
+/// Message returned to clients whose page token is rejected.
+pub const INVALID_PAGE_TOKEN_MESSAGE: &str = "Invalid page token";
+
+/// Signs and verifies opaque page tokens.
+#[derive(Clone)]
+pub struct CursorKey {
+    mac: Hmac<Sha256>,
+}
+
+impl CursorKey {
+    /// Create a key from a server secret.
+    pub fn new(secret: &[u8]) -> Self {
+        let mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        Self { mac }
+    }
+
+    /// Create a key from a random secret, valid for the life of the process.
+    pub fn random() -> Self {
+        let secret: [u8; 32] = rand::random();
+        Self::new(&secret)
+    }
+
+    /// Create a key from `page_token_secret`, or a random key when it is not set.
+    pub fn from_config(server_config: &ServerConfig) -> Self {
+        match &server_config.page_token_secret {
+            Some(secret) => Self::new(secret.expose_secret().as_bytes()),
+            None => {
+                tracing::info!("No page_token_secret configured, page tokens will not survive a restart");
+                Self::random()
+            }
+        }
+    }
+
+    /// Encode the sort key `created_on` and `row_id` as an opaque page token.
+    pub fn encode(&self, created_on: DateTime<Utc>, row_id: &domain::RowID) -> String {
+        let mut token = Vec::with_capacity(TOKEN_LENGTH);
+        token.push(CURSOR_VERSION);
+        token.extend_from_slice(&created_on.timestamp().to_be_bytes());
+        token.extend_from_slice(&created_on.timestamp_subsec_nanos().to_be_bytes());
+        token.extend_from_slice(row_id.as_uuid().as_bytes());
+
+        let tag = self.tag(&token);
+        token.extend_from_slice(&tag[..TAG_LENGTH]);
+
+        URL_SAFE_NO_PAD.encode(token)
+    }
+
+    /// Decode and verify a page token produced by [`CursorKey::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `tonic::Code::InvalidArgument` if the token is not valid base64,
+    /// has the wrong length or version, or fails signature verification.
+    pub fn decode(&self, token: &str) -> Result<(DateTime<Utc>, domain::RowID), tonic::Status> {
+        let invalid = || tonic::Status::invalid_argument(INVALID_PAGE_TOKEN_MESSAGE);
+
+        let bytes = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        if bytes.len() != TOKEN_LENGTH || bytes[0] != CURSOR_VERSION {
+            return Err(invalid());
+        }
+
+        let (payload, tag) = bytes.split_at(TOKEN_LENGTH - TAG_LENGTH);
+        let mut mac = self.mac.clone();
+        mac.update(payload);
+        if mac.verify_truncated_left(tag).is_err() {
+            tracing::warn!("Rejected page token with an invalid signature");
+            return Err(invalid());
+        }
+
+        let (seconds, rest) = payload[1..].split_at(8);
+        let (nanos, row_id) = rest.split_at(4);
+        let seconds = i64::from_be_bytes(seconds.try_into().map_err(|_| invalid())?);
+        let nanos = u32::from_be_bytes(nanos.try_into().map_err(|_| invalid())?);
+        let created_on = DateTime::from_timestamp(seconds, nanos).ok_or_else(invalid)?;
+
+        let uuid = uuid::Uuid::from_slice(row_id).map_err(|_| invalid())?;
+        let row_id = domain::RowID::try_from(uuid).map_err(|_| invalid())?;
+        Ok((created_on, row_id))
+    }
+
+    fn tag(&self, payload: &[u8]) -> [u8; 32] {
+        let mut mac = self.mac.clone();
+        mac.update(payload);
+        mac.finalize().into_bytes().into()
+    }
+}
+
+/// A verified position in a listing, read from a request's page token.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PageToken {
+    /// No token was given, so the listing starts at the beginning.
+    #[default]
+    First,
+
+    /// The listing continues after the row with this sort key.
+    After(DateTime<Utc>, domain::RowID),
+}
+
+impl PageToken {
+    /// Read the page token sent in the request parameter `parameter`.
+    ///
+    /// A missing, empty or whitespace only token means the first page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `tonic::Code::InvalidArgument` naming `parameter` if the token
+    /// fails [`CursorKey::decode`].
+    pub fn parse(token: Option<&str>, parameter: &str, key: &CursorKey) -> Result<Self, tonic::Status> {
+        match token.map(str::trim).filter(|token| !token.is_empty()) {
+            Some(token) => key.decode(token).map(|(created_on, row_id)| Self::After(created_on, row_id)).map_err(|_| {
+                tonic::Status::invalid_argument(format!("{} in '{}'", INVALID_PAGE_TOKEN_MESSAGE, parameter))
+            }),
+            None => Ok(Self::First),
+        }
+    }
+
+    /// Token pointing after the row with `created_on` and `row_id`, for a
+    /// response's next page.
+    pub fn after(created_on: DateTime<Utc>, row_id: domain::RowID) -> Self {
+        Self::After(created_on, row_id)
+    }
+
+    /// The sort key the page continues after, or `None` on the first page.
+    pub fn position(&self) -> Option<(DateTime<Utc>, domain::RowID)> {
+        match self {
+            Self::First => None,
+            Self::After(created_on, row_id) => Some((*created_on, *row_id)),
+        }
+    }
+
+    /// Returns `true` if this is the first page.
+    pub fn is_first(&self) -> bool {
+        matches!(self, Self::First)
+    }
+
+    /// Encode this position for a response, or `None` for the first page,
+    /// which clients request by sending no token.
+    pub fn encode(&self, key: &CursorKey) -> Option<String> {
+        self.position().map(|(created_on, row_id)| key.encode(created_on, &row_id))
+    }
+}
+
+impl std::fmt::Debug for CursorKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CursorKey").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_returns_original_sort_key() {
+        let key = CursorKey::new(b"test secret");
+        for _ in 0..10 {
+            let created_on = Utc::now();
+            let row_id = domain::RowID::new();
+            let token = key.encode(created_on, &row_id);
+            assert_eq!(key.decode(&token).unwrap(), (created_on, row_id));
+        }
+    }
+
+    #[test]
+    fn test_token_is_opaque() {
+        let key = CursorKey::new(b"test secret");
+        let row_id = domain::RowID::new();
+        let token = key.encode(Utc::now(), &row_id);
+
+        assert!(!token.contains(&row_id.to_string()));
+        assert!(!token.contains(&row_id.as_uuid().simple().to_string()));
+    }
+
+    #[test]
+    fn test_tampered_token_is_rejected() {
+        let key = CursorKey::new(b"test secret");
+        let token = key.encode(Utc::now(), &domain::RowID::new());
+        let mut bytes = URL_SAFE_NO_PAD.decode(&token).unwrap();
+
+        // Flip one bit in every position: version, created_on, row id and tag
+        for i in 0..bytes.len() {
+            bytes[i] ^= 0x01;
+            let tampered = URL_SAFE_NO_PAD.encode(&bytes);
+            let status = key.decode(&tampered).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "byte {}", i);
+            assert_eq!(status.message(), INVALID_PAGE_TOKEN_MESSAGE);
+            bytes[i] ^= 0x01;
+        }
+    }
+
+    #[test]
+    fn test_token_from_another_key_is_rejected() {
+        let token = CursorKey::new(b"secret one").encode(Utc::now(), &domain::RowID::new());
+        let status = CursorKey::new(b"secret two").decode(&token).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_malformed_tokens_are_rejected() {
+        let key = CursorKey::random();
+        let row_id = domain::RowID::new();
+        let truncated = &key.encode(Utc::now(), &row_id)[..10];
+        let raw_row_id = row_id.to_string();
+
+        for token in ["", "not base64!", truncated, raw_row_id.as_str()] {
+            let status = key.decode(token).unwrap_err();
+            assert_eq!(status.code(), tonic::Code::InvalidArgument, "{:?}", token);
+        }
+    }
+
+    #[test]
+    fn test_from_config_uses_configured_secret() {
+        let config = ServerConfig {
+            page_token_secret: Some("shared secret".into()),
+            ..ServerConfig::default()
+        };
+        let created_on = Utc::now();
+        let row_id = domain::RowID::new();
+        let token = CursorKey::from_config(&config).encode(created_on, &row_id);
+
+        // Another instance configured with the same secret accepts the token
+        assert_eq!(CursorKey::new(b"shared secret").decode(&token).unwrap(), (created_on, row_id));
+    }
+
+    #[test]
+    fn test_debug_does_not_expose_key() {
+        assert_eq!(format!("{:?}", CursorKey::new(b"hidden")), "CursorKey { .. }");
+    }
+
+    #[test]
+    fn test_page_token_round_trip() {
+        let key = CursorKey::new(b"test secret");
+        let page = PageToken::after(Utc::now(), domain::RowID::new());
+
+        let token = page.encode(&key).unwrap();
+        assert_eq!(PageToken::parse(Some(&token), "page_token", &key).unwrap(), page);
+        assert_eq!(PageToken::First.encode(&key), None);
+    }
+
+    #[test]
+    fn test_empty_page_token_is_first_page() {
+        let key = CursorKey::new(b"test secret");
+        for token in [None, Some(""), Some("   ")] {
+            let page = PageToken::parse(token, "page_token", &key).unwrap();
+            assert!(page.is_first());
+            assert_eq!(page.position(), None);
+        }
+    }
+
+    #[test]
+    fn test_corrupted_page_token_names_parameter() {
+        let key = CursorKey::new(b"test secret");
+        let mut token = key.encode(Utc::now(), &domain::RowID::new());
+        token.replace_range(..2, "AA");
+
+        let status = PageToken::parse(Some(&token), "page_token", &key).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Invalid page token in 'page_token'");
+    }
+}
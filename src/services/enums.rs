@@ -0,0 +1,104 @@
+//! # Proto Enum Conversion
+//!
+//! Proto3 enums arrive as plain `i32` values, so a client built against a
+//! newer proto can send a number this server does not know. Every conversion
+//! from a proto enum integer goes through [`parse_enum_i32`], so unknown values
+//! are handled the same way for every enum field:
+//!
+//! - In strict mode (the default, see `strict_enums` in the server
+//!   configuration) an unknown value is a validation error with the enum's
+//!   reason code
+//! - In lenient mode it is replaced by the enum's
+//!   [`LENIENT_DEFAULT`](RpcEnum::LENIENT_DEFAULT) and a warning is logged
+//!
+//! New enum fields implement [`RpcEnum`] rather than matching on the integer
+//! themselves.
+
+use crate::domain;
+use crate::services::ServiceError;
+
+/// A domain enum carried as an `i32` in the proto messages.
+pub trait RpcEnum: Sized {
+    /// Name of the enum in error messages, e.g. `category type`.
+    const NAME: &'static str;
+
+    /// Stable reason code of the error for an unknown value.
+    const INVALID_REASON: &'static str;
+
+    /// Value used in place of an unknown one in lenient mode.
+    const LENIENT_DEFAULT: Self;
+
+    /// Convert a known proto value, or `None` for an unknown one.
+    fn from_known_i32(value: i32) -> Option<Self>;
+}
+
+impl RpcEnum for domain::CategoryTypes {
+    const NAME: &'static str = "category type";
+    const INVALID_REASON: &'static str = "CATEGORY_TYPE_INVALID";
+    const LENIENT_DEFAULT: Self = domain::CategoryTypes::Expense;
+
+    fn from_known_i32(value: i32) -> Option<Self> {
+        domain::CategoryTypes::from_rpc_i32(value).ok()
+    }
+}
+
+/// Convert a proto enum integer to its domain enum.
+///
+/// # Errors
+///
+/// Returns a validation error with [`RpcEnum::INVALID_REASON`] if the value is
+/// unknown and `strict` is `true`.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::domain::CategoryTypes;
+/// use personal_ledger_backend::services::parse_enum_i32;
+///
+/// let income = parse_enum_i32::<CategoryTypes>(CategoryTypes::Income.to_rpc_i32(), true).unwrap();
+/// assert_eq!(income, CategoryTypes::Income);
+///
+/// assert!(parse_enum_i32::<CategoryTypes>(999, true).is_err());
+/// assert_eq!(parse_enum_i32::<CategoryTypes>(999, false).unwrap(), CategoryTypes::Expense);
+/// ```
+pub fn parse_enum_i32<T: RpcEnum>(value: i32, strict: bool) -> Result<T, ServiceError> {
+    match T::from_known_i32(value) {
+        Some(known) => Ok(known),
+        None if strict => Err(ServiceError::rule_violation(
+            T::INVALID_REASON,
+            format!("Invalid {} value: {}", T::NAME, value),
+        )),
+        None => {
+            tracing::warn!("Unknown {} value {}, using the lenient default", T::NAME, value);
+            Ok(T::LENIENT_DEFAULT)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_enum_i32_known_value() {
+        for strict in [true, false] {
+            let parsed = parse_enum_i32::<domain::CategoryTypes>(domain::CategoryTypes::Asset.to_rpc_i32(), strict);
+            assert_eq!(parsed.unwrap(), domain::CategoryTypes::Asset);
+        }
+    }
+
+    #[test]
+    fn test_parse_enum_i32_unknown_value_strict() {
+        let err = parse_enum_i32::<domain::CategoryTypes>(999, true).unwrap_err();
+        assert!(err.is_validation());
+        assert_eq!(err.reason(), Some("CATEGORY_TYPE_INVALID"));
+        assert_eq!(err.to_string(), "Validation error: Invalid category type value: 999");
+    }
+
+    #[test]
+    fn test_parse_enum_i32_unknown_value_lenient() {
+        let parsed = parse_enum_i32::<domain::CategoryTypes>(999, false).unwrap();
+        assert_eq!(parsed, domain::CategoryTypes::LENIENT_DEFAULT);
+        assert_eq!(parsed, domain::CategoryTypes::Expense);
+    }
+}
@@ -7,7 +7,11 @@
 //! ## Error Categories
 //!
 //! - **Database Errors**: SQLx database operation failures
-//! - **Validation Errors**: Domain type parsing and validation failures
+//! - **Validation Errors**: Domain type parsing and validation failures. Those
+//!   raised by a domain rule carry a stable reason code, e.g. `SLUG_EMPTY`,
+//!   sent to clients as a `google.rpc.ErrorInfo` status detail
+//! - **Malformed Import Errors**: Import files whose structure is wrong, reported
+//!   before any row is validated
 //! - **Not Found Errors**: Resource not found scenarios
 //! - **Authentication Errors**: Auth and authorization failures
 //! - **Business Logic Errors**: Application-specific business rule violations
@@ -28,8 +32,19 @@
 
 use chrono;
 use sqlx;
+use tonic_types::{ErrorDetails, StatusExt};
 use uuid;
 
+/// Domain of the `google.rpc.ErrorInfo` detail attached to statuses that
+/// carry a reason code.
+pub const ERROR_REASON_DOMAIN: &str = "personal-ledger";
+
+/// Reason code for a category without a code.
+pub const REASON_CODE_REQUIRED: &str = "CODE_REQUIRED";
+
+/// Reason code for a category without a name.
+pub const REASON_NAME_REQUIRED: &str = "NAME_REQUIRED";
+
 /// Comprehensive error type for service layer operations.
 ///
 /// This enum wraps various error types that can occur during service operations,
@@ -44,6 +59,16 @@ pub enum ServiceError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Validation errors raised by a domain rule, with the rule's stable
+    /// reason code, e.g. `COLOR_BAD_FORMAT`.
+    #[error("Validation error: {message}")]
+    RuleViolation { reason: &'static str, message: String },
+
+    /// Import file structure errors (missing columns, unsupported envelope
+    /// version), raised before any row is validated.
+    #[error("Malformed import: {0}")]
+    MalformedImport(String),
+
     /// Resource not found errors.
     #[error("{resource_type} with {field} '{value}' not found")]
     NotFound {
@@ -79,6 +104,16 @@ impl ServiceError {
         Self::Validation(message.into())
     }
 
+    /// Create a validation error for a broken domain rule.
+    pub fn rule_violation<S: Into<String>>(reason: &'static str, message: S) -> Self {
+        Self::RuleViolation { reason, message: message.into() }
+    }
+
+    /// Create a malformed import error from a message.
+    pub fn malformed_import<S: Into<String>>(message: S) -> Self {
+        Self::MalformedImport(message.into())
+    }
+
     /// Create a not found error for a specific resource.
     pub fn not_found(resource_type: &str, field: &str, value: &str) -> Self {
         Self::NotFound {
@@ -120,7 +155,20 @@ impl ServiceError {
 
     /// Check if this is a validation error.
     pub fn is_validation(&self) -> bool {
-        matches!(self, Self::Validation(_))
+        matches!(self, Self::Validation(_) | Self::RuleViolation { .. })
+    }
+
+    /// The stable reason code of a broken domain rule, if this is one.
+    pub fn reason(&self) -> Option<&'static str> {
+        match self {
+            Self::RuleViolation { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Check if this is a malformed import error.
+    pub fn is_malformed_import(&self) -> bool {
+        matches!(self, Self::MalformedImport(_))
     }
 
     /// Check if this is an authentication error.
@@ -134,6 +182,8 @@ impl ServiceError {
             Self::Database(sqlx::Error::RowNotFound) => 404,
             Self::Database(_) => 500,
             Self::Validation(_) => 400,
+            Self::RuleViolation { .. } => 400,
+            Self::MalformedImport(_) => 400,
             Self::NotFound { .. } => 404,
             Self::Authentication(_) => 401,
             Self::BusinessLogic(_) => 422,
@@ -142,6 +192,91 @@ impl ServiceError {
             Self::Internal(_) => 500,
         }
     }
+
+    /// Get the gRPC status code that should be returned for this error.
+    pub fn grpc_code(&self) -> tonic::Code {
+        match self.http_status_code() {
+            400 => tonic::Code::InvalidArgument,
+            401 => tonic::Code::Unauthenticated,
+            404 => tonic::Code::NotFound,
+            422 => tonic::Code::FailedPrecondition,
+            502 => tonic::Code::Unavailable,
+            _ => tonic::Code::Internal,
+        }
+    }
+
+    /// Convert to a gRPC status with this error's message.
+    pub fn to_status(&self) -> tonic::Status {
+        self.to_status_with_message(self.to_string())
+    }
+
+    /// Convert to a gRPC status with `message`, e.g. this error's message
+    /// prefixed with the position of the offending item in a batch.
+    ///
+    /// A reason code is attached as a `google.rpc.ErrorInfo` detail in
+    /// [`ERROR_REASON_DOMAIN`].
+    pub fn to_status_with_message(&self, message: impl Into<String>) -> tonic::Status {
+        match self.reason() {
+            Some(reason) => tonic::Status::with_error_details(
+                self.grpc_code(),
+                message,
+                ErrorDetails::with_error_info(reason, ERROR_REASON_DOMAIN, std::collections::HashMap::new()),
+            ),
+            None => tonic::Status::new(self.grpc_code(), message),
+        }
+    }
+}
+
+impl From<ServiceError> for tonic::Status {
+    fn from(error: ServiceError) -> Self {
+        error.to_status()
+    }
+}
+
+/// Unwrap an optional lookup result or return a `NotFound` gRPC status.
+///
+/// Handlers use this to turn `Ok(None)` from a database lookup into a uniform
+/// [`tonic::Code::NotFound`] status whose message matches
+/// [`ServiceError::NotFound`], e.g. `Category with ID '123' not found`.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::services::require_found;
+///
+/// let found = require_found(Some(42), "Category", "ID", "123").unwrap();
+/// assert_eq!(found, 42);
+///
+/// let status = require_found(None::<i32>, "Category", "ID", "123").unwrap_err();
+/// assert_eq!(status.code(), tonic::Code::NotFound);
+/// assert_eq!(status.message(), "Category with ID '123' not found");
+/// ```
+pub fn require_found<T>(
+    found: Option<T>,
+    resource_type: &str,
+    field: &str,
+    value: &str,
+) -> Result<T, tonic::Status> {
+    found.ok_or_else(|| not_found_status(resource_type, field, value))
+}
+
+/// Build the `NotFound` gRPC status for a missing resource.
+///
+/// Used by [`require_found`], and by handlers that learn a resource is
+/// missing from a write, so every `NotFound` message matches
+/// [`ServiceError::NotFound`].
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::services::not_found_status;
+///
+/// let status = not_found_status("Category", "ID", "123");
+/// assert_eq!(status.code(), tonic::Code::NotFound);
+/// assert_eq!(status.message(), "Category with ID '123' not found");
+/// ```
+pub fn not_found_status(resource_type: &str, field: &str, value: &str) -> tonic::Status {
+    tonic::Status::not_found(ServiceError::not_found(resource_type, field, value).to_string())
 }
 
 /// Convert UUID parsing errors to ServiceError.
@@ -161,14 +296,42 @@ impl From<chrono::ParseError> for ServiceError {
 /// Convert URL slug parsing errors to ServiceError.
 impl From<crate::domain::UrlSlugError> for ServiceError {
     fn from(err: crate::domain::UrlSlugError) -> Self {
-        Self::Validation(format!("Invalid URL slug: {}", err))
+        Self::rule_violation(err.code(), format!("Invalid URL slug: {}", err))
     }
 }
 
 /// Convert hex color parsing errors to ServiceError.
 impl From<crate::domain::HexColorError> for ServiceError {
     fn from(err: crate::domain::HexColorError) -> Self {
-        Self::Validation(format!("Invalid hex color: {}", err))
+        Self::rule_violation(err.code(), format!("Invalid hex color: {}", err))
+    }
+}
+
+/// Convert category type parsing errors to ServiceError.
+impl From<crate::domain::CategoryTypesError> for ServiceError {
+    fn from(err: crate::domain::CategoryTypesError) -> Self {
+        Self::rule_violation(err.code(), err.to_string())
+    }
+}
+
+/// Convert row ID parsing errors to ServiceError.
+impl From<crate::domain::RowIDError> for ServiceError {
+    fn from(err: crate::domain::RowIDError) -> Self {
+        Self::rule_violation(err.code(), format!("Invalid ID: {}", err))
+    }
+}
+
+/// Convert tag parsing errors to ServiceError.
+impl From<crate::domain::TagError> for ServiceError {
+    fn from(err: crate::domain::TagError) -> Self {
+        Self::rule_violation(err.code(), format!("Invalid tag: {}", err))
+    }
+}
+
+/// Convert external reference parsing errors to ServiceError.
+impl From<crate::domain::ExternalRefError> for ServiceError {
+    fn from(err: crate::domain::ExternalRefError) -> Self {
+        Self::rule_violation(err.code(), format!("Invalid external reference: {}", err))
     }
 }
 
@@ -210,12 +373,70 @@ mod tests {
     fn test_http_status_codes() {
         assert_eq!(ServiceError::not_found("Test", "id", "1").http_status_code(), 404);
         assert_eq!(ServiceError::validation("test").http_status_code(), 400);
+        assert_eq!(ServiceError::malformed_import("test").http_status_code(), 400);
         assert_eq!(ServiceError::authentication("test").http_status_code(), 401);
         assert_eq!(ServiceError::business_logic("test").http_status_code(), 422);
         assert_eq!(ServiceError::external_service("test").http_status_code(), 502);
         assert_eq!(ServiceError::internal("test").http_status_code(), 500);
     }
 
+    /// The reason code carried in a status's `ErrorInfo` detail
+    fn status_reason(status: &tonic::Status) -> Option<String> {
+        let info = status.get_error_details().error_info().cloned()?;
+        assert_eq!(info.domain, ERROR_REASON_DOMAIN);
+        Some(info.reason)
+    }
+
+    #[test]
+    fn test_domain_errors_carry_reason_into_status() {
+        use crate::domain::{CategoryTypesError, ExternalRefError, HexColorError, RowIDError, TagError, UrlSlugError};
+
+        let cases: Vec<(ServiceError, &str)> = vec![
+            (UrlSlugError::EmptySlug.into(), "SLUG_EMPTY"),
+            (UrlSlugError::InvalidCharacters("a_b".to_string()).into(), "SLUG_BAD_CHARACTERS"),
+            (UrlSlugError::StartsOrEndsWithHyphen("-a".to_string()).into(), "SLUG_EDGE_HYPHEN"),
+            (UrlSlugError::ConsecutiveHyphens("a--b".to_string()).into(), "SLUG_CONSECUTIVE_HYPHENS"),
+            (HexColorError::Empty.into(), "COLOR_EMPTY"),
+            (HexColorError::InvalidLength("#12".to_string()).into(), "COLOR_BAD_LENGTH"),
+            (HexColorError::InvalidCharacters("#GG0000".to_string()).into(), "COLOR_BAD_FORMAT"),
+            (CategoryTypesError::InvalidCategoryType("savings".to_string()).into(), "CATEGORY_TYPE_INVALID"),
+            (RowIDError::InvalidUuid("nope".to_string()).into(), "ID_BAD_FORMAT"),
+            (RowIDError::InvalidVersion(4).into(), "ID_BAD_VERSION"),
+            (TagError::Empty.into(), "TAG_EMPTY"),
+            (TagError::TooLong(99).into(), "TAG_TOO_LONG"),
+            (ExternalRefError::Empty.into(), "EXTERNAL_ID_EMPTY"),
+            (ExternalRefError::TooLong(999).into(), "EXTERNAL_ID_TOO_LONG"),
+            (ServiceError::rule_violation(REASON_CODE_REQUIRED, "Category code is required"), "CODE_REQUIRED"),
+            (ServiceError::rule_violation(REASON_NAME_REQUIRED, "Category name is required"), "NAME_REQUIRED"),
+        ];
+
+        for (error, reason) in cases {
+            assert!(error.is_validation());
+            let status = tonic::Status::from(error);
+            assert_eq!(status.code(), tonic::Code::InvalidArgument);
+            assert!(status.message().starts_with("Validation error: "), "{}", status.message());
+            assert_eq!(status_reason(&status).as_deref(), Some(reason));
+        }
+    }
+
+    #[test]
+    fn test_status_without_reason_has_no_details() {
+        let status = ServiceError::validation("Invalid input").to_status();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.details().is_empty());
+
+        let status = ServiceError::internal("boom").to_status();
+        assert_eq!(status.code(), tonic::Code::Internal);
+    }
+
+    #[test]
+    fn test_status_with_message_keeps_reason() {
+        let error = ServiceError::from(crate::domain::HexColorError::Empty);
+        let status = error.to_status_with_message(format!("Category at index 2: {}", error));
+        assert_eq!(status.message(), "Category at index 2: Validation error: Invalid hex color: Hex colour cannot be empty");
+        assert_eq!(status_reason(&status).as_deref(), Some("COLOR_EMPTY"));
+    }
+
     #[test]
     fn test_from_sqlx_error() {
         let sqlx_error = sqlx::Error::RowNotFound;
@@ -247,4 +468,28 @@ mod tests {
         let error = ServiceError::internal("test");
         assert!(matches!(error, ServiceError::Internal(_)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_require_found_some_returns_value() {
+        let result = require_found(Some("category"), "Category", "ID", "123");
+        assert_eq!(result.unwrap(), "category");
+    }
+
+    #[test]
+    fn test_require_found_none_returns_not_found_status() {
+        let status = require_found(None::<()>, "Category", "code", "FOOD").unwrap_err();
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), "Category with code 'FOOD' not found");
+        assert_eq!(
+            status.message(),
+            ServiceError::not_found("Category", "code", "FOOD").to_string()
+        );
+    }
+
+    #[test]
+    fn test_not_found_status_matches_require_found() {
+        let status = not_found_status("Category", "ID", "123");
+        assert_eq!(status.code(), tonic::Code::NotFound);
+        assert_eq!(status.message(), require_found(None::<()>, "Category", "ID", "123").unwrap_err().message());
+    }
+}
@@ -0,0 +1,185 @@
+//! # Domain Events
+//!
+//! This module provides the live feed of changes behind the `EventStream`
+//! RPC. Services publish a [`DomainEvent`] on a shared [`EventBus`] after each
+//! successful write, and every connected `EventStream` client receives a copy
+//! through a `tokio::sync::broadcast` channel.
+//!
+//! The feed is a debugging aid, not an audit log: events are only kept in
+//! memory, publishing with no subscribers drops the event, and a client that
+//! falls more than [`EVENT_BUS_CAPACITY`] events behind skips the ones it
+//! missed.
+
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::rpc;
+
+/// Number of events buffered for each subscriber before the oldest are dropped.
+pub const EVENT_BUS_CAPACITY: usize = 256;
+
+/// Capacity of the channel between a subscriber task and its gRPC stream.
+const EVENT_STREAM_CHANNEL_CAPACITY: usize = 16;
+
+/// Resource name used for category events.
+pub const EVENT_RESOURCE_CATEGORY: &str = "category";
+
+/// Stream type returned by the event stream handler.
+pub type EventStreamStream = ReceiverStream<Result<rpc::ServerEvent, tonic::Status>>;
+
+/// What happened to a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl EventKind {
+    /// The name sent to clients, e.g. `created`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Created => "created",
+            EventKind::Updated => "updated",
+            EventKind::Deleted => "deleted",
+        }
+    }
+}
+
+/// A change made through one of the services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainEvent {
+    /// What happened.
+    pub kind: EventKind,
+
+    /// The kind of resource that changed, e.g. [`EVENT_RESOURCE_CATEGORY`].
+    pub resource: &'static str,
+
+    /// ID of the resource that changed.
+    pub id: String,
+
+    /// When the change was published.
+    pub occurred_on: DateTime<Utc>,
+}
+
+impl DomainEvent {
+    /// Create an event for the resource `id`, stamped with the current time.
+    pub fn new(kind: EventKind, resource: &'static str, id: impl Into<String>) -> Self {
+        Self { kind, resource, id: id.into(), occurred_on: Utc::now() }
+    }
+}
+
+impl From<DomainEvent> for rpc::ServerEvent {
+    fn from(event: DomainEvent) -> Self {
+        Self {
+            kind: event.kind.as_str().to_string(),
+            resource: event.resource.to_string(),
+            id: event.id,
+            occurred_on: Some(prost_types::Timestamp {
+                seconds: event.occurred_on.timestamp(),
+                nanos: event.occurred_on.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+}
+
+/// Broadcast channel shared by the services that publish events and the
+/// `EventStream` handler. Clones publish to the same subscribers.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(EVENT_BUS_CAPACITY)
+    }
+}
+
+impl EventBus {
+    /// Create a bus buffering up to `capacity` events per subscriber.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Send `event` to every current subscriber. Without subscribers the
+    /// event is dropped.
+    pub fn publish(&self, event: DomainEvent) {
+        // Only fails when nobody is listening, which is the common case
+        let _ = self.sender.send(event);
+    }
+
+    /// Receive every event published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Forward events to a gRPC stream until the client disconnects.
+    ///
+    /// A client that falls behind skips the events it missed rather than
+    /// ending the stream.
+    pub fn stream(&self) -> EventStreamStream {
+        let mut events = self.subscribe();
+        let (tx, rx) = tokio::sync::mpsc::channel(EVENT_STREAM_CHANNEL_CAPACITY);
+
+        tokio::spawn(async move {
+            loop {
+                let event = tokio::select! {
+                    _ = tx.closed() => return,
+                    event = events.recv() => event,
+                };
+
+                match event {
+                    Ok(event) => {
+                        if tx.send(Ok(event.into())).await.is_err() {
+                            return;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Event stream subscriber fell behind, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_subscribers_receive_published_events() {
+        let bus = EventBus::default();
+
+        // Publishing without subscribers is not an error
+        bus.publish(DomainEvent::new(EventKind::Created, EVENT_RESOURCE_CATEGORY, "dropped"));
+
+        let mut stream = bus.clone().stream();
+        bus.publish(DomainEvent::new(EventKind::Deleted, EVENT_RESOURCE_CATEGORY, "42"));
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.kind, "deleted");
+        assert_eq!(event.resource, "category");
+        assert_eq!(event.id, "42");
+        assert!(event.occurred_on.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_skips_missed_events() {
+        let bus = EventBus::new(1);
+        let mut events = bus.subscribe();
+
+        bus.publish(DomainEvent::new(EventKind::Created, EVENT_RESOURCE_CATEGORY, "1"));
+        bus.publish(DomainEvent::new(EventKind::Updated, EVENT_RESOURCE_CATEGORY, "1"));
+
+        assert!(matches!(events.recv().await, Err(broadcast::error::RecvError::Lagged(1))));
+        assert_eq!(events.recv().await.unwrap().kind, EventKind::Updated);
+    }
+}
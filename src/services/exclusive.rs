@@ -0,0 +1,63 @@
+//! # Mutually Exclusive Request Fields
+//!
+//! Some requests offer alternative ways to say the same thing, such as an
+//! `offset` or a `page_token` to choose a page. Rather than silently preferring
+//! one when a client sends both, handlers reject the request with
+//! `tonic::Code::InvalidArgument` naming the fields that conflict.
+
+/// Reject a request that sets more than one field of a mutually exclusive group.
+///
+/// Each entry pairs a request field name with whether the client set it.
+///
+/// # Errors
+///
+/// Returns `tonic::Code::InvalidArgument` naming every set field when more
+/// than one is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::services::require_exclusive;
+///
+/// assert!(require_exclusive(&[("offset", true), ("page_token", false)]).is_ok());
+///
+/// let status = require_exclusive(&[("offset", true), ("page_token", true)]).unwrap_err();
+/// assert_eq!(status.message(), "Fields 'offset' and 'page_token' cannot be used together");
+/// ```
+pub fn require_exclusive(fields: &[(&str, bool)]) -> Result<(), tonic::Status> {
+    let set: Vec<String> = fields
+        .iter()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(name, _)| format!("'{}'", name))
+        .collect();
+
+    match set.as_slice() {
+        [] | [_] => Ok(()),
+        [rest @ .., last] => Err(tonic::Status::invalid_argument(format!(
+            "Fields {} and {} cannot be used together",
+            rest.join(", "),
+            last
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_or_no_field_is_accepted() {
+        assert!(require_exclusive(&[]).is_ok());
+        assert!(require_exclusive(&[("offset", false), ("page_token", false)]).is_ok());
+        assert!(require_exclusive(&[("offset", true), ("page_token", false)]).is_ok());
+        assert!(require_exclusive(&[("offset", false), ("page_token", true)]).is_ok());
+    }
+
+    #[test]
+    fn test_conflict_names_every_set_field() {
+        let status = require_exclusive(&[("url", true), ("host", true), ("port", false), ("database", true)])
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "Fields 'url', 'host' and 'database' cannot be used together");
+    }
+}
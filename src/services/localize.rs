@@ -0,0 +1,90 @@
+//! # Localized Timestamps
+//!
+//! This module renders response timestamps in the server's configured
+//! `display_timezone` for clients that show server-local times. Stored and
+//! compared values stay UTC; the localized rendering is an extra RFC 3339
+//! string next to the UTC `Timestamp`, with the zone's offset at that instant,
+//! so daylight saving transitions are applied per timestamp.
+
+use chrono::{DateTime, SecondsFormat};
+use chrono_tz::Tz;
+
+use crate::rpc;
+
+/// Render a UTC `timestamp` as RFC 3339 in `timezone`.
+///
+/// Returns `None` if the timestamp is out of range.
+pub fn localize_timestamp(timestamp: &rpc::Timestamp, timezone: Tz) -> Option<String> {
+    let utc = DateTime::from_timestamp(timestamp.seconds, u32::try_from(timestamp.nanos).ok()?)?;
+    Some(utc.with_timezone(&timezone).to_rfc3339_opts(SecondsFormat::AutoSi, false))
+}
+
+/// Fill the localized renderings of a category's timestamps.
+///
+/// Without a `timezone`, or for a timestamp that is not set, the localized
+/// field is left empty.
+pub fn localize_category(mut category: rpc::Category, timezone: Option<Tz>) -> rpc::Category {
+    if let Some(timezone) = timezone {
+        category.created_on_local = category.created_on.as_ref().and_then(|t| localize_timestamp(t, timezone));
+        category.updated_on_local = category.updated_on.as_ref().and_then(|t| localize_timestamp(t, timezone));
+    }
+    category
+}
+
+/// Localize every category in a tree node, see [`localize_category`].
+pub fn localize_category_node(node: rpc::CategoryNode, timezone: Option<Tz>) -> rpc::CategoryNode {
+    rpc::CategoryNode {
+        category: node.category.map(|category| localize_category(category, timezone)),
+        children: node.children.into_iter().map(|child| localize_category_node(child, timezone)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::Australia::Sydney;
+
+    fn timestamp(utc: DateTime<Utc>) -> rpc::Timestamp {
+        rpc::Timestamp { seconds: utc.timestamp(), nanos: utc.timestamp_subsec_nanos() as i32 }
+    }
+
+    #[test]
+    fn test_localize_timestamp_applies_offset() {
+        // Sydney is on AEST (+10:00) in winter
+        let winter = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap();
+        assert_eq!(localize_timestamp(&timestamp(winter), Sydney).unwrap(), "2026-07-01T10:00:00+10:00");
+
+        // and on AEDT (+11:00) in summer, crossing into the next day
+        let summer = Utc.with_ymd_and_hms(2026, 1, 15, 13, 30, 0).unwrap();
+        assert_eq!(localize_timestamp(&timestamp(summer), Sydney).unwrap(), "2026-01-16T00:30:00+11:00");
+    }
+
+    #[test]
+    fn test_localize_timestamp_across_dst_end() {
+        // Daylight saving ends at 03:00 AEDT on 5 April 2026 (16:00 UTC the day before)
+        let before = Utc.with_ymd_and_hms(2026, 4, 4, 15, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 4, 4, 16, 0, 0).unwrap();
+
+        assert_eq!(localize_timestamp(&timestamp(before), Sydney).unwrap(), "2026-04-05T02:59:59+11:00");
+        assert_eq!(localize_timestamp(&timestamp(after), Sydney).unwrap(), "2026-04-05T02:00:00+10:00");
+    }
+
+    #[test]
+    fn test_localize_timestamp_keeps_subseconds() {
+        let utc = Utc.with_ymd_and_hms(2026, 7, 1, 0, 0, 0).unwrap() + chrono::Duration::milliseconds(250);
+        assert_eq!(localize_timestamp(&timestamp(utc), Sydney).unwrap(), "2026-07-01T10:00:00.250+10:00");
+    }
+
+    #[test]
+    fn test_localize_category_only_with_timezone() {
+        let category: rpc::Category = crate::database::Categories::mock().into();
+
+        let unchanged = localize_category(category.clone(), None);
+        assert_eq!(unchanged, category);
+
+        let localized = localize_category(category, Some(Sydney));
+        assert!(localized.created_on_local.unwrap().starts_with("20"));
+        assert!(localized.updated_on_local.is_some());
+    }
+}
@@ -1,7 +1,24 @@
 mod categories;
+mod cursor;
+mod enums;
 mod error;
+mod events;
+mod exclusive;
+mod localize;
+mod normalize;
+mod shutdown;
 mod utilities;
 
-pub use error::ServiceError;
+pub use cursor::{CursorKey, PageToken, INVALID_PAGE_TOKEN_MESSAGE};
+pub use enums::{parse_enum_i32, RpcEnum};
+pub use error::{not_found_status, require_found, ServiceError, ERROR_REASON_DOMAIN, REASON_CODE_REQUIRED, REASON_NAME_REQUIRED};
+pub use events::{DomainEvent, EventBus, EventKind, EventStreamStream, EVENT_BUS_CAPACITY, EVENT_RESOURCE_CATEGORY};
+pub use exclusive::require_exclusive;
+pub use localize::{localize_category, localize_category_node, localize_timestamp};
+pub use normalize::{normalize_optional, normalize_optional_text};
+pub use shutdown::{database_error_status, shutting_down_status, ShutdownInterceptor, SHUTTING_DOWN_MESSAGE};
 pub use utilities::UtilitiesService;
-pub use categories::CategoriesService;
\ No newline at end of file
+pub use categories::{
+    run_reactivation_sweep, CategoriesService, ImportSchema, CATEGORIES_IMPORT_ENVELOPE_VERSION,
+    CATEGORIES_IMPORT_REQUIRED_COLUMNS, REACTIVATION_SWEEP_INTERVAL, restore_from_csv_diff,
+};
\ No newline at end of file
@@ -0,0 +1,74 @@
+//! # Optional Field Normalisation
+//!
+//! This module defines how optional string fields from gRPC requests are
+//! normalised before they reach the domain types. Proto3 clients cannot always
+//! distinguish "unset" from "empty", so a blank value is treated as absent.
+//!
+//! ## Policy
+//!
+//! - [`normalize_optional`] is the default for every optional field: the value
+//!   is trimmed and an empty result becomes `None`
+//! - [`normalize_optional_text`] is the exception for free text (currently only
+//!   a category `description`): a blank value becomes `None`, but any other
+//!   value is kept exactly as sent, because its whitespace is user content
+//!
+//! Fields parsed into a domain type (`url_slug`, `color`) are normalised with
+//! [`normalize_optional`] first, so a blank value clears the field instead of
+//! failing to parse.
+
+/// Trim an optional value, treating an empty result as absent.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::services::normalize_optional;
+///
+/// assert_eq!(normalize_optional(Some("  cart  ".to_string())), Some("cart".to_string()));
+/// assert_eq!(normalize_optional(Some(" \t ".to_string())), None);
+/// assert_eq!(normalize_optional(None), None);
+/// ```
+pub fn normalize_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Treat a blank free text value as absent, otherwise keep it unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use personal_ledger_backend::services::normalize_optional_text;
+///
+/// assert_eq!(normalize_optional_text(Some("  Indented  ".to_string())), Some("  Indented  ".to_string()));
+/// assert_eq!(normalize_optional_text(Some("\n".to_string())), None);
+/// ```
+pub fn normalize_optional_text(value: Option<String>) -> Option<String> {
+    value.filter(|s| !s.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some(s: &str) -> Option<String> {
+        Some(s.to_string())
+    }
+
+    #[test]
+    fn test_normalize_optional() {
+        assert_eq!(normalize_optional(None), None);
+        assert_eq!(normalize_optional(some("")), None);
+        assert_eq!(normalize_optional(some(" \t\r\n ")), None);
+        assert_eq!(normalize_optional(some("value")), some("value"));
+        assert_eq!(normalize_optional(some("  padded value\t")), some("padded value"));
+    }
+
+    #[test]
+    fn test_normalize_optional_text() {
+        assert_eq!(normalize_optional_text(None), None);
+        assert_eq!(normalize_optional_text(some("")), None);
+        assert_eq!(normalize_optional_text(some(" \t\r\n ")), None);
+        assert_eq!(normalize_optional_text(some("  line one\n  line two  ")), some("  line one\n  line two  "));
+    }
+}
@@ -0,0 +1,106 @@
+//! # Shutdown Handling
+//!
+//! This module lets services reject requests cleanly while the server is
+//! shutting down. Graceful shutdown drains the transport before closing the
+//! database pool, but a request can still reach the services once the pool
+//! has started closing, such as when the pool is closed outside that sequence.
+//! Rather than surfacing an opaque internal error, such requests receive
+//! `tonic::Code::Unavailable` so clients know to retry elsewhere or later.
+//!
+//! - [`ShutdownInterceptor`] rejects new requests once the pool is closing
+//! - [`database_error_status`] maps in-flight pool-closed failures to `Unavailable`
+
+use std::sync::Arc;
+
+use crate::database;
+
+/// Message returned to clients whose request is rejected during shutdown.
+pub const SHUTTING_DOWN_MESSAGE: &str = "Server shutting down";
+
+/// Build the status returned to requests rejected during shutdown.
+pub fn shutting_down_status() -> tonic::Status {
+    tonic::Status::unavailable(SHUTTING_DOWN_MESSAGE)
+}
+
+/// Convert a database error into a gRPC status for the client.
+///
+/// Errors caused by the pool having been closed for shutdown become
+/// [`tonic::Code::Unavailable`]; everything else becomes an internal error with
+/// the given `message`, so database details are never leaked to clients.
+pub fn database_error_status(db_error: &database::DatabaseError, message: &str) -> tonic::Status {
+    if db_error.is_pool_closed() {
+        return shutting_down_status();
+    }
+
+    tonic::Status::internal(message)
+}
+
+/// Interceptor that short-circuits requests once the database pool is closing.
+///
+/// Wrap a service with it via `with_interceptor` so requests racing shutdown
+/// are rejected before reaching the handler.
+#[derive(Debug, Clone)]
+pub struct ShutdownInterceptor {
+    database_pool: Arc<sqlx::SqlitePool>,
+}
+
+impl ShutdownInterceptor {
+    /// Create an interceptor watching the given database pool.
+    pub fn new(database_pool: Arc<sqlx::SqlitePool>) -> Self {
+        Self { database_pool }
+    }
+}
+
+impl tonic::service::Interceptor for ShutdownInterceptor {
+    fn call(&mut self, request: tonic::Request<()>) -> Result<tonic::Request<()>, tonic::Status> {
+        if self.database_pool.is_closed() {
+            tracing::debug!("Rejecting request received while shutting down");
+            return Err(shutting_down_status());
+        }
+
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::service::Interceptor;
+
+    #[test]
+    fn test_database_error_status_pool_closed_is_unavailable() {
+        let db_error = database::DatabaseError::Sqlx(sqlx::Error::PoolClosed);
+        let status = database_error_status(&db_error, "Failed to retrieve category");
+
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(status.message(), SHUTTING_DOWN_MESSAGE);
+    }
+
+    #[test]
+    fn test_database_error_status_other_errors_are_internal() {
+        let db_error = database::DatabaseError::Sqlx(sqlx::Error::RowNotFound);
+        let status = database_error_status(&db_error, "Failed to retrieve category");
+
+        assert_eq!(status.code(), tonic::Code::Internal);
+        assert_eq!(status.message(), "Failed to retrieve category");
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_passes_requests_while_pool_open() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let mut interceptor = ShutdownInterceptor::new(Arc::new(pool));
+
+        assert!(interceptor.call(tonic::Request::new(())).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_rejects_requests_after_pool_closed() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        pool.close().await;
+        let mut interceptor = ShutdownInterceptor::new(Arc::new(pool));
+
+        let status = interceptor.call(tonic::Request::new(())).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(status.message(), SHUTTING_DOWN_MESSAGE);
+    }
+}
@@ -1,7 +1,29 @@
-use crate::rpc;
+use std::sync::Arc;
 
-#[derive(Default)]
-pub struct UtilitiesService {}
+use crate::{config, rpc, services, LedgerConfig};
+
+pub struct UtilitiesService {
+    ledger_config: Arc<LedgerConfig>,
+    event_bus: services::EventBus,
+    started: std::time::Instant,
+}
+
+impl UtilitiesService {
+    /// Create a new UtilitiesService reporting features from `ledger_config`.
+    ///
+    /// The uptime reported by `Ping` counts from when the service is created,
+    /// which is when the server starts.
+    pub fn new(ledger_config: Arc<LedgerConfig>) -> Self {
+        Self { ledger_config, event_bus: services::EventBus::default(), started: std::time::Instant::now() }
+    }
+
+    /// Serve `EventStream` from `event_bus`, shared with the services that
+    /// publish to it.
+    pub fn with_event_bus(mut self, event_bus: services::EventBus) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+}
 
 #[tonic::async_trait]
 impl rpc::UtilitiesService for UtilitiesService {
@@ -13,8 +35,85 @@ impl rpc::UtilitiesService for UtilitiesService {
 
         let reply: rpc::PingResponse = rpc::PingResponse {
             message: "Pong...".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            uptime_seconds: self.started.elapsed().as_secs(),
         };
 
         Ok(tonic::Response::new(reply)) // Send back ping response
     }
-}
\ No newline at end of file
+
+    async fn get_features(
+        &self,
+        _request: tonic::Request<rpc::GetFeaturesRequest>,
+    ) -> Result<tonic::Response<rpc::GetFeaturesResponse>, tonic::Status> {
+        let features = config::feature_flags(&self.ledger_config.server)
+            .into_iter()
+            .map(|(name, enabled)| (name.to_string(), enabled))
+            .collect();
+
+        Ok(tonic::Response::new(rpc::GetFeaturesResponse { features }))
+    }
+
+    /// Server stream of changes published on the event bus.
+    type EventStreamStream = services::EventStreamStream;
+
+    async fn event_stream(
+        &self,
+        _request: tonic::Request<rpc::EventStreamRequest>,
+    ) -> Result<tonic::Response<Self::EventStreamStream>, tonic::Status> {
+        if !config::feature_flags(&self.ledger_config.server)
+            .get(config::FEATURE_EVENTS)
+            .copied()
+            .unwrap_or(false)
+        {
+            return Err(tonic::Status::unimplemented(format!(
+                "Feature '{}' is disabled on this server",
+                config::FEATURE_EVENTS
+            )));
+        }
+
+        Ok(tonic::Response::new(self.event_bus.stream()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rpc::UtilitiesService as _;
+
+    async fn features(ledger_config: LedgerConfig) -> std::collections::HashMap<String, bool> {
+        UtilitiesService::new(Arc::new(ledger_config))
+            .get_features(tonic::Request::new(rpc::GetFeaturesRequest {}))
+            .await
+            .unwrap()
+            .into_inner()
+            .features
+    }
+
+    #[tokio::test]
+    async fn test_get_features_follows_config_toggle() {
+        let mut ledger_config = LedgerConfig::default();
+        assert_eq!(features(ledger_config.clone()).await.get(config::FEATURE_TAGS), Some(&true));
+
+        ledger_config.server.tags_enabled = false;
+        let features = features(ledger_config).await;
+        assert_eq!(features.get(config::FEATURE_TAGS), Some(&false));
+        assert_eq!(features.get(config::FEATURE_HIERARCHY), Some(&true));
+    }
+
+    #[tokio::test]
+    async fn test_ping_reports_version_and_uptime() {
+        let service = UtilitiesService::new(Arc::new(LedgerConfig::default()));
+        let ping = || async {
+            service.ping(tonic::Request::new(rpc::PingRequest {})).await.unwrap().into_inner()
+        };
+
+        let first = ping().await;
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        let second = ping().await;
+
+        assert_eq!(first.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(second.version, env!("CARGO_PKG_VERSION"));
+        assert!(second.uptime_seconds > first.uptime_seconds, "{} then {}", first.uptime_seconds, second.uptime_seconds);
+    }
+}
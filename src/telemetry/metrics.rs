@@ -0,0 +1,441 @@
+//! # gRPC Metrics
+//!
+//! This module provides [`Metrics`], the counters shared between the gRPC
+//! server and the admin `/metrics` endpoint, and [`MetricsLayer`], which
+//! records every RPC into them:
+//!
+//! - `ledger_grpc_requests_total`: requests received since startup
+//! - `ledger_grpc_handled_total`: completed calls by `method` and status `code`
+//! - `ledger_grpc_request_duration_seconds`: a latency histogram by `method`
+//!
+//! As with [`crate::server::RequestLogLayer`], the status of a successful call,
+//! and of a stream failing part way, is only known once its trailers are sent,
+//! so a call is recorded when its response body finishes. A response body
+//! dropped before then is recorded as `Cancelled`.
+
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_util::future::BoxFuture;
+
+/// Upper bounds, in seconds, of the request duration histogram buckets.
+pub const LATENCY_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Most distinct `method` label values recorded, after which further
+/// methods are counted under [`OTHER_METHOD`].
+pub const MAX_METHOD_LABELS: usize = 64;
+
+/// `method` label for calls to unknown methods, and to methods past
+/// [`MAX_METHOD_LABELS`].
+pub const OTHER_METHOD: &str = "other";
+
+/// Counters shared between the gRPC server and the admin `/metrics` endpoint.
+///
+/// The server holds one instance behind an `Arc`; [`MetricsLayer`] records
+/// gRPC traffic and the admin server renders it with [`Metrics::render`].
+#[derive(Debug, Default)]
+pub struct Metrics {
+    grpc_requests_total: AtomicU64,
+    grpc_calls: Mutex<BTreeMap<String, MethodMetrics>>,
+}
+
+/// Status code counts and latencies of one method.
+#[derive(Debug, Default)]
+struct MethodMetrics {
+    handled: BTreeMap<i32, u64>,
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    duration_sum: f64,
+    duration_count: u64,
+}
+
+impl Metrics {
+    /// Record one gRPC request.
+    pub fn record_grpc_request(&self) {
+        self.grpc_requests_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Number of gRPC requests received since startup.
+    pub fn grpc_requests_total(&self) -> u64 {
+        self.grpc_requests_total.load(Ordering::Relaxed)
+    }
+
+    /// Record a completed call to `method` with its status `code` and
+    /// duration.
+    ///
+    /// Any path can be requested, so a method seen for the first time is only
+    /// given its own label when it did not answer `Unimplemented`, and while
+    /// fewer than [`MAX_METHOD_LABELS`] methods are labelled. Other calls are
+    /// recorded under [`OTHER_METHOD`], keeping the number of series bounded.
+    pub fn record_grpc_call(&self, method: &str, code: tonic::Code, elapsed: Duration) {
+        let mut grpc_calls = self.grpc_calls.lock().unwrap_or_else(|e| e.into_inner());
+
+        let known = grpc_calls.contains_key(method);
+        let method = if known || (code != tonic::Code::Unimplemented && grpc_calls.len() < MAX_METHOD_LABELS) {
+            method
+        } else {
+            OTHER_METHOD
+        };
+        let method_metrics = grpc_calls.entry(method.to_string()).or_default();
+
+        *method_metrics.handled.entry(code as i32).or_default() += 1;
+        let seconds = elapsed.as_secs_f64();
+        if let Some(bucket) = LATENCY_BUCKETS.iter().position(|upper_bound| seconds <= *upper_bound) {
+            method_metrics.buckets[bucket] += 1;
+        }
+        method_metrics.duration_sum += seconds;
+        method_metrics.duration_count += 1;
+    }
+
+    /// Number of completed calls to `method` that ended with `code`.
+    pub fn grpc_handled_total(&self, method: &str, code: tonic::Code) -> u64 {
+        let grpc_calls = self.grpc_calls.lock().unwrap_or_else(|e| e.into_inner());
+        grpc_calls
+            .get(method)
+            .and_then(|method_metrics| method_metrics.handled.get(&(code as i32)).copied())
+            .unwrap_or(0)
+    }
+
+    /// Render the counters, plus point-in-time gauges supplied by the caller,
+    /// in the Prometheus text exposition format.
+    ///
+    /// # Arguments
+    ///
+    /// * `database_up` - Whether the database answered a health check
+    /// * `streams_available` - Free streaming RPC slots, or `None` when uncapped
+    pub fn render(&self, database_up: bool, streams_available: Option<usize>) -> String {
+        let mut output = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: String| {
+            output.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n"));
+        };
+
+        metric(
+            "ledger_grpc_requests_total",
+            "counter",
+            "gRPC requests received since startup.",
+            self.grpc_requests_total().to_string(),
+        );
+        metric(
+            "ledger_database_up",
+            "gauge",
+            "Whether the database answered a health check (1) or not (0).",
+            u8::from(database_up).to_string(),
+        );
+        if let Some(streams_available) = streams_available {
+            metric(
+                "ledger_streams_available",
+                "gauge",
+                "Streaming RPC slots free under max_concurrent_streams.",
+                streams_available.to_string(),
+            );
+        }
+
+        self.render_grpc_calls(&mut output);
+        output
+    }
+
+    /// Append the per-method call counters and latency histogram to `output`.
+    fn render_grpc_calls(&self, output: &mut String) {
+        let grpc_calls = self.grpc_calls.lock().unwrap_or_else(|e| e.into_inner());
+
+        output.push_str("# HELP ledger_grpc_handled_total gRPC calls completed, by method and status code.\n");
+        output.push_str("# TYPE ledger_grpc_handled_total counter\n");
+        for (method, method_metrics) in grpc_calls.iter() {
+            let method = escape_label_value(method);
+            for (code, count) in &method_metrics.handled {
+                let code = tonic::Code::from_i32(*code);
+                output.push_str(&format!("ledger_grpc_handled_total{{method=\"{method}\",code=\"{code:?}\"}} {count}\n"));
+            }
+        }
+
+        output.push_str("# HELP ledger_grpc_request_duration_seconds gRPC call duration in seconds, by method.\n");
+        output.push_str("# TYPE ledger_grpc_request_duration_seconds histogram\n");
+        for (method, method_metrics) in grpc_calls.iter() {
+            let method = escape_label_value(method);
+            let mut cumulative = 0;
+            for (upper_bound, count) in LATENCY_BUCKETS.iter().zip(method_metrics.buckets) {
+                cumulative += count;
+                output.push_str(&format!(
+                    "ledger_grpc_request_duration_seconds_bucket{{method=\"{method}\",le=\"{upper_bound}\"}} {cumulative}\n"
+                ));
+            }
+            let count = method_metrics.duration_count;
+            output.push_str(&format!(
+                "ledger_grpc_request_duration_seconds_bucket{{method=\"{method}\",le=\"+Inf\"}} {count}\n"
+            ));
+            output.push_str(&format!(
+                "ledger_grpc_request_duration_seconds_sum{{method=\"{method}\"}} {}\n",
+                method_metrics.duration_sum
+            ));
+            output.push_str(&format!("ledger_grpc_request_duration_seconds_count{{method=\"{method}\"}} {count}\n"));
+        }
+    }
+}
+
+/// Escape a Prometheus label value.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Tower layer recording every RPC in a shared [`Metrics`].
+#[derive(Debug, Clone)]
+pub struct MetricsLayer {
+    metrics: Arc<Metrics>,
+}
+
+impl MetricsLayer {
+    /// Create a layer recording into `metrics`.
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        Self { metrics }
+    }
+}
+
+impl<S> tower::Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService { inner, metrics: Arc::clone(&self.metrics) }
+    }
+}
+
+/// Service produced by [`MetricsLayer`].
+#[derive(Debug, Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    metrics: Arc<Metrics>,
+}
+
+impl<S, ReqBody, ResBody> tower::Service<http::Request<ReqBody>> for MetricsService<S>
+where
+    S: tower::Service<http::Request<ReqBody>, Response = http::Response<ResBody>>
+        + Clone
+        + Send
+        + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+    ResBody: http_body::Body<Data = bytes::Bytes> + Send + 'static,
+    ResBody::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = http::Response<tonic::body::Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        self.metrics.record_grpc_request();
+        let call = CallMetrics {
+            metrics: Arc::clone(&self.metrics),
+            method: request.uri().path().to_string(),
+            started: Instant::now(),
+        };
+        let future = inner.call(request);
+        Box::pin(async move {
+            let response = match future.await {
+                Ok(response) => response,
+                Err(e) => {
+                    call.finish(tonic::Code::Unknown);
+                    return Err(e);
+                }
+            };
+
+            // Errors returned by the handler arrive as a trailers-only
+            // response, with the status in the headers
+            if let Some(status) = tonic::Status::from_header_map(response.headers()) {
+                call.finish(status.code());
+                return Ok(response.map(tonic::body::Body::new));
+            }
+
+            Ok(response.map(|body| {
+                let body = tonic::body::Body::new(body);
+                tonic::body::Body::new(MetricsBody { body, call: Some(call) })
+            }))
+        })
+    }
+}
+
+impl<S: tonic::server::NamedService> tonic::server::NamedService for MetricsService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+/// The method and start time of an RPC still to be recorded.
+struct CallMetrics {
+    metrics: Arc<Metrics>,
+    method: String,
+    started: Instant,
+}
+
+impl CallMetrics {
+    /// Record the call with its status code and duration.
+    fn finish(self, code: tonic::Code) {
+        self.metrics.record_grpc_call(&self.method, code, self.started.elapsed());
+    }
+}
+
+/// Response body that records the call once its trailers are sent.
+struct MetricsBody {
+    body: tonic::body::Body,
+    call: Option<CallMetrics>,
+}
+
+impl MetricsBody {
+    fn finish(&mut self, code: tonic::Code) {
+        if let Some(call) = self.call.take() {
+            call.finish(code);
+        }
+    }
+}
+
+impl http_body::Body for MetricsBody {
+    type Data = bytes::Bytes;
+    type Error = tonic::Status;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        let frame = std::task::ready!(Pin::new(&mut self.body).poll_frame(cx));
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(trailers) = frame.trailers_ref() {
+                    let code = tonic::Status::from_header_map(trailers).map_or(tonic::Code::Unknown, |status| status.code());
+                    self.finish(code);
+                }
+            }
+            Some(Err(status)) => self.finish(status.code()),
+            None => self.finish(tonic::Code::Unknown),
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.body.size_hint()
+    }
+}
+
+impl Drop for MetricsBody {
+    fn drop(&mut self) {
+        self.finish(tonic::Code::Cancelled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+    use tower::{Layer, ServiceExt};
+
+    const PING_PATH: &str = "/personal_ledger.UtilitiesService/Ping";
+
+    fn utilities_server() -> crate::rpc::UtilitiesServiceServer<crate::services::UtilitiesService> {
+        let ledger_config = Arc::new(crate::LedgerConfig::default());
+        crate::rpc::UtilitiesServiceServer::new(crate::services::UtilitiesService::new(ledger_config))
+    }
+
+    /// A gRPC request for `path` carrying one empty message.
+    fn grpc_request(path: &str) -> http::Request<tonic::body::Body> {
+        let frame = bytes::Bytes::from_static(&[0, 0, 0, 0, 0]);
+        http::Request::builder()
+            .method(http::Method::POST)
+            .uri(path)
+            .header(http::header::CONTENT_TYPE, "application/grpc")
+            .header("te", "trailers")
+            .body(tonic::body::Body::new(http_body_util::Full::new(frame)))
+            .unwrap()
+    }
+
+    /// Tests that the layer counts requests and records each call's method and code.
+    #[tokio::test]
+    async fn test_metrics_layer_records_calls() {
+        let metrics = Arc::new(Metrics::default());
+        let layer = MetricsLayer::new(Arc::clone(&metrics));
+
+        for _ in 0..3 {
+            let response = layer.layer(utilities_server()).oneshot(grpc_request(PING_PATH)).await.unwrap();
+            response.into_body().collect().await.unwrap();
+        }
+        let response = layer
+            .layer(utilities_server())
+            .oneshot(grpc_request("/personal_ledger.UtilitiesService/Missing"))
+            .await
+            .unwrap();
+        drop(response);
+
+        assert_eq!(metrics.grpc_requests_total(), 4);
+        assert_eq!(metrics.grpc_handled_total(PING_PATH, tonic::Code::Ok), 3);
+        // Unknown methods do not get a label of their own
+        assert_eq!(metrics.grpc_handled_total("/personal_ledger.UtilitiesService/Missing", tonic::Code::Unimplemented), 0);
+        assert_eq!(metrics.grpc_handled_total(OTHER_METHOD, tonic::Code::Unimplemented), 1);
+    }
+
+    /// Tests that a response dropped before its trailers is recorded as cancelled.
+    #[tokio::test]
+    async fn test_metrics_layer_records_dropped_response_as_cancelled() {
+        let metrics = Arc::new(Metrics::default());
+
+        let response = MetricsLayer::new(Arc::clone(&metrics))
+            .layer(utilities_server())
+            .oneshot(grpc_request(PING_PATH))
+            .await
+            .unwrap();
+        drop(response);
+
+        assert_eq!(metrics.grpc_handled_total(PING_PATH, tonic::Code::Cancelled), 1);
+        assert_eq!(metrics.grpc_handled_total(PING_PATH, tonic::Code::Ok), 0);
+    }
+
+    /// Tests that render reports the counters, gauges and latency histogram.
+    #[test]
+    fn test_metrics_render() {
+        let metrics = Metrics::default();
+        for _ in 0..3 {
+            metrics.record_grpc_request();
+        }
+        metrics.record_grpc_call(PING_PATH, tonic::Code::Ok, Duration::from_millis(3));
+        metrics.record_grpc_call(PING_PATH, tonic::Code::Ok, Duration::from_millis(40));
+        metrics.record_grpc_call(PING_PATH, tonic::Code::Internal, Duration::from_secs(20));
+
+        let rendered = metrics.render(true, Some(7));
+        assert!(rendered.contains("# TYPE ledger_grpc_requests_total counter\nledger_grpc_requests_total 3\n"));
+        assert!(rendered.contains("\nledger_database_up 1\n"));
+        assert!(rendered.contains("\nledger_streams_available 7\n"));
+        assert!(rendered.contains(&format!("\nledger_grpc_handled_total{{method=\"{PING_PATH}\",code=\"Ok\"}} 2\n")));
+        assert!(rendered.contains(&format!("\nledger_grpc_handled_total{{method=\"{PING_PATH}\",code=\"Internal\"}} 1\n")));
+        assert!(rendered.contains("# TYPE ledger_grpc_request_duration_seconds histogram\n"));
+        for (le, count) in [("0.005", 1), ("0.025", 1), ("0.05", 2), ("10", 2), ("+Inf", 3)] {
+            let bucket = format!("\nledger_grpc_request_duration_seconds_bucket{{method=\"{PING_PATH}\",le=\"{le}\"}} {count}\n");
+            assert!(rendered.contains(&bucket), "missing {bucket} in {rendered}");
+        }
+        assert!(rendered.contains(&format!("\nledger_grpc_request_duration_seconds_count{{method=\"{PING_PATH}\"}} 3\n")));
+
+        let rendered = metrics.render(false, None);
+        assert!(rendered.contains("\nledger_database_up 0\n"));
+        assert!(!rendered.contains("ledger_streams_available"));
+    }
+
+    /// Tests that methods past the label cap are counted under `other`.
+    #[test]
+    fn test_method_labels_are_capped() {
+        let metrics = Metrics::default();
+        for n in 0..MAX_METHOD_LABELS + 5 {
+            metrics.record_grpc_call(&format!("/test.Service/Method{n}"), tonic::Code::Ok, Duration::ZERO);
+        }
+
+        assert_eq!(metrics.grpc_handled_total("/test.Service/Method0", tonic::Code::Ok), 1);
+        assert_eq!(metrics.grpc_handled_total(&format!("/test.Service/Method{MAX_METHOD_LABELS}"), tonic::Code::Ok), 0);
+        assert_eq!(metrics.grpc_handled_total(OTHER_METHOD, tonic::Code::Ok), 5);
+    }
+}
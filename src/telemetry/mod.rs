@@ -11,6 +11,7 @@
 //! - **Error Handling**: Comprehensive error types specific to telemetry operations
 //! - **Integration**: Seamless integration with the application's main error handling system
 //! - **Convenient Aliases**: Short aliases like `TelemetryLevel` for common types
+//! - **Metrics**: gRPC request counters and a latency histogram, rendered for Prometheus
 //!
 //! ## Actions
 //! 
@@ -78,6 +79,11 @@ use tracing_log;
 
 use crate::LedgerResult;
 
+mod metrics;
+
+/// gRPC request counters, latency histogram and the layer recording them
+pub use metrics::{Metrics, MetricsLayer, MetricsService, LATENCY_BUCKETS, MAX_METHOD_LABELS, OTHER_METHOD};
+
 // Re-export serde derives for convenience in this module
 use serde::{Serialize, de};
 
@@ -572,4 +578,4 @@ mod tests {
         let s = serde_json::from_str::<S>("{\"level\":\"warn\"}").expect("struct should deserialize");
         assert_eq!(s.level, LogLevel::WARN);
     }
-}
\ No newline at end of file
+}
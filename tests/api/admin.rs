@@ -0,0 +1,216 @@
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use personal_ledger_backend::{rpc, server};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Send a plain HTTP/1.1 GET and return the status code and body.
+async fn http_get(address: std::net::SocketAddr, path: &str) -> Result<(u16, String)> {
+    http_request(address, "GET", path, "").await
+}
+
+/// Send a plain HTTP/1.1 request with a JSON body and return the status code and body.
+async fn http_request(address: std::net::SocketAddr, method: &str, path: &str, body: &str) -> Result<(u16, String)> {
+    http_request_with_headers(address, method, path, &[], body).await
+}
+
+/// Send a plain HTTP/1.1 request with extra `(name, value)` headers and a JSON body.
+async fn http_request_with_headers(
+    address: std::net::SocketAddr,
+    method: &str,
+    path: &str,
+    headers: &[(&str, &str)],
+    body: &str,
+) -> Result<(u16, String)> {
+    let mut stream = tokio::net::TcpStream::connect(address).await?;
+    let extra_headers: String = headers.iter().map(|(name, value)| format!("{}: {}\r\n", name, value)).collect();
+    let request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        address,
+        extra_headers,
+        body.len(),
+        body
+    );
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+
+    let status = response
+        .split_whitespace()
+        .nth(1)
+        .ok_or("missing status line")?
+        .parse()?;
+    let body = response.split_once("\r\n\r\n").map(|(_, body)| body.to_string()).unwrap_or_default();
+    Ok((status, body))
+}
+
+async fn spawn_with_admin(database_pool: sqlx::SqlitePool) -> Result<(helpers::SpawnTonicServer, std::net::SocketAddr)> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.admin_port = Some(0);
+    })
+    .await?;
+    let admin_address = tonic_server.admin_address.ok_or("admin server should be enabled")?;
+    Ok((tonic_server, admin_address))
+}
+
+#[sqlx::test]
+async fn readyz_reflects_database_health(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let (_tonic_server, admin_address) = spawn_with_admin(database_pool.clone()).await?;
+
+    let (status, body) = http_get(admin_address, "/readyz").await?;
+    assert_eq!(status, 200);
+    assert_eq!(body, "ready\ndatabase: healthy (query answered)\n");
+
+    // The server shares this pool, so closing it makes the database unreachable
+    database_pool.close().await;
+
+    let (status, body) = http_get(admin_address, "/readyz").await?;
+    assert_eq!(status, 503);
+    assert!(body.contains("database: unhealthy"), "{}", body);
+
+    // Liveness does not depend on the database
+    let (status, _) = http_get(admin_address, "/healthz").await?;
+    assert_eq!(status, 200);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn metrics_count_grpc_requests(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let (tonic_server, admin_address) = spawn_with_admin(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    for _ in 0..2 {
+        let list_request = tonic::Request::new(personal_ledger_backend::rpc::CategoriesListRequest {
+            limit: 10,
+            ..Default::default()
+        });
+        tonic_client.category().categories_list(list_request).await?;
+    }
+
+    let (status, body) = http_get(admin_address, "/metrics").await?;
+    assert_eq!(status, 200);
+    assert!(body.contains("\nledger_grpc_requests_total 2\n"), "{}", body);
+    assert!(body.contains("\nledger_database_up 1\n"), "{}", body);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn metrics_count_handled_calls_by_method(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let (tonic_server, admin_address) = spawn_with_admin(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    tonic_client
+        .utilities()
+        .ping(personal_ledger_backend::rpc::PingRequest {})
+        .await?;
+
+    let (status, body) = http_get(admin_address, "/metrics").await?;
+    assert_eq!(status, 200);
+    assert!(
+        body.contains("\nledger_grpc_handled_total{method=\"/personal_ledger.UtilitiesService/Ping\",code=\"Ok\"} 1\n"),
+        "{}",
+        body
+    );
+    assert!(
+        body.contains("\nledger_grpc_request_duration_seconds_count{method=\"/personal_ledger.UtilitiesService/Ping\"} 1\n"),
+        "{}",
+        body
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn admin_server_is_disabled_by_default(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    assert!(tonic_server.admin_address.is_none());
+    Ok(())
+}
+
+#[sqlx::test]
+async fn json_api_create_matches_grpc_get(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.admin_port = Some(0);
+        config.server.enable_json_api = true;
+    })
+    .await?;
+    let admin_address = tonic_server.admin_address.ok_or("admin server should be enabled")?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let body = serde_json::json!({
+        "code": "JSON.API",
+        "name": "Created over JSON",
+        "description": "Sent through the admin JSON API",
+        "category_type": "expense",
+    });
+
+    //-- Execute Function (Act)
+    let (status, body) = http_request(admin_address, "POST", "/api/categories", &body.to_string()).await?;
+    assert_eq!(status, 201, "{}", body);
+    let created: server::CategoryJson = serde_json::from_str(&body)?;
+
+    let request = tonic::Request::new(rpc::CategoryGetRequest { id: created.id.clone(), read_mask: None });
+    let fetched = tonic_client.category().category_get(request).await?.into_inner().category.ok_or("missing category")?;
+
+    //-- Checks (Assertions)
+    assert_eq!(created.code, "JSON.API");
+    assert_eq!(created.category_type, "expense");
+    assert!(created.is_active);
+    assert_eq!(created, server::CategoryJson::from(fetched));
+
+    let (status, _) = http_request(admin_address, "DELETE", &format!("/api/categories/{}", created.id), "").await?;
+    assert_eq!(status, 204);
+    let (status, body) = http_get(admin_address, &format!("/api/categories/{}", created.id)).await?;
+    assert_eq!(status, 404);
+    assert!(body.contains("\"code\":\"NotFound\""), "{}", body);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn json_api_is_disabled_by_default(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let (_tonic_server, admin_address) = spawn_with_admin(database_pool).await?;
+
+    let (status, _) = http_get(admin_address, "/api/categories").await?;
+    assert_eq!(status, 404);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn json_api_requires_bearer_token(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.admin_port = Some(0);
+        config.server.enable_json_api = true;
+        config.server.auth_token = Some(secrecy::SecretString::from("json-api-token"));
+    })
+    .await?;
+    let admin_address = tonic_server.admin_address.ok_or("admin server should be enabled")?;
+
+    let (status, body) = http_get(admin_address, "/api/categories").await?;
+    assert_eq!(status, 401);
+    assert!(body.contains("\"code\":\"Unauthenticated\""), "{}", body);
+
+    let wrong_token = [(server::AUTHORIZATION_HEADER, "Bearer wrong-token")];
+    let (status, _) = http_request_with_headers(admin_address, "GET", "/api/categories", &wrong_token, "").await?;
+    assert_eq!(status, 401);
+
+    let token = [(server::AUTHORIZATION_HEADER, "Bearer json-api-token")];
+    let (status, body) = http_request_with_headers(admin_address, "GET", "/api/categories", &token, "").await?;
+    assert_eq!(status, 200, "{}", body);
+
+    // Probes stay open without the token
+    let (status, _) = http_get(admin_address, "/healthz").await?;
+    assert_eq!(status, 200);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use secrecy::SecretString;
+use personal_ledger_backend::{rpc, server};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+const TOKEN: &str = "test-bearer-token";
+
+fn with_authorization<T>(message: T, authorization: Option<&str>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    if let Some(value) = authorization {
+        request
+            .metadata_mut()
+            .insert(server::AUTHORIZATION_HEADER, value.parse().unwrap());
+    }
+    request
+}
+
+async fn spawn_with_token(database_pool: sqlx::SqlitePool) -> Result<helpers::SpawnTonicServer> {
+    helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.auth_token = Some(SecretString::from(TOKEN));
+    })
+    .await
+}
+
+#[sqlx::test]
+async fn requests_without_token_are_unauthenticated(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = spawn_with_token(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let status = tonic_client
+        .utilities()
+        .ping(with_authorization(rpc::PingRequest {}, None))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    assert_eq!(status.message(), server::MISSING_TOKEN_MESSAGE);
+
+    let list = rpc::CategoriesListRequest { limit: 10, ..Default::default() };
+    let status = tonic_client
+        .category()
+        .categories_list(with_authorization(list, Some("Bearer wrong-token")))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    assert_eq!(status.message(), server::INVALID_TOKEN_MESSAGE);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn requests_with_token_are_served(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = spawn_with_token(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+    let authorization = format!("Bearer {}", TOKEN);
+
+    tonic_client
+        .utilities()
+        .ping(with_authorization(rpc::PingRequest {}, Some(&authorization)))
+        .await?;
+
+    let list = rpc::CategoriesListRequest { limit: 10, ..Default::default() };
+    tonic_client
+        .category()
+        .categories_list(with_authorization(list, Some(&authorization)))
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn health_checks_do_not_need_token(database_pool: sqlx::SqlitePool) -> Result<()> {
+    use tonic_health::pb::{health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest};
+
+    let tonic_server = spawn_with_token(database_pool).await?;
+
+    let response = HealthClient::new(tonic_server.transport_channel())
+        .check(HealthCheckRequest { service: String::new() })
+        .await?
+        .into_inner();
+    assert_eq!(response.status, ServingStatus::Serving as i32);
+
+    Ok(())
+}
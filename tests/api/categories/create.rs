@@ -1,5 +1,6 @@
 use personal_ledger_backend::rpc;
 use personal_ledger_backend::domain;
+use personal_ledger_backend::database;
 
 use crate::{categories, helpers};
 
@@ -39,8 +40,15 @@ async fn insert_returns_created_category(database_pool: sqlx::SqlitePool) -> Res
     assert_eq!(created_category.description, rpc_category.description);
     assert_eq!(created_category.url_slug, rpc_category.url_slug);
     assert_eq!(created_category.category_type, rpc_category.category_type);
-    assert_eq!(created_category.color, rpc_category.color);
-    assert_eq!(created_category.icon, rpc_category.icon);
+    let category_type = domain::CategoryTypes::from_rpc_i32(rpc_category.category_type)?;
+    assert_eq!(
+        created_category.color,
+        rpc_category.color.clone().or_else(|| Some(category_type.default_color().to_string()))
+    );
+    assert_eq!(
+        created_category.icon,
+        rpc_category.icon.clone().or_else(|| Some(category_type.default_icon().to_string()))
+    );
     assert_eq!(created_category.is_active, rpc_category.is_active);
 
     // Verify that the ID is generated by the server (not the same as input)
@@ -99,8 +107,11 @@ async fn create_fails_with_missing_required_fields(database_pool: sqlx::SqlitePo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         }),
     };
     let request = tonic::Request::new(request_message);
@@ -119,8 +130,11 @@ async fn create_fails_with_missing_required_fields(database_pool: sqlx::SqlitePo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         }),
     };
     let request = tonic::Request::new(request_message);
@@ -147,8 +161,11 @@ async fn create_fails_with_invalid_category_type(database_pool: sqlx::SqlitePool
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         }),
     };
     let request = tonic::Request::new(request_message);
@@ -175,8 +192,11 @@ async fn create_fails_with_invalid_color_format(database_pool: sqlx::SqlitePool)
             color: Some("invalid-color".to_string()), // Invalid color format
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         }),
     };
     let request = tonic::Request::new(request_message);
@@ -212,8 +232,11 @@ async fn create_fails_with_duplicate_code(database_pool: sqlx::SqlitePool) -> Re
         color: None,
         icon: None,
         is_active: true,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     let request_message2 = rpc::CategoryCreateRequest {
@@ -226,6 +249,56 @@ async fn create_fails_with_duplicate_code(database_pool: sqlx::SqlitePool) -> Re
     Ok(())
 }
 
+#[sqlx::test]
+async fn create_duplicate_code_returns_already_exists(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let existing = categories::mock_rpc_category();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(existing.clone()) });
+    tonic_client.category().category_create(request).await?;
+
+    let mut duplicate = categories::mock_rpc_category();
+    duplicate.code = existing.code.clone();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(duplicate) });
+    let status = tonic_client
+        .category()
+        .category_create(request)
+        .await
+        .expect_err("Duplicate code should be rejected");
+
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains(&existing.code), "{}", status.message());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_soft_deleted_code_returns_already_exists(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let existing = categories::mock_rpc_category();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(existing.clone()) });
+    let created = tonic_client.category().category_create(request).await?.into_inner().category.ok_or("missing category")?;
+    database::Categories::soft_delete(created.id.parse()?, &database_pool).await?;
+
+    // The soft-deleted row still holds the code under the unique constraint
+    let mut duplicate = categories::mock_rpc_category();
+    duplicate.code = existing.code.clone();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(duplicate) });
+    let status = tonic_client
+        .category()
+        .category_create(request)
+        .await
+        .expect_err("Code of a soft-deleted category should be rejected");
+
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains(&existing.code), "{}", status.message());
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn create_fails_with_duplicate_name(database_pool: sqlx::SqlitePool) -> Result<()> {
     let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
@@ -252,8 +325,11 @@ async fn create_fails_with_duplicate_name(database_pool: sqlx::SqlitePool) -> Re
         color: None,
         icon: None,
         is_active: true,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     let request_message2 = rpc::CategoryCreateRequest {
@@ -262,6 +338,7 @@ async fn create_fails_with_duplicate_name(database_pool: sqlx::SqlitePool) -> Re
     let request2 = tonic::Request::new(request_message2);
     let result = tonic_client.category().category_create(request2).await;
     assert!(result.is_err()); // Should fail due to duplicate name
+    assert_eq!(result.unwrap_err().code(), tonic::Code::AlreadyExists);
 
     Ok(())
 }
@@ -283,8 +360,11 @@ async fn create_succeeds_with_minimal_valid_request(database_pool: sqlx::SqliteP
         color: None,
         icon: None,
         is_active: true, // Default value
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     let request_message = rpc::CategoryCreateRequest {
@@ -303,11 +383,12 @@ async fn create_succeeds_with_minimal_valid_request(database_pool: sqlx::SqliteP
     assert_eq!(created_category.category_type, rpc_category.category_type);
     assert_eq!(created_category.is_active, rpc_category.is_active);
 
-    // Verify optional fields are None/empty
+    // Verify optional fields are None/empty, apart from the type's default colour and icon
+    let category_type = domain::CategoryTypes::from_rpc_i32(rpc_category.category_type)?;
     assert!(created_category.description.is_none());
     assert!(created_category.url_slug.is_none());
-    assert!(created_category.color.is_none());
-    assert!(created_category.icon.is_none());
+    assert_eq!(created_category.color.as_deref(), Some(category_type.default_color().as_str()));
+    assert_eq!(created_category.icon.as_deref(), Some(category_type.default_icon()));
 
     // Verify server-generated fields
     assert_ne!(created_category.id, rpc_category.id);
@@ -335,8 +416,11 @@ async fn batch_create_succeeds_with_multiple_categories(database_pool: sqlx::Sql
             color: Some("#FF5733".to_string()),
             icon: Some("utensils".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -348,8 +432,11 @@ async fn batch_create_succeeds_with_multiple_categories(database_pool: sqlx::Sql
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -361,8 +448,11 @@ async fn batch_create_succeeds_with_multiple_categories(database_pool: sqlx::Sql
             color: Some("#4A90E2".to_string()),
             icon: Some("bolt".to_string()),
             is_active: false, // Test inactive category
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -387,11 +477,18 @@ async fn batch_create_succeeds_with_multiple_categories(database_pool: sqlx::Sql
         assert_eq!(created_category.category_type, original.category_type);
         assert_eq!(created_category.is_active, original.is_active);
 
-        // Verify optional fields
+        // Verify optional fields, with the type's default colour and icon when omitted
+        let category_type = domain::CategoryTypes::from_rpc_i32(original.category_type)?;
         assert_eq!(created_category.description, original.description);
         assert_eq!(created_category.url_slug, original.url_slug);
-        assert_eq!(created_category.color, original.color);
-        assert_eq!(created_category.icon, original.icon);
+        assert_eq!(
+            created_category.color,
+            original.color.clone().or_else(|| Some(category_type.default_color().to_string()))
+        );
+        assert_eq!(
+            created_category.icon,
+            original.icon.clone().or_else(|| Some(category_type.default_icon().to_string()))
+        );
 
         // Verify server-generated fields
         assert_ne!(created_category.id, original.id); // Should be different from empty string
@@ -441,8 +538,11 @@ async fn batch_create_fails_with_invalid_category(database_pool: sqlx::SqlitePoo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -454,8 +554,11 @@ async fn batch_create_fails_with_invalid_category(database_pool: sqlx::SqlitePoo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -487,8 +590,11 @@ async fn batch_create_fails_with_duplicate_codes_in_batch(database_pool: sqlx::S
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -500,8 +606,11 @@ async fn batch_create_fails_with_duplicate_codes_in_batch(database_pool: sqlx::S
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -534,8 +643,11 @@ async fn batch_create_succeeds_with_different_category_types(database_pool: sqlx
             color: Some("#228B22".to_string()),
             icon: Some("bank".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -547,8 +659,11 @@ async fn batch_create_succeeds_with_different_category_types(database_pool: sqlx
             color: Some("#DC143C".to_string()),
             icon: Some("credit-card".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -560,8 +675,11 @@ async fn batch_create_succeeds_with_different_category_types(database_pool: sqlx
             color: Some("#FFD700".to_string()),
             icon: Some("chart-line".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -573,8 +691,11 @@ async fn batch_create_succeeds_with_different_category_types(database_pool: sqlx
             color: Some("#32CD32".to_string()),
             icon: Some("dollar-sign".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -607,11 +728,18 @@ async fn batch_create_succeeds_with_different_category_types(database_pool: sqlx
         assert_eq!(created_category.category_type, expected_type as i32);
         assert_eq!(created_category.is_active, original.is_active);
 
-        // Verify optional fields
+        // Verify optional fields, with the type's default colour and icon when omitted
+        let category_type = domain::CategoryTypes::from_rpc_i32(original.category_type)?;
         assert_eq!(created_category.description, original.description);
         assert_eq!(created_category.url_slug, original.url_slug);
-        assert_eq!(created_category.color, original.color);
-        assert_eq!(created_category.icon, original.icon);
+        assert_eq!(
+            created_category.color,
+            original.color.clone().or_else(|| Some(category_type.default_color().to_string()))
+        );
+        assert_eq!(
+            created_category.icon,
+            original.icon.clone().or_else(|| Some(category_type.default_icon().to_string()))
+        );
 
         // Verify server-generated fields
         assert_ne!(created_category.id, original.id);
@@ -639,8 +767,11 @@ async fn batch_create_fails_with_invalid_category_type(database_pool: sqlx::Sqli
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -652,8 +783,11 @@ async fn batch_create_fails_with_invalid_category_type(database_pool: sqlx::Sqli
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -685,8 +819,11 @@ async fn batch_create_fails_with_invalid_color_format(database_pool: sqlx::Sqlit
             color: Some("#FF5733".to_string()), // Valid color
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -698,8 +835,11 @@ async fn batch_create_fails_with_invalid_color_format(database_pool: sqlx::Sqlit
             color: Some("invalid-color".to_string()), // Invalid color format
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -741,8 +881,11 @@ async fn batch_create_fails_with_duplicate_code_against_existing(database_pool:
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -784,8 +927,11 @@ async fn batch_create_fails_with_duplicate_name_against_existing(database_pool:
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -818,8 +964,11 @@ async fn batch_create_succeeds_with_minimal_valid_requests(database_pool: sqlx::
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -831,8 +980,11 @@ async fn batch_create_succeeds_with_minimal_valid_requests(database_pool: sqlx::
             color: None,
             icon: None,
             is_active: false, // Test inactive
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -856,11 +1008,12 @@ async fn batch_create_succeeds_with_minimal_valid_requests(database_pool: sqlx::
         assert_eq!(created_category.category_type, original.category_type);
         assert_eq!(created_category.is_active, original.is_active);
 
-        // Verify optional fields are None/empty
+        // Verify optional fields are None/empty, apart from the type's default colour and icon
+        let category_type = domain::CategoryTypes::from_rpc_i32(original.category_type)?;
         assert!(created_category.description.is_none());
         assert!(created_category.url_slug.is_none());
-        assert!(created_category.color.is_none());
-        assert!(created_category.icon.is_none());
+        assert_eq!(created_category.color.as_deref(), Some(category_type.default_color().as_str()));
+        assert_eq!(created_category.icon.as_deref(), Some(category_type.default_icon()));
 
         // Verify server-generated fields
         assert_ne!(created_category.id, original.id);
@@ -888,8 +1041,11 @@ async fn batch_create_fails_with_invalid_url_slug(database_pool: sqlx::SqlitePoo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -901,8 +1057,11 @@ async fn batch_create_fails_with_invalid_url_slug(database_pool: sqlx::SqlitePoo
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -915,4 +1074,250 @@ async fn batch_create_fails_with_invalid_url_slug(database_pool: sqlx::SqlitePoo
     assert!(result.is_err()); // Should fail due to invalid URL slug
 
     Ok(())
-}
\ No newline at end of file
+}
+#[sqlx::test]
+async fn validate_batch_reports_every_error_with_its_index(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let valid = categories::mock_rpc_category();
+    let mut empty_code = categories::mock_rpc_category();
+    empty_code.code = String::new();
+    let mut bad_color_and_type = categories::mock_rpc_category();
+    bad_color_and_type.color = Some("not-a-color".to_string());
+    bad_color_and_type.category_type = 999;
+    let mut empty_name = categories::mock_rpc_category();
+    empty_name.name = "  ".to_string();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesValidateBatchRequest {
+        categories: vec![valid, empty_code, bad_color_and_type, empty_name],
+    });
+    let response = tonic_client.category().categories_validate_batch(request).await?.into_inner();
+
+    //-- Checks (Assertions)
+    let errors: Vec<(i32, &str)> = response.errors.iter().map(|e| (e.index, e.field.as_str())).collect();
+    assert_eq!(errors, vec![(1, "code"), (2, "category_type"), (2, "color"), (3, "name")]);
+    assert!(response.errors.iter().all(|e| !e.message.is_empty()));
+    assert_eq!(response.valid_count, 1);
+
+    // Nothing was created
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { limit: 10, ..Default::default() });
+    let listed = tonic_client.category().categories_list(list_request).await?.into_inner();
+    assert_eq!(listed.total_count, 0);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn validate_batch_rejects_empty_batch(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let request = tonic::Request::new(rpc::CategoriesValidateBatchRequest { categories: vec![] });
+    let status = tonic_client.category().categories_validate_batch(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_keeps_client_id_when_accepted(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.accept_client_ids = true;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let client_id = domain::RowID::new().to_string();
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.id = client_id.clone();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created = tonic_client.category().category_create(request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert_eq!(created.id, client_id);
+
+    // A blank id still gets a generated one
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.id = String::new();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created = tonic_client.category().category_create(request).await?.into_inner().category.unwrap();
+    assert!(created.id.parse::<domain::RowID>().is_ok());
+
+    // Ids that are not UUID v7 are rejected
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.id = "550e8400-e29b-41d4-a716-446655440000".to_string();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let status = tonic_client.category().category_create(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_ignores_client_id_when_not_accepted(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let client_id = domain::RowID::new().to_string();
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.id = client_id.clone();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created = tonic_client.category().category_create(request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert_ne!(created.id, client_id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_rejects_duplicate_client_id(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.accept_client_ids = true;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let client_id = domain::RowID::new().to_string();
+    let mut first = categories::mock_rpc_category();
+    first.id = client_id.clone();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(first) });
+    tonic_client.category().category_create(request).await?;
+
+    //-- Execute Function (Act)
+    let mut second = categories::mock_rpc_category();
+    second.id = client_id.clone();
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(second) });
+    let status = tonic_client.category().category_create(request).await.unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+
+    // The same id twice in one batch conflicts too
+    let fresh_id = domain::RowID::new().to_string();
+    let mut third = categories::mock_rpc_category();
+    third.id = fresh_id.clone();
+    let mut fourth = categories::mock_rpc_category();
+    fourth.id = fresh_id;
+    let request = tonic::Request::new(rpc::CategoriesCreateBatchRequest { categories: vec![third, fourth] });
+    let status = tonic_client.category().categories_create_batch(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().starts_with("Category at index 1"), "{}", status.message());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_respects_max_categories(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool.clone(), |config| {
+        config.server.max_categories = 3;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    for _ in 0..2 {
+        let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(categories::mock_rpc_category()) });
+        tonic_client.category().category_create(request).await?;
+    }
+
+    //-- Execute Function (Act)
+    // Two more would make four, so the whole batch is rejected
+    let request = tonic::Request::new(rpc::CategoriesCreateBatchRequest {
+        categories: vec![categories::mock_rpc_category(), categories::mock_rpc_category()],
+    });
+    let status = tonic_client.category().categories_create_batch(request).await.unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(database::Categories::count(&database_pool).await?, 2);
+
+    // Creating up to the cap succeeds, and the next create is rejected
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(categories::mock_rpc_category()) });
+    tonic_client.category().category_create(request).await?;
+
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(categories::mock_rpc_category()) });
+    let status = tonic_client.category().category_create(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(database::Categories::count(&database_pool).await?, 3);
+
+    Ok(())
+}
+
+/// The reason code in a status's `google.rpc.ErrorInfo` detail
+fn status_reason(status: &tonic::Status) -> Option<String> {
+    use tonic_types::StatusExt;
+    status.get_error_details().error_info().map(|info| info.reason.clone())
+}
+
+#[sqlx::test]
+async fn create_validation_errors_carry_reason_codes(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let mut bad_color = categories::mock_rpc_category();
+    bad_color.color = Some("#GG0000".to_string());
+    let mut no_code = categories::mock_rpc_category();
+    no_code.code = "  ".to_string();
+    let mut bad_slug = categories::mock_rpc_category();
+    bad_slug.url_slug = Some("---".to_string());
+
+    //-- Execute Function (Act) and Checks (Assertions)
+    for (category, reason) in [(bad_color, "COLOR_BAD_FORMAT"), (no_code, "CODE_REQUIRED")] {
+        let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(category) });
+        let status = tonic_client.category().category_create(request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status_reason(&status).as_deref(), Some(reason), "{}", status.message());
+    }
+
+    // The batch prefixes the message with the index but keeps the reason
+    let request = tonic::Request::new(rpc::CategoriesCreateBatchRequest {
+        categories: vec![categories::mock_rpc_category(), bad_slug],
+    });
+    let status = tonic_client.category().categories_create_batch(request).await.unwrap_err();
+    assert!(status.message().starts_with("Category at index 1"), "{}", status.message());
+    assert!(status_reason(&status).is_some_and(|reason| reason.starts_with("SLUG_")), "{}", status.message());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_defaults_unknown_category_type_when_not_strict(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.strict_enums = false;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.category_type = 999;
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created = tonic_client.category().category_create(request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert_eq!(created.category_type, domain::CategoryTypes::Expense.to_rpc_i32());
+
+    Ok(())
+}
@@ -28,7 +28,9 @@ async fn deactivate_succeeds_with_existing_active_category(database_pool: sqlx::
 
     // Now deactivate the category
     let deactivate_request_message = rpc::CategoryDeactivateRequest {
-        id: created_category.id.clone()
+        id: created_category.id.clone(),
+        reactivate_on: None,
+        code: None,
     };
     let deactivate_request = tonic::Request::new(deactivate_request_message);
     let deactivate_response = tonic_client.category().category_deactivate(deactivate_request).await?;
@@ -84,7 +86,9 @@ async fn deactivate_succeeds_with_already_inactive_category(database_pool: sqlx:
 
     // Now deactivate the already inactive category (should still work)
     let deactivate_request_message = rpc::CategoryDeactivateRequest {
-        id: created_category.id.clone()
+        id: created_category.id.clone(),
+        reactivate_on: None,
+        code: None,
     };
     let deactivate_request = tonic::Request::new(deactivate_request_message);
     let deactivate_response = tonic_client.category().category_deactivate(deactivate_request).await?;
@@ -114,7 +118,9 @@ async fn deactivate_fails_with_invalid_id_format(database_pool: sqlx::SqlitePool
 
     // Try to deactivate with an invalid ID format
     let deactivate_request_message = rpc::CategoryDeactivateRequest {
-        id: "invalid-id-format".to_string()
+        id: "invalid-id-format".to_string(),
+        reactivate_on: None,
+        code: None,
     };
     let deactivate_request = tonic::Request::new(deactivate_request_message);
 
@@ -138,7 +144,9 @@ async fn deactivate_fails_with_nonexistent_category(database_pool: sqlx::SqliteP
     // Try to deactivate a category that doesn't exist
     let fake_id = domain::RowID::new().to_string();
     let deactivate_request_message = rpc::CategoryDeactivateRequest {
-        id: fake_id.clone()
+        id: fake_id.clone(),
+        reactivate_on: None,
+        code: None,
     };
     let deactivate_request = tonic::Request::new(deactivate_request_message);
 
@@ -150,4 +158,160 @@ async fn deactivate_fails_with_nonexistent_category(database_pool: sqlx::SqliteP
     assert!(error.message().contains(&format!("Category with ID '{}' not found", fake_id)));
 
     Ok(())
-}
\ No newline at end of file
+}
+#[sqlx::test]
+async fn deactivate_with_reactivate_on_schedules_reactivation(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.is_active = true;
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let reactivate_on = chrono::Utc::now() + chrono::Duration::days(30);
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        id: created_category.id.clone(),
+        reactivate_on: Some(prost_types::Timestamp { seconds: reactivate_on.timestamp(), nanos: 0 }),
+        code: None,
+    });
+    let deactivated_category = tonic_client.category().category_deactivate(deactivate_request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert!(!deactivated_category.is_active);
+
+    let id: domain::RowID = created_category.id.parse()?;
+    let stored = personal_ledger_backend::database::Categories::find_by_id(id, &database_pool).await?.unwrap();
+    assert_eq!(stored.reactivate_on.map(|date| date.timestamp()), Some(reactivate_on.timestamp()));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_fails_with_past_reactivate_on(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(categories::mock_rpc_category()) });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let reactivate_on = chrono::Utc::now() - chrono::Duration::days(1);
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        id: created_category.id,
+        reactivate_on: Some(prost_types::Timestamp { seconds: reactivate_on.timestamp(), nanos: 0 }),
+        code: None,
+    });
+    let error = tonic_client.category().category_deactivate(deactivate_request).await
+        .expect_err("Expected deactivate to fail with a past reactivate_on");
+
+    //-- Checks (Assertions)
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+    assert_eq!(error.message(), "reactivate_on must be in the future");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_by_code_succeeds_with_existing_category(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.is_active = true;
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        code: Some(created_category.code.clone()),
+        ..Default::default()
+    });
+    let deactivated_category = tonic_client.category().category_deactivate(deactivate_request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert_eq!(deactivated_category.id, created_category.id);
+    assert!(!deactivated_category.is_active);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_by_code_with_reactivate_on_schedules_reactivation(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(categories::mock_rpc_category()) });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let reactivate_on = chrono::Utc::now() + chrono::Duration::days(7);
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        code: Some(created_category.code.clone()),
+        reactivate_on: Some(prost_types::Timestamp { seconds: reactivate_on.timestamp(), nanos: 0 }),
+        ..Default::default()
+    });
+    let deactivated_category = tonic_client.category().category_deactivate(deactivate_request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert!(!deactivated_category.is_active);
+
+    let id: domain::RowID = created_category.id.parse()?;
+    let stored = personal_ledger_backend::database::Categories::find_by_id(id, &database_pool).await?.unwrap();
+    assert_eq!(stored.reactivate_on.map(|date| date.timestamp()), Some(reactivate_on.timestamp()));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_by_code_fails_with_unknown_code(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    //-- Execute Function (Act)
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        code: Some("NO-SUCH-CODE".to_string()),
+        ..Default::default()
+    });
+    let error = tonic_client.category().category_deactivate(deactivate_request).await
+        .expect_err("Expected deactivate to fail with an unknown code");
+
+    //-- Checks (Assertions)
+    assert_eq!(error.code(), tonic::Code::NotFound);
+    assert_eq!(error.message(), "Category with code 'NO-SUCH-CODE' not found");
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn deactivate_fails_with_both_id_and_code(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    //-- Execute Function (Act)
+    let deactivate_request = tonic::Request::new(rpc::CategoryDeactivateRequest {
+        id: domain::RowID::new().to_string(),
+        code: Some("CODE".to_string()),
+        ..Default::default()
+    });
+    let error = tonic_client.category().category_deactivate(deactivate_request).await
+        .expect_err("Expected deactivate to fail with both an id and a code");
+
+    //-- Checks (Assertions)
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+
+    Ok(())
+}
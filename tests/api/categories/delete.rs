@@ -35,7 +35,8 @@ async fn delete_succeeds_with_existing_category(database_pool: sqlx::SqlitePool)
 
     // Verify the category is actually deleted by trying to get it
     let get_request_message = rpc::CategoryGetRequest {
-        id: created_category.id.clone()
+        id: created_category.id.clone(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let get_error = tonic_client.category().category_get(get_request).await
@@ -43,7 +43,8 @@ async fn delete_batch_succeeds_with_existing_categories(database_pool: sqlx::Sql
     // Verify all categories are actually deleted by trying to get them
     for id in &created_ids {
         let get_request_message = rpc::CategoryGetRequest {
-            id: id.clone()
+            id: id.clone(),
+            read_mask: None,
         };
         let get_request = tonic::Request::new(get_request_message);
         let get_error = tonic_client.category().category_get(get_request).await
@@ -97,7 +98,8 @@ async fn delete_batch_returns_partial_count_for_mixed_existing_and_nonexistent(d
     // Verify the existing categories are deleted
     for id in &created_ids {
         let get_request_message = rpc::CategoryGetRequest {
-            id: id.clone()
+            id: id.clone(),
+            read_mask: None,
         };
         let get_request = tonic::Request::new(get_request_message);
         let get_error = tonic_client.category().category_get(get_request).await
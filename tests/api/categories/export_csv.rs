@@ -0,0 +1,89 @@
+use personal_ledger_backend::rpc;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+const EXPECTED_HEADER: &str =
+    "id,code,name,description,url_slug,category_type,color,icon,is_active,display_order,created_on,updated_on\n";
+
+/// Call the export RPC and collect every streamed chunk.
+async fn collect_export_chunks(tonic_client: &mut helpers::SpawnTonicClient) -> Result<Vec<String>> {
+    let request = tonic::Request::new(rpc::CategoriesExportCsvRequest {});
+    let mut stream = tonic_client.category().categories_export_csv(request).await?.into_inner();
+
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.message().await? {
+        chunks.push(chunk.data);
+    }
+
+    Ok(chunks)
+}
+
+#[sqlx::test]
+async fn export_csv_streams_header_and_all_categories(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    // Create some test categories
+    let categories_to_create: Vec<rpc::Category> = (0..5)
+        .map(|_| super::mock_rpc_category())
+        .collect();
+    let create_request = tonic::Request::new(rpc::CategoriesCreateBatchRequest {
+        categories: categories_to_create,
+    });
+    let created_categories = tonic_client
+        .category()
+        .categories_create_batch(create_request)
+        .await?
+        .into_inner()
+        .categories;
+
+    let chunks = collect_export_chunks(&mut tonic_client).await?;
+
+    // The header is always sent on its own as the first chunk
+    assert_eq!(chunks.first().map(String::as_str), Some(EXPECTED_HEADER));
+
+    let csv_output = chunks.concat();
+    let mut reader = csv::Reader::from_reader(csv_output.as_bytes());
+    let rows: Vec<csv::StringRecord> = reader.records().collect::<core::result::Result<_, _>>()?;
+    assert_eq!(rows.len(), created_categories.len());
+
+    // Rows are ordered by code
+    let exported_codes: Vec<&str> = rows.iter().map(|row| &row[1]).collect();
+    let mut sorted_codes = exported_codes.clone();
+    sorted_codes.sort();
+    assert_eq!(exported_codes, sorted_codes);
+
+    for created in &created_categories {
+        let row = rows
+            .iter()
+            .find(|row| &row[0] == created.id.as_str())
+            .expect("Created category should be in the CSV export");
+
+        assert_eq!(&row[1], created.code.as_str());
+        assert_eq!(&row[2], created.name.as_str());
+        assert_eq!(&row[3], created.description.as_deref().unwrap_or_default());
+        assert_eq!(&row[4], created.url_slug.as_deref().unwrap_or_default());
+        assert_eq!(&row[6], created.color.as_deref().unwrap_or_default());
+        assert_eq!(&row[7], created.icon.as_deref().unwrap_or_default());
+        assert_eq!(&row[8], created.is_active.to_string().as_str());
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn export_csv_with_no_categories_returns_header_only(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let chunks = collect_export_chunks(&mut tonic_client).await?;
+
+    assert_eq!(chunks, vec![EXPECTED_HEADER.to_string()]);
+
+    Ok(())
+}
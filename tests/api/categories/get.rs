@@ -1,5 +1,5 @@
 use personal_ledger_backend::rpc;
-use personal_ledger_backend::domain;
+use personal_ledger_backend::{database, domain};
 
 use crate::{categories, helpers};
 
@@ -9,7 +9,7 @@ pub type Result<T> = core::result::Result<T, Error>;
 #[sqlx::test]
 async fn get_returns_existing_category(database_pool: sqlx::SqlitePool) -> Result<()> {
     //-- Setup and Fixtures (Arrange)
-    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
     let transport_channel = tonic_server.transport_channel();
     let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
 
@@ -24,7 +24,8 @@ async fn get_returns_existing_category(database_pool: sqlx::SqlitePool) -> Resul
 
     // Now get the category by ID
     let get_request_message = rpc::CategoryGetRequest {
-        id: created_category.id.clone()
+        id: created_category.id.clone(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let get_response = tonic_client.category().category_get(get_request).await?;
@@ -49,6 +50,15 @@ async fn get_returns_existing_category(database_pool: sqlx::SqlitePool) -> Resul
     assert!(retrieved_category.created_on.is_some());
     assert!(retrieved_category.updated_on.is_some());
 
+    // No display timezone is configured, so there is no local rendering
+    assert!(retrieved_category.created_on_local.is_none());
+
+    // Verify the response matches the stored row
+    let stored_category = database::Categories::find_by_id(retrieved_category.id.parse()?, &database_pool)
+        .await?
+        .unwrap();
+    helpers::assert_rpc_matches_db(&retrieved_category, &stored_category);
+
     Ok(())
 }
 
@@ -61,7 +71,8 @@ async fn get_fails_with_nonexistent_id(database_pool: sqlx::SqlitePool) -> Resul
     // Try to get a category with a non-existent ID
     let nonexistent_id = domain::RowID::new().to_string();
     let get_request_message = rpc::CategoryGetRequest {
-        id: nonexistent_id.clone()
+        id: nonexistent_id.clone(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get(get_request).await;
@@ -83,7 +94,8 @@ async fn get_fails_with_invalid_id_format(database_pool: sqlx::SqlitePool) -> Re
 
     // Try to get a category with an invalid ID format
     let get_request_message = rpc::CategoryGetRequest {
-        id: "invalid-uuid".to_string()
+        id: "invalid-uuid".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get(get_request).await;
@@ -114,7 +126,8 @@ async fn get_by_code_returns_existing_category(database_pool: sqlx::SqlitePool)
 
     // Now get the category by code
     let get_request_message = rpc::CategoryGetByCodeRequest {
-        code: created_category.code.clone()
+        code: created_category.code.clone(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let get_response = tonic_client.category().category_get_by_code(get_request).await?;
@@ -140,7 +153,8 @@ async fn get_by_code_fails_with_nonexistent_code(database_pool: sqlx::SqlitePool
 
     // Try to get a category with a non-existent code
     let get_request_message = rpc::CategoryGetByCodeRequest {
-        code: "NONEXISTENT".to_string()
+        code: "NONEXISTENT".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get_by_code(get_request).await;
@@ -162,7 +176,8 @@ async fn get_by_code_fails_with_empty_code(database_pool: sqlx::SqlitePool) -> R
 
     // Try to get a category with an empty code
     let get_request_message = rpc::CategoryGetByCodeRequest {
-        code: "".to_string()
+        code: "".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get_by_code(get_request).await;
@@ -195,7 +210,8 @@ async fn get_by_slug_returns_existing_category(database_pool: sqlx::SqlitePool)
 
     // Now get the category by slug
     let get_request_message = rpc::CategoryGetBySlugRequest {
-        url_slug: created_category.url_slug.clone().unwrap()
+        url_slug: created_category.url_slug.clone().unwrap(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let get_response = tonic_client.category().category_get_by_slug(get_request).await?;
@@ -220,7 +236,8 @@ async fn get_by_slug_fails_with_nonexistent_slug(database_pool: sqlx::SqlitePool
 
     // Try to get a category with a non-existent slug
     let get_request_message = rpc::CategoryGetBySlugRequest {
-        url_slug: "nonexistent-slug".to_string()
+        url_slug: "nonexistent-slug".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get_by_slug(get_request).await;
@@ -242,7 +259,8 @@ async fn get_by_slug_fails_with_empty_slug(database_pool: sqlx::SqlitePool) -> R
 
     // Try to get a category with an empty slug
     let get_request_message = rpc::CategoryGetBySlugRequest {
-        url_slug: "".to_string()
+        url_slug: "".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get_by_slug(get_request).await;
@@ -264,7 +282,8 @@ async fn get_by_slug_fails_with_invalid_slug_format(database_pool: sqlx::SqliteP
 
     // Try to get a category with an invalid slug format
     let get_request_message = rpc::CategoryGetBySlugRequest {
-        url_slug: "!@#$%^&*()".to_string()
+        url_slug: "!@#$%^&*()".to_string(),
+        read_mask: None,
     };
     let get_request = tonic::Request::new(get_request_message);
     let result = tonic_client.category().category_get_by_slug(get_request).await;
@@ -276,4 +295,98 @@ async fn get_by_slug_fails_with_invalid_slug_format(database_pool: sqlx::SqliteP
     assert!(status.message().contains("Invalid URL slug format"));
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[sqlx::test]
+async fn get_with_read_mask_returns_only_requested_fields(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let mut rpc_category = categories::mock_rpc_category();
+    rpc_category.description = Some("Weekly shop".to_string());
+    rpc_category.icon = Some("cart".to_string());
+    rpc_category.color = Some("#00AA00".to_string());
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(rpc_category) });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest {
+        id: created_category.id.clone(),
+        read_mask: Some(rpc::FieldMask { paths: vec!["name".to_string(), "code".to_string()] }),
+    });
+    let retrieved_category = tonic_client.category().category_get(get_request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    assert_eq!(retrieved_category.id, created_category.id);
+    assert_eq!(retrieved_category.name, created_category.name);
+    assert_eq!(retrieved_category.code, created_category.code);
+    assert_eq!(retrieved_category.description, None);
+    assert_eq!(retrieved_category.icon, None);
+    assert_eq!(retrieved_category.color, None);
+    assert_eq!(retrieved_category.url_slug, None);
+    assert!(retrieved_category.created_on.is_none());
+    assert!(retrieved_category.updated_on.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_by_code_rejects_unknown_read_mask_field(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let get_request = tonic::Request::new(rpc::CategoryGetByCodeRequest {
+        code: "NO.SUCH.CODE".to_string(),
+        read_mask: Some(rpc::FieldMask { paths: vec!["colour".to_string()] }),
+    });
+    let status = tonic_client.category().category_get_by_code(get_request).await.unwrap_err();
+
+    // The mask is checked before the lookup, so this is not a NotFound
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("colour"));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn get_includes_timestamps_in_display_timezone(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.display_timezone = Some("Australia/Sydney".to_string());
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest {
+        id: created_category.id.clone(),
+        read_mask: None,
+    });
+    let retrieved_category = tonic_client.category().category_get(get_request).await?.into_inner().category.unwrap();
+
+    //-- Checks (Assertions)
+    // The UTC timestamps are unchanged, the local renderings carry Sydney's offset
+    let created_on = retrieved_category.created_on.unwrap();
+    let created_on_local = retrieved_category.created_on_local.unwrap();
+    let local = chrono::DateTime::parse_from_rfc3339(&created_on_local)?;
+    assert_eq!(local.timestamp(), created_on.seconds);
+    assert!(created_on_local.ends_with("+10:00") || created_on_local.ends_with("+11:00"), "{}", created_on_local);
+    assert_eq!(
+        created_category.updated_on_local,
+        personal_ledger_backend::services::localize_timestamp(
+            created_category.updated_on.as_ref().unwrap(),
+            chrono_tz::Australia::Sydney
+        )
+    );
+
+    Ok(())
+}
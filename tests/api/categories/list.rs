@@ -1,6 +1,7 @@
 use personal_ledger_backend::rpc;
+use personal_ledger_backend::{database, domain};
 
-use crate::helpers;
+use crate::{categories, helpers};
 
 pub type Error = Box<dyn std::error::Error>;
 pub type Result<T> = core::result::Result<T, Error>;
@@ -23,8 +24,11 @@ async fn list_returns_all_categories(database_pool: sqlx::SqlitePool) -> Result<
             color: Some("#FF5733".to_string()),
             icon: Some("test-icon-1".to_string()),
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
         rpc::Category {
             id: "".to_string(),
@@ -36,8 +40,11 @@ async fn list_returns_all_categories(database_pool: sqlx::SqlitePool) -> Result<
             color: Some("#4A90E2".to_string()),
             icon: Some("test-icon-2".to_string()),
             is_active: false,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         },
     ];
 
@@ -58,6 +65,9 @@ async fn list_returns_all_categories(database_pool: sqlx::SqlitePool) -> Result<
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -104,8 +114,11 @@ async fn list_filters_by_category_type(database_pool: sqlx::SqlitePool) -> Resul
         color: None,
         icon: None,
         is_active: true,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     let income_category = rpc::Category {
@@ -118,8 +131,11 @@ async fn list_filters_by_category_type(database_pool: sqlx::SqlitePool) -> Resul
         color: None,
         icon: None,
         is_active: true,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     // Create the categories
@@ -139,6 +155,9 @@ async fn list_filters_by_category_type(database_pool: sqlx::SqlitePool) -> Resul
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -177,8 +196,11 @@ async fn list_filters_by_active_status(database_pool: sqlx::SqlitePool) -> Resul
         color: None,
         icon: None,
         is_active: true,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     let inactive_category = rpc::Category {
@@ -191,8 +213,11 @@ async fn list_filters_by_active_status(database_pool: sqlx::SqlitePool) -> Resul
         color: None,
         icon: None,
         is_active: false,
+        display_order: 0,
         created_on: None,
         updated_on: None,
+        created_on_local: None,
+        updated_on_local: None,
     };
 
     // Create the categories
@@ -212,6 +237,9 @@ async fn list_filters_by_active_status(database_pool: sqlx::SqlitePool) -> Resul
         is_active: Some(true),
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -233,6 +261,70 @@ async fn list_filters_by_active_status(database_pool: sqlx::SqlitePool) -> Resul
     Ok(())
 }
 
+#[sqlx::test]
+async fn list_filters_by_type_and_active_status_combinations(database_pool: sqlx::SqlitePool) -> Result<()> {
+    use rpc::CategoryTypes::{Expense, Income};
+
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    // Two active and one inactive expense, one active and two inactive income
+    let seeded = [
+        ("MIX.EXP.1", Expense, true),
+        ("MIX.EXP.2", Expense, true),
+        ("MIX.EXP.3", Expense, false),
+        ("MIX.INC.1", Income, true),
+        ("MIX.INC.2", Income, false),
+        ("MIX.INC.3", Income, false),
+    ];
+    for (code, category_type, is_active) in seeded {
+        let category = rpc::Category {
+            code: code.to_string(),
+            name: format!("Mix {}", code),
+            category_type: category_type as i32,
+            is_active,
+            ..Default::default()
+        };
+        let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(category) });
+        tonic_client.category().category_create(create_request).await?;
+    }
+
+    for category_type in [None, Some(Expense), Some(Income)] {
+        for is_active in [None, Some(true), Some(false)] {
+            let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+                limit: 100,
+                category_type: category_type.map(|category_type| category_type as i32),
+                is_active,
+                ..Default::default()
+            });
+            let listed = tonic_client.category().categories_list(list_request).await?.into_inner();
+
+            let mut expected: Vec<&str> = seeded
+                .iter()
+                .filter(|(_, t, a)| category_type.is_none_or(|c| c == *t) && is_active.is_none_or(|active| active == *a))
+                .map(|(code, _, _)| *code)
+                .collect();
+            expected.sort();
+            let mut codes: Vec<&str> = listed.categories.iter().map(|category| category.code.as_str()).collect();
+            codes.sort();
+
+            assert_eq!(codes, expected, "category_type={:?} is_active={:?}", category_type, is_active);
+            assert_eq!(listed.total_count as usize, expected.len());
+        }
+    }
+
+    // An unknown category type is rejected rather than matching nothing
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 100,
+        category_type: Some(999),
+        ..Default::default()
+    });
+    let status = tonic_client.category().categories_list(list_request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn list_supports_pagination(database_pool: sqlx::SqlitePool) -> Result<()> {
     let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
@@ -252,8 +344,11 @@ async fn list_supports_pagination(database_pool: sqlx::SqlitePool) -> Result<()>
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         };
 
         let create_request_message = rpc::CategoryCreateRequest {
@@ -273,6 +368,9 @@ async fn list_supports_pagination(database_pool: sqlx::SqlitePool) -> Result<()>
         is_active: None,
         sort_by: Some("code".to_string()),
         sort_desc: Some(false), // ascending
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -315,8 +413,11 @@ async fn list_supports_sorting(database_pool: sqlx::SqlitePool) -> Result<()> {
             color: None,
             icon: None,
             is_active: true,
+            display_order: 0,
             created_on: None,
             updated_on: None,
+            created_on_local: None,
+            updated_on_local: None,
         };
 
         let create_request_message = rpc::CategoryCreateRequest {
@@ -334,6 +435,9 @@ async fn list_supports_sorting(database_pool: sqlx::SqlitePool) -> Result<()> {
         is_active: None,
         sort_by: Some("name".to_string()),
         sort_desc: Some(false), // ascending
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -369,6 +473,9 @@ async fn list_validates_pagination_parameters(database_pool: sqlx::SqlitePool) -
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let result = tonic_client.category().categories_list(list_request).await;
@@ -386,6 +493,9 @@ async fn list_validates_pagination_parameters(database_pool: sqlx::SqlitePool) -
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let result = tonic_client.category().categories_list(list_request).await;
@@ -403,6 +513,9 @@ async fn list_validates_pagination_parameters(database_pool: sqlx::SqlitePool) -
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let result = tonic_client.category().categories_list(list_request).await;
@@ -429,6 +542,9 @@ async fn list_handles_empty_results(database_pool: sqlx::SqlitePool) -> Result<(
         is_active: None,
         sort_by: None,
         sort_desc: None,
+        page_token: None,
+        tag: None,
+        read_mask: None,
     };
     let list_request = tonic::Request::new(list_request_message);
     let list_response = tonic_client.category().categories_list(list_request).await?;
@@ -441,4 +557,308 @@ async fn list_handles_empty_results(database_pool: sqlx::SqlitePool) -> Result<(
     assert_eq!(list_response_message.limit, 100);
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Create `count` categories with unique codes through the API
+async fn create_paging_categories(tonic_client: &mut helpers::SpawnTonicClient, count: usize) -> Result<Vec<String>> {
+    let mut ids = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut category = categories::mock_rpc_category();
+        category.code = format!("PAGE_{:02}", i);
+        category.name = format!("Page Category {:02}", i);
+        category.url_slug = None;
+        let create_request = tonic::Request::new(rpc::CategoryCreateRequest { category: Some(category) });
+        let created = tonic_client.category().category_create(create_request).await?.into_inner();
+        ids.push(created.category.unwrap().id);
+    }
+    Ok(ids)
+}
+
+#[sqlx::test]
+async fn list_page_tokens_walk_every_category_once(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    let created_ids = create_paging_categories(&mut tonic_client, 7).await?;
+
+    let mut seen_ids = Vec::new();
+    let mut page_token = None;
+    let mut pages = 0;
+    loop {
+        let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+            limit: 3,
+            page_token: page_token.take(),
+            ..Default::default()
+        });
+        let response = tonic_client.category().categories_list(list_request).await?.into_inner();
+        pages += 1;
+        assert_eq!(response.total_count, 7);
+        seen_ids.extend(response.categories.into_iter().map(|c| c.id));
+
+        match response.next_page_token {
+            Some(token) => {
+                // The token is opaque, it must not expose a row id
+                assert!(!created_ids.iter().any(|id| token.contains(id.as_str())));
+                page_token = Some(token);
+            }
+            None => break,
+        }
+    }
+
+    assert_eq!(pages, 3);
+    let mut sorted_seen = seen_ids.clone();
+    sorted_seen.sort();
+    sorted_seen.dedup();
+    assert_eq!(sorted_seen.len(), 7, "every category is returned exactly once: {:?}", seen_ids);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_page_token_survives_deleting_its_category(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    create_paging_categories(&mut tonic_client, 5).await?;
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { limit: 2, ..Default::default() });
+    let first_page = tonic_client.category().categories_list(list_request).await?.into_inner();
+    let token = first_page.next_page_token.expect("a second page should follow");
+
+    // Delete the row the token points after, the next page still follows on
+    let last_id = first_page.categories.last().unwrap().id.clone();
+    let delete_request = tonic::Request::new(rpc::CategoryDeleteRequest { id: last_id });
+    tonic_client.category().category_delete(delete_request).await?;
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 5,
+        page_token: Some(token),
+        ..Default::default()
+    });
+    let second_page = tonic_client.category().categories_list(list_request).await?.into_inner();
+    assert_eq!(second_page.total_count, 4);
+    assert_eq!(second_page.categories.len(), 3);
+    assert!(second_page.next_page_token.is_none());
+
+    let first_ids: Vec<&String> = first_page.categories.iter().map(|c| &c.id).collect();
+    assert!(second_page.categories.iter().all(|c| !first_ids.contains(&&c.id)));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_rejects_tampered_page_token(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    create_paging_categories(&mut tonic_client, 3).await?;
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { limit: 1, ..Default::default() });
+    let response = tonic_client.category().categories_list(list_request).await?.into_inner();
+    let token = response.next_page_token.expect("a second page should follow");
+
+    // Change the last character so the signature no longer matches, and also
+    // try a raw row id as a client probing for cursors might
+    let mut tampered = token.clone();
+    let last = tampered.pop().unwrap();
+    tampered.push(if last == 'A' { 'B' } else { 'A' });
+
+    for page_token in [tampered, helpers::mock_row_id().to_string(), "not-a-token".to_string()] {
+        let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+            limit: 1,
+            page_token: Some(page_token),
+            ..Default::default()
+        });
+        let status = tonic_client.category().categories_list(list_request).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert!(status.message().contains("'page_token'"), "{}", status.message());
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_rejects_offset_combined_with_page_token(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    create_paging_categories(&mut tonic_client, 3).await?;
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { limit: 1, ..Default::default() });
+    let response = tonic_client.category().categories_list(list_request).await?.into_inner();
+    let token = response.next_page_token.expect("a second page should follow");
+
+    // Each field alone is accepted
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { offset: 1, limit: 1, ..Default::default() });
+    let by_offset = tonic_client.category().categories_list(list_request).await?.into_inner();
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 1,
+        page_token: Some(token.clone()),
+        ..Default::default()
+    });
+    let by_token = tonic_client.category().categories_list(list_request).await?.into_inner();
+    assert_eq!(by_offset.categories, by_token.categories);
+
+    // Both together are rejected, naming the conflicting fields
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        offset: 1,
+        limit: 1,
+        page_token: Some(token),
+        ..Default::default()
+    });
+    let status = tonic_client.category().categories_list(list_request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert_eq!(status.message(), "Fields 'offset' and 'page_token' cannot be used together");
+
+    Ok(())
+}
+
+
+#[sqlx::test]
+async fn list_filters_by_tag(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    let created_ids = create_paging_categories(&mut tonic_client, 4).await?;
+
+    let recurring = domain::Tag::parse("recurring")?;
+    for id in &created_ids[..3] {
+        database::Categories::add_tag(id.parse()?, &recurring, &database_pool).await?;
+    }
+
+    // Tags are matched case-insensitively and pages continue within the tag
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 2,
+        tag: Some("Recurring".to_string()),
+        ..Default::default()
+    });
+    let first_page = tonic_client.category().categories_list(list_request).await?.into_inner();
+    assert_eq!(first_page.total_count, 3);
+    assert_eq!(first_page.categories.len(), 2);
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 2,
+        tag: Some("recurring".to_string()),
+        page_token: first_page.next_page_token,
+        ..Default::default()
+    });
+    let second_page = tonic_client.category().categories_list(list_request).await?.into_inner();
+    assert_eq!(second_page.categories.len(), 1);
+    assert!(second_page.next_page_token.is_none());
+
+    let mut listed: Vec<String> = first_page.categories.into_iter()
+        .chain(second_page.categories)
+        .map(|category| category.id)
+        .collect();
+    listed.sort();
+    let mut expected = created_ids[..3].to_vec();
+    expected.sort();
+    assert_eq!(listed, expected);
+
+    // An invalid tag is rejected
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 2,
+        tag: Some("not a tag".to_string()),
+        ..Default::default()
+    });
+    let status = tonic_client.category().categories_list(list_request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().starts_with("Invalid tag"));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_with_read_mask_returns_only_requested_fields(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    let ids = create_paging_categories(&mut tonic_client, 3).await?;
+
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest {
+        limit: 2,
+        read_mask: Some(rpc::FieldMask { paths: vec!["name".to_string(), "code".to_string()] }),
+        ..Default::default()
+    });
+    let response = tonic_client.category().categories_list(list_request).await?.into_inner();
+
+    assert_eq!(response.categories.len(), 2);
+    assert_eq!(response.total_count, 3);
+    assert!(response.next_page_token.is_some());
+    for category in &response.categories {
+        assert!(ids.contains(&category.id));
+        assert!(category.name.starts_with("Page Category"));
+        assert!(category.code.starts_with("PAGE_"));
+        assert_eq!(category.category_type, 0);
+        assert_eq!(category.description, None);
+        assert_eq!(category.icon, None);
+        assert_eq!(category.color, None);
+        assert!(category.created_on.is_none());
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_stream_returns_every_category_in_creation_order(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let categories_to_create: Vec<rpc::Category> = (0..50)
+        .map(|i| rpc::Category {
+            code: format!("STREAM.{:03}", i),
+            name: format!("Stream Category {}", i),
+            url_slug: None,
+            ..categories::mock_rpc_category()
+        })
+        .collect();
+    let create_request = tonic::Request::new(rpc::CategoriesCreateBatchRequest { categories: categories_to_create });
+    let mut created = tonic_client
+        .category()
+        .categories_create_batch(create_request)
+        .await?
+        .into_inner()
+        .categories;
+    created.sort_by_key(|category| {
+        let created_on = category.created_on.expect("Created categories have a created_on");
+        (created_on.seconds, created_on.nanos, category.id.clone())
+    });
+    let expected_ids: Vec<String> = created.into_iter().map(|category| category.id).collect();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesListStreamRequest {});
+    let mut stream = tonic_client.category().categories_list_stream(request).await?.into_inner();
+    let mut streamed = Vec::new();
+    while let Some(message) = stream.message().await? {
+        streamed.push(message.category.expect("Every message carries a category"));
+    }
+
+    //-- Checks (Assertions)
+    let streamed_ids: Vec<String> = streamed.iter().map(|category| category.id.clone()).collect();
+    assert_eq!(streamed_ids, expected_ids);
+
+    let unique_ids: std::collections::HashSet<&String> = streamed_ids.iter().collect();
+    assert_eq!(unique_ids.len(), 50);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_stream_is_unimplemented_when_streaming_disabled(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.streaming_enabled = false;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let request = tonic::Request::new(rpc::CategoriesListStreamRequest {});
+    let status = tonic_client
+        .category()
+        .categories_list_stream(request)
+        .await
+        .expect_err("Streaming should be disabled");
+    assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+    Ok(())
+}
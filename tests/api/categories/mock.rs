@@ -58,8 +58,11 @@ pub fn mock_rpc_category() -> rpc::Category {
         color: helpers::mock_hex_color_with_option().map(|c| c.to_string()),
         icon: generate_mock_icon(),
         is_active: generate_mock_is_active(),
+        display_order: 0,
         created_on: Some(helpers::to_rpc_datetime(created_on)),
         updated_on: Some(helpers::to_rpc_datetime(helpers::mock_datetime_after(created_on))),
+        created_on_local: None,
+        updated_on_local: None,
     }
 }
 
@@ -8,5 +8,9 @@ mod activate;
 mod deactivate;
 mod delete;
 mod delete_batch;
+mod export_csv;
+mod reorder;
+mod schema;
+mod tree;
 
 pub use mock::*;
\ No newline at end of file
@@ -0,0 +1,125 @@
+use personal_ledger_backend::{database, domain, rpc};
+
+use crate::{categories, helpers};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Create `count` categories through the API and return them in creation order.
+async fn create_categories(
+    tonic_client: &mut helpers::SpawnTonicClient,
+    count: usize,
+) -> Result<Vec<rpc::Category>> {
+    let mut created = Vec::with_capacity(count);
+    for _ in 0..count {
+        let request = tonic::Request::new(rpc::CategoryCreateRequest {
+            category: Some(categories::mock_rpc_category()),
+        });
+        let response = tonic_client.category().category_create(request).await?;
+        created.push(response.into_inner().category.unwrap());
+    }
+    Ok(created)
+}
+
+#[sqlx::test]
+async fn reorder_applies_full_ordering(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    let created = create_categories(&mut tonic_client, 4).await?;
+
+    // Reverse the creation order
+    let orders = created
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(position, category)| rpc::CategoryDisplayOrder {
+            id: category.id.clone(),
+            display_order: position as i64,
+        })
+        .collect::<Vec<_>>();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesReorderRequest { orders: orders.clone() });
+    let response = tonic_client.category().categories_reorder(request).await?;
+    let reordered = response.into_inner().categories;
+
+    //-- Checks (Assertions)
+    assert_eq!(reordered.len(), orders.len());
+    for (order, category) in orders.iter().zip(&reordered) {
+        assert_eq!(category.id, order.id);
+        assert_eq!(category.display_order, order.display_order);
+    }
+
+    let ordered_ids = database::Categories::find_all_ordered_by_display(&database_pool)
+        .await?
+        .into_iter()
+        .map(|category| category.id.to_string())
+        .collect::<Vec<_>>();
+    let expected_ids = created.iter().rev().map(|category| category.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ordered_ids, expected_ids);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn reorder_with_unknown_id_changes_nothing(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+    let created = create_categories(&mut tonic_client, 2).await?;
+
+    let mut orders = created
+        .iter()
+        .map(|category| rpc::CategoryDisplayOrder {
+            id: category.id.clone(),
+            display_order: 10,
+        })
+        .collect::<Vec<_>>();
+    orders.push(rpc::CategoryDisplayOrder {
+        id: domain::RowID::new().to_string(),
+        display_order: 20,
+    });
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesReorderRequest { orders });
+    let result = tonic_client.category().categories_reorder(request).await;
+
+    //-- Checks (Assertions)
+    let status = result.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    for category in database::Categories::find_all_ordered_by_display(&database_pool).await? {
+        assert_eq!(category.display_order, 0, "Category {} was reordered", category.code);
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn reorder_rejects_invalid_and_empty_requests(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    //-- Execute Function (Act)
+    let empty_request = tonic::Request::new(rpc::CategoriesReorderRequest { orders: Vec::new() });
+    let empty_status = tonic_client.category().categories_reorder(empty_request).await.unwrap_err();
+
+    let invalid_request = tonic::Request::new(rpc::CategoriesReorderRequest {
+        orders: vec![rpc::CategoryDisplayOrder {
+            id: "not-a-valid-id".to_string(),
+            display_order: 1,
+        }],
+    });
+    let invalid_status = tonic_client.category().categories_reorder(invalid_request).await.unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(empty_status.code(), tonic::Code::InvalidArgument);
+    assert_eq!(invalid_status.code(), tonic::Code::InvalidArgument);
+
+    Ok(())
+}
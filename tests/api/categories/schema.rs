@@ -0,0 +1,35 @@
+use personal_ledger_backend::{domain, rpc};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn schema_lists_category_types_and_length_limits(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::GetCategorySchemaRequest {});
+    let schema = tonic_client.category().get_category_schema(request).await?.into_inner();
+
+    //-- Checks (Assertions)
+    let expected_types: Vec<String> = domain::CategoryTypes::all().iter().map(|t| t.as_str().to_string()).collect();
+    assert_eq!(schema.category_types, expected_types);
+
+    let max_length = |field: &str| {
+        schema
+            .fields
+            .iter()
+            .find(|rule| rule.field == field)
+            .and_then(|rule| rule.max_length)
+    };
+    assert_eq!(max_length("tag"), Some(domain::TAG_MAX_LENGTH as u32));
+    assert_eq!(max_length("external_id"), Some(domain::EXTERNAL_REF_MAX_LENGTH as u32));
+    assert_eq!(max_length("color"), Some(7));
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use personal_ledger_backend::{database, domain, rpc};
+
+use crate::{categories, helpers};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Create a category through the API and return its ID.
+async fn create_category(tonic_client: &mut helpers::SpawnTonicClient) -> Result<domain::RowID> {
+    let request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let response = tonic_client.category().category_create(request).await?;
+    Ok(response.into_inner().category.unwrap().id.parse()?)
+}
+
+/// Nest a category under a parent directly in the database.
+async fn set_parent(id: domain::RowID, parent_id: domain::RowID, pool: &sqlx::SqlitePool) -> Result<()> {
+    let category = database::Categories::find_by_id(id, pool).await?.unwrap();
+    database::Categories { parent_id: Some(parent_id), ..category }.update(pool).await?;
+    Ok(())
+}
+
+#[sqlx::test]
+async fn tree_returns_nested_categories(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let parent = create_category(&mut tonic_client).await?;
+    let child = create_category(&mut tonic_client).await?;
+    let grandchild = create_category(&mut tonic_client).await?;
+    set_parent(child, parent, &database_pool).await?;
+    set_parent(grandchild, child, &database_pool).await?;
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesTreeRequest {});
+    let roots = tonic_client.category().categories_tree(request).await?.into_inner().roots;
+
+    //-- Checks (Assertions)
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].category.as_ref().unwrap().id, parent.to_string());
+    assert_eq!(roots[0].children.len(), 1);
+    assert_eq!(roots[0].children[0].category.as_ref().unwrap().id, child.to_string());
+    assert_eq!(roots[0].children[0].children.len(), 1);
+    assert_eq!(roots[0].children[0].children[0].category.as_ref().unwrap().id, grandchild.to_string());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn tree_fails_with_cyclic_hierarchy(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let first = create_category(&mut tonic_client).await?;
+    let second = create_category(&mut tonic_client).await?;
+    set_parent(first, second, &database_pool).await?;
+    set_parent(second, first, &database_pool).await?;
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoriesTreeRequest {});
+    let error = tonic_client.category().categories_tree(request).await
+        .expect_err("Expected the tree to fail with a cycle");
+
+    //-- Checks (Assertions)
+    assert_eq!(error.code(), tonic::Code::FailedPrecondition);
+    assert!(error.message().contains("cycle"));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn tree_is_unimplemented_when_hierarchy_disabled(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.hierarchy_enabled = false;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let request = tonic::Request::new(rpc::CategoriesTreeRequest {});
+    let status = tonic_client.category().categories_tree(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unimplemented);
+    assert_eq!(status.message(), "Feature 'hierarchy' is disabled on this server");
+
+    Ok(())
+}
@@ -79,3 +79,89 @@ async fn update_fails_with_nonexistent_category(database_pool: sqlx::SqlitePool)
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn update_preview_reports_changes_without_saving(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    //-- Execute Function (Act)
+    let preview_request = tonic::Request::new(rpc::CategoryUpdatePreviewRequest {
+        id: created_category.id.clone(),
+        category: Some(rpc::Category {
+            name: "Previewed Name".to_string(),
+            ..created_category.clone()
+        }),
+        update_mask: Some(prost_types::FieldMask { paths: vec!["name".to_string()] }),
+    });
+    let preview = tonic_client.category().category_update_preview(preview_request).await?.into_inner();
+
+    //-- Checks (Assertions)
+    assert_eq!(
+        preview.changes,
+        vec![rpc::CategoryFieldChange {
+            field: "name".to_string(),
+            old_value: Some(created_category.name.clone()),
+            new_value: Some("Previewed Name".to_string()),
+        }]
+    );
+    assert_eq!(preview.category.unwrap().name, "Previewed Name");
+
+    // Nothing was saved
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest { id: created_category.id.clone(), read_mask: None });
+    let stored = tonic_client.category().category_get(get_request).await?.into_inner().category.unwrap();
+    assert_eq!(stored.name, created_category.name);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_is_visible_through_category_cache(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.category_cache_size = 16;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let created_category = tonic_client.category().category_create(create_request).await?.into_inner().category.unwrap();
+
+    // Read the category once so it is cached
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest { id: created_category.id.clone(), read_mask: None });
+    tonic_client.category().category_get(get_request).await?;
+
+    //-- Execute Function (Act)
+    let mut updated_category = created_category.clone();
+    updated_category.name = "Renamed Behind The Cache".to_string();
+    let update_request = tonic::Request::new(rpc::CategoryUpdateRequest {
+        id: created_category.id.clone(),
+        category: Some(updated_category),
+        update_mask: Some(prost_types::FieldMask { paths: vec!["name".to_string()] }),
+    });
+    tonic_client.category().category_update(update_request).await?;
+
+    //-- Checks (Assertions)
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest { id: created_category.id.clone(), read_mask: None });
+    let fetched = tonic_client.category().category_get(get_request).await?.into_inner().category.unwrap();
+    assert_eq!(fetched.name, "Renamed Behind The Cache");
+
+    // A deleted category is not served from the cache either
+    let delete_request = tonic::Request::new(rpc::CategoryDeleteRequest { id: created_category.id.clone() });
+    tonic_client.category().category_delete(delete_request).await?;
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest { id: created_category.id, read_mask: None });
+    let status = tonic_client.category().category_get(get_request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use personal_ledger_backend::rpc;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Requests served per connection in the tests.
+const MAX_REQUESTS_PER_CONNECTION: u64 = 3;
+
+async fn ping(tonic_client: &mut helpers::SpawnTonicClient) -> core::result::Result<(), tonic::Status> {
+    tonic_client.utilities().ping(rpc::PingRequest {}).await.map(|_| ())
+}
+
+/// Connect through a connector counting the TCP connections the channel opens.
+async fn counting_client(
+    tonic_server: &helpers::SpawnTonicServer,
+) -> Result<(helpers::SpawnTonicClient, Arc<AtomicUsize>)> {
+    let address = tonic_server.address();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&connections);
+
+    let channel = tonic::transport::Endpoint::from_shared(format!("http://{}", address))?
+        .connect_with_connector(tower::service_fn(move |_: http::Uri| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { tokio::net::TcpStream::connect(address).await.map(hyper_util::rt::TokioIo::new) }
+        }))
+        .await?;
+
+    Ok((helpers::SpawnTonicClient::new(channel), connections))
+}
+
+#[sqlx::test]
+async fn connection_is_closed_after_request_limit(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_requests_per_connection = MAX_REQUESTS_PER_CONNECTION;
+    })
+    .await?;
+    let (mut tonic_client, connections) = counting_client(&tonic_server).await?;
+
+    for _ in 0..MAX_REQUESTS_PER_CONNECTION {
+        ping(&mut tonic_client).await?;
+    }
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+    // The request past the limit either fails on the closed connection or
+    // is sent on a new one
+    if let Err(status) = ping(&mut tonic_client).await {
+        assert_eq!(status.code(), tonic::Code::Unavailable, "{}", status.message());
+        ping(&mut tonic_client).await?;
+    }
+    assert_eq!(connections.load(Ordering::SeqCst), 2, "the client should have reconnected");
+
+    // A fresh connection gets a fresh budget
+    let mut fresh_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+    for _ in 0..MAX_REQUESTS_PER_CONNECTION {
+        ping(&mut fresh_client).await?;
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn connections_are_not_limited_by_default(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+
+    let (mut tonic_client, connections) = counting_client(&tonic_server).await?;
+
+    for _ in 0..3 * MAX_REQUESTS_PER_CONNECTION {
+        ping(&mut tonic_client).await?;
+    }
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use personal_ledger_backend::rpc;
+
+use crate::{categories, helpers};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// How long to wait for an event before failing the test.
+const EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Wait for the next event on the stream.
+async fn next_event(stream: &mut tonic::Streaming<rpc::ServerEvent>) -> Result<rpc::ServerEvent> {
+    let event = tokio::time::timeout(EVENT_TIMEOUT, stream.message()).await??;
+    Ok(event.ok_or("event stream ended")?)
+}
+
+#[sqlx::test]
+async fn event_stream_receives_category_changes(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.events_enabled = true;
+    })
+    .await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    let request = tonic::Request::new(rpc::EventStreamRequest {});
+    let mut stream = tonic_client.utilities().event_stream(request).await?.into_inner();
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let category = tonic_client.category().category_create(request).await?.into_inner().category.unwrap();
+
+    let request = tonic::Request::new(rpc::CategoryDeleteRequest { id: category.id.clone() });
+    tonic_client.category().category_delete(request).await?;
+
+    //-- Checks (Assertions)
+    let created = next_event(&mut stream).await?;
+    assert_eq!(created.kind, "created");
+    assert_eq!(created.resource, "category");
+    assert_eq!(created.id, category.id);
+    assert!(created.occurred_on.is_some());
+
+    let deleted = next_event(&mut stream).await?;
+    assert_eq!(deleted.kind, "deleted");
+    assert_eq!(deleted.id, category.id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn event_stream_is_unimplemented_when_disabled(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    //-- Execute Function (Act)
+    let request = tonic::Request::new(rpc::EventStreamRequest {});
+    let status = tonic_client.utilities().event_stream(request).await.unwrap_err();
+
+    //-- Checks (Assertions)
+    assert_eq!(status.code(), tonic::Code::Unimplemented);
+
+    Ok(())
+}
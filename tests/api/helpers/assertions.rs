@@ -0,0 +1,150 @@
+//! # Integration Test Assertions
+//!
+//! This module provides assertion helpers for comparing what the API returns
+//! with what is stored in the database. A gRPC `rpc::Category` and a
+//! `database::Categories` row describe the same category in different types,
+//! so comparing them field by field in every test is repetitive and brittle.
+//!
+//! ## Comparison Rules
+//! - Category types are compared through their gRPC enum value
+//! - Blank optional strings over the wire count as unset, matching the
+//!   service's normalisation of optional fields
+//! - Colours are compared case-insensitively
+//! - Timestamps must be present and within [`TIMESTAMP_TOLERANCE`]
+//!
+//! ## Usage
+//! ```rust
+//! use tests::api::helpers::assert_rpc_matches_db;
+//!
+//! let stored = database::Categories::find_by_id(id, &pool).await?.unwrap();
+//! assert_rpc_matches_db(&returned_category, &stored);
+//! ```
+
+use personal_ledger_backend::{database, rpc};
+
+/// How far apart an API timestamp and a database timestamp may be.
+pub const TIMESTAMP_TOLERANCE: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// List the fields in which `rpc` differs from `db`, empty when they match.
+pub fn rpc_db_mismatches(rpc: &rpc::Category, db: &database::Categories) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    let mut compare = |field: &str, rpc_value: String, db_value: String| {
+        if rpc_value != db_value {
+            mismatches.push(format!("{field}: rpc {rpc_value} != db {db_value}"));
+        }
+    };
+
+    compare("id", format!("{:?}", rpc.id), format!("{:?}", db.id.to_string()));
+    compare("code", format!("{:?}", rpc.code), format!("{:?}", db.code));
+    compare("name", format!("{:?}", rpc.name), format!("{:?}", db.name));
+    compare(
+        "description",
+        format!("{:?}", normalized(&rpc.description)),
+        format!("{:?}", db.description),
+    );
+    compare(
+        "url_slug",
+        format!("{:?}", normalized(&rpc.url_slug)),
+        format!("{:?}", db.url_slug.as_ref().map(ToString::to_string)),
+    );
+    compare(
+        "category_type",
+        format!("{:?}", rpc.category_type),
+        format!("{:?}", db.category_type.to_rpc_i32()),
+    );
+    compare(
+        "color",
+        format!("{:?}", normalized(&rpc.color).map(|color| color.to_ascii_uppercase())),
+        format!("{:?}", db.color.as_ref().map(|color| color.to_string().to_ascii_uppercase())),
+    );
+    compare("icon", format!("{:?}", normalized(&rpc.icon)), format!("{:?}", db.icon));
+    compare("is_active", rpc.is_active.to_string(), db.is_active.to_string());
+    compare("display_order", rpc.display_order.to_string(), db.display_order.to_string());
+
+    for (field, rpc_timestamp, db_timestamp) in [
+        ("created_on", &rpc.created_on, db.created_on),
+        ("updated_on", &rpc.updated_on, db.updated_on),
+    ] {
+        let rpc_timestamp = rpc_timestamp
+            .as_ref()
+            .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp.seconds, timestamp.nanos as u32));
+        match rpc_timestamp {
+            Some(rpc_timestamp) if (rpc_timestamp - db_timestamp).abs().to_std().unwrap() <= TIMESTAMP_TOLERANCE => {}
+            Some(rpc_timestamp) => mismatches.push(format!("{field}: rpc {rpc_timestamp} != db {db_timestamp}")),
+            None => mismatches.push(format!("{field}: rpc missing or invalid, db {db_timestamp}")),
+        }
+    }
+
+    mismatches
+}
+
+/// Assert that `rpc` describes the same category as the stored `db` row.
+///
+/// # Panics
+///
+/// Panics listing every mismatched field.
+#[track_caller]
+pub fn assert_rpc_matches_db(rpc: &rpc::Category, db: &database::Categories) {
+    let mismatches = rpc_db_mismatches(rpc, db);
+    assert!(
+        mismatches.is_empty(),
+        "rpc category does not match database category:\n  {}",
+        mismatches.join("\n  ")
+    );
+}
+
+fn normalized(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|value| !value.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use personal_ledger_backend::domain;
+
+    fn stored_category() -> database::Categories {
+        database::CategoriesBuilder::new()
+            .with_code("FOOD.001.001")
+            .with_name("Food")
+            .with_description("Groceries and dining")
+            .with_category_type(domain::CategoryTypes::Expense)
+            .with_color("#00ff00".parse().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn matching_pair_passes() {
+        let db = stored_category();
+        let mut rpc: rpc::Category = db.clone().into();
+        // Differences the comparison deliberately ignores
+        rpc.icon = Some("  ".to_string());
+        rpc.color = rpc.color.map(|color| color.to_ascii_lowercase());
+        rpc.updated_on.as_mut().unwrap().nanos += 500_000;
+
+        assert_rpc_matches_db(&rpc, &db);
+    }
+
+    #[test]
+    fn mismatched_pair_lists_each_field() {
+        let db = stored_category();
+        let mut rpc: rpc::Category = db.clone().into();
+        rpc.name = "Dining".to_string();
+        rpc.category_type = rpc::CategoryTypes::Income as i32;
+        rpc.created_on.as_mut().unwrap().seconds -= 5;
+        rpc.updated_on = None;
+
+        let mismatches = rpc_db_mismatches(&rpc, &db);
+        let fields: Vec<&str> = mismatches.iter().map(|m| m.split(':').next().unwrap()).collect();
+        assert_eq!(fields, ["name", "category_type", "created_on", "updated_on"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rpc category does not match database category")]
+    fn mismatched_pair_panics() {
+        let db = stored_category();
+        let rpc = rpc::Category { is_active: !db.is_active, ..db.clone().into() };
+
+        assert_rpc_matches_db(&rpc, &db);
+    }
+}
@@ -176,7 +176,7 @@ pub fn mock_category_types() -> personal_ledger_backend::domain::CategoryTypes {
     // Get all category types and randomly select one
     let all_types = personal_ledger_backend::domain::CategoryTypes::all();
     let random_index: usize = (0..all_types.len()).fake();
-    all_types[random_index].clone()
+    all_types[random_index]
 }
 
 /// Generates a random hex color for integration testing.
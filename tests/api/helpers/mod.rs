@@ -8,6 +8,7 @@
 //! - `spawn_server`: Utilities for spawning test gRPC servers
 //! - `spawn_client`: Utilities for creating test gRPC clients
 //! - `mocks`: Mock data generation functions for domain types
+//! - `assertions`: Comparing API responses with stored database rows
 //!
 //! ## Usage
 //! Import helpers in your integration test files as needed:
@@ -24,7 +25,9 @@
 mod spawn_server;
 mod spawn_client;
 mod mocks;
+mod assertions;
 
 pub use spawn_server::SpawnTonicServer;
 pub use spawn_client::SpawnTonicClient;
-pub use mocks::*;
\ No newline at end of file
+pub use mocks::*;
+pub use assertions::*;
\ No newline at end of file
@@ -1,18 +1,27 @@
 /// Type alias for the categories service client to improve readability.
 pub type CategoryServicesClient = personal_ledger_backend::rpc::CategoriesServiceClient<tonic::transport::Channel>;
 
+/// Type alias for the utilities service client to improve readability.
+pub type UtilitiesServicesClient = personal_ledger_backend::rpc::UtilitiesServiceClient<tonic::transport::Channel>;
+
+/// Bytes buffered in each direction of an in-process client's duplex stream.
+const IN_PROCESS_BUFFER_SIZE: usize = 64 * 1024;
+
 /// A test gRPC client for integration testing.
 ///
 /// This struct provides a convenient wrapper around gRPC service clients
 /// for use in integration tests. It manages client connections and provides
 /// access to individual service clients.
 ///
-/// Currently supports the Categories service, with potential for expansion
-/// to other services as the API grows.
+/// Currently supports the Categories and Utilities services, with potential
+/// for expansion to other services as the API grows.
 #[derive(Clone)]
 pub struct SpawnTonicClient {
     /// The categories service client for making RPC calls
     category: CategoryServicesClient,
+
+    /// The utilities service client for making RPC calls
+    utilities: UtilitiesServicesClient,
 }
 
 impl SpawnTonicClient {
@@ -32,8 +41,55 @@ impl SpawnTonicClient {
     /// let client = SpawnTonicClient::new(channel);
     /// ```
     pub fn new(tonic_channel: tonic::transport::Channel) -> Self {
-        let category = CategoryServicesClient::new(tonic_channel);
-        Self { category }
+        let category = CategoryServicesClient::new(tonic_channel.clone());
+        let utilities = UtilitiesServicesClient::new(tonic_channel);
+        Self { category, utilities }
+    }
+
+    /// Creates a test gRPC client served by `router` over an in-memory duplex
+    /// stream, without binding a port or opening a TCP socket.
+    ///
+    /// The router is served on a background task for the one connection the
+    /// client makes. This suits small tests that only need to make calls;
+    /// tests of transport behaviour such as TLS or connection limits should
+    /// use [`SpawnTonicServer`](super::SpawnTonicServer).
+    ///
+    /// # Arguments
+    ///
+    /// * `router` - The router to serve, for example from `server::Router::new`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP/2 connection cannot be established.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let router = server::Router::new(database_pool, LedgerConfig::parse()?).await?;
+    /// let mut client = SpawnTonicClient::in_process(router).await?;
+    /// let response = client.utilities().ping(request).await?;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn in_process(router: personal_ledger_backend::server::Router) -> Result<Self, tonic::transport::Error> {
+        let (client_io, server_io) = tokio::io::duplex(IN_PROCESS_BUFFER_SIZE);
+
+        let incoming = tokio_stream::once(Ok::<_, std::io::Error>(server_io));
+        tokio::spawn(router.into_inner().serve_with_incoming(incoming));
+
+        // The channel connects once; there is no second stream to reconnect with
+        let mut client_io = Some(client_io);
+        let channel = tonic::transport::Endpoint::from_static("http://in-process")
+            .connect_with_connector(tower::service_fn(move |_: http::Uri| {
+                let client_io = client_io.take();
+                async move {
+                    client_io
+                        .map(hyper_util::rt::TokioIo::new)
+                        .ok_or_else(|| std::io::Error::other("In-process client cannot reconnect"))
+                }
+            }))
+            .await?;
+
+        Ok(Self::new(channel))
     }
 
     /// Returns a mutable reference to the categories service client.
@@ -54,4 +110,16 @@ impl SpawnTonicClient {
     pub fn category(&mut self) -> &mut CategoryServicesClient {
         &mut self.category
     }
+
+    /// Returns a mutable reference to the utilities service client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut client = SpawnTonicClient::new(channel);
+    /// let response = client.utilities().ping(request).await?;
+    /// ```
+    pub fn utilities(&mut self) -> &mut UtilitiesServicesClient {
+        &mut self.utilities
+    }
 }
\ No newline at end of file
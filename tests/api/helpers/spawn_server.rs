@@ -26,6 +26,8 @@ static TRACING: once_cell::sync::Lazy<()> = once_cell::sync::Lazy::new(|| {
 pub struct SpawnTonicServer {
     /// The actual address the server is bound to (with OS-assigned port)
     pub address: net::SocketAddr,
+    /// The admin HTTP server address, when `admin_port` was configured
+    pub admin_address: Option<net::SocketAddr>,
     /// Shutdown sender to gracefully stop the server
     #[allow(dead_code)]
     shutdown_tx: Arc<tokio::sync::Mutex<Option<oneshot::Sender<()>>>>,
@@ -50,35 +52,50 @@ impl SpawnTonicServer {
     /// # Errors
     /// Returns an error if configuration parsing, server creation, or startup fails
     pub async fn init(database_pool: sqlx::SqlitePool) -> Result<Self> {
+        Self::init_with_config(database_pool, |_| {}).await
+    }
+
+    /// Initialize a new test gRPC server, letting the test adjust the parsed
+    /// configuration before the server is created.
+    ///
+    /// The port is always reset to 0 after `configure` runs, as is the admin
+    /// port when `configure` enables it.
+    ///
+    /// # Errors
+    /// Returns an error if configuration parsing, server creation, or startup fails
+    pub async fn init_with_config(
+        database_pool: sqlx::SqlitePool,
+        configure: impl FnOnce(&mut LedgerConfig),
+    ) -> Result<Self> {
         // Initialize tracing for integration testing
         once_cell::sync::Lazy::force(&TRACING);
 
         // Parse configuration and modify for testing
         let mut ledger_config = LedgerConfig::parse()?;
+        configure(&mut ledger_config);
         // Use port 0 to let OS assign an available port, avoiding conflicts
         ledger_config.server.port = 0;
+        if ledger_config.server.admin_port.is_some() {
+            ledger_config.server.admin_port = Some(0);
+        }
 
         // Create the server instance
         let server = server::TonicServer::new(database_pool, ledger_config).await?;
         let actual_address = server.local_addr()?;
+        let admin_address = server.admin_addr()?;
 
         // Create shutdown channel for graceful server termination
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
-        // Spawn server in background task
+        // Spawn server in background task, shutting down gracefully when signalled
         tokio::spawn(async move {
-            let server_future = server.run();
-            let shutdown_future = shutdown_rx;
+            let shutdown_future = async move {
+                let _ = shutdown_rx.await;
+                tracing::info!("Test server shutting down gracefully");
+            };
 
-            tokio::select! {
-                result = server_future => {
-                    if let Err(e) = result {
-                        tracing::error!("Test server error: {}", e);
-                    }
-                }
-                _ = shutdown_future => {
-                    tracing::info!("Test server shutting down gracefully");
-                }
+            if let Err(e) = server.run_with_shutdown(shutdown_future).await {
+                tracing::error!("Test server error: {}", e);
             }
         });
 
@@ -87,6 +104,7 @@ impl SpawnTonicServer {
 
         Ok(Self {
             address: actual_address,
+            admin_address,
             shutdown_tx: Arc::new(tokio::sync::Mutex::new(Some(shutdown_tx))),
         })
     }
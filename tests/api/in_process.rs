@@ -0,0 +1,22 @@
+use personal_ledger_backend::{rpc, server, LedgerConfig};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn in_process_client_pings_without_a_socket(database_pool: sqlx::SqlitePool) -> Result<()> {
+    //-- Setup and Fixtures (Arrange)
+    // A router is served only where it is told to be; it never binds a port itself
+    let router = server::Router::new(database_pool, LedgerConfig::parse()?).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::in_process(router).await?;
+
+    //-- Execute Function (Act)
+    let response = tonic_client.utilities().ping(rpc::PingRequest {}).await?;
+
+    //-- Checks (Assertions)
+    assert!(!response.get_ref().message.is_empty());
+
+    Ok(())
+}
@@ -1,4 +1,14 @@
 // Add modules to include in integration binary
 mod helpers;
 
-mod categories;
\ No newline at end of file
+mod admin;
+mod auth;
+mod categories;
+mod connection_limit;
+mod events;
+mod in_process;
+mod metadata_limit;
+mod migrations;
+mod shutdown;
+mod stream_limit;
+mod tls;
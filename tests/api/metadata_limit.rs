@@ -0,0 +1,71 @@
+use personal_ledger_backend::rpc;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Metadata byte limit used by the tests, well under the HTTP/2 header limits.
+const MAX_METADATA_BYTES: usize = 1024;
+
+fn list_request(padding_len: usize) -> tonic::Request<rpc::CategoriesListRequest> {
+    let mut request = tonic::Request::new(rpc::CategoriesListRequest { limit: 10, ..Default::default() });
+    if padding_len > 0 {
+        let padding = "x".repeat(padding_len).parse().unwrap();
+        request.metadata_mut().insert("x-padding", padding);
+    }
+    request
+}
+
+#[sqlx::test]
+async fn oversized_metadata_is_rejected(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_metadata_bytes = MAX_METADATA_BYTES;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let status = tonic_client
+        .category()
+        .categories_list(list_request(2 * MAX_METADATA_BYTES))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().starts_with("Request metadata is "), "{}", status.message());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn too_many_metadata_entries_are_rejected(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_metadata_entries = 16;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let mut request = list_request(0);
+    for i in 0..16 {
+        let name: tonic::metadata::MetadataKey<_> = format!("x-entry-{:02}", i).parse()?;
+        request.metadata_mut().insert(name, "1".parse()?);
+    }
+    let status = tonic_client.category().categories_list(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().starts_with("Request metadata has "), "{}", status.message());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn request_within_metadata_limits_passes(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_metadata_bytes = MAX_METADATA_BYTES;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    let response = tonic_client.category().categories_list(list_request(64)).await?;
+    assert!(response.into_inner().categories.is_empty());
+
+    Ok(())
+}
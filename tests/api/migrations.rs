@@ -0,0 +1,70 @@
+use personal_ledger_backend::{database, LedgerError};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Forget the most recent migration so it is reported as pending again.
+async fn mark_latest_migration_pending(database_pool: &sqlx::SqlitePool) -> Result<database::PendingMigration> {
+    let latest = database::MIGRATOR
+        .iter()
+        .filter(|migration| migration.migration_type.is_up_migration())
+        .max_by_key(|migration| migration.version)
+        .expect("At least one migration should be embedded");
+
+    sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+        .bind(latest.version)
+        .execute(database_pool)
+        .await?;
+
+    Ok(database::PendingMigration {
+        version: latest.version,
+        description: latest.description.to_string(),
+    })
+}
+
+#[sqlx::test]
+async fn startup_with_require_migrated_fails_on_pending_migration(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let pending = mark_latest_migration_pending(&database_pool).await?;
+
+    let status = database::MigrationStatus::check(&database_pool).await?;
+    assert_eq!(status.pending, vec![pending.clone()]);
+
+    let startup_error: LedgerError = database::prepare_schema(&database_pool, true)
+        .await
+        .expect_err("Startup should fail while a migration is pending")
+        .into();
+
+    let message = startup_error.to_string();
+    assert!(message.contains("pending migrations"), "unexpected message: {}", message);
+    assert!(message.contains(&pending.to_string()), "unexpected message: {}", message);
+    assert!(message.contains("require_migrated"), "unexpected message: {}", message);
+
+    // Nothing should have been applied
+    let status = database::MigrationStatus::check(&database_pool).await?;
+    assert_eq!(status.pending, vec![pending]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn startup_with_require_migrated_succeeds_when_up_to_date(database_pool: sqlx::SqlitePool) -> Result<()> {
+    database::prepare_schema(&database_pool, true).await?;
+
+    let status = database::MigrationStatus::check(&database_pool).await?;
+    assert!(status.is_up_to_date());
+
+    Ok(())
+}
+
+#[sqlx::test(migrations = false)]
+async fn startup_without_require_migrated_applies_pending_migrations(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let status = database::MigrationStatus::check(&database_pool).await?;
+    assert!(!status.is_up_to_date());
+
+    database::prepare_schema(&database_pool, false).await?;
+
+    let status = database::MigrationStatus::check(&database_pool).await?;
+    assert!(status.is_up_to_date());
+
+    Ok(())
+}
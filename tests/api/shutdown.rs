@@ -0,0 +1,113 @@
+use personal_ledger_backend::{rpc, services};
+
+use crate::{categories, helpers};
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+#[sqlx::test]
+async fn request_after_pool_closes_is_unavailable(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let transport_channel = tonic_server.transport_channel();
+    let mut tonic_client = helpers::SpawnTonicClient::new(transport_channel);
+
+    // Establish the connection and prove the service works before the pool closes
+    let create_request = tonic::Request::new(rpc::CategoryCreateRequest {
+        category: Some(categories::mock_rpc_category()),
+    });
+    let created_category = tonic_client
+        .category()
+        .category_create(create_request)
+        .await?
+        .into_inner()
+        .category
+        .unwrap();
+
+    // The server shares this pool, so closing it is what a request racing
+    // the end of shutdown sees
+    database_pool.close().await;
+
+    let get_request = tonic::Request::new(rpc::CategoryGetRequest {
+        id: created_category.id,
+        read_mask: None,
+    });
+    let status = tonic_client
+        .category()
+        .category_get(get_request)
+        .await
+        .expect_err("Request once the pool closes should be rejected");
+
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+    assert_eq!(status.message(), services::SHUTTING_DOWN_MESSAGE);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn utilities_request_after_pool_closes_is_unavailable(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init(database_pool.clone()).await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    tonic_client.utilities().ping(rpc::PingRequest {}).await?;
+
+    database_pool.close().await;
+
+    let status = tonic_client
+        .utilities()
+        .ping(rpc::PingRequest {})
+        .await
+        .expect_err("Request once the pool closes should be rejected");
+
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+    assert_eq!(status.message(), services::SHUTTING_DOWN_MESSAGE);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn in_flight_request_completes_across_shutdown(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool.clone(), |config| {
+        config.server.shutdown_grace_period_secs = 0;
+    })
+    .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+
+    // Enough categories that the stream needs several pages, and more than
+    // the transport buffers, so it is still reading the database at shutdown
+    const CATEGORY_COUNT: usize = 1_000;
+    for batch in 0..(CATEGORY_COUNT / 250) {
+        let categories_to_create: Vec<rpc::Category> = (0..250)
+            .map(|i| rpc::Category {
+                code: format!("DRAIN.{:04}", batch * 250 + i),
+                name: format!("Drain Category {}", batch * 250 + i),
+                url_slug: None,
+                ..categories::mock_rpc_category()
+            })
+            .collect();
+        let create_request = tonic::Request::new(rpc::CategoriesCreateBatchRequest { categories: categories_to_create });
+        tonic_client.category().categories_create_batch(create_request).await?;
+    }
+
+    let request = tonic::Request::new(rpc::CategoriesListStreamRequest {});
+    let mut stream = tonic_client.category().categories_list_stream(request).await?.into_inner();
+    assert!(stream.message().await?.is_some(), "The stream should have started");
+
+    // Shut down with the stream held open
+    tonic_server.shutdown().await;
+
+    let mut streamed = 1;
+    while stream.message().await?.is_some() {
+        streamed += 1;
+    }
+    assert_eq!(streamed, CATEGORY_COUNT);
+
+    // The pool closes once the transport has drained
+    tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        while !database_pool.is_closed() {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use personal_ledger_backend::{database, domain, rpc, server};
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Cap used by the tests, small enough to hit quickly.
+const MAX_CONCURRENT_STREAMS: usize = 2;
+
+/// Enough rows that an unread export fills the HTTP/2 flow control window,
+/// keeping the stream open until the client reads it.
+const CATEGORY_COUNT: usize = 2_000;
+
+/// Insert enough categories for the export to outgrow the flow control window.
+async fn insert_categories(database_pool: &sqlx::SqlitePool) -> Result<()> {
+    let description = "x".repeat(500);
+    let categories = (0..CATEGORY_COUNT)
+        .map(|index| {
+            database::CategoriesBuilder::new()
+                .with_id(domain::RowID::new())
+                .with_code(format!("STR.{:04}", index))
+                .with_name(format!("Streamed {}", index))
+                .with_description(description.as_str())
+                .with_category_type(domain::CategoryTypes::Expense)
+                .build()
+        })
+        .collect::<core::result::Result<Vec<_>, _>>()?;
+
+    database::Categories::insert_many(&categories, database_pool).await?;
+
+    Ok(())
+}
+
+/// Connect on a dedicated connection with the minimum HTTP/2 windows, so the
+/// server cannot finish an export the client is not reading.
+async fn connect(tonic_server: &helpers::SpawnTonicServer) -> Result<helpers::SpawnTonicClient> {
+    let channel = tonic::transport::Endpoint::from_shared(format!("http://{}", tonic_server.address()))?
+        .initial_stream_window_size(65_535)
+        .initial_connection_window_size(65_535)
+        .connect()
+        .await?;
+
+    Ok(helpers::SpawnTonicClient::new(channel))
+}
+
+async fn open_export(
+    tonic_client: &mut helpers::SpawnTonicClient,
+) -> core::result::Result<tonic::Streaming<rpc::CategoriesExportCsvChunk>, tonic::Status> {
+    let request = tonic::Request::new(rpc::CategoriesExportCsvRequest {});
+    let response = tonic_client.category().categories_export_csv(request).await?;
+    Ok(response.into_inner())
+}
+
+/// Read the rest of an export and return the number of CSV data rows.
+async fn drain_export(mut stream: tonic::Streaming<rpc::CategoriesExportCsvChunk>) -> Result<usize> {
+    let mut csv_output = String::new();
+    while let Some(chunk) = stream.message().await? {
+        csv_output.push_str(&chunk.data);
+    }
+
+    Ok(csv::Reader::from_reader(csv_output.as_bytes()).records().count())
+}
+
+#[sqlx::test]
+async fn stream_past_cap_is_rejected_while_others_keep_flowing(database_pool: sqlx::SqlitePool) -> Result<()> {
+    insert_categories(&database_pool).await?;
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_concurrent_streams = MAX_CONCURRENT_STREAMS;
+    })
+    .await?;
+
+    // Fill every slot with an export that is left unread
+    let mut open_streams = Vec::new();
+    for _ in 0..MAX_CONCURRENT_STREAMS {
+        let mut tonic_client = connect(&tonic_server).await?;
+        open_streams.push(open_export(&mut tonic_client).await?);
+    }
+
+    let mut tonic_client = connect(&tonic_server).await?;
+    let status = open_export(&mut tonic_client)
+        .await
+        .expect_err("Stream past the cap should be rejected");
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(status.message(), server::STREAM_LIMIT_MESSAGE);
+
+    // Unary requests are not counted against the cap
+    let list_request = tonic::Request::new(rpc::CategoriesListRequest { limit: 10, ..Default::default() });
+    tonic_client.category().categories_list(list_request).await?;
+
+    // The admitted streams still deliver every row
+    for stream in open_streams {
+        assert_eq!(drain_export(stream).await?, CATEGORY_COUNT);
+    }
+
+    // Finished streams free their slots for new ones
+    let stream = open_export(&mut tonic_client).await?;
+    assert_eq!(drain_export(stream).await?, CATEGORY_COUNT);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn zero_cap_disables_stream_limit(database_pool: sqlx::SqlitePool) -> Result<()> {
+    insert_categories(&database_pool).await?;
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.max_concurrent_streams = 0;
+    })
+    .await?;
+
+    let mut open_streams = Vec::new();
+    for _ in 0..MAX_CONCURRENT_STREAMS + 1 {
+        let mut tonic_client = connect(&tonic_server).await?;
+        open_streams.push(open_export(&mut tonic_client).await?);
+    }
+
+    for stream in open_streams {
+        assert_eq!(drain_export(stream).await?, CATEGORY_COUNT);
+    }
+
+    Ok(())
+}
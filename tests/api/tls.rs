@@ -0,0 +1,135 @@
+use personal_ledger_backend::rpc;
+
+use crate::helpers;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Self-signed certificate written to a temporary directory for one test.
+struct TestCertificate {
+    directory: std::path::PathBuf,
+    cert_pem: String,
+}
+
+impl TestCertificate {
+    /// Generate a certificate for `localhost` and write the PEM files.
+    fn generate() -> Result<Self> {
+        let certified_key = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+        let cert_pem = certified_key.cert.pem();
+
+        let directory = std::env::temp_dir().join(format!("ledger_tls_{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("cert.pem"), &cert_pem)?;
+        std::fs::write(directory.join("key.pem"), certified_key.signing_key.serialize_pem())?;
+
+        Ok(Self { directory, cert_pem })
+    }
+
+    fn cert_path(&self) -> std::path::PathBuf {
+        self.directory.join("cert.pem")
+    }
+
+    fn key_path(&self) -> std::path::PathBuf {
+        self.directory.join("key.pem")
+    }
+}
+
+impl Drop for TestCertificate {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.directory);
+    }
+}
+
+fn list_request() -> tonic::Request<rpc::CategoriesListRequest> {
+    tonic::Request::new(rpc::CategoriesListRequest { limit: 10, ..Default::default() })
+}
+
+#[sqlx::test]
+async fn require_tls_without_certificates_fails_startup(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let result = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.tls_enabled = false;
+        config.server.require_tls = true;
+    })
+    .await;
+
+    let err = result.err().expect("Startup should fail without TLS configured");
+    assert!(err.to_string().contains("require_tls"));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn require_tls_accepts_tls_clients_only(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let certificate = TestCertificate::generate()?;
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.tls_enabled = true;
+        config.server.tls_cert_path = Some(certificate.cert_path());
+        config.server.tls_key_path = Some(certificate.key_path());
+        config.server.require_tls = true;
+    })
+    .await?;
+
+    // A client trusting the certificate is served
+    let tls_config = tonic::transport::ClientTlsConfig::new()
+        .ca_certificate(tonic::transport::Certificate::from_pem(&certificate.cert_pem))
+        .domain_name("localhost");
+    let channel = tonic::transport::Endpoint::from_shared(format!("https://{}", tonic_server.address()))?
+        .tls_config(tls_config)?
+        .connect()
+        .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(channel);
+    let response = tonic_client.category().categories_list(list_request()).await?;
+    assert!(response.into_inner().categories.is_empty());
+
+    // A plaintext client never reaches a service
+    let mut plaintext_client = helpers::SpawnTonicClient::new(tonic_server.transport_channel());
+    let status = plaintext_client
+        .category()
+        .categories_list(list_request())
+        .await
+        .expect_err("Plaintext request should be rejected");
+    assert_ne!(status.code(), tonic::Code::Ok);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn tls_enabled_serves_tls_clients(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let certificate = TestCertificate::generate()?;
+    let tonic_server = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.tls_enabled = true;
+        config.server.tls_cert_path = Some(certificate.cert_path());
+        config.server.tls_key_path = Some(certificate.key_path());
+    })
+    .await?;
+
+    let tls_config = tonic::transport::ClientTlsConfig::new()
+        .ca_certificate(tonic::transport::Certificate::from_pem(&certificate.cert_pem))
+        .domain_name("localhost");
+    let channel = tonic::transport::Endpoint::from_shared(format!("https://{}", tonic_server.address()))?
+        .tls_config(tls_config)?
+        .connect()
+        .await?;
+    let mut tonic_client = helpers::SpawnTonicClient::new(channel);
+    tonic_client.utilities().ping(rpc::PingRequest {}).await?;
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invalid_private_key_fails_startup(database_pool: sqlx::SqlitePool) -> Result<()> {
+    let certificate = TestCertificate::generate()?;
+    std::fs::write(certificate.key_path(), "not a private key")?;
+
+    let result = helpers::SpawnTonicServer::init_with_config(database_pool, |config| {
+        config.server.tls_enabled = true;
+        config.server.tls_cert_path = Some(certificate.cert_path());
+        config.server.tls_key_path = Some(certificate.key_path());
+    })
+    .await;
+
+    let err = result.err().expect("Startup should fail with an invalid private key");
+    assert!(err.to_string().contains("Invalid TLS certificate or private key"), "{}", err);
+
+    Ok(())
+}